@@ -0,0 +1,24 @@
+//! Encodes `poseidon1` calls for arbitrary words and checks the hash is a field element
+
+#![no_main]
+
+use alloy_primitives::U256;
+use alloy_sol_types::{SolCall, SolValue};
+use libfuzzer_sys::fuzz_target;
+use precompile::utils::BN254_MODULUS;
+use precompile::{poseidon_precompile, IPoseidonHash};
+
+fuzz_target!(|word: [u8; 32]| {
+    let input = U256::from_be_bytes(word);
+    let call_data = IPoseidonHash::poseidon1Call { input }.abi_encode();
+
+    match poseidon_precompile(&call_data) {
+        Ok(output) => {
+            assert!(input < BN254_MODULUS, "accepted a non-field input {}", input);
+            let hash = U256::abi_decode(&output, true).expect("output decodes as uint256");
+            assert!(hash < BN254_MODULUS, "hash {} is outside the field", hash);
+        }
+        // Inputs at or above the modulus are rejected rather than reduced
+        Err(_) => assert!(input >= BN254_MODULUS, "rejected field input {}", input),
+    }
+});
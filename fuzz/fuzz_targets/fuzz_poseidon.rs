@@ -0,0 +1,11 @@
+//! Arbitrary calldata must make `poseidon_precompile` return, never panic
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use precompile::poseidon_precompile;
+
+fuzz_target!(|data: &[u8]| {
+    let result = poseidon_precompile(data);
+    assert!(result.is_ok() || result.is_err());
+});
@@ -0,0 +1,51 @@
+//! Derive macros for the precompile framework
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, ItemMod};
+
+mod sol_struct;
+mod storage_layout;
+mod test_suite;
+
+/// Generates `abi_encode` and `abi_decode` for a struct of Solidity-compatible fields
+///
+/// Fields of type `U256`, `Address`, `bool` and `Vec<U256>` are mapped to
+/// their Solidity types automatically. Other field types need a
+/// `#[sol_struct_type(...)]` attribute naming the Solidity type, e.g.
+/// `#[sol_struct_type(uint64)]` on a `u64` field.
+#[proc_macro_derive(SolStruct, attributes(sol_struct_type))]
+pub fn derive_sol_struct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    sol_struct::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `SolStorageLayout` for a Stylus storage struct
+///
+/// Fields are placed with the same packing rules as the `#[storage]` macro.
+#[proc_macro_derive(SolStorageLayout)]
+pub fn derive_sol_storage_layout(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    storage_layout::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Adds the standard battery of hash tests to an inline test module
+///
+/// `#[precompile_test_suite(hasher = PoseidonHash, modulus = BN254_MODULUS)]`
+/// generates determinism, field-range, input-validation and 1000-input load tests
+/// against the hasher's `hash_single`, `hash_pair` and `hash_array` methods.
+/// `single = ...`, `pair = ...` and `array = ...` select other method names.
+/// Paths are resolved inside the annotated module.
+#[proc_macro_attribute]
+pub fn precompile_test_suite(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut suite_args = test_suite::SuiteArgs::default();
+    let parser = syn::meta::parser(|meta| suite_args.parse(meta));
+    parse_macro_input!(args with parser);
+    let module = parse_macro_input!(item as ItemMod);
+    test_suite::expand(suite_args, module)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
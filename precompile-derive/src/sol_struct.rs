@@ -0,0 +1,159 @@
+//! `#[derive(SolStruct)]` expansion
+//!
+//! The struct is encoded as the tuple of its fields, which is exactly how
+//! `alloy_sol_types` encodes a `sol!` struct with the same field types.
+
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, GenericArgument, PathArguments, Result, Type};
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input,
+                    "SolStruct requires a struct with named fields",
+                ))
+            }
+        },
+        _ => return Err(Error::new_spanned(&input, "SolStruct can only be derived for structs")),
+    };
+
+    let mut idents = Vec::new();
+    let mut sol_types = Vec::new();
+    for field in fields {
+        idents.push(field.ident.clone().expect("named field"));
+        sol_types.push(field_sol_type(field)?);
+    }
+
+    let bindings: Vec<Ident> = (0..idents.len()).map(|i| format_ident!("__field{}", i)).collect();
+    let tuple = quote!((#(#sol_types,)*));
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// ABI-encodes the struct as a Solidity tuple
+            pub fn abi_encode(&self) -> ::std::vec::Vec<u8> {
+                <#tuple as ::alloy_sol_types::SolType>::abi_encode(
+                    &(#(::core::clone::Clone::clone(&self.#idents),)*)
+                )
+            }
+
+            /// Decodes a struct previously encoded with `abi_encode`
+            pub fn abi_decode(
+                data: &[u8],
+            ) -> ::core::result::Result<Self, ::precompile::errors::PrecompileError> {
+                let mut decoder = ::alloy_sol_types::abi::Decoder::new(data, true);
+                let decoded = decoder
+                    .decode_sequence::<(<#tuple as ::alloy_sol_types::SolType>::Token<'_>,)>()
+                    .and_then(|(token,)| {
+                        <#tuple as ::alloy_sol_types::SolType>::type_check(&token)?;
+                        if ::alloy_sol_types::abi::encode(&token) != data {
+                            return Err(::alloy_sol_types::Error::ReserMismatch);
+                        }
+                        Ok(token)
+                    });
+
+                match decoded {
+                    Ok(token) => {
+                        let (#(#bindings,)*) =
+                            <#tuple as ::alloy_sol_types::SolType>::detokenize(token);
+                        Ok(Self { #(#idents: #bindings,)* })
+                    }
+                    Err(reason) => Err(::precompile::errors::AbiDecodeError {
+                        selector: [0u8; 4],
+                        offset: decoder.offset(),
+                        reason,
+                    }
+                    .into()),
+                }
+            }
+        }
+    })
+}
+
+/// Resolves the `alloy_sol_types::sol_data` type for a field
+fn field_sol_type(field: &syn::Field) -> Result<TokenStream> {
+    for attr in &field.attrs {
+        if attr.path().is_ident("sol_struct_type") {
+            let ty: Ident = attr.parse_args()?;
+            return parse_sol_type(&ty);
+        }
+    }
+
+    infer_sol_type(&field.ty).ok_or_else(|| {
+        Error::new_spanned(
+            &field.ty,
+            "unsupported field type, expected U256, Address, bool or Vec<U256>; \
+             use #[sol_struct_type(...)] to name the Solidity type",
+        )
+    })
+}
+
+/// Maps the supported Rust field types to their Solidity types
+fn infer_sol_type(ty: &Type) -> Option<TokenStream> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+
+    match segment.ident.to_string().as_str() {
+        "U256" => Some(quote!(::alloy_sol_types::sol_data::Uint<256>)),
+        "Address" => Some(quote!(::alloy_sol_types::sol_data::Address)),
+        "bool" => Some(quote!(::alloy_sol_types::sol_data::Bool)),
+        "Vec" => {
+            let PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            let Some(GenericArgument::Type(inner)) = args.args.first() else {
+                return None;
+            };
+            let inner = infer_sol_type(inner)?;
+            Some(quote!(::alloy_sol_types::sol_data::Array<#inner>))
+        }
+        _ => None,
+    }
+}
+
+/// Parses a Solidity type name such as `uint64`, `address` or `bytes32`
+fn parse_sol_type(ty: &Ident) -> Result<TokenStream> {
+    let name = ty.to_string();
+    let sized = |prefix: &str, default: usize| -> Option<usize> {
+        let bits = name.strip_prefix(prefix)?;
+        if bits.is_empty() {
+            Some(default)
+        } else {
+            bits.parse().ok()
+        }
+    };
+
+    let tokens = match name.as_str() {
+        "address" => quote!(::alloy_sol_types::sol_data::Address),
+        "bool" => quote!(::alloy_sol_types::sol_data::Bool),
+        "string" => quote!(::alloy_sol_types::sol_data::String),
+        "bytes" => quote!(::alloy_sol_types::sol_data::Bytes),
+        _ => {
+            let bits_ok = |b: &usize| *b > 0 && *b <= 256 && b % 8 == 0;
+            if let Some(bits) = sized("uint", 256).filter(bits_ok) {
+                let bits = Literal::usize_unsuffixed(bits);
+                quote!(::alloy_sol_types::sol_data::Uint<#bits>)
+            } else if let Some(bits) = sized("int", 256).filter(bits_ok) {
+                let bits = Literal::usize_unsuffixed(bits);
+                quote!(::alloy_sol_types::sol_data::Int<#bits>)
+            } else if let Some(len) = sized("bytes", 0).filter(|l| *l > 0 && *l <= 32) {
+                let len = Literal::usize_unsuffixed(len);
+                quote!(::alloy_sol_types::sol_data::FixedBytes<#len>)
+            } else {
+                return Err(Error::new_spanned(
+                    ty,
+                    format!("unsupported Solidity type `{}`", name),
+                ));
+            }
+        }
+    };
+
+    Ok(tokens)
+}
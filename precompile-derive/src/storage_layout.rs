@@ -0,0 +1,48 @@
+//! `#[derive(SolStorageLayout)]` expansion
+//!
+//! Each field is handed to `StorageLayoutBuilder` with its type, so slot
+//! assignment uses the `StorageType` constants the `#[storage]` macro uses.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Result};
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields.named.iter().collect(),
+            Fields::Unit => Vec::new(),
+            Fields::Unnamed(_) => {
+                return Err(Error::new_spanned(
+                    &input,
+                    "SolStorageLayout requires a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input,
+                "SolStorageLayout can only be derived for structs",
+            ))
+        }
+    };
+
+    let placements = fields.iter().map(|field| {
+        let ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let label = ident.to_string();
+        quote!(builder.field::<#ty>(#label);)
+    });
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::precompile::storage::SolStorageLayout for #name #ty_generics #where_clause {
+            fn storage_layout() -> ::precompile::storage::StorageLayout {
+                let mut builder = ::precompile::storage::StorageLayoutBuilder::new();
+                #(#placements)*
+                builder.build()
+            }
+        }
+    })
+}
@@ -0,0 +1,132 @@
+//! `#[precompile_test_suite]` expansion
+//!
+//! The generated tests call the hasher's methods by name, so any type with
+//! `new()` and `hash_single` / `hash_pair` / `hash_array` methods returning
+//! `Result<U256, _>` can use the suite. The method names can be overridden,
+//! e.g. `single = hash_single_production`.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{meta::ParseNestedMeta, Error, Expr, Ident, ItemMod, Path, Result};
+
+#[derive(Default)]
+pub struct SuiteArgs {
+    hasher: Option<Path>,
+    modulus: Option<Expr>,
+    single: Option<Ident>,
+    pair: Option<Ident>,
+    array: Option<Ident>,
+}
+
+impl SuiteArgs {
+    pub fn parse(&mut self, meta: ParseNestedMeta) -> Result<()> {
+        if meta.path.is_ident("hasher") {
+            self.hasher = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("modulus") {
+            self.modulus = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("single") {
+            self.single = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("pair") {
+            self.pair = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("array") {
+            self.array = Some(meta.value()?.parse()?);
+        } else {
+            return Err(meta.error("expected `hasher`, `modulus`, `single`, `pair` or `array`"));
+        }
+        Ok(())
+    }
+}
+
+pub fn expand(args: SuiteArgs, mut module: ItemMod) -> Result<TokenStream> {
+    let hasher = args
+        .hasher
+        .ok_or_else(|| Error::new_spanned(&module.ident, "missing `hasher = Type` argument"))?;
+    let modulus = args
+        .modulus
+        .ok_or_else(|| Error::new_spanned(&module.ident, "missing `modulus = expr` argument"))?;
+    let single = args.single.unwrap_or_else(|| Ident::new("hash_single", Span::call_site()));
+    let pair = args.pair.unwrap_or_else(|| Ident::new("hash_pair", Span::call_site()));
+    let array = args.array.unwrap_or_else(|| Ident::new("hash_array", Span::call_site()));
+
+    if module.content.is_none() {
+        return Err(Error::new_spanned(&module, "precompile_test_suite requires an inline module"));
+    }
+
+    let tests = quote! {
+        #[test]
+        fn test_determinism() {
+            let hasher = <#hasher>::new();
+            let x = ::precompile::U256::from(12345);
+            let y = ::precompile::U256::from(67890);
+            assert_eq!(hasher.#single(x).unwrap(), hasher.#single(x).unwrap());
+            assert_eq!(hasher.#pair(x, y).unwrap(), hasher.#pair(x, y).unwrap());
+            assert_eq!(hasher.#array(&[x, y, x]).unwrap(), hasher.#array(&[x, y, x]).unwrap());
+        }
+
+        #[test]
+        fn test_different_inputs_differ() {
+            let hasher = <#hasher>::new();
+            let one = ::precompile::U256::from(1);
+            let two = ::precompile::U256::from(2);
+            assert_ne!(hasher.#single(one).unwrap(), hasher.#single(two).unwrap());
+            assert_ne!(hasher.#pair(one, two).unwrap(), hasher.#pair(two, one).unwrap());
+            assert_ne!(hasher.#array(&[one, two]).unwrap(), hasher.#array(&[one, one]).unwrap());
+        }
+
+        #[test]
+        fn test_output_in_field() {
+            let hasher = <#hasher>::new();
+            let modulus: ::precompile::U256 = #modulus;
+            let max = modulus - ::precompile::U256::from(1);
+            for input in [::precompile::U256::ZERO, ::precompile::U256::from(1), max] {
+                assert!(hasher.#single(input).unwrap() < modulus);
+                assert!(hasher.#pair(input, max).unwrap() < modulus);
+                assert!(hasher.#array(&[input, max, input]).unwrap() < modulus);
+            }
+        }
+
+        #[test]
+        fn test_empty_input_errors() {
+            let hasher = <#hasher>::new();
+            assert!(hasher.#array(&[]).is_err());
+        }
+
+        #[test]
+        fn test_modulus_rejected() {
+            let hasher = <#hasher>::new();
+            let modulus: ::precompile::U256 = #modulus;
+            let one = ::precompile::U256::from(1);
+            assert!(hasher.#single(modulus).is_err());
+            assert!(hasher.#pair(modulus, one).is_err());
+            assert!(hasher.#pair(one, modulus).is_err());
+            assert!(hasher.#array(&[one, modulus]).is_err());
+        }
+
+        #[test]
+        fn test_hash_single_vs_array() {
+            let hasher = <#hasher>::new();
+            for value in [0u64, 1, 42, u64::MAX] {
+                let input = ::precompile::U256::from(value);
+                assert_eq!(hasher.#single(input).unwrap(), hasher.#array(&[input]).unwrap());
+            }
+        }
+
+        #[test]
+        fn test_benchmark_1000_iterations() {
+            // A load run, not a timing: wall-clock numbers belong in a bench
+            let hasher = <#hasher>::new();
+            let mut seen = ::std::collections::HashSet::new();
+            for i in 0..1000u64 {
+                let hash = hasher.#single(::precompile::U256::from(i)).unwrap();
+                assert!(seen.insert(::std::hint::black_box(hash)), "collision at input {}", i);
+            }
+        }
+    };
+
+    let file: syn::File = syn::parse2(tests)?;
+    if let Some((_, items)) = module.content.as_mut() {
+        items.extend(file.items);
+    }
+
+    Ok(quote! { #module })
+}
@@ -48,6 +48,10 @@ cargo clean
 echo -e "${YELLOW}🧪 Running tests...${NC}"
 cargo test
 
+# Fail if the committed ABI artifacts no longer match the sol! interfaces
+echo -e "${YELLOW}📄 Checking ABI artifacts...${NC}"
+cargo test --features export-abi --test abi_export
+
 # Build for native target (development)
 echo -e "${YELLOW}🔨 Building native target...${NC}"
 cargo build
@@ -24,6 +24,7 @@ NETWORK="arbitrum-sepolia"
 PRIVATE_KEY=""
 RPC_URL=""
 WASM_FILE="target/wasm32-unknown-unknown/release/precompile.wasm"
+CONFIG_FILE=""
 
 # Function to display usage
 usage() {
@@ -33,6 +34,7 @@ usage() {
     echo "  -k, --private-key KEY     Private key for deployment"
     echo "  -r, --rpc-url URL         RPC endpoint URL"
     echo "  -w, --wasm-file FILE      WASM file path (default: $WASM_FILE)"
+    echo "  -c, --config FILE         JSON file with the Poseidon parameters"
     echo "  -h, --help                Show this help message"
     echo ""
     echo "Examples:"
@@ -60,6 +62,10 @@ while [[ $# -gt 0 ]]; do
             WASM_FILE="$2"
             shift 2
             ;;
+        -c|--config)
+            CONFIG_FILE="$2"
+            shift 2
+            ;;
         -h|--help)
             usage
             ;;
@@ -111,6 +117,9 @@ echo "  File size: $(ls -lh "$WASM_FILE" | awk '{print $5}')"
 if [ -n "$RPC_URL" ]; then
     echo "  RPC URL: $RPC_URL"
 fi
+if [ -n "$CONFIG_FILE" ]; then
+    echo "  Poseidon config: $CONFIG_FILE"
+fi
 
 # Confirm deployment
 echo -e "${YELLOW}⚠️  This will deploy the precompile to $NETWORK${NC}"
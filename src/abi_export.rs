@@ -0,0 +1,55 @@
+//! JSON ABI artifacts for the precompile interfaces
+//!
+//! `generate_abi` builds the JSON ABI of every `sol!` interface the crate
+//! dispatches on. The artifacts are committed under `abi/`, and the
+//! `abi_export` integration test fails when they no longer match the
+//! interfaces. Rerun it with `UPDATE_ABI=1` to rewrite them.
+
+use alloy_json_abi::JsonAbi;
+use std::{fs, io, path::Path};
+
+use crate::babyjubjub::{IBabyJubJub, IBabyJubjubCurve};
+use crate::curves::{IBN254, IBN254MSM};
+use crate::groth16::IGroth16Verifier;
+use crate::kzg::IKZG;
+use crate::merkle::{IKeccakMerkle, IPoseidonMerkle};
+use crate::mimc::IMiMCHash;
+use crate::pedersen::IPedersenCommitment;
+use crate::plonk::IPolyHasher;
+use crate::poseidon::IPoseidonHash;
+use crate::rescue::IRescueHash;
+
+/// Returns the JSON ABI of each precompile interface, keyed by interface name
+pub fn generate_abi() -> Vec<(&'static str, JsonAbi)> {
+    vec![
+        ("IPoseidonHash", IPoseidonHash::abi::contract()),
+        ("IPolyHasher", IPolyHasher::abi::contract()),
+        ("IKeccakMerkle", IKeccakMerkle::abi::contract()),
+        ("IBabyJubJub", IBabyJubJub::abi::contract()),
+        ("IBabyJubjubCurve", IBabyJubjubCurve::abi::contract()),
+        ("IBN254", IBN254::abi::contract()),
+        ("IBN254MSM", IBN254MSM::abi::contract()),
+        ("IGroth16Verifier", IGroth16Verifier::abi::contract()),
+        ("IKZG", IKZG::abi::contract()),
+        ("IMiMCHash", IMiMCHash::abi::contract()),
+        ("IPedersenCommitment", IPedersenCommitment::abi::contract()),
+        ("IRescueHash", IRescueHash::abi::contract()),
+        ("IPoseidonMerkle", IPoseidonMerkle::abi::contract()),
+    ]
+}
+
+/// Renders an ABI as pretty-printed JSON with a trailing newline
+pub fn abi_to_json(abi: &JsonAbi) -> String {
+    let mut json = serde_json::to_string_pretty(abi).expect("JSON ABI serializes");
+    json.push('\n');
+    json
+}
+
+/// Writes `<dir>/<Interface>.json` for every interface
+pub fn write_abi_artifacts(dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    for (name, abi) in generate_abi() {
+        fs::write(dir.join(format!("{}.json", name)), abi_to_json(&abi))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,205 @@
+//! Baby JubJub precompile interface and ABI definitions
+
+use super::te_arithmetic::{Point, BABYJUBJUB};
+use crate::curves::BabyJubjubPoint;
+use crate::errors::{CurveError, InvalidSelectorError, PrecompileError};
+use crate::gas::GasCosts;
+use crate::poseidon::interface::decode_call;
+use crate::traits::GenericPrecompile;
+use alloy_primitives::U256;
+use alloy_sol_types::{sol, SolCall, SolValue};
+
+// Solidity interface definition
+sol! {
+    #[sol(abi)]
+    interface IBabyJubJub {
+        /// Adds two Baby JubJub points in circomlib's parameterization
+        /// @return rx The x coordinate of the sum
+        /// @return ry The y coordinate of the sum
+        function babyAdd(uint256 ax, uint256 ay, uint256 bx, uint256 by) external pure returns (uint256 rx, uint256 ry);
+
+        /// Multiplies a Baby JubJub point by a scalar
+        /// @return rx The x coordinate of the product
+        /// @return ry The y coordinate of the product
+        function babyMul(uint256 scalar, uint256 x, uint256 y) external pure returns (uint256 rx, uint256 ry);
+    }
+
+    #[sol(abi)]
+    interface IBabyJubjubCurve {
+        /// Adds two Baby Jubjub points
+        /// @return rx The x coordinate of the sum
+        /// @return ry The y coordinate of the sum
+        function bjjAdd(uint256 ax, uint256 ay, uint256 bx, uint256 by) external pure returns (uint256 rx, uint256 ry);
+
+        /// Multiplies a Baby Jubjub point by a scalar
+        /// @return rx The x coordinate of the product
+        /// @return ry The y coordinate of the product
+        function bjjMulScalar(uint256 px, uint256 py, uint256 scalar) external pure returns (uint256 rx, uint256 ry);
+    }
+}
+
+/// Parses a point, rejecting coordinates that are not on the curve
+fn checked_point(x: U256, y: U256) -> Result<Point, CurveError> {
+    let point = Point::new(x, y);
+    if !BABYJUBJUB.is_on_curve(point) {
+        return Err(CurveError::PointNotOnCurve { x, y });
+    }
+    Ok(point)
+}
+
+/// Baby JubJub precompile behind the `IBabyJubJub` and `IBabyJubjubCurve` selectors
+///
+/// `IBabyJubjubCurve` is the [`BabyJubjubPoint`] view of the same group law,
+/// taking the point before the scalar.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BabyJubJubPrecompile;
+
+impl BabyJubJubPrecompile {
+    /// Selectors of every `IBabyJubJub` and `IBabyJubjubCurve` function
+    pub const SELECTORS: [[u8; 4]; 4] = [
+        IBabyJubJub::babyAddCall::SELECTOR,
+        IBabyJubJub::babyMulCall::SELECTOR,
+        IBabyJubjubCurve::bjjAddCall::SELECTOR,
+        IBabyJubjubCurve::bjjMulScalarCall::SELECTOR,
+    ];
+}
+
+impl GenericPrecompile for BabyJubJubPrecompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        babyjubjub_precompile(input)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, input: &[u8]) -> u64 {
+        match input.get(0..4) {
+            Some(s) if s == IBabyJubJub::babyAddCall::SELECTOR => GasCosts::BABYJUBJUB_ADD,
+            Some(s) if s == IBabyJubjubCurve::bjjAddCall::SELECTOR => GasCosts::BABYJUBJUB_ADD,
+            _ => GasCosts::BABYJUBJUB_MUL,
+        }
+    }
+}
+
+/// Precompile entry point - handles the raw call interface
+pub fn babyjubjub_precompile(input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+    if input.len() < 4 {
+        return Err(InvalidSelectorError::from_input(input).into());
+    }
+
+    match &input[0..4] {
+        // babyAdd(uint256,uint256,uint256,uint256)
+        s if s == IBabyJubJub::babyAddCall::SELECTOR => {
+            let decoded = decode_call::<IBabyJubJub::babyAddCall>(input)?;
+
+            let a = checked_point(decoded.ax, decoded.ay)?;
+            let b = checked_point(decoded.bx, decoded.by)?;
+            let sum = BABYJUBJUB.add(a, b);
+            Ok((sum.x, sum.y).abi_encode_params())
+        }
+
+        // babyMul(uint256,uint256,uint256)
+        s if s == IBabyJubJub::babyMulCall::SELECTOR => {
+            let decoded = decode_call::<IBabyJubJub::babyMulCall>(input)?;
+
+            let point = checked_point(decoded.x, decoded.y)?;
+            let product = BABYJUBJUB.mul(point, decoded.scalar);
+            Ok((product.x, product.y).abi_encode_params())
+        }
+
+        // bjjAdd(uint256,uint256,uint256,uint256)
+        s if s == IBabyJubjubCurve::bjjAddCall::SELECTOR => {
+            let decoded = decode_call::<IBabyJubjubCurve::bjjAddCall>(input)?;
+
+            let a = BabyJubjubPoint::new(decoded.ax, decoded.ay)?;
+            let b = BabyJubjubPoint::new(decoded.bx, decoded.by)?;
+            Ok(BabyJubjubPoint::add(&a, &b).to_affine().abi_encode_params())
+        }
+
+        // bjjMulScalar(uint256,uint256,uint256)
+        s if s == IBabyJubjubCurve::bjjMulScalarCall::SELECTOR => {
+            let decoded = decode_call::<IBabyJubjubCurve::bjjMulScalarCall>(input)?;
+
+            let point = BabyJubjubPoint::new(decoded.px, decoded.py)?;
+            let product = BabyJubjubPoint::scalar_mul(&point, decoded.scalar);
+            Ok(product.to_affine().abi_encode_params())
+        }
+
+        _ => Err(InvalidSelectorError::from_input(input).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::babyjubjub::BASE8;
+    use crate::curves::BASE_POINT;
+
+    #[test]
+    fn test_precompile_interface() {
+        let call = IBabyJubJub::babyMulCall { scalar: U256::from(3), x: BASE8.x, y: BASE8.y }.abi_encode();
+        let output = babyjubjub_precompile(&call).unwrap();
+        let decoded = IBabyJubJub::babyMulCall::abi_decode_returns(&output, true).unwrap();
+        let expected = BABYJUBJUB.mul(BASE8, U256::from(3));
+        assert_eq!((decoded.rx, decoded.ry), (expected.x, expected.y));
+
+        let call = IBabyJubJub::babyAddCall { ax: BASE8.x, ay: BASE8.y, bx: BASE8.x, by: BASE8.y }.abi_encode();
+        let output = babyjubjub_precompile(&call).unwrap();
+        let decoded = IBabyJubJub::babyAddCall::abi_decode_returns(&output, true).unwrap();
+        let expected = BABYJUBJUB.double(BASE8);
+        assert_eq!((decoded.rx, decoded.ry), (expected.x, expected.y));
+    }
+
+    #[test]
+    fn test_rejects_points_off_curve() {
+        let call = IBabyJubJub::babyMulCall { scalar: U256::from(3), x: U256::from(1), y: U256::from(1) }.abi_encode();
+        assert!(matches!(
+            babyjubjub_precompile(&call),
+            Err(PrecompileError::Curve(CurveError::PointNotOnCurve { .. }))
+        ));
+        assert!(matches!(babyjubjub_precompile(&[0u8; 8]), Err(PrecompileError::Selector(_))));
+
+        let (px, py) = (U256::from(1), U256::from(1));
+        let call =
+            IBabyJubjubCurve::bjjMulScalarCall { px, py, scalar: U256::from(3) }.abi_encode();
+        assert!(matches!(
+            babyjubjub_precompile(&call),
+            Err(PrecompileError::Curve(CurveError::PointNotOnCurve { .. }))
+        ));
+        assert!(matches!(babyjubjub_precompile(&call[..40]), Err(PrecompileError::Abi(_))));
+    }
+
+    #[test]
+    fn test_curve_selectors() {
+        let (x, y) = BASE_POINT.to_affine();
+
+        let scalar = U256::from(2);
+        let call = IBabyJubjubCurve::bjjMulScalarCall { px: x, py: y, scalar }.abi_encode();
+        let output = babyjubjub_precompile(&call).unwrap();
+        let product =
+            IBabyJubjubCurve::bjjMulScalarCall::abi_decode_returns(&output, true).unwrap();
+
+        let call = IBabyJubjubCurve::bjjAddCall { ax: x, ay: y, bx: x, by: y }.abi_encode();
+        let output = babyjubjub_precompile(&call).unwrap();
+        let sum = IBabyJubjubCurve::bjjAddCall::abi_decode_returns(&output, true).unwrap();
+        assert_eq!((product.rx, product.ry), (sum.rx, sum.ry));
+
+        // Same group law as the `IBabyJubJub` selectors
+        let call = IBabyJubJub::babyMulCall { scalar, x, y }.abi_encode();
+        assert_eq!(babyjubjub_precompile(&call).unwrap(), output);
+    }
+
+    #[test]
+    fn test_generic_precompile() {
+        let (x, y) = BASE_POINT.to_affine();
+        let add = IBabyJubjubCurve::bjjAddCall { ax: x, ay: y, bx: x, by: y }.abi_encode();
+        let scalar = U256::from(2);
+        let mul = IBabyJubjubCurve::bjjMulScalarCall { px: x, py: y, scalar }.abi_encode();
+
+        let precompile = BabyJubJubPrecompile;
+        assert_eq!(precompile.call(&add).unwrap(), babyjubjub_precompile(&add).unwrap());
+        assert_eq!(precompile.gas_cost(&add), GasCosts::BABYJUBJUB_ADD);
+        assert_eq!(precompile.gas_cost(&mul), GasCosts::BABYJUBJUB_MUL);
+    }
+}
@@ -0,0 +1,7 @@
+//! Baby JubJub twisted Edwards curve over the BN254 scalar field
+
+pub mod interface;
+pub mod te_arithmetic;
+
+pub use interface::{babyjubjub_precompile, BabyJubJubPrecompile, IBabyJubJub, IBabyJubjubCurve};
+pub use te_arithmetic::{Point, TwistedEdwards, BABYJUBJUB, BASE8};
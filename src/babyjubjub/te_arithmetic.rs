@@ -0,0 +1,300 @@
+//! Twisted Edwards arithmetic on Baby JubJub
+//!
+//! Points satisfy `a·x² + y² = 1 + d·x²·y²` over the BN254 scalar field.
+//! circomlib uses `a = 168700, d = 168696`, which is what circuits and the
+//! precompile ABI expect. The curve is isomorphic to the reduced form with
+//! `a = -1, d = -168696/168700` through `x' = x·√(-168700)`; `to_reduced` and
+//! `from_reduced` convert between the two.
+//!
+//! `d` is not a square in either form, so the addition law is complete and the
+//! denominators never vanish for points on the curve. `mul` runs the same law
+//! in extended coordinates `(X : Y : T : Z)`, so a scalar multiplication needs
+//! a single inversion instead of two per addition.
+
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::{uint, U256};
+
+/// Affine point on a twisted Edwards curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point {
+    pub x: U256,
+    pub y: U256,
+}
+
+impl Point {
+    /// Neutral element `(0, 1)`
+    pub const IDENTITY: Self = Self { x: U256::ZERO, y: uint!(1_U256) };
+
+    pub const fn new(x: U256, y: U256) -> Self {
+        Self { x, y }
+    }
+}
+
+/// Curve coefficients of `a·x² + y² = 1 + d·x²·y²`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TwistedEdwards {
+    pub a: U256,
+    pub d: U256,
+}
+
+/// Baby JubJub in circomlib's parameterization
+pub const BABYJUBJUB: TwistedEdwards = TwistedEdwards {
+    a: uint!(168700_U256),
+    d: uint!(168696_U256),
+};
+
+/// Generator of the prime-order subgroup used by circomlib (`Base8`)
+pub const BASE8: Point = Point {
+    x: uint!(5299619240641551281634865583518297030282874472190772894086521144482721001553_U256),
+    y: uint!(16950150798460657717958625567821834550301663161624707787222815936182638968203_U256),
+};
+
+impl TwistedEdwards {
+    /// Baby JubJub in the reduced form `a = -1, d = -168696/168700`
+    pub fn reduced_babyjubjub() -> Self {
+        Self {
+            a: neg(uint!(1_U256)),
+            d: neg(div(BABYJUBJUB.d, BABYJUBJUB.a)),
+        }
+    }
+
+    /// Adds two points on the curve
+    pub fn add(&self, p: Point, q: Point) -> Point {
+        let x1x2 = mul(p.x, q.x);
+        let y1y2 = mul(p.y, q.y);
+        let dxxyy = mul(self.d, mul(x1x2, y1y2));
+
+        let x = div(add(mul(p.x, q.y), mul(p.y, q.x)), add(uint!(1_U256), dxxyy));
+        let y = div(sub(y1y2, mul(self.a, x1x2)), sub(uint!(1_U256), dxxyy));
+        Point { x, y }
+    }
+
+    /// Doubles a point on the curve
+    pub fn double(&self, p: Point) -> Point {
+        self.add(p, p)
+    }
+
+    /// Multiplies a point by a scalar with double-and-add
+    pub fn mul(&self, p: Point, scalar: U256) -> Point {
+        let mut result = Extended::IDENTITY;
+        let mut addend = Extended::from_affine(p);
+        for bit in 0..scalar.bit_len() {
+            if scalar.bit(bit) {
+                result = self.add_extended(&result, &addend);
+            }
+            addend = self.add_extended(&addend, &addend);
+        }
+        result.to_affine()
+    }
+
+    /// Whether the point has canonical coordinates and satisfies the curve equation
+    pub fn is_on_curve(&self, p: Point) -> bool {
+        if p.x >= BN254_MODULUS || p.y >= BN254_MODULUS {
+            return false;
+        }
+
+        let xx = mul(p.x, p.x);
+        let yy = mul(p.y, p.y);
+        add(mul(self.a, xx), yy) == add(uint!(1_U256), mul(self.d, mul(xx, yy)))
+    }
+
+    /// Whether the point is the neutral element
+    pub fn is_identity(&self, p: Point) -> bool {
+        p == Point::IDENTITY
+    }
+
+    /// Unified addition in extended coordinates (Hisil-Wong-Carter-Dawson)
+    fn add_extended(&self, p: &Extended, q: &Extended) -> Extended {
+        let a = mul(p.x, q.x);
+        let b = mul(p.y, q.y);
+        let c = mul(self.d, mul(p.t, q.t));
+        let d = mul(p.z, q.z);
+        let e = sub(sub(mul(add(p.x, p.y), add(q.x, q.y)), a), b);
+        let f = sub(d, c);
+        let g = add(d, c);
+        let h = sub(b, mul(self.a, a));
+
+        Extended { x: mul(e, f), y: mul(g, h), t: mul(e, h), z: mul(f, g) }
+    }
+}
+
+/// Point `(X : Y : T : Z)` standing for `(X/Z, Y/Z)`, with `T = XY/Z`
+#[derive(Debug, Clone, Copy)]
+struct Extended {
+    x: U256,
+    y: U256,
+    t: U256,
+    z: U256,
+}
+
+impl Extended {
+    const IDENTITY: Self = Self {
+        x: U256::ZERO,
+        y: uint!(1_U256),
+        t: U256::ZERO,
+        z: uint!(1_U256),
+    };
+
+    fn from_affine(p: Point) -> Self {
+        Self { x: p.x, y: p.y, t: mul(p.x, p.y), z: uint!(1_U256) }
+    }
+
+    fn to_affine(self) -> Point {
+        let z_inv = div(uint!(1_U256), self.z);
+        Point::new(mul(self.x, z_inv), mul(self.y, z_inv))
+    }
+}
+
+/// Maps a point from circomlib's form to the reduced form
+pub fn to_reduced(p: Point) -> Point {
+    Point::new(mul(p.x, reduction_factor()), p.y)
+}
+
+/// Maps a point from the reduced form back to circomlib's form
+pub fn from_reduced(p: Point) -> Point {
+    Point::new(div(p.x, reduction_factor()), p.y)
+}
+
+/// `√(-168700)`, the x scaling between the two forms
+fn reduction_factor() -> U256 {
+    sqrt(neg(BABYJUBJUB.a)).expect("-168700 is a square mod p")
+}
+
+fn add(a: U256, b: U256) -> U256 {
+    a.add_mod(b, BN254_MODULUS)
+}
+
+fn sub(a: U256, b: U256) -> U256 {
+    a.add_mod(neg(b), BN254_MODULUS)
+}
+
+fn neg(a: U256) -> U256 {
+    (BN254_MODULUS - a % BN254_MODULUS) % BN254_MODULUS
+}
+
+fn mul(a: U256, b: U256) -> U256 {
+    a.mul_mod(b, BN254_MODULUS)
+}
+
+/// Division through Fermat inversion; dividing by zero yields zero
+fn div(a: U256, b: U256) -> U256 {
+    mul(a, b.pow_mod(BN254_MODULUS - uint!(2_U256), BN254_MODULUS))
+}
+
+/// Tonelli-Shanks square root, `None` for non-residues
+fn sqrt(n: U256) -> Option<U256> {
+    let p = BN254_MODULUS;
+    let one = uint!(1_U256);
+    let is_residue = |v: U256| v.pow_mod((p - one) >> 1, p) == one;
+
+    if n.is_zero() {
+        return Some(U256::ZERO);
+    }
+    if !is_residue(n) {
+        return None;
+    }
+
+    // p - 1 = q · 2^s with q odd
+    let s = (p - one).trailing_zeros();
+    let q = (p - one) >> s;
+    let mut z = uint!(2_U256);
+    while is_residue(z) {
+        z += one;
+    }
+
+    let mut m = s;
+    let mut c = z.pow_mod(q, p);
+    let mut t = n.pow_mod(q, p);
+    let mut r = n.pow_mod((q + one) >> 1, p);
+    while t != one {
+        let mut i = 0;
+        let mut t2i = t;
+        while t2i != one {
+            t2i = mul(t2i, t2i);
+            i += 1;
+        }
+        let b = c.pow_mod(one << (m - i - 1), p);
+        m = i;
+        c = mul(b, b);
+        t = mul(t, c);
+        r = mul(r, b);
+    }
+    Some(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from circomlib's test/babyjub.js
+    const P1: Point = Point {
+        x: uint!(17777552123799933955779906779655732241715742912184938656739573121738514868268_U256),
+        y: uint!(2626589144620713026669568689430873010625803728049924121243784502389097019475_U256),
+    };
+    const P2: Point = Point {
+        x: uint!(16540640123574156134436876038791482806971768689494387082833631921987005038935_U256),
+        y: uint!(20819045374670962167435360035096875258406992893633759881276124905556507972311_U256),
+    };
+
+    #[test]
+    fn test_circomlib_add_vectors() {
+        assert!(BABYJUBJUB.is_on_curve(P1));
+        assert!(BABYJUBJUB.is_on_curve(P2));
+
+        assert_eq!(
+            BABYJUBJUB.add(P1, P1),
+            Point::new(
+                uint!(6890855772600357754907169075114257697580319025794532037257385534741338397365_U256),
+                uint!(4338620300185947561074059802482547481416142213883829469920100239455078257889_U256),
+            )
+        );
+        assert_eq!(
+            BABYJUBJUB.add(P1, P2),
+            Point::new(
+                uint!(7916061937171219682591368294088513039687205273691143098332585753343424131937_U256),
+                uint!(14035240266687799601661095864649209771790948434046947201833777492504781204499_U256),
+            )
+        );
+    }
+
+    #[test]
+    fn test_circomlib_mul_vector() {
+        assert_eq!(
+            BABYJUBJUB.mul(P1, U256::from(3)),
+            Point::new(
+                uint!(19372461775513343691590086534037741906533799473648040012278229434133483800898_U256),
+                uint!(9458658722007214007257525444427903161243386465067105737478306991484593958249_U256),
+            )
+        );
+    }
+
+    #[test]
+    fn test_group_laws() {
+        assert_eq!(BABYJUBJUB.add(P1, Point::IDENTITY), P1);
+        assert_eq!(BABYJUBJUB.double(P1), BABYJUBJUB.mul(P1, U256::from(2)));
+        assert_eq!(BABYJUBJUB.add(BABYJUBJUB.double(P1), P1), BABYJUBJUB.mul(P1, U256::from(3)));
+        assert!(BABYJUBJUB.is_identity(BABYJUBJUB.mul(P1, U256::ZERO)));
+
+        // Negation is (-x, y)
+        let neg_p1 = Point::new(neg(P1.x), P1.y);
+        assert!(BABYJUBJUB.is_identity(BABYJUBJUB.add(P1, neg_p1)));
+
+        // Base8 generates a subgroup of prime order l
+        assert!(BABYJUBJUB.is_on_curve(BASE8));
+        let order = uint!(2736030358979909402780800718157159386076813972158567259200215660948447373041_U256);
+        assert!(BABYJUBJUB.is_identity(BABYJUBJUB.mul(BASE8, order)));
+        assert!(!BABYJUBJUB.is_on_curve(Point::new(U256::from(1), U256::from(1))));
+    }
+
+    #[test]
+    fn test_reduced_form_matches_circomlib() {
+        let reduced = TwistedEdwards::reduced_babyjubjub();
+        assert_eq!(reduced.a, BN254_MODULUS - U256::from(1));
+        assert_eq!(mul(reduced.d, U256::from(168700)), neg(U256::from(168696)));
+
+        let (r1, r2) = (to_reduced(P1), to_reduced(P2));
+        assert!(reduced.is_on_curve(r1));
+        assert_eq!(from_reduced(reduced.add(r1, r2)), BABYJUBJUB.add(P1, P2));
+        assert_eq!(from_reduced(r1), P1);
+    }
+}
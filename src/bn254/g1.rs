@@ -0,0 +1,282 @@
+//! BN254 G1 group arithmetic
+//!
+//! G1 is the curve `y² = x³ + 3` over the BN254 base field. Points are exposed
+//! in affine coordinates with the point at infinity encoded as `(0, 0)`, as the
+//! EIP-196 precompiles do. Scalar multiplication runs in Jacobian coordinates
+//! so only the final conversion needs a field inversion.
+//!
+//! G1 has cofactor 1, so every point on the curve is in the prime-order
+//! subgroup and `hash_to_curve` only has to find a point on the curve.
+//!
+//! `G1Point` is `bytemuck::Pod`, laid out as `x` then `y` limbs. Its zeroed
+//! value is the point at infinity, not a point on the curve, so bytes cast
+//! into a point should still be checked with `is_on_curve`.
+
+use alloy_primitives::{keccak256, uint, U256};
+
+/// BN254 base field modulus `q`
+pub const BN254_BASE_MODULUS: U256 =
+    uint!(0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47_U256);
+
+/// Curve coefficient `b` of `y² = x³ + b`
+const CURVE_B: U256 = uint!(3_U256);
+
+/// Affine G1 point, `(0, 0)` being the point at infinity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct G1Point {
+    pub x: U256,
+    pub y: U256,
+}
+
+// SAFETY: two `U256`s with no padding; all zeros is the point at infinity.
+unsafe impl bytemuck::Zeroable for G1Point {}
+
+// SAFETY: every bit pattern is a pair of valid `U256`s, though not
+// necessarily a point on the curve.
+unsafe impl bytemuck::Pod for G1Point {}
+
+/// Standard generator `(1, 2)`
+pub const G1_GENERATOR: G1Point = G1Point { x: uint!(1_U256), y: uint!(2_U256) };
+
+/// Point in Jacobian coordinates `(X/Z², Y/Z³)`, infinity when `Z = 0`
+#[derive(Clone, Copy)]
+pub(crate) struct Jacobian {
+    x: U256,
+    y: U256,
+    z: U256,
+}
+
+impl G1Point {
+    /// Point at infinity
+    pub const INFINITY: Self = Self { x: U256::ZERO, y: U256::ZERO };
+
+    pub const fn new(x: U256, y: U256) -> Self {
+        Self { x, y }
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        *self == Self::INFINITY
+    }
+
+    /// Whether the point has canonical coordinates and lies on the curve
+    pub fn is_on_curve(&self) -> bool {
+        if self.is_infinity() {
+            return true;
+        }
+        if self.x >= BN254_BASE_MODULUS || self.y >= BN254_BASE_MODULUS {
+            return false;
+        }
+        mul(self.y, self.y) == curve_rhs(self.x)
+    }
+
+    pub fn neg(&self) -> Self {
+        if self.is_infinity() {
+            return *self;
+        }
+        Self::new(self.x, sub(U256::ZERO, self.y))
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        self.to_jacobian().add(&other.to_jacobian()).to_affine()
+    }
+
+    pub fn double(&self) -> Self {
+        self.to_jacobian().double().to_affine()
+    }
+
+    /// Multiplies the point by `scalar` with double-and-add
+    pub fn mul(&self, scalar: U256) -> Self {
+        let base = self.to_jacobian();
+        let mut acc = Jacobian::INFINITY;
+        for bit in (0..scalar.bit_len()).rev() {
+            acc = acc.double();
+            if scalar.bit(bit) {
+                acc = acc.add(&base);
+            }
+        }
+        acc.to_affine()
+    }
+
+    /// Deterministically maps `seed` to a point with unknown discrete log
+    ///
+    /// Tries `x = keccak256(seed || counter) mod q` for increasing counters
+    /// until `x³ + 3` is a square, and takes the even square root as `y`.
+    pub fn hash_to_curve(seed: &[u8]) -> Self {
+        for counter in 0u32.. {
+            let mut preimage = seed.to_vec();
+            preimage.extend_from_slice(&counter.to_be_bytes());
+            let x = U256::from_be_bytes(keccak256(&preimage).0) % BN254_BASE_MODULUS;
+
+            if let Some(y) = sqrt(curve_rhs(x)) {
+                let y = if y.bit(0) { sub(U256::ZERO, y) } else { y };
+                return Self::new(x, y);
+            }
+        }
+        unreachable!("half of all x coordinates are on the curve")
+    }
+
+    pub(crate) fn to_jacobian(self) -> Jacobian {
+        if self.is_infinity() {
+            return Jacobian::INFINITY;
+        }
+        Jacobian { x: self.x, y: self.y, z: uint!(1_U256) }
+    }
+}
+
+impl Jacobian {
+    pub(crate) const INFINITY: Self = Self { x: uint!(1_U256), y: uint!(1_U256), z: U256::ZERO };
+
+    fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    // dbl-2009-l
+    pub(crate) fn double(&self) -> Self {
+        if self.is_infinity() || self.y.is_zero() {
+            return Self::INFINITY;
+        }
+
+        let a = mul(self.x, self.x);
+        let b = mul(self.y, self.y);
+        let c = mul(b, b);
+        let x_plus_b = add(self.x, b);
+        let d = mul2(sub(sub(mul(x_plus_b, x_plus_b), a), c));
+        let e = add(mul2(a), a);
+        let f = mul(e, e);
+
+        let x = sub(f, mul2(d));
+        let y = sub(mul(e, sub(d, x)), mul2(mul2(mul2(c))));
+        let z = mul2(mul(self.y, self.z));
+        Self { x, y, z }
+    }
+
+    // add-2007-bl
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        if self.is_infinity() {
+            return *other;
+        }
+        if other.is_infinity() {
+            return *self;
+        }
+
+        let z1z1 = mul(self.z, self.z);
+        let z2z2 = mul(other.z, other.z);
+        let u1 = mul(self.x, z2z2);
+        let u2 = mul(other.x, z1z1);
+        let s1 = mul(mul(self.y, other.z), z2z2);
+        let s2 = mul(mul(other.y, self.z), z1z1);
+
+        let h = sub(u2, u1);
+        let r = mul2(sub(s2, s1));
+        if h.is_zero() {
+            return if r.is_zero() { self.double() } else { Self::INFINITY };
+        }
+
+        let i = mul(mul2(h), mul2(h));
+        let j = mul(h, i);
+        let v = mul(u1, i);
+
+        let x = sub(sub(mul(r, r), j), mul2(v));
+        let y = sub(mul(r, sub(v, x)), mul2(mul(s1, j)));
+        let z = mul(mul2(mul(self.z, other.z)), h);
+        Self { x, y, z }
+    }
+
+    pub(crate) fn to_affine(self) -> G1Point {
+        if self.is_infinity() {
+            return G1Point::INFINITY;
+        }
+
+        let z_inv = inv(self.z);
+        let z_inv2 = mul(z_inv, z_inv);
+        G1Point::new(mul(self.x, z_inv2), mul(self.y, mul(z_inv2, z_inv)))
+    }
+}
+
+fn curve_rhs(x: U256) -> U256 {
+    add(mul(mul(x, x), x), CURVE_B)
+}
+
+fn add(a: U256, b: U256) -> U256 {
+    a.add_mod(b, BN254_BASE_MODULUS)
+}
+
+fn mul2(a: U256) -> U256 {
+    add(a, a)
+}
+
+fn sub(a: U256, b: U256) -> U256 {
+    a.add_mod(BN254_BASE_MODULUS - b, BN254_BASE_MODULUS)
+}
+
+fn mul(a: U256, b: U256) -> U256 {
+    a.mul_mod(b, BN254_BASE_MODULUS)
+}
+
+fn inv(a: U256) -> U256 {
+    a.pow_mod(BN254_BASE_MODULUS - uint!(2_U256), BN254_BASE_MODULUS)
+}
+
+/// Square root for `q ≡ 3 (mod 4)`, `None` for non-residues
+fn sqrt(a: U256) -> Option<U256> {
+    let root = a.pow_mod((BN254_BASE_MODULUS + uint!(1_U256)) >> 2, BN254_BASE_MODULUS);
+    (mul(root, root) == a).then_some(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_law() {
+        let g = G1_GENERATOR;
+        assert!(g.is_on_curve());
+        assert_eq!(g.double(), g.add(&g));
+        assert_eq!(g.mul(U256::from(3)), g.double().add(&g));
+        assert!(g.add(&g.neg()).is_infinity());
+        assert_eq!(g.add(&G1Point::INFINITY), g);
+        assert!(g.mul(U256::ZERO).is_infinity());
+    }
+
+    #[test]
+    fn test_known_multiple() {
+        // 2·G from the EIP-196 test vectors
+        assert_eq!(
+            G1_GENERATOR.double(),
+            G1Point::new(
+                uint!(0x030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3_U256),
+                uint!(0x15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4_U256),
+            )
+        );
+    }
+
+    #[test]
+    fn test_group_order() {
+        // The group order is the scalar field modulus
+        assert!(G1_GENERATOR.mul(crate::utils::BN254_MODULUS).is_infinity());
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_byte_layout() {
+        let bytes = bytemuck::bytes_of(&G1_GENERATOR);
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(&bytes[..32], &G1_GENERATOR.x.to_le_bytes::<32>());
+        assert_eq!(&bytes[32..], &G1_GENERATOR.y.to_le_bytes::<32>());
+        assert_eq!((bytes[0], bytes[32]), (1, 2));
+
+        let infinity: G1Point = bytemuck::Zeroable::zeroed();
+        assert!(infinity.is_infinity());
+        assert_eq!(*bytemuck::from_bytes::<G1Point>(bytes), G1_GENERATOR);
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        let h = G1Point::hash_to_curve(b"test");
+        assert!(h.is_on_curve());
+        assert!(!h.y.bit(0));
+        assert_eq!(h, G1Point::hash_to_curve(b"test"));
+        assert_ne!(h, G1Point::hash_to_curve(b"other"));
+    }
+}
@@ -0,0 +1,7 @@
+//! BN254 field and curve helpers
+
+pub mod g1;
+pub mod typed_field;
+
+pub use g1::{G1Point, BN254_BASE_MODULUS, G1_GENERATOR};
+pub use typed_field::{CommitmentTag, NullifierTag, RandomnessTag, SecretTag, TypedField};
@@ -0,0 +1,143 @@
+//! Phantom-tagged BN254 field elements
+//!
+//! Commitments, nullifiers, secrets and randomness are all BN254 field
+//! elements, which makes them easy to mix up. `TypedField<T>` tags each value
+//! with its role so that swapping them is a compile error:
+//!
+//! ```compile_fail
+//! use precompile::bn254::{RandomnessTag, SecretTag, TypedField};
+//! use precompile::U256;
+//!
+//! let secret = TypedField::<SecretTag>::new(U256::from(1)).unwrap();
+//! let randomness: TypedField<RandomnessTag> = secret; // mismatched tags
+//! ```
+//!
+//! Arithmetic is only defined between values with the same tag:
+//!
+//! ```compile_fail
+//! use precompile::bn254::{RandomnessTag, SecretTag, TypedField};
+//! use precompile::U256;
+//!
+//! let secret = TypedField::<SecretTag>::new(U256::from(1)).unwrap();
+//! let randomness = TypedField::<RandomnessTag>::new(U256::from(2)).unwrap();
+//! let _ = secret + randomness;
+//! ```
+
+use crate::errors::PoseidonError;
+use crate::poseidon::PoseidonHash;
+use alloy_primitives::U256;
+use core::marker::PhantomData;
+use core::ops::Add;
+
+/// Tag for Poseidon commitments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommitmentTag;
+
+/// Tag for nullifiers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NullifierTag;
+
+/// Tag for secret values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SecretTag;
+
+/// Tag for blinding randomness
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RandomnessTag;
+
+/// Tags for values supplied directly by the caller rather than derived by hashing
+pub trait InputTag {}
+
+impl InputTag for SecretTag {}
+impl InputTag for RandomnessTag {}
+
+/// BN254 field element tagged with its protocol role
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypedField<T>(U256, PhantomData<T>);
+
+impl<T> TypedField<T> {
+    /// Returns the underlying field element
+    pub fn value(&self) -> U256 {
+        self.0
+    }
+
+    /// Wraps a value already known to be a valid field element
+    fn from_valid(value: U256) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+impl<T: InputTag> TypedField<T> {
+    /// Creates a caller-supplied value, validating it against the BN254 modulus
+    pub fn new(value: U256) -> Result<Self, PoseidonError> {
+        PoseidonHash::new().validate_field_element(value)?;
+        Ok(Self::from_valid(value))
+    }
+}
+
+impl TypedField<CommitmentTag> {
+    /// Commits to `secret` with `randomness` as `H(secret, randomness)`
+    pub fn from_hash(
+        h: &PoseidonHash,
+        secret: TypedField<SecretTag>,
+        randomness: TypedField<RandomnessTag>,
+    ) -> Result<Self, PoseidonError> {
+        let commitment = h.hash_pair_production(secret.value(), randomness.value())?;
+        Ok(Self::from_valid(commitment))
+    }
+}
+
+impl TypedField<NullifierTag> {
+    /// Derives the nullifier `H(secret, leaf_index)`
+    pub fn from_secret(
+        h: &PoseidonHash,
+        secret: &TypedField<SecretTag>,
+        leaf_index: U256,
+    ) -> Result<Self, PoseidonError> {
+        let nullifier = h.hash_pair_production(secret.value(), leaf_index)?;
+        Ok(Self::from_valid(nullifier))
+    }
+}
+
+impl<T> Add for TypedField<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let modulus = PoseidonHash::new().params.modulus;
+        Self::from_valid(self.0.add_mod(rhs.0, modulus))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commitment_from_hash() {
+        let hasher = PoseidonHash::new();
+        let secret = TypedField::<SecretTag>::new(U256::from(42)).unwrap();
+        let randomness = TypedField::<RandomnessTag>::new(U256::from(12345)).unwrap();
+
+        let commitment = TypedField::<CommitmentTag>::from_hash(&hasher, secret, randomness).unwrap();
+        let expected = hasher
+            .hash_pair_production(U256::from(42), U256::from(12345))
+            .unwrap();
+        assert_eq!(commitment.value(), expected);
+    }
+
+    #[test]
+    fn test_input_validation() {
+        let modulus = PoseidonHash::new().params.modulus;
+        assert!(TypedField::<SecretTag>::new(modulus).is_err());
+        assert!(TypedField::<RandomnessTag>::new(modulus - U256::from(1)).is_ok());
+    }
+
+    #[test]
+    fn test_same_tag_addition_wraps() {
+        let modulus = PoseidonHash::new().params.modulus;
+        let a = TypedField::<SecretTag>::new(modulus - U256::from(1)).unwrap();
+        let b = TypedField::<SecretTag>::new(U256::from(3)).unwrap();
+
+        assert_eq!((a + b).value(), U256::from(2));
+    }
+}
@@ -0,0 +1,5 @@
+//! Routing between EVM-native and ZK-friendly hashes
+
+pub mod native_hash;
+
+pub use native_hash::NativeHashBridge;
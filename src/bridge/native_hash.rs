@@ -0,0 +1,135 @@
+//! Picks keccak256 or Poseidon for a byte input
+//!
+//! keccak256 is far cheaper for short inputs, but its output cannot be checked
+//! cheaply inside a circuit. Callers whose digests end up in a ZK proof set
+//! `prefer_zk` and always get Poseidon.
+//!
+//! For Poseidon the bytes are split into 31-byte big-endian chunks, which are
+//! always below the BN254 modulus, and prefixed with the input length so
+//! inputs differing only in trailing zeros hash differently. The elements are
+//! absorbed with the chained chunking from `plonk::poly_hasher`.
+
+use crate::errors::PoseidonError;
+use crate::plonk::hash_polynomial_evaluations;
+use crate::poseidon::{bytes_to_elements, BYTES_PER_ELEMENT};
+use crate::utils::field_to_bytes32;
+use alloy_primitives::{keccak256, U256};
+
+/// Inputs shorter than this go to keccak256 unless ZK output is preferred
+pub const KECCAK_THRESHOLD: usize = 64;
+
+/// Elements absorbed per Poseidon permutation after the running digest
+const ELEMENTS_PER_PERMUTATION: usize = 15;
+
+/// Static gas of the KECCAK256 opcode
+const KECCAK_BASE_GAS: u64 = 30;
+
+/// Gas per 32-byte word hashed by KECCAK256
+const KECCAK_WORD_GAS: u64 = 6;
+
+/// Estimated fixed gas of a Poseidon call, covering the field encoding
+const POSEIDON_BASE_GAS: u64 = 2_000;
+
+/// Estimated gas of one Poseidon permutation on Stylus
+const POSEIDON_PERMUTATION_GAS: u64 = 20_000;
+
+/// Hashes bytes with the cheapest hash allowed by the caller
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NativeHashBridge {
+    prefer_zk: bool,
+}
+
+impl NativeHashBridge {
+    /// Creates a bridge, forcing Poseidon when `prefer_zk` is set
+    pub fn new(prefer_zk: bool) -> Self {
+        Self { prefer_zk }
+    }
+
+    /// Whether `input` is routed to keccak256
+    pub fn uses_keccak(&self, input: &[u8]) -> bool {
+        !self.prefer_zk && input.len() < KECCAK_THRESHOLD
+    }
+
+    /// Hashes `input`, returning a 32-byte digest
+    pub fn hash(&self, input: &[u8]) -> Result<Vec<u8>, PoseidonError> {
+        if self.uses_keccak(input) {
+            return Ok(keccak256(input).to_vec());
+        }
+
+        let digest = hash_polynomial_evaluations(&encode_field_elements(input))?;
+        Ok(field_to_bytes32(digest).to_vec())
+    }
+
+    /// Estimates the gas `hash` spends on `input`
+    pub fn estimate_cost(&self, input: &[u8]) -> u64 {
+        if self.uses_keccak(input) {
+            return KECCAK_BASE_GAS + KECCAK_WORD_GAS * input.len().div_ceil(32) as u64;
+        }
+
+        let elements = 1 + input.len().div_ceil(BYTES_PER_ELEMENT);
+        let permutations = elements.div_ceil(ELEMENTS_PER_PERMUTATION) as u64;
+        POSEIDON_BASE_GAS + POSEIDON_PERMUTATION_GAS * permutations
+    }
+}
+
+/// Encodes bytes as their length followed by 31-byte chunks
+fn encode_field_elements(input: &[u8]) -> Vec<U256> {
+    let mut elements = Vec::with_capacity(1 + input.len().div_ceil(BYTES_PER_ELEMENT));
+    elements.push(U256::from(input.len()));
+    elements.extend(bytes_to_elements(input));
+    elements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_routing() {
+        let short = [0xabu8; 10];
+        let long = [0xabu8; 100];
+
+        let fast = NativeHashBridge::new(false);
+        assert!(fast.uses_keccak(&short));
+        assert!(!fast.uses_keccak(&long));
+        assert!(!fast.uses_keccak(&[0u8; KECCAK_THRESHOLD]));
+        assert_eq!(fast.hash(&short).unwrap(), keccak256(short).to_vec());
+
+        let zk = NativeHashBridge::new(true);
+        assert!(!zk.uses_keccak(&short));
+        assert_ne!(zk.hash(&short).unwrap(), fast.hash(&short).unwrap());
+    }
+
+    #[test]
+    fn test_outputs_are_deterministic() {
+        for bridge in [NativeHashBridge::new(false), NativeHashBridge::new(true)] {
+            for input in [&b""[..], &b"hello"[..], &[7u8; 200][..]] {
+                let digest = bridge.hash(input).unwrap();
+                assert_eq!(digest.len(), 32);
+                assert_eq!(digest, bridge.hash(input).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_poseidon_encoding_separates_trailing_zeros() {
+        let bridge = NativeHashBridge::new(true);
+        assert_ne!(bridge.hash(&[1, 2]).unwrap(), bridge.hash(&[1, 2, 0]).unwrap());
+        assert_ne!(bridge.hash(&[]).unwrap(), bridge.hash(&[0]).unwrap());
+    }
+
+    #[test]
+    fn test_estimate_cost() {
+        let bridge = NativeHashBridge::new(false);
+        assert_eq!(bridge.estimate_cost(&[0u8; 32]), 36);
+        assert_eq!(bridge.estimate_cost(&[0u8; 33]), 42);
+        assert!(bridge.estimate_cost(&[0u8; 63]) < bridge.estimate_cost(&[0u8; 64]));
+
+        let zk = NativeHashBridge::new(true);
+        assert_eq!(zk.estimate_cost(&[0u8; 31]), POSEIDON_BASE_GAS + POSEIDON_PERMUTATION_GAS);
+        assert_eq!(
+            zk.estimate_cost(&[0u8; 31 * 15]),
+            POSEIDON_BASE_GAS + 2 * POSEIDON_PERMUTATION_GAS
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Conversion between circom's BigInt limbs and `U256`
+//!
+//! circom's witness calculator stores the magnitude of a field element as
+//! 32-bit limbs in little-endian order, eight limbs for the BN254 scalar
+//! field. Field elements are never negative, so only the magnitude is
+//! converted.
+
+use crate::errors::CircomError;
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::U256;
+
+/// 32-bit limbs per BN254 field element
+pub const CIRCOM_LIMBS: usize = 8;
+
+/// Assembles little-endian 32-bit limbs into a field element
+pub fn circom_bigint_to_u256(limbs: &[u32]) -> Result<U256, CircomError> {
+    // Zero limbs past the eighth are tolerated, anything else overflows
+    let significant = limbs.iter().rposition(|&limb| limb != 0).map_or(0, |i| i + 1);
+    if significant > CIRCOM_LIMBS {
+        return Err(CircomError::BigIntOverflow(significant));
+    }
+
+    let value = limbs[..significant]
+        .iter()
+        .rev()
+        .fold(U256::ZERO, |acc, &limb| (acc << 32) | U256::from(limb));
+    if value >= BN254_MODULUS {
+        return Err(CircomError::FieldElementTooLarge(value));
+    }
+    Ok(value)
+}
+
+/// Splits a value into eight little-endian 32-bit limbs
+pub fn u256_to_circom_bigint(value: U256) -> Vec<u32> {
+    value
+        .as_limbs()
+        .iter()
+        .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+        .collect()
+}
+
+/// Whether a field element survives the limb encoding unchanged
+pub fn verify_round_trip(value: U256) -> bool {
+    circom_bigint_to_u256(&u256_to_circom_bigint(value)).is_ok_and(|decoded| decoded == value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::keccak256;
+
+    #[test]
+    fn test_round_trip_random_elements() {
+        for i in 0u64..20 {
+            let value = U256::from_be_bytes(keccak256(i.to_be_bytes()).0) % BN254_MODULUS;
+            let limbs = u256_to_circom_bigint(value);
+            assert_eq!(limbs.len(), CIRCOM_LIMBS);
+            assert!(verify_round_trip(value));
+        }
+        assert!(verify_round_trip(BN254_MODULUS - U256::from(1)));
+    }
+
+    #[test]
+    fn test_limb_order() {
+        let value = U256::from(0x1_0000_0002u64);
+        assert_eq!(u256_to_circom_bigint(value), vec![2, 1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(circom_bigint_to_u256(&[2, 1]).unwrap(), value);
+        assert_eq!(circom_bigint_to_u256(&[]).unwrap(), U256::ZERO);
+    }
+
+    #[test]
+    fn test_rejects_out_of_range() {
+        let mut limbs = vec![0u32; 9];
+        limbs[8] = 1;
+        assert!(matches!(circom_bigint_to_u256(&limbs), Err(CircomError::BigIntOverflow(9))));
+
+        let mut padded = u256_to_circom_bigint(U256::from(7));
+        padded.extend([0, 0]);
+        assert_eq!(circom_bigint_to_u256(&padded).unwrap(), U256::from(7));
+
+        assert!(!verify_round_trip(BN254_MODULUS));
+    }
+}
@@ -0,0 +1,179 @@
+//! R1CS witness checking
+//!
+//! A circom R1CS constraint is a triple of linear combinations `(A, B, C)`
+//! over the witness, satisfied when `<A, w> * <B, w> - <C, w> = 0` in the
+//! BN254 scalar field. Each linear combination is stored sparsely as
+//! `(signal index, coefficient)` terms, as in the `.r1cs` file, so checking
+//! a constraint costs one multiplication per non-zero term.
+//!
+//! By circom convention signal 0 is the constant 1.
+
+use crate::errors::CircomError;
+use crate::poseidon::PoseidonParams;
+use alloy_primitives::U256;
+
+/// Sparse linear combination of witness signals
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SparseVec {
+    pub terms: Vec<(usize, U256)>,
+}
+
+impl SparseVec {
+    /// Creates a linear combination from `(signal index, coefficient)` terms
+    pub fn new(terms: Vec<(usize, U256)>) -> Self {
+        Self { terms }
+    }
+
+    /// Evaluates the linear combination at `witness`
+    pub fn dot(&self, witness: &[U256], modulus: U256) -> Result<U256, CircomError> {
+        let mut sum = U256::ZERO;
+        for &(index, coefficient) in &self.terms {
+            if coefficient >= modulus {
+                return Err(CircomError::FieldElementTooLarge(coefficient));
+            }
+            let signal = witness.get(index).ok_or(CircomError::WitnessIndexOutOfRange {
+                index,
+                len: witness.len(),
+            })?;
+            sum = sum.add_mod(coefficient.mul_mod(*signal, modulus), modulus);
+        }
+        Ok(sum)
+    }
+}
+
+/// Rank-1 constraint system in circom's `A * B = C` form
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct R1CS {
+    pub constraints: Vec<(SparseVec, SparseVec, SparseVec)>,
+}
+
+/// Checks that `witness` satisfies every constraint of `r1cs`
+pub fn verify_r1cs_witness(r1cs: &R1CS, witness: &[U256]) -> Result<bool, CircomError> {
+    let modulus = PoseidonParams::default().modulus;
+
+    if let Some(&one) = witness.first() {
+        if one != U256::from(1) {
+            return Err(CircomError::InvalidConstantSignal(one));
+        }
+    }
+    if let Some(&signal) = witness.iter().find(|signal| **signal >= modulus) {
+        return Err(CircomError::FieldElementTooLarge(signal));
+    }
+
+    for (a, b, c) in &r1cs.constraints {
+        let product = a.dot(witness, modulus)?.mul_mod(b.dot(witness, modulus)?, modulus);
+        if product != c.dot(witness, modulus)? {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modulus() -> U256 {
+        PoseidonParams::default().modulus
+    }
+
+    fn lc(terms: &[(usize, u64)]) -> SparseVec {
+        SparseVec::new(terms.iter().map(|&(i, c)| (i, U256::from(c))).collect())
+    }
+
+    /// Three Poseidon S-box rounds `s' = (s + c)^5` followed by a final constant addition
+    ///
+    /// Signals: 0 = one, 1 = input, then (square, fourth power, output) per round, then the
+    /// circuit output. That gives 3 constraints per round plus one, 10 in total.
+    fn sbox_circuit(round_constants: &[u64; 4]) -> R1CS {
+        let mut constraints = Vec::new();
+        let mut state = 1;
+
+        for (round, &c) in round_constants[..3].iter().enumerate() {
+            let square = 2 + round * 3;
+            let fourth = square + 1;
+            let out = square + 2;
+            let shifted = lc(&[(state, 1), (0, c)]);
+
+            constraints.push((shifted.clone(), shifted.clone(), lc(&[(square, 1)])));
+            constraints.push((lc(&[(square, 1)]), lc(&[(square, 1)]), lc(&[(fourth, 1)])));
+            constraints.push((lc(&[(fourth, 1)]), shifted, lc(&[(out, 1)])));
+            state = out;
+        }
+
+        let output = state + 1;
+        constraints.push((
+            lc(&[(state, 1), (0, round_constants[3])]),
+            lc(&[(0, 1)]),
+            lc(&[(output, 1)]),
+        ));
+
+        R1CS { constraints }
+    }
+
+    fn sbox_witness(input: U256, round_constants: &[u64; 4]) -> Vec<U256> {
+        let p = modulus();
+        let mut witness = vec![U256::from(1), input];
+        let mut state = input;
+
+        for &c in &round_constants[..3] {
+            let shifted = state.add_mod(U256::from(c), p);
+            let square = shifted.mul_mod(shifted, p);
+            let fourth = square.mul_mod(square, p);
+            state = fourth.mul_mod(shifted, p);
+            witness.extend([square, fourth, state]);
+        }
+
+        witness.push(state.add_mod(U256::from(round_constants[3]), p));
+        witness
+    }
+
+    const ROUND_CONSTANTS: [u64; 4] = [11, 22, 33, 44];
+
+    #[test]
+    fn test_valid_witness() {
+        let r1cs = sbox_circuit(&ROUND_CONSTANTS);
+        assert_eq!(r1cs.constraints.len(), 10);
+
+        let witness = sbox_witness(U256::from(12345), &ROUND_CONSTANTS);
+        assert!(verify_r1cs_witness(&r1cs, &witness).unwrap());
+    }
+
+    #[test]
+    fn test_tampered_witness() {
+        let r1cs = sbox_circuit(&ROUND_CONSTANTS);
+        let witness = sbox_witness(U256::from(12345), &ROUND_CONSTANTS);
+
+        for i in 1..witness.len() {
+            let mut tampered = witness.clone();
+            tampered[i] = tampered[i].add_mod(U256::from(1), modulus());
+            assert!(!verify_r1cs_witness(&r1cs, &tampered).unwrap(), "signal {}", i);
+        }
+    }
+
+    #[test]
+    fn test_malformed_inputs() {
+        let r1cs = sbox_circuit(&ROUND_CONSTANTS);
+        let witness = sbox_witness(U256::from(7), &ROUND_CONSTANTS);
+
+        assert!(matches!(
+            verify_r1cs_witness(&r1cs, &witness[..5]),
+            Err(CircomError::WitnessIndexOutOfRange { .. })
+        ));
+
+        let mut bad_one = witness.clone();
+        bad_one[0] = U256::from(2);
+        assert!(matches!(
+            verify_r1cs_witness(&r1cs, &bad_one),
+            Err(CircomError::InvalidConstantSignal(_))
+        ));
+
+        let mut too_large = witness;
+        too_large[1] = modulus();
+        assert!(matches!(
+            verify_r1cs_witness(&r1cs, &too_large),
+            Err(CircomError::FieldElementTooLarge(_))
+        ));
+    }
+}
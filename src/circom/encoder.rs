@@ -0,0 +1,149 @@
+//! Encoding of Rust values into circom input signals
+//!
+//! circom witnesses take every signal as a decimal string. Signals are
+//! produced as `(name, value)` pairs, where names may use circom's array
+//! (`in[0]`) and member (`pt.x`) syntax, and are then folded into the nested
+//! `input.json` layout expected by the witness generator.
+
+use alloy_primitives::U256;
+use serde_json::{Map, Value};
+
+/// Encoder from Rust values to circom signal assignments
+pub struct CircomSignalEncoder;
+
+/// One component of a signal name
+enum PathSegment<'a> {
+    Field(&'a str),
+    Index(usize),
+}
+
+impl CircomSignalEncoder {
+    /// Encodes a field element as a single decimal signal
+    pub fn encode_field(name: &str, value: U256) -> (String, String) {
+        (name.to_string(), value.to_string())
+    }
+
+    /// Decomposes `value` into `bits` little-endian bit signals `name[0]`, `name[1]`, ...
+    pub fn encode_bits(name: &str, value: U256, bits: usize) -> Vec<(String, String)> {
+        (0..bits)
+            .map(|i| {
+                let bit = i < 256 && value.bit(i);
+                (format!("{}[{}]", name, i), u8::from(bit).to_string())
+            })
+            .collect()
+    }
+
+    /// Encodes an affine curve point `(x, y)` as `name.x` and `name.y` signals
+    pub fn encode_point(name: &str, pt: &(U256, U256)) -> Vec<(String, String)> {
+        vec![
+            Self::encode_field(&format!("{}.x", name), pt.0),
+            Self::encode_field(&format!("{}.y", name), pt.1),
+        ]
+    }
+
+    /// Folds signal assignments into a circom `input.json` object
+    pub fn build_input_json(signals: &[(String, String)]) -> Value {
+        let mut root = Value::Object(Map::new());
+        for (name, value) in signals {
+            let path = Self::parse_name(name);
+            Self::insert(&mut root, &path, Value::String(value.clone()));
+        }
+        root
+    }
+
+    /// Splits `a.b[2][3]` into its member and index segments
+    fn parse_name(name: &str) -> Vec<PathSegment<'_>> {
+        let mut segments = Vec::new();
+        for part in name.split('.') {
+            let (field, mut rest) = part.split_at(part.find('[').unwrap_or(part.len()));
+            segments.push(PathSegment::Field(field));
+
+            while let Some(close) = rest.find(']') {
+                if let Ok(index) = rest[1..close].parse() {
+                    segments.push(PathSegment::Index(index));
+                }
+                rest = &rest[close + 1..];
+            }
+        }
+        segments
+    }
+
+    /// Inserts `value` at `path`, creating intermediate objects and arrays
+    fn insert(node: &mut Value, path: &[PathSegment<'_>], value: Value) {
+        let Some((head, tail)) = path.split_first() else {
+            *node = value;
+            return;
+        };
+
+        let child = match head {
+            PathSegment::Field(field) => {
+                if !node.is_object() {
+                    *node = Value::Object(Map::new());
+                }
+                node.as_object_mut()
+                    .unwrap()
+                    .entry(field.to_string())
+                    .or_insert(Value::Null)
+            }
+            PathSegment::Index(index) => {
+                if !node.is_array() {
+                    *node = Value::Array(Vec::new());
+                }
+                let items = node.as_array_mut().unwrap();
+                if items.len() <= *index {
+                    items.resize(*index + 1, Value::Null);
+                }
+                &mut items[*index]
+            }
+        };
+
+        Self::insert(child, tail, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_encode_field() {
+        let (name, value) = CircomSignalEncoder::encode_field("in", U256::from(1234));
+        assert_eq!(name, "in");
+        assert_eq!(value, "1234");
+    }
+
+    #[test]
+    fn test_encode_bits() {
+        let signals = CircomSignalEncoder::encode_bits("b", U256::from(0b1011), 5);
+        let values: Vec<&str> = signals.iter().map(|(_, v)| v.as_str()).collect();
+
+        assert_eq!(signals[0].0, "b[0]");
+        assert_eq!(signals[4].0, "b[4]");
+        assert_eq!(values, ["1", "1", "0", "1", "0"]);
+    }
+
+    #[test]
+    fn test_poseidon2_input_json() {
+        // circomlib's Poseidon(2) template takes `signal input inputs[2]`
+        let signals = vec![
+            CircomSignalEncoder::encode_field("inputs[0]", U256::from(1)),
+            CircomSignalEncoder::encode_field("inputs[1]", U256::from(2)),
+        ];
+
+        let input = CircomSignalEncoder::build_input_json(&signals);
+        assert_eq!(input, json!({ "inputs": ["1", "2"] }));
+    }
+
+    #[test]
+    fn test_nested_input_json() {
+        let mut signals = CircomSignalEncoder::encode_point("pk", &(U256::from(5), U256::from(6)));
+        signals.extend(CircomSignalEncoder::encode_bits("path", U256::from(2), 2));
+
+        let input = CircomSignalEncoder::build_input_json(&signals);
+        assert_eq!(
+            input,
+            json!({ "pk": { "x": "5", "y": "6" }, "path": ["0", "1"] })
+        );
+    }
+}
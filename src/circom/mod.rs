@@ -0,0 +1,9 @@
+//! Helpers for preparing circom circuit inputs
+
+pub mod bigint;
+pub mod constraint_verifier;
+pub mod encoder;
+
+pub use bigint::{circom_bigint_to_u256, u256_to_circom_bigint, verify_round_trip};
+pub use constraint_verifier::{verify_r1cs_witness, SparseVec, R1CS};
+pub use encoder::CircomSignalEncoder;
@@ -0,0 +1,5 @@
+//! Programmatic generation of circuits for the precompile hashes
+
+pub mod poseidon_r1cs;
+
+pub use poseidon_r1cs::{PoseidonCircuitBuilder, R1CSDescription};
@@ -0,0 +1,301 @@
+//! R1CS generation for the circomlib Poseidon hash
+//!
+//! The circuit follows the permutation computed by poseidon-rs (and
+//! circomlib's `Poseidon(n)` template) with width `t = n + 1`: each round adds
+//! round constants, applies x^5 to every element (full rounds) or to the
+//! first one (partial rounds), and multiplies by the MDS matrix.
+//!
+//! Round constant addition and the MDS layer are linear, so they are folded
+//! into the linear combinations carried between S-boxes and cost no
+//! constraints. Each S-box takes three constraints (x^2, x^4, x^5) and one
+//! final constraint binds the output signal, for `3 * (t * R_F + R_P) + 1`
+//! constraints in total.
+//!
+//! Signals are laid out as circom does: 0 is the constant 1, then the output,
+//! then the inputs, then intermediate signals.
+
+use std::collections::BTreeMap;
+
+use crate::circom::{SparseVec, R1CS};
+use crate::errors::CircomError;
+use crate::poseidon::permutation::{constants_for_width, PermutationConstants};
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::U256;
+
+/// Linear combination of signals, keyed by signal index
+type LinearCombination = BTreeMap<usize, U256>;
+
+/// Generated R1CS together with its signal counts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct R1CSDescription {
+    pub n_wires: usize,
+    pub n_pub_outputs: usize,
+    pub n_pub_inputs: usize,
+    pub n_prv_inputs: usize,
+    pub r1cs: R1CS,
+}
+
+/// Builder for the R1CS of a Poseidon hash over a fixed number of inputs
+pub struct PoseidonCircuitBuilder {
+    inputs: usize,
+    width: usize,
+    constants: &'static PermutationConstants,
+}
+
+impl PoseidonCircuitBuilder {
+    /// Creates a builder for `inputs` inputs, which needs `width == inputs + 1`
+    pub fn new(inputs: usize, width: usize) -> Result<Self, CircomError> {
+        let constants = constants_for_width(width)
+            .filter(|_| inputs > 0 && width == inputs + 1)
+            .ok_or(CircomError::UnsupportedShape { inputs, width })?;
+
+        Ok(Self { inputs, width, constants })
+    }
+
+    /// Generates the constraints of the circuit
+    pub fn build(&self) -> R1CSDescription {
+        let mut constraints = Vec::new();
+        let mut next_wire = 2 + self.inputs;
+
+        // state = [0, in_1, ..., in_n]
+        let mut state: Vec<LinearCombination> = (0..self.width)
+            .map(|i| {
+                let mut lc = LinearCombination::new();
+                if i > 0 {
+                    lc.insert(1 + i, U256::from(1));
+                }
+                lc
+            })
+            .collect();
+
+        for round in 0..self.constants.full_rounds + self.constants.partial_rounds {
+            for (i, lc) in state.iter_mut().enumerate() {
+                let constant = self.constants.round_constants[round * self.width + i];
+                add_term(lc, 0, constant);
+            }
+
+            let sbox_count = if self.is_full_round(round) { self.width } else { 1 };
+            for lc in state.iter_mut().take(sbox_count) {
+                let x = to_sparse(lc);
+                let (square, fourth, fifth) = (next_wire, next_wire + 1, next_wire + 2);
+                next_wire += 3;
+
+                constraints.push((x.clone(), x.clone(), single(square)));
+                constraints.push((single(square), single(square), single(fourth)));
+                constraints.push((single(fourth), x, single(fifth)));
+
+                *lc = LinearCombination::from([(fifth, U256::from(1))]);
+            }
+
+            state = self.mix(&state);
+        }
+
+        // output = state[0]
+        constraints.push((to_sparse(&state[0]), single(0), single(1)));
+
+        R1CSDescription {
+            n_wires: next_wire,
+            n_pub_outputs: 1,
+            n_pub_inputs: 0,
+            n_prv_inputs: self.inputs,
+            r1cs: R1CS { constraints },
+        }
+    }
+
+    /// Computes the full witness for `inputs` by solving the constraints in order
+    pub fn generate_witness(&self, inputs: &[U256]) -> Result<Vec<U256>, CircomError> {
+        if inputs.len() != self.inputs {
+            return Err(CircomError::InputCountMismatch {
+                expected: self.inputs,
+                got: inputs.len(),
+            });
+        }
+        if let Some(&input) = inputs.iter().find(|input| **input >= BN254_MODULUS) {
+            return Err(CircomError::FieldElementTooLarge(input));
+        }
+
+        let description = self.build();
+        let mut witness = vec![U256::ZERO; description.n_wires];
+        witness[0] = U256::from(1);
+        witness[2..2 + self.inputs].copy_from_slice(inputs);
+
+        // Every constraint defines a single new signal in C from signals already known
+        for (a, b, c) in &description.r1cs.constraints {
+            let value = a
+                .dot(&witness, BN254_MODULUS)?
+                .mul_mod(b.dot(&witness, BN254_MODULUS)?, BN254_MODULUS);
+            witness[c.terms[0].0] = value;
+        }
+
+        Ok(witness)
+    }
+
+    /// Serializes the circuit in circom's `.r1cs` binary format (version 1)
+    pub fn to_circom_r1cs_binary(&self) -> Vec<u8> {
+        let description = self.build();
+
+        let mut header = Vec::new();
+        header.extend_from_slice(&32u32.to_le_bytes());
+        header.extend_from_slice(&BN254_MODULUS.to_le_bytes::<32>());
+        header.extend_from_slice(&(description.n_wires as u32).to_le_bytes());
+        header.extend_from_slice(&(description.n_pub_outputs as u32).to_le_bytes());
+        header.extend_from_slice(&(description.n_pub_inputs as u32).to_le_bytes());
+        header.extend_from_slice(&(description.n_prv_inputs as u32).to_le_bytes());
+        header.extend_from_slice(&(description.n_wires as u64).to_le_bytes());
+        header.extend_from_slice(&(description.r1cs.constraints.len() as u32).to_le_bytes());
+
+        let mut constraints = Vec::new();
+        for (a, b, c) in &description.r1cs.constraints {
+            for lc in [a, b, c] {
+                constraints.extend_from_slice(&(lc.terms.len() as u32).to_le_bytes());
+                for (wire, coefficient) in &lc.terms {
+                    constraints.extend_from_slice(&(*wire as u32).to_le_bytes());
+                    constraints.extend_from_slice(&coefficient.to_le_bytes::<32>());
+                }
+            }
+        }
+
+        // Signals map to labels one to one
+        let labels: Vec<u8> = (0..description.n_wires as u64)
+            .flat_map(|label| label.to_le_bytes())
+            .collect();
+
+        let mut out = Vec::new();
+        out.extend_from_slice(b"r1cs");
+        out.extend_from_slice(&1u32.to_le_bytes());
+        out.extend_from_slice(&3u32.to_le_bytes());
+        for (section_type, data) in [(1u32, header), (2, constraints), (3, labels)] {
+            out.extend_from_slice(&section_type.to_le_bytes());
+            out.extend_from_slice(&(data.len() as u64).to_le_bytes());
+            out.extend_from_slice(&data);
+        }
+        out
+    }
+
+    fn is_full_round(&self, round: usize) -> bool {
+        let half_full = self.constants.full_rounds / 2;
+        round < half_full || round >= half_full + self.constants.partial_rounds
+    }
+
+    /// Multiplies the state by the MDS matrix
+    fn mix(&self, state: &[LinearCombination]) -> Vec<LinearCombination> {
+        self.constants
+            .mds
+            .iter()
+            .map(|row| {
+                let mut mixed = LinearCombination::new();
+                for (coefficient, lc) in row.iter().zip(state) {
+                    for (&wire, &value) in lc {
+                        add_term(&mut mixed, wire, coefficient.mul_mod(value, BN254_MODULUS));
+                    }
+                }
+                mixed
+            })
+            .collect()
+    }
+}
+
+fn add_term(lc: &mut LinearCombination, wire: usize, value: U256) {
+    let entry = lc.entry(wire).or_insert(U256::ZERO);
+    *entry = entry.add_mod(value, BN254_MODULUS);
+}
+
+fn to_sparse(lc: &LinearCombination) -> SparseVec {
+    SparseVec::new(
+        lc.iter()
+            .filter(|(_, value)| !value.is_zero())
+            .map(|(&wire, &value)| (wire, value))
+            .collect(),
+    )
+}
+
+fn single(wire: usize) -> SparseVec {
+    SparseVec::new(vec![(wire, U256::from(1))])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circom::verify_r1cs_witness;
+    use crate::poseidon::PoseidonHash;
+
+    #[test]
+    fn test_witness_satisfies_r1cs_and_matches_poseidon() {
+        let hasher = PoseidonHash::new();
+
+        let builder = PoseidonCircuitBuilder::new(1, 2).unwrap();
+        let input = U256::from(42);
+        let witness = builder.generate_witness(&[input]).unwrap();
+        assert!(verify_r1cs_witness(&builder.build().r1cs, &witness).unwrap());
+        assert_eq!(witness[1], hasher.hash_single_production(input).unwrap());
+
+        let builder = PoseidonCircuitBuilder::new(2, 3).unwrap();
+        let (left, right) = (U256::from(1), U256::from(2));
+        let witness = builder.generate_witness(&[left, right]).unwrap();
+        assert!(verify_r1cs_witness(&builder.build().r1cs, &witness).unwrap());
+        assert_eq!(witness[1], hasher.hash_pair_production(left, right).unwrap());
+    }
+
+    #[test]
+    fn test_constraint_count() {
+        let builder = PoseidonCircuitBuilder::new(2, 3).unwrap();
+        let description = builder.build();
+
+        // 3 constraints per S-box (8 full rounds of 3, 57 partial rounds of 1) plus the output
+        assert_eq!(description.r1cs.constraints.len(), 3 * (8 * 3 + 57) + 1);
+        assert_eq!(description.n_wires, 4 + 3 * (8 * 3 + 57));
+    }
+
+    #[test]
+    fn test_tampered_output_rejected() {
+        let builder = PoseidonCircuitBuilder::new(2, 3).unwrap();
+        let mut witness = builder.generate_witness(&[U256::from(1), U256::from(2)]).unwrap();
+        witness[1] = witness[1].add_mod(U256::from(1), BN254_MODULUS);
+
+        assert!(!verify_r1cs_witness(&builder.build().r1cs, &witness).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_shapes() {
+        assert!(PoseidonCircuitBuilder::new(0, 1).is_err());
+        assert!(PoseidonCircuitBuilder::new(2, 4).is_err());
+        assert!(PoseidonCircuitBuilder::new(17, 18).is_err());
+
+        let builder = PoseidonCircuitBuilder::new(2, 3).unwrap();
+        assert!(builder.generate_witness(&[U256::from(1)]).is_err());
+    }
+
+    #[test]
+    fn test_r1cs_binary_layout() {
+        let builder = PoseidonCircuitBuilder::new(1, 2).unwrap();
+        let description = builder.build();
+        let binary = builder.to_circom_r1cs_binary();
+
+        assert_eq!(&binary[..4], b"r1cs");
+        assert_eq!(u32::from_le_bytes(binary[4..8].try_into().unwrap()), 1);
+        assert_eq!(u32::from_le_bytes(binary[8..12].try_into().unwrap()), 3);
+
+        // Header section
+        assert_eq!(u32::from_le_bytes(binary[12..16].try_into().unwrap()), 1);
+        let header_len = u64::from_le_bytes(binary[16..24].try_into().unwrap()) as usize;
+        let header = &binary[24..24 + header_len];
+        assert_eq!(u32::from_le_bytes(header[..4].try_into().unwrap()), 32);
+        assert_eq!(U256::from_le_slice(&header[4..36]), BN254_MODULUS);
+        assert_eq!(
+            u32::from_le_bytes(header[36..40].try_into().unwrap()) as usize,
+            description.n_wires
+        );
+        assert_eq!(
+            u32::from_le_bytes(header[header_len - 4..].try_into().unwrap()) as usize,
+            description.r1cs.constraints.len()
+        );
+
+        // Section sizes account for the whole file
+        let mut offset = 12;
+        for _ in 0..3 {
+            let len = u64::from_le_bytes(binary[offset + 4..offset + 12].try_into().unwrap());
+            offset += 12 + len as usize;
+        }
+        assert_eq!(offset, binary.len());
+    }
+}
@@ -2,15 +2,31 @@
 //! 
 //! Provides command-line interface for generating and managing precompiles.
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use colored::*;
-use std::process::Command;
-use anyhow::Result;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use anyhow::{anyhow, Result};
+
+use crate::abi_export::{abi_to_json, generate_abi};
+use crate::config::{Config, DEFAULT_CONFIG, DEFAULT_DEPLOY_TIMEOUT};
+use crate::deployment::{parse_deploy_tx_hash, DeployMonitor, DeploymentVerifier, TestVector};
+use crate::poseidon::{from_config, poseidon_gas_cost, IPoseidonHash, PoseidonHash};
+use crate::utils::{hex_to_u256, u256_to_hex};
+use alloy_primitives::U256;
+use alloy_sol_types::SolCall;
+use serde::Deserialize;
+use serde_json::json;
 
 #[derive(Parser)]
 #[command(name = "stylus-forge")]
 #[command(about = "A CLI tool for creating and managing Arbitrum Stylus precompiles", long_about = None)]
 pub struct Cli {
+    /// Print a JSON result object instead of colored text
+    #[arg(long, global = true)]
+    pub json: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -40,101 +56,1172 @@ pub enum Commands {
         pattern: Option<String>,
     },
     
+    /// Write a default Stylus.toml
+    Init {
+        /// Overwrite an existing config file
+        #[arg(short, long)]
+        force: bool,
+    },
+
     /// Deploy to Arbitrum Stylus
     Deploy {
-        /// Network to deploy to
+        /// Network to deploy to, defaults to `[network] name`
         #[arg(short, long)]
-        network: String,
+        network: Option<String>,
         
         /// Private key for deployment
         #[arg(short, long)]
         private_key: Option<String>,
+
+        /// RPC endpoint, defaults to the public endpoint of the network
+        #[arg(short, long)]
+        rpc_url: Option<String>,
+
+        /// Wait until the deployment transaction is confirmed
+        #[arg(short, long)]
+        wait: bool,
+
+        /// Seconds to wait for confirmation [default: 300]
+        #[arg(long)]
+        timeout: Option<u64>,
+
+        /// JSON file with the `PoseidonParams` to deploy, defaults to the circomlib parameters
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+    },
+
+    /// Export a precompile interface's ABI
+    ExportAbi {
+        /// File to write, stdout when omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = AbiFormat::Json)]
+        format: AbiFormat,
+
+        /// Interface to export
+        #[arg(short, long, default_value = "IPoseidonHash")]
+        interface: String,
+    },
+
+    /// Compute a Poseidon hash
+    Hash {
+        /// Field elements as 0x-prefixed hex, decimal, or `zero`
+        #[arg(required = true)]
+        inputs: Vec<String>,
+
+        /// Hash function to apply [default: poseidon-n]
+        #[arg(long, value_enum)]
+        function: Option<HashFunction>,
+
+        /// Output format [default: hex]
+        #[arg(long, value_enum)]
+        format: Option<HashFormat>,
+    },
+
+    /// ABI-decode raw IPoseidonHash calldata
+    Decode {
+        /// 0x-prefixed calldata, selector included
+        calldata: String,
+    },
+
+    /// Check that a deployed precompile returns the expected hashes
+    VerifyDeployment {
+        /// Address of the deployed Stylus contract
+        address: String,
+
+        /// RPC endpoint of the network it is deployed on, defaults to `[network] rpc_url`
+        #[arg(short, long)]
+        rpc_url: Option<String>,
+
+        /// JSON file of `{ "function", "inputs" }` vectors, replacing the built-in ones
+        #[arg(long)]
+        test_vector_file: Option<PathBuf>,
+    },
+
+    /// Estimate the gas of a Poseidon call
+    EstimateGas {
+        /// Hash function to estimate
+        #[arg(long, value_enum, default_value_t = HashFunction::Poseidon1)]
+        function: HashFunction,
+
+        /// Number of input elements, fixed by poseidon1 and poseidon2
+        #[arg(short, long)]
+        n_elements: Option<usize>,
+
+        /// L2 gas price in gwei
+        #[arg(long, default_value_t = 0.01)]
+        gwei_price: f64,
+
+        /// ETH price in USD, a placeholder unless set
+        #[arg(long, default_value_t = 3000.0)]
+        eth_usd_price: f64,
+    },
+
+    /// List the precompiles available as templates for `generate`
+    List {
+        /// Also print each template's description and Solidity interface
+        #[arg(short, long)]
+        verbose: bool,
     },
 }
 
-pub fn run() -> Result<()> {
-    let cli = Cli::parse();
-    
-    match cli.command {
-        Commands::Generate { name, description } => {
-            println!("{}", "🔨 Generating new precompile...".bright_blue());
-            
-            // Run the generate script
-            let status = Command::new("bash")
-                .arg("scripts/generate_precompile.sh")
-                .arg(&name)
-                .arg(&description)
-                .status()?;
-                
-            if status.success() {
-                println!("{}", format!("✅ Successfully generated {} precompile!", name).bright_green());
-            } else {
-                println!("{}", "❌ Failed to generate precompile".bright_red());
-            }
+/// A precompile shipped with the crate that new precompiles can start from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Template {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// `sol!` interface the precompile dispatches on
+    pub interface: &'static str,
+}
+
+/// Precompiles listed by `stylus-forge list`
+pub static TEMPLATES: &[Template] = &[
+    Template {
+        name: "poseidon",
+        description: "Poseidon hash over the BN254 scalar field",
+        interface: "IPoseidonHash",
+    },
+    Template {
+        name: "mimc",
+        description: "MiMC-7 hash over BN254",
+        interface: "IMiMCHash",
+    },
+    Template {
+        name: "rescue",
+        description: "Rescue-Prime hash over BN254",
+        interface: "IRescueHash",
+    },
+    Template {
+        name: "pedersen",
+        description: "Pedersen commitments over BN254 G1",
+        interface: "IPedersenCommitment",
+    },
+    Template {
+        name: "poseidon-merkle",
+        description: "Poseidon Merkle proof verification",
+        interface: "IPoseidonMerkle",
+    },
+    Template {
+        name: "baby-jubjub",
+        description: "Baby Jubjub twisted Edwards curve arithmetic",
+        interface: "IBabyJubjubCurve",
+    },
+    Template {
+        name: "bn254",
+        description: "BN254 G1 addition, scalar multiplication and pairing check",
+        interface: "IBN254",
+    },
+    Template {
+        name: "msm",
+        description: "BN254 G1 multi-scalar multiplication",
+        interface: "IBN254MSM",
+    },
+    Template {
+        name: "groth16",
+        description: "Groth16 proof verification over BN254",
+        interface: "IGroth16Verifier",
+    },
+    Template {
+        name: "kzg",
+        description: "KZG polynomial commitment opening verification",
+        interface: "IKZG",
+    },
+];
+
+/// Renders `templates` one name per line, or with details when `verbose`
+pub fn render_template_list(templates: &[Template], verbose: bool) -> Result<String> {
+    let mut out = String::new();
+    for template in templates {
+        if !verbose {
+            out += &format!("{}\n", template.name);
+            continue;
         }
-        
-        Commands::Build { release } => {
-            println!("{}", "🔨 Building project...".bright_blue());
-            
-            let mut cmd = Command::new("cargo");
-            cmd.arg("build")
-                .arg("--target")
-                .arg("wasm32-unknown-unknown");
-                
-            if release {
-                cmd.arg("--release");
-            }
-            
-            let status = cmd.status()?;
-            
-            if status.success() {
-                println!("{}", "✅ Build successful!".bright_green());
-            } else {
-                println!("{}", "❌ Build failed".bright_red());
-            }
+
+        out += &format!("{} - {}\n", template.name.bright_green().bold(), template.description);
+        for line in render_abi(template.interface, AbiFormat::HumanReadable)?.lines() {
+            out += &format!("    {}\n", line.dimmed());
         }
-        
-        Commands::Test { pattern } => {
-            println!("{}", "🧪 Running tests...".bright_blue());
-            
-            let mut cmd = Command::new("cargo");
-            cmd.arg("test");
-            
-            if let Some(p) = pattern {
-                cmd.arg(&p);
-            }
-            
-            let status = cmd.status()?;
-            
-            if status.success() {
-                println!("{}", "✅ All tests passed!".bright_green());
-            } else {
-                println!("{}", "❌ Some tests failed".bright_red());
+    }
+    Ok(out)
+}
+
+/// Poseidon entry points of the `hash` subcommand
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HashFunction {
+    /// Hash exactly one element
+    Poseidon1,
+    /// Hash exactly two elements
+    Poseidon2,
+    /// Hash any number of elements
+    PoseidonN,
+}
+
+impl HashFunction {
+    /// Number of inputs of a fixed-arity function
+    pub fn arity(self) -> Option<usize> {
+        match self {
+            Self::Poseidon1 => Some(1),
+            Self::Poseidon2 => Some(2),
+            Self::PoseidonN => None,
+        }
+    }
+
+    /// Solidity name of the function
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Poseidon1 => "poseidon1",
+            Self::Poseidon2 => "poseidon2",
+            Self::PoseidonN => "poseidonN",
+        }
+    }
+}
+
+/// Output formats of the `hash` subcommand
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HashFormat {
+    /// 0x-prefixed hex without leading zeros
+    Hex,
+    /// Decimal integer
+    Decimal,
+    /// 0x-prefixed hex padded to 32 bytes
+    Bytes32,
+}
+
+/// Output formats of `export-abi`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AbiFormat {
+    /// Standard Ethereum JSON ABI, as read by Hardhat and Foundry
+    Json,
+    /// One human-readable signature per line, as read by ethers and viem
+    HumanReadable,
+    /// A `sol!` block declaring the interface for alloy
+    Rust,
+}
+
+/// Renders the ABI of the named interface in `format`
+pub fn render_abi(interface: &str, format: AbiFormat) -> Result<String> {
+    let (name, abi) = generate_abi()
+        .into_iter()
+        .find(|(name, _)| *name == interface)
+        .ok_or_else(|| anyhow!("unknown interface {}", interface))?;
+
+    Ok(match format {
+        AbiFormat::Json => abi_to_json(&abi),
+        AbiFormat::HumanReadable => {
+            let functions = abi.functions().map(|f| f.full_signature());
+            let events = abi.events().map(|e| e.full_signature());
+            functions.chain(events).map(|line| line + "\n").collect()
+        }
+        AbiFormat::Rust => format!("alloy_sol_types::sol! {{\n{}\n}}\n", abi.to_sol(name, None)),
+    })
+}
+
+/// Public RPC endpoint for a known network name
+fn default_rpc_url(network: &str) -> Option<&'static str> {
+    match network {
+        "arbitrum-sepolia" => Some("https://sepolia-rollup.arbitrum.io/rpc"),
+        "arbitrum-mainnet" | "arbitrum-one" => Some("https://arb1.arbitrum.io/rpc"),
+        _ => None,
+    }
+}
+
+/// Parses a `hash` input as 0x-prefixed hex, decimal, or `zero`
+pub fn parse_field_input(input: &str) -> Result<U256> {
+    if input == "zero" {
+        return Ok(U256::ZERO);
+    }
+    if input.starts_with("0x") {
+        return hex_to_u256(input).map_err(|e| anyhow!("{}: {}", e, input));
+    }
+    U256::from_str_radix(input, 10).map_err(|_| anyhow!("Invalid field element: {}", input))
+}
+
+/// Hashes `inputs` with the production Poseidon entry point of `function`
+pub fn compute_hash(
+    hasher: &PoseidonHash,
+    function: HashFunction,
+    inputs: &[U256],
+) -> Result<U256> {
+    let hash = match (function, inputs) {
+        (HashFunction::Poseidon1, [input]) => hasher.hash_single_production(*input)?,
+        (HashFunction::Poseidon2, [left, right]) => hasher.hash_pair_production(*left, *right)?,
+        (HashFunction::PoseidonN, _) => hasher.hash_n(inputs)?,
+        (function, _) => {
+            let arity = if function == HashFunction::Poseidon1 { 1 } else { 2 };
+            return Err(anyhow!("{:?} takes {} inputs, got {}", function, arity, inputs.len()));
+        }
+    };
+    Ok(hash)
+}
+
+/// Renders a hash in the requested format
+pub fn format_hash(hash: U256, format: HashFormat) -> String {
+    match format {
+        HashFormat::Hex => u256_to_hex(hash),
+        HashFormat::Decimal => hash.to_string(),
+        HashFormat::Bytes32 => format!("0x{}", hex::encode(hash.to_be_bytes::<32>())),
+    }
+}
+
+/// Calldata decoded against `IPoseidonHash`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedCall {
+    pub selector: [u8; 4],
+    /// Matched function, `None` for an unrecognized selector
+    pub function: Option<&'static str>,
+    /// Name, Solidity type and value of each argument
+    pub arguments: Vec<(&'static str, &'static str, String)>,
+}
+
+impl DecodedCall {
+    /// Renders the call as an aligned table
+    pub fn to_table(&self) -> String {
+        let selector = format!("0x{}", hex::encode(self.selector));
+        let Some(function) = self.function else {
+            return format!("Selector: {} (unrecognized)\n", selector);
+        };
+
+        let mut table = format!("Function: {}\nSelector: {}\n", function, selector);
+        let name_width = self.arguments.iter().map(|(name, ..)| name.len()).max().unwrap_or(0);
+        let type_width = self.arguments.iter().map(|(_, ty, _)| ty.len()).max().unwrap_or(0);
+        for (name, ty, value) in &self.arguments {
+            table += &format!("  {:name_width$}  {:type_width$}  {}\n", name, ty, value);
+        }
+        table
+    }
+
+    /// Renders the call as JSON
+    pub fn to_json(&self) -> serde_json::Value {
+        let arguments: Vec<_> = self
+            .arguments
+            .iter()
+            .map(|(name, ty, value)| json!({ "name": name, "type": ty, "value": value }))
+            .collect();
+        json!({
+            "selector": format!("0x{}", hex::encode(self.selector)),
+            "function": self.function,
+            "arguments": arguments,
+        })
+    }
+}
+
+/// Decodes 0x-prefixed calldata against the `IPoseidonHash` functions
+pub fn decode_calldata(calldata: &str) -> Result<DecodedCall> {
+    let data = hex::decode(calldata.strip_prefix("0x").unwrap_or(calldata))
+        .map_err(|e| anyhow!("Invalid calldata hex: {}", e))?;
+    if data.len() < 4 {
+        return Err(anyhow!("Calldata of {} bytes has no selector", data.len()));
+    }
+
+    let selector: [u8; 4] = data[0..4].try_into().expect("four bytes");
+    let word = |value: U256| value.to_string();
+    let words = |values: &[U256]| {
+        format!("[{}]", values.iter().map(U256::to_string).collect::<Vec<_>>().join(", "))
+    };
+
+    let (function, arguments) = match selector {
+        s if s == IPoseidonHash::poseidon1Call::SELECTOR => {
+            let call = decode::<IPoseidonHash::poseidon1Call>(&data)?;
+            ("poseidon1", vec![("input", "uint256", word(call.input))])
+        }
+        s if s == IPoseidonHash::poseidon2Call::SELECTOR => {
+            let call = decode::<IPoseidonHash::poseidon2Call>(&data)?;
+            let arguments = vec![
+                ("left", "uint256", word(call.left)),
+                ("right", "uint256", word(call.right)),
+            ];
+            ("poseidon2", arguments)
+        }
+        s if s == IPoseidonHash::poseidon1TaggedCall::SELECTOR => {
+            let call = decode::<IPoseidonHash::poseidon1TaggedCall>(&data)?;
+            let arguments = vec![
+                ("tag", "uint256", word(call.tag)),
+                ("input", "uint256", word(call.input)),
+            ];
+            ("poseidon1Tagged", arguments)
+        }
+        s if s == IPoseidonHash::poseidon2TaggedCall::SELECTOR => {
+            let call = decode::<IPoseidonHash::poseidon2TaggedCall>(&data)?;
+            let arguments = vec![
+                ("tag", "uint256", word(call.tag)),
+                ("left", "uint256", word(call.left)),
+                ("right", "uint256", word(call.right)),
+            ];
+            ("poseidon2Tagged", arguments)
+        }
+        s if s == IPoseidonHash::poseidon3Call::SELECTOR => {
+            let call = decode::<IPoseidonHash::poseidon3Call>(&data)?;
+            ("poseidon3", vec![("inputs", "uint256[3]", words(&call.inputs))])
+        }
+        s if s == IPoseidonHash::poseidon4Call::SELECTOR => {
+            let call = decode::<IPoseidonHash::poseidon4Call>(&data)?;
+            ("poseidon4", vec![("inputs", "uint256[4]", words(&call.inputs))])
+        }
+        s if s == IPoseidonHash::poseidon5Call::SELECTOR => {
+            let call = decode::<IPoseidonHash::poseidon5Call>(&data)?;
+            ("poseidon5", vec![("inputs", "uint256[5]", words(&call.inputs))])
+        }
+        s if s == IPoseidonHash::poseidonNCall::SELECTOR => {
+            let call = decode::<IPoseidonHash::poseidonNCall>(&data)?;
+            ("poseidonN", vec![("inputs", "uint256[]", words(&call.inputs))])
+        }
+        s if s == IPoseidonHash::poseidonBytes32Call::SELECTOR => {
+            let call = decode::<IPoseidonHash::poseidonBytes32Call>(&data)?;
+            ("poseidonBytes32", vec![("data", "bytes32", call.data.to_string())])
+        }
+        s if s == IPoseidonHash::poseidonBytesCall::SELECTOR => {
+            let call = decode::<IPoseidonHash::poseidonBytesCall>(&data)?;
+            ("poseidonBytes", vec![("input", "bytes", call.input.to_string())])
+        }
+        s if s == IPoseidonHash::nullifierCall::SELECTOR => {
+            let call = decode::<IPoseidonHash::nullifierCall>(&data)?;
+            let arguments = vec![
+                ("secretKey", "uint256", word(call.secretKey)),
+                ("leafIndex", "uint256", word(call.leafIndex)),
+            ];
+            ("nullifier", arguments)
+        }
+        s if s == IPoseidonHash::commitCall::SELECTOR => {
+            let call = decode::<IPoseidonHash::commitCall>(&data)?;
+            let arguments = vec![
+                ("value", "uint256", word(call.value)),
+                ("randomness", "uint256", word(call.randomness)),
+            ];
+            ("commit", arguments)
+        }
+        s if s == IPoseidonHash::openCommitmentCall::SELECTOR => {
+            let call = decode::<IPoseidonHash::openCommitmentCall>(&data)?;
+            let arguments = vec![
+                ("commitment", "uint256", word(call.commitment)),
+                ("value", "uint256", word(call.value)),
+                ("randomness", "uint256", word(call.randomness)),
+            ];
+            ("openCommitment", arguments)
+        }
+        s if s == IPoseidonHash::prfEvalCall::SELECTOR => {
+            let call = decode::<IPoseidonHash::prfEvalCall>(&data)?;
+            let arguments =
+                vec![("key", "uint256", word(call.key)), ("input", "uint256", word(call.input))];
+            ("prfEval", arguments)
+        }
+        _ => return Ok(DecodedCall { selector, function: None, arguments: Vec::new() }),
+    };
+
+    Ok(DecodedCall { selector, function: Some(function), arguments })
+}
+
+fn decode<C: SolCall>(data: &[u8]) -> Result<C> {
+    C::abi_decode(data, true).map_err(|e| anyhow!("ABI decode error for {}: {}", C::SIGNATURE, e))
+}
+
+/// Approximate gas of iden3's circomlibjs Solidity Poseidon for 1 to 5 inputs
+const IDEN3_SOLIDITY_GAS: [u64; 5] = [29_000, 38_000, 56_000, 74_000, 92_000];
+
+/// Extra gas per input past the widest generated iden3 contract
+const IDEN3_SOLIDITY_GAS_PER_INPUT: u64 = 18_000;
+
+/// Estimated gas of hashing `n` inputs with the iden3 Solidity contracts
+fn iden3_solidity_gas(n: usize) -> u64 {
+    let widest = IDEN3_SOLIDITY_GAS.len();
+    match n {
+        0 => 0,
+        n if n <= widest => IDEN3_SOLIDITY_GAS[n - 1],
+        n => IDEN3_SOLIDITY_GAS[widest - 1] + (n - widest) as u64 * IDEN3_SOLIDITY_GAS_PER_INPUT,
+    }
+}
+
+/// Gas of one Poseidon call on Stylus, priced and compared to Solidity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GasEstimate {
+    pub function: HashFunction,
+    pub n_elements: usize,
+    pub stylus_gas: u64,
+    pub gwei_price: f64,
+    pub eth_usd_price: f64,
+    /// Approximate gas of the iden3 Solidity contract for the same inputs
+    pub solidity_gas: u64,
+}
+
+impl GasEstimate {
+    /// L2 cost in ETH
+    pub fn eth(&self) -> f64 {
+        self.stylus_gas as f64 * self.gwei_price * 1e-9
+    }
+
+    /// Percentage of the Solidity gas saved
+    pub fn savings(&self) -> f64 {
+        100.0 * (1.0 - self.stylus_gas as f64 / self.solidity_gas as f64)
+    }
+
+    /// Renders the estimate as `estimate-gas`'s table
+    pub fn to_table(&self) -> String {
+        let rows = [
+            ("Function", format!("{}({} elements)", self.function.name(), self.n_elements)),
+            ("Stylus gas units", self.stylus_gas.to_string()),
+            ("L2 cost (ETH)", format!("{:.12} at {} gwei", self.eth(), self.gwei_price)),
+            (
+                "L2 cost (USD)",
+                format!("{:.8} at ${}/ETH", self.eth() * self.eth_usd_price, self.eth_usd_price),
+            ),
+            (
+                "iden3 Solidity gas",
+                format!("~{} ({:.1}% saved)", self.solidity_gas, self.savings()),
+            ),
+        ];
+        let width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+        rows.iter().map(|(label, value)| format!("{:width$}  {}\n", label, value)).collect()
+    }
+
+    /// Renders the estimate as JSON
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "function": self.function.name(),
+            "n_elements": self.n_elements,
+            "stylus_gas": self.stylus_gas,
+            "gwei_price": self.gwei_price,
+            "eth_cost": self.eth(),
+            "eth_usd_price": self.eth_usd_price,
+            "usd_cost": self.eth() * self.eth_usd_price,
+            "solidity_gas": self.solidity_gas,
+            "savings_percent": self.savings(),
+        })
+    }
+}
+
+/// Estimates the gas of `n_elements` inputs to `function`
+///
+/// `n_elements` defaults to the arity of poseidon1 and poseidon2 and is
+/// required for poseidonN.
+pub fn estimate_gas(
+    function: HashFunction,
+    n_elements: Option<usize>,
+    gwei_price: f64,
+    eth_usd_price: f64,
+) -> Result<GasEstimate> {
+    let n = match (function.arity(), n_elements) {
+        (Some(arity), None) => arity,
+        (Some(arity), Some(n)) if n == arity => arity,
+        (None, Some(n)) if n > 0 => n,
+        (Some(arity), Some(n)) => {
+            return Err(anyhow!("{} takes {} inputs, not {}", function.name(), arity, n));
+        }
+        (None, _) => return Err(anyhow!("poseidonN needs --n-elements of at least 1")),
+    };
+
+    let x = U256::from(1);
+    let calldata = match function {
+        HashFunction::Poseidon1 => IPoseidonHash::poseidon1Call { input: x }.abi_encode(),
+        HashFunction::Poseidon2 => IPoseidonHash::poseidon2Call { left: x, right: x }.abi_encode(),
+        HashFunction::PoseidonN => IPoseidonHash::poseidonNCall { inputs: vec![x; n] }.abi_encode(),
+    };
+    Ok(GasEstimate {
+        function,
+        n_elements: n,
+        stylus_gas: poseidon_gas_cost(&calldata)?,
+        gwei_price,
+        eth_usd_price,
+        solidity_gas: iden3_solidity_gas(n),
+    })
+}
+
+/// Builds `estimate-gas`'s table for `n_elements` inputs to `function`
+pub fn gas_estimate_table(
+    function: HashFunction,
+    n_elements: Option<usize>,
+    gwei_price: f64,
+    eth_usd_price: f64,
+) -> Result<String> {
+    Ok(estimate_gas(function, n_elements, gwei_price, eth_usd_price)?.to_table())
+}
+
+/// What `run` leaves for `main` to print
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliOutput {
+    /// Colored text for a terminal
+    Human(String),
+    /// `{ "status", "data", "error_message" }` object for `--json`
+    Json(serde_json::Value),
+}
+
+impl CliOutput {
+    /// JSON result of a command that succeeded with `data`
+    pub fn ok(data: serde_json::Value) -> Self {
+        Self::Json(json!({ "status": "ok", "data": data, "error_message": null }))
+    }
+
+    /// JSON result of a command that failed
+    pub fn error(message: &str) -> Self {
+        Self::Json(json!({ "status": "error", "data": null, "error_message": message }))
+    }
+
+    /// Whether this is a JSON error result
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::Json(value) if value["status"] == "error")
+    }
+
+    /// Text to write to stdout
+    pub fn render(&self) -> String {
+        match self {
+            Self::Human(text) => text.clone(),
+            Self::Json(value) => format!("{:#}\n", value),
+        }
+    }
+}
+
+/// Result of a subcommand handler: terminal text and the `--json` payload
+struct Report {
+    human: String,
+    data: serde_json::Value,
+}
+
+impl Report {
+    fn new(human: impl Into<String>, data: serde_json::Value) -> Self {
+        Self { human: human.into(), data }
+    }
+}
+
+/// Progress output of the handlers, kept off stdout in JSON mode
+struct Console {
+    json: bool,
+}
+
+impl Console {
+    fn progress(&self, line: impl std::fmt::Display) {
+        if !self.json {
+            println!("{}", line);
+        }
+    }
+
+    /// Where a spawned script's stdout goes, stderr when stdout is reserved for JSON
+    fn child_stdout(&self) -> Stdio {
+        if self.json {
+            std::io::stderr().into()
+        } else {
+            Stdio::inherit()
+        }
+    }
+
+    /// Echoes a line of a script's output that is being scanned
+    fn echo(&self, line: &str) {
+        if self.json {
+            eprintln!("{}", line);
+        } else {
+            println!("{}", line);
+        }
+    }
+}
+
+/// Parses the command line and runs the command
+pub fn run() -> Result<CliOutput> {
+    execute(Cli::parse())
+}
+
+/// Runs a parsed command line
+///
+/// With `--json` every outcome, failures included, is returned as a
+/// `CliOutput::Json` result object; otherwise errors are returned as `Err`.
+pub fn execute(cli: Cli) -> Result<CliOutput> {
+    if cli.json {
+        colored::control::set_override(false);
+    }
+    let console = Console { json: cli.json };
+    let report = Config::load().and_then(|config| dispatch(cli.command, config, &console));
+
+    match (cli.json, report) {
+        (false, report) => Ok(CliOutput::Human(report?.human)),
+        (true, Ok(report)) => Ok(CliOutput::ok(report.data)),
+        (true, Err(e)) => Ok(CliOutput::error(&e.to_string())),
+    }
+}
+
+fn dispatch(command: Commands, config: Config, console: &Console) -> Result<Report> {
+    match command {
+        Commands::Generate { name, description } => generate(&name, &description, console),
+        Commands::Build { release } => build(release, console),
+        Commands::Test { pattern } => test(pattern, console),
+        Commands::Init { force } => init(force),
+        Commands::Deploy { network, private_key, rpc_url, wait, timeout, config: params_file } => {
+            let network = network.or(config.network.name).ok_or_else(|| {
+                anyhow!("--network is required unless Stylus.toml sets [network] name")
+            })?;
+            let rpc_url = rpc_url
+                .or(config.network.rpc_url)
+                .or_else(|| default_rpc_url(&network).map(String::from));
+            let deploy = Deploy {
+                network,
+                private_key: private_key.or(config.deploy.private_key),
+                rpc_url,
+                wait: wait || config.deploy.wait.unwrap_or(false),
+                timeout: timeout.or(config.deploy.timeout).unwrap_or(DEFAULT_DEPLOY_TIMEOUT),
+                params_file,
+            };
+            deploy.run(console)
+        }
+        Commands::ExportAbi { output, format, interface } => {
+            export_abi(output, format, &interface, console)
+        }
+        Commands::Hash { inputs, function, format } => {
+            let function = function.or(config.hash.function).unwrap_or(HashFunction::PoseidonN);
+            let format = format.or(config.hash.format).unwrap_or(HashFormat::Hex);
+            hash(&inputs, function, format, &config.hash.hasher()?)
+        }
+        Commands::Decode { calldata } => {
+            let decoded = decode_calldata(&calldata)?;
+            Ok(Report::new(decoded.to_table(), decoded.to_json()))
+        }
+        Commands::VerifyDeployment { address, rpc_url, test_vector_file } => {
+            let rpc_url = rpc_url.or(config.network.rpc_url).ok_or_else(|| {
+                anyhow!("--rpc-url is required unless Stylus.toml sets [network] rpc_url")
+            })?;
+            verify_deployment(&address, &rpc_url, test_vector_file, console)
+        }
+        Commands::EstimateGas { function, n_elements, gwei_price, eth_usd_price } => {
+            let estimate = estimate_gas(function, n_elements, gwei_price, eth_usd_price)?;
+            Ok(Report::new(estimate.to_table(), estimate.to_json()))
+        }
+        Commands::List { verbose } => {
+            let templates: Vec<_> = TEMPLATES
+                .iter()
+                .map(|t| {
+                    json!({
+                        "name": t.name,
+                        "description": t.description,
+                        "interface": t.interface,
+                    })
+                })
+                .collect();
+            Ok(Report::new(render_template_list(TEMPLATES, verbose)?, json!(templates)))
+        }
+    }
+}
+
+fn generate(name: &str, description: &str, console: &Console) -> Result<Report> {
+    console.progress("🔨 Generating new precompile...".bright_blue());
+
+    // Run the generate script
+    let status = Command::new("bash")
+        .arg("scripts/generate_precompile.sh")
+        .arg(name)
+        .arg(description)
+        .stdout(console.child_stdout())
+        .status()?;
+
+    if !status.success() {
+        return Err(anyhow!("Failed to generate precompile {}", name));
+    }
+    Ok(Report::new(
+        format!("{}\n", format!("✅ Successfully generated {} precompile!", name).bright_green()),
+        json!({ "name": name, "description": description }),
+    ))
+}
+
+fn build(release: bool, console: &Console) -> Result<Report> {
+    console.progress("🔨 Building project...".bright_blue());
+
+    let mut cmd = Command::new("cargo");
+    cmd.arg("build")
+        .arg("--target")
+        .arg("wasm32-unknown-unknown")
+        .stdout(console.child_stdout());
+
+    if release {
+        cmd.arg("--release");
+    }
+
+    if !cmd.status()?.success() {
+        return Err(anyhow!("Build failed"));
+    }
+    Ok(Report::new(
+        format!("{}\n", "✅ Build successful!".bright_green()),
+        json!({ "release": release }),
+    ))
+}
+
+fn test(pattern: Option<String>, console: &Console) -> Result<Report> {
+    console.progress("🧪 Running tests...".bright_blue());
+
+    let mut cmd = Command::new("cargo");
+    cmd.arg("test").stdout(console.child_stdout());
+
+    if let Some(p) = &pattern {
+        cmd.arg(p);
+    }
+
+    if !cmd.status()?.success() {
+        return Err(anyhow!("Some tests failed"));
+    }
+    Ok(Report::new(
+        format!("{}\n", "✅ All tests passed!".bright_green()),
+        json!({ "pattern": pattern }),
+    ))
+}
+
+fn init(force: bool) -> Result<Report> {
+    let path = Config::path();
+    if path.exists() && !force {
+        let path = path.display();
+        return Err(anyhow!("{} already exists, pass --force to overwrite it", path));
+    }
+    std::fs::write(&path, DEFAULT_CONFIG)?;
+    Ok(Report::new(
+        format!("{}\n", format!("✅ Wrote {}", path.display()).bright_green()),
+        json!({ "path": path }),
+    ))
+}
+
+/// `deploy` arguments with the config defaults applied
+struct Deploy {
+    network: String,
+    private_key: Option<String>,
+    rpc_url: Option<String>,
+    wait: bool,
+    timeout: u64,
+    params_file: Option<PathBuf>,
+}
+
+impl Deploy {
+    fn run(self, console: &Console) -> Result<Report> {
+        console.progress(format!("🚀 Deploying to {}...", self.network).bright_blue());
+
+        if let Some(path) = &self.params_file {
+            let json = std::fs::read_to_string(path)?;
+            let hasher = from_config(&json)
+                .map_err(|e| anyhow!("invalid Poseidon config {}: {}", path.display(), e))?;
+            let params = &hasher.params;
+            console.progress(
+                format!(
+                    "📋 Poseidon parameters: t = {}, {} full rounds, {} partial rounds",
+                    params.width, params.full_rounds, params.partial_rounds
+                )
+                .bright_blue(),
+            );
+        }
+
+        let mut cmd = Command::new("bash");
+        cmd.arg("scripts/deploy.sh")
+            .arg("--network")
+            .arg(&self.network);
+
+        if let Some(key) = &self.private_key {
+            cmd.arg("--private-key")
+                .arg(key);
+        }
+
+        if let Some(url) = &self.rpc_url {
+            cmd.arg("--rpc-url")
+                .arg(url);
+        }
+
+        if let Some(path) = &self.params_file {
+            cmd.arg("--config")
+                .arg(path);
+        }
+
+        let data = json!({ "network": self.network, "rpc_url": self.rpc_url });
+        if !self.wait {
+            if !cmd.stdout(console.child_stdout()).status()?.success() {
+                return Err(anyhow!("Deployment failed"));
             }
+            let human = format!("{}\n", "✅ Deployment successful!".bright_green());
+            return Ok(Report::new(human, data));
         }
-        
-        Commands::Deploy { network, private_key } => {
-            println!("{}", format!("🚀 Deploying to {}...", network).bright_blue());
-            
-            let mut cmd = Command::new("bash");
-            cmd.arg("scripts/deploy.sh")
-                .arg("--network")
-                .arg(&network);
-                
-            if let Some(key) = private_key {
-                cmd.arg("--private-key")
-                    .arg(&key);
+
+        // Echo the script output while scanning it for the deployment tx hash
+        let mut child = cmd.stdout(Stdio::piped()).spawn()?;
+        let mut output = String::new();
+        if let Some(stdout) = child.stdout.take() {
+            for line in BufReader::new(stdout).lines() {
+                let line = line?;
+                console.echo(&line);
+                output.push_str(&line);
+                output.push('\n');
             }
-            
-            let status = cmd.status()?;
-            
-            if status.success() {
-                println!("{}", "✅ Deployment successful!".bright_green());
-            } else {
-                println!("{}", "❌ Deployment failed".bright_red());
+        }
+        if !child.wait()?.success() {
+            return Err(anyhow!("Deployment failed"));
+        }
+
+        let tx_hash = parse_deploy_tx_hash(&output)
+            .ok_or_else(|| anyhow!("no deployment tx hash in the deploy output"))?;
+        let rpc_url = self
+            .rpc_url
+            .ok_or_else(|| anyhow!("--rpc-url is required to wait on network {}", self.network))?;
+
+        console.progress("⏳ Waiting for confirmation...".bright_blue());
+        let receipt = DeployMonitor::new(tx_hash, &rpc_url).wait_for_deployment(self.timeout)?;
+        let mut data = data;
+        data["tx_hash"] = json!(format!("0x{}", hex::encode(receipt.tx_hash)));
+        data["block_number"] = json!(receipt.block_number);
+        data["contract_address"] = json!(receipt.contract_address.map(|a| a.to_string()));
+        Ok(Report::new(
+            format!(
+                "{}\n",
+                format!("✅ Deployment confirmed in block {}", receipt.block_number).bright_green()
+            ),
+            data,
+        ))
+    }
+}
+
+fn export_abi(
+    output: Option<PathBuf>,
+    format: AbiFormat,
+    interface: &str,
+    console: &Console,
+) -> Result<Report> {
+    let rendered = render_abi(interface, format)?;
+
+    let Some(path) = output else {
+        let data = json!({ "interface": interface, "abi": rendered });
+        return Ok(Report::new(rendered, data));
+    };
+    std::fs::write(&path, &rendered)?;
+    if !console.json {
+        eprintln!(
+            "{}",
+            format!("✅ Wrote {} ABI to {}", interface, path.display()).bright_green()
+        );
+    }
+    Ok(Report::new("", json!({ "interface": interface, "path": path })))
+}
+
+fn hash(
+    inputs: &[String],
+    function: HashFunction,
+    format: HashFormat,
+    hasher: &PoseidonHash,
+) -> Result<Report> {
+    let inputs = inputs
+        .iter()
+        .map(|input| parse_field_input(input))
+        .collect::<Result<Vec<_>>>()?;
+    let hash = format_hash(compute_hash(hasher, function, &inputs)?, format);
+    let data = json!({
+        "function": function.name(),
+        "inputs": inputs.iter().map(|input| input.to_string()).collect::<Vec<_>>(),
+        "hash": hash,
+    });
+    Ok(Report::new(format!("{}\n", hash), data))
+}
+
+fn verify_deployment(
+    address: &str,
+    rpc_url: &str,
+    test_vector_file: Option<PathBuf>,
+    console: &Console,
+) -> Result<Report> {
+    let address = address
+        .parse()
+        .map_err(|e| anyhow!("Invalid address {}: {}", address, e))?;
+    let vectors = match test_vector_file {
+        Some(path) => TestVector::from_json(&std::fs::read_to_string(path)?)?,
+        None => TestVector::defaults(),
+    };
+
+    console.progress(format!("🔍 Verifying {} test vectors...", vectors.len()).bright_blue());
+    let results = DeploymentVerifier::new(address, rpc_url).verify(&vectors)?;
+
+    let mut human = String::new();
+    for result in &results {
+        let call = format!("{}({:?})", result.vector.function, result.vector.inputs);
+        if result.passed() {
+            human += &format!("{}\n", format!("✅ {}", call).bright_green());
+        } else {
+            human += &format!("{}\n", format!("❌ {}", call).bright_red());
+            human += &format!("   expected: {}\n", u256_to_hex(result.expected));
+            human += &format!("   actual:   {}\n", u256_to_hex(result.actual));
+        }
+    }
+
+    let failed = results.iter().filter(|result| !result.passed()).count();
+    if failed > 0 {
+        // The per-vector lines explain the failure, so show them before the error
+        console.progress(human.trim_end());
+        return Err(anyhow!(
+            "deployed precompile does not match the local implementation ({} of {} vectors)",
+            failed,
+            results.len()
+        ));
+    }
+
+    let data: Vec<_> = results
+        .iter()
+        .map(|result| {
+            json!({
+                "function": result.vector.function,
+                "inputs": result.vector.inputs.iter().map(U256::to_string).collect::<Vec<_>>(),
+                "expected": u256_to_hex(result.expected),
+                "actual": u256_to_hex(result.actual),
+                "passed": result.passed(),
+            })
+        })
+        .collect();
+    Ok(Report::new(human, json!(data)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_json_abi::JsonAbi;
+
+    #[test]
+    fn test_json_abi_export() {
+        let json = render_abi("IPoseidonHash", AbiFormat::Json).unwrap();
+        let abi: JsonAbi = serde_json::from_str(&json).unwrap();
+
+        let input_types = |name: &str| -> Vec<String> {
+            abi.function(name).unwrap()[0].inputs.iter().map(|p| p.ty.clone()).collect()
+        };
+        assert_eq!(input_types("poseidon1"), ["uint256"]);
+        assert_eq!(input_types("poseidon2"), ["uint256", "uint256"]);
+        assert_eq!(input_types("poseidonN"), ["uint256[]"]);
+    }
+
+    #[test]
+    fn test_other_formats() {
+        let readable = render_abi("IPoseidonHash", AbiFormat::HumanReadable).unwrap();
+        assert!(readable.lines().any(|line| line.starts_with("function poseidon2(uint256 ")));
+
+        let rust = render_abi("IPoseidonHash", AbiFormat::Rust).unwrap();
+        assert!(rust.starts_with("alloy_sol_types::sol! {"));
+        assert!(rust.contains("interface IPoseidonHash"));
+
+        assert!(render_abi("INope", AbiFormat::Json).is_err());
+    }
+
+    #[test]
+    fn test_parse_field_input() {
+        assert_eq!(parse_field_input("zero").unwrap(), U256::ZERO);
+        assert_eq!(parse_field_input("0xff").unwrap(), U256::from(255));
+        assert_eq!(parse_field_input("255").unwrap(), U256::from(255));
+        assert!(parse_field_input("0xzz").is_err());
+        assert!(parse_field_input("twelve").is_err());
+    }
+
+    #[test]
+    fn test_hash_command() {
+        let (a, b) = (U256::from(1), U256::from(2));
+        let hasher = PoseidonHash::new();
+
+        let pair = compute_hash(&hasher, HashFunction::Poseidon2, &[a, b]).unwrap();
+        assert_eq!(pair, hasher.hash_pair_production(a, b).unwrap());
+        assert_eq!(
+            compute_hash(&hasher, HashFunction::Poseidon1, &[a]).unwrap(),
+            hasher.hash_single_production(a).unwrap()
+        );
+        assert!(compute_hash(&hasher, HashFunction::Poseidon1, &[a, b]).is_err());
+        assert!(compute_hash(&hasher, HashFunction::Poseidon2, &[a]).is_err());
+
+        assert_eq!(format_hash(U256::from(255), HashFormat::Hex), "0xff");
+        assert_eq!(format_hash(U256::from(255), HashFormat::Decimal), "255");
+        assert_eq!(
+            format_hash(U256::from(255), HashFormat::Bytes32),
+            format!("0x{}ff", "0".repeat(62))
+        );
+    }
+
+    #[test]
+    fn test_decode_round_trip() {
+        let (left, right) = (U256::from(100), U256::from(200));
+        let calldata = hex::encode(IPoseidonHash::poseidon2Call { left, right }.abi_encode());
+        let decoded = decode_calldata(&format!("0x{}", calldata)).unwrap();
+        assert_eq!(decoded.function, Some("poseidon2"));
+        assert_eq!(
+            decoded.arguments,
+            [("left", "uint256", left.to_string()), ("right", "uint256", right.to_string())]
+        );
+        assert!(decoded.to_table().contains("right  uint256  200"));
+
+        let inputs = vec![U256::from(1), U256::from(2), U256::from(3)];
+        let calldata = hex::encode(IPoseidonHash::poseidonNCall { inputs }.abi_encode());
+        let json = decode_calldata(&calldata).unwrap().to_json();
+        assert_eq!(json["function"], "poseidonN");
+        assert_eq!(json["arguments"][0]["value"], "[1, 2, 3]");
+    }
+
+    #[test]
+    fn test_decode_unknown_selector() {
+        let decoded = decode_calldata("0xdeadbeef").unwrap();
+        assert_eq!(decoded.function, None);
+        assert!(decoded.to_table().contains("0xdeadbeef (unrecognized)"));
+        assert!(decode_calldata("0xdead").is_err());
+
+        let selector = hex::encode(IPoseidonHash::poseidon1Call::SELECTOR);
+        assert!(decode_calldata(&selector).is_err());
+    }
+
+    #[test]
+    fn test_list_templates() {
+        let names = render_template_list(TEMPLATES, false).unwrap();
+        let names: Vec<&str> = names.lines().collect();
+        for name in ["poseidon", "mimc", "rescue", "pedersen", "groth16", "kzg", "msm"] {
+            assert!(names.contains(&name), "missing {}", name);
+        }
+
+        let mock = [Template { name: "mock", description: "A mock hash", interface: "IMiMCHash" }];
+        assert_eq!(render_template_list(&mock, false).unwrap(), "mock\n");
+        let verbose = render_template_list(&mock, true).unwrap();
+        assert!(verbose.contains("A mock hash"));
+        assert!(verbose.contains("function mimc2(uint256 "));
+
+        let broken = [Template { interface: "INope", ..mock[0] }];
+        assert!(render_template_list(&broken, true).is_err());
+    }
+
+    fn execute_args(args: &[&str]) -> CliOutput {
+        let args = std::iter::once("stylus-forge").chain(args.iter().copied());
+        execute(Cli::try_parse_from(args).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_json_output() {
+        let output = execute_args(&["--json", "hash", "1", "2", "--function", "poseidon2"]);
+        let value: serde_json::Value = serde_json::from_str(&output.render()).unwrap();
+        let expected = PoseidonHash::new().hash_pair_production(U256::from(1), U256::from(2));
+        assert_eq!(value["status"], "ok");
+        assert_eq!(value["data"]["function"], "poseidon2");
+        assert_eq!(value["data"]["hash"], u256_to_hex(expected.unwrap()));
+        assert!(value["error_message"].is_null());
+        assert!(!output.is_error());
+
+        // Failures are reported in the result object rather than as `Err`
+        let output = execute_args(&["hash", "0xzz", "--json"]);
+        let value: serde_json::Value = serde_json::from_str(&output.render()).unwrap();
+        assert!(output.is_error());
+        assert_eq!(value["status"], "error");
+        assert!(value["error_message"].as_str().unwrap().contains("0xzz"));
+    }
+
+    #[test]
+    fn test_human_output() {
+        let output = execute_args(&["hash", "255", "--format", "decimal", "--function=poseidon1"]);
+        let expected = PoseidonHash::new().hash_single_production(U256::from(255)).unwrap();
+        assert_eq!(output, CliOutput::Human(format!("{}\n", expected)));
+
+        let cli = Cli::try_parse_from(["stylus-forge", "hash", "0xzz"]).unwrap();
+        assert!(execute(cli).is_err());
+    }
+
+    #[test]
+    fn test_gas_estimate_table() {
+        let single = gas_estimate_table(HashFunction::Poseidon1, None, 0.01, 3000.0).unwrap();
+        let many = gas_estimate_table(HashFunction::PoseidonN, Some(10), 0.01, 3000.0).unwrap();
+        let columns = ["Stylus gas units", "L2 cost (ETH)", "L2 cost (USD)", "iden3 Solidity gas"];
+        for (table, function) in [(&single, "poseidon1"), (&many, "poseidonN")] {
+            assert!(table.contains(function));
+            for column in columns {
+                assert!(table.contains(column), "{} table missing {}", function, column);
             }
         }
+
+        let units = crate::gas::GasCosts::POSEIDON1_BASE;
+        assert!(single.contains(&format!("Stylus gas units    {}", units)));
+
+        assert!(gas_estimate_table(HashFunction::Poseidon2, Some(3), 0.01, 3000.0).is_err());
+        assert!(gas_estimate_table(HashFunction::PoseidonN, None, 0.01, 3000.0).is_err());
     }
-    
-    Ok(())
-}
\ No newline at end of file
+}
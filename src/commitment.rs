@@ -0,0 +1,76 @@
+//! Tagged Poseidon commitments
+//!
+//! A commitment is `hash_pair_tagged(tag, value, randomness)`. It hides
+//! `value` as long as the randomness stays secret and binds to it through the
+//! collision resistance of Poseidon. The domain tag keeps commitments apart from other
+//! tagged hashes over the same inputs, such as nullifiers or Merkle nodes.
+
+use crate::errors::PoseidonError;
+use crate::poseidon::{DomainTag, PoseidonHash};
+use alloy_primitives::U256;
+
+/// Poseidon commitment to a field element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Commitment(pub U256);
+
+impl Commitment {
+    /// Commits to `value` with `randomness` under `tag`
+    pub fn commit(
+        value: U256,
+        randomness: U256,
+        tag: DomainTag,
+        hasher: &PoseidonHash,
+    ) -> Result<Self, PoseidonError> {
+        hasher.hash_pair_tagged(tag, value, randomness).map(Self)
+    }
+
+    /// Whether `c` opens to `value` and `randomness` under `tag`
+    ///
+    /// Openings with inputs outside the field are rejected.
+    pub fn open(
+        c: &Commitment,
+        value: U256,
+        randomness: U256,
+        tag: DomainTag,
+        hasher: &PoseidonHash,
+    ) -> bool {
+        Self::commit(value, randomness, tag, hasher).is_ok_and(|opened| opened == *c)
+    }
+
+    /// Big-endian `bytes32` for on-chain storage
+    pub fn abi_encode(&self) -> [u8; 32] {
+        self.0.to_be_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poseidon::{COMMITMENT_TAG, NULLIFIER_TAG};
+
+    #[test]
+    fn test_commit_and_open() {
+        let hasher = PoseidonHash::new();
+        let (value, randomness) = (U256::from(42), U256::from(12345));
+
+        let c = Commitment::commit(value, randomness, COMMITMENT_TAG, &hasher).unwrap();
+        let expected = hasher.hash_pair_tagged(COMMITMENT_TAG, value, randomness).unwrap();
+        assert_eq!(c.0, expected);
+        assert!(Commitment::open(&c, value, randomness, COMMITMENT_TAG, &hasher));
+
+        assert!(!Commitment::open(&c, value + U256::from(1), randomness, COMMITMENT_TAG, &hasher));
+        assert!(!Commitment::open(&c, value, randomness + U256::from(1), COMMITMENT_TAG, &hasher));
+        assert!(!Commitment::open(&c, value, randomness, NULLIFIER_TAG, &hasher));
+    }
+
+    #[test]
+    fn test_abi_encoding() {
+        let hasher = PoseidonHash::new();
+        let c = Commitment::commit(U256::from(1), U256::from(2), COMMITMENT_TAG, &hasher).unwrap();
+        assert_eq!(U256::from_be_bytes(c.abi_encode()), c.0);
+
+        let modulus = hasher.params.modulus;
+        assert!(Commitment::commit(modulus, U256::ZERO, COMMITMENT_TAG, &hasher).is_err());
+        assert!(!Commitment::open(&c, modulus, U256::ZERO, COMMITMENT_TAG, &hasher));
+    }
+}
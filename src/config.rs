@@ -0,0 +1,215 @@
+//! `Stylus.toml` project configuration for stylus-forge
+//!
+//! The file is read from `STYLUS_CONFIG` when set and from `./Stylus.toml`
+//! otherwise. Each section holds defaults for the matching subcommands, and
+//! flags given on the command line take precedence over them.
+
+use alloy_primitives::U256;
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use crate::cli::{parse_field_input, HashFormat, HashFunction};
+use crate::poseidon::{PoseidonHash, PoseidonParams};
+
+/// Config file looked up in the current directory
+pub const CONFIG_FILE: &str = "Stylus.toml";
+
+/// Environment variable overriding the config file path
+pub const CONFIG_ENV: &str = "STYLUS_CONFIG";
+
+/// Seconds `deploy --wait` waits when neither the flag nor the config set it
+pub const DEFAULT_DEPLOY_TIMEOUT: u64 = 300;
+
+/// `Stylus.toml` written by `stylus-forge init`
+pub const DEFAULT_CONFIG: &str = r#"# stylus-forge project configuration
+#
+# Flags passed on the command line override these values.
+
+[network]
+name = "arbitrum-sepolia"
+# rpc_url = "https://sepolia-rollup.arbitrum.io/rpc"
+
+[deploy]
+wait = false
+timeout = 300
+# private_key = "0x..."
+
+[hash]
+function = "poseidon-n"
+format = "hex"
+
+# Poseidon parameters, given as strings
+[hash.params]
+modulus = "21888242871839275222246405745257275088548364400416034343698204186575808495617"
+full_rounds = "8"
+partial_rounds = "57"
+"#;
+
+/// Parsed `Stylus.toml`, every value optional
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub network: NetworkConfig,
+    pub deploy: DeployConfig,
+    pub hash: HashConfig,
+}
+
+/// `[network]`: target of `deploy` and `verify-deployment`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NetworkConfig {
+    pub name: Option<String>,
+    pub rpc_url: Option<String>,
+}
+
+/// `[deploy]`: defaults of `deploy`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DeployConfig {
+    pub private_key: Option<String>,
+    pub wait: Option<bool>,
+    pub timeout: Option<u64>,
+}
+
+/// `[hash]`: defaults of `hash`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HashConfig {
+    pub function: Option<HashFunction>,
+    pub format: Option<HashFormat>,
+    pub params: PoseidonParamsConfig,
+}
+
+/// `[hash.params]`: `PoseidonParams` overrides as decimal or 0x-prefixed strings
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PoseidonParamsConfig {
+    pub modulus: Option<String>,
+    pub full_rounds: Option<String>,
+    pub partial_rounds: Option<String>,
+}
+
+impl Config {
+    /// Path of the config file, `STYLUS_CONFIG` or `./Stylus.toml`
+    pub fn path() -> PathBuf {
+        std::env::var_os(CONFIG_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(CONFIG_FILE))
+    }
+
+    /// Loads the config file, empty when `./Stylus.toml` does not exist
+    ///
+    /// A path set through `STYLUS_CONFIG` must exist.
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        if std::env::var_os(CONFIG_ENV).is_none() && !path.exists() {
+            return Ok(Self::default());
+        }
+        Self::from_file(&path)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Cannot read {}: {}", path.display(), e))?;
+        Self::parse(&text).map_err(|e| anyhow!("Invalid {}: {}", path.display(), e))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        Ok(toml::from_str(text)?)
+    }
+}
+
+impl HashConfig {
+    /// Hasher with the configured parameters, circomlib's where unset
+    pub fn hasher(&self) -> Result<PoseidonHash> {
+        let mut hasher = PoseidonHash::new();
+        hasher.params = self.params.to_params()?;
+        Ok(hasher)
+    }
+}
+
+impl PoseidonParamsConfig {
+    /// Applies the overrides to `PoseidonParams::default()`
+    pub fn to_params(&self) -> Result<PoseidonParams> {
+        let mut params = PoseidonParams::default();
+        if let Some(modulus) = &self.modulus {
+            params.modulus = parse_field_input(modulus)?;
+            if params.modulus <= U256::from(2) {
+                return Err(anyhow!("Poseidon modulus must be greater than 2, got {}", modulus));
+            }
+        }
+        if let Some(rounds) = &self.full_rounds {
+            params.full_rounds = parse_rounds("full_rounds", rounds)?;
+        }
+        if let Some(rounds) = &self.partial_rounds {
+            params.partial_rounds = parse_rounds("partial_rounds", rounds)?;
+        }
+        Ok(params)
+    }
+}
+
+fn parse_rounds(field: &str, value: &str) -> Result<usize> {
+    value.trim().parse().map_err(|_| anyhow!("Invalid {}: {}", field, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config = Config::parse(DEFAULT_CONFIG).unwrap();
+        assert_eq!(config.network.name.as_deref(), Some("arbitrum-sepolia"));
+        assert_eq!(config.deploy.timeout, Some(DEFAULT_DEPLOY_TIMEOUT));
+        assert_eq!(config.hash.function, Some(HashFunction::PoseidonN));
+        assert_eq!(config.hash.format, Some(HashFormat::Hex));
+        assert_eq!(config.hash.hasher().unwrap().params, PoseidonParams::default());
+
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_poseidon_params() {
+        let config = Config::parse(
+            r#"
+            [hash]
+            function = "poseidon2"
+
+            [hash.params]
+            modulus = "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+            full_rounds = "8"
+            partial_rounds = "60"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.hash.function, Some(HashFunction::Poseidon2));
+
+        // BLS12-381 scalar field
+        let bls_modulus = "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+        let hasher = config.hash.hasher().unwrap();
+        let params = &hasher.params;
+        assert_eq!(params.modulus, crate::utils::hex_to_u256(bls_modulus).unwrap());
+        assert_eq!(params.full_rounds, 8);
+        assert_eq!(params.partial_rounds, 60);
+        assert_eq!(params.width, PoseidonParams::default().width);
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(Config::parse("[network]\nchain = \"arbitrum\"").is_err());
+        assert!(Config::parse("[hash]\nfunction = \"sha256\"").is_err());
+
+        let rounds = Config::parse("[hash.params]\npartial_rounds = \"many\"").unwrap();
+        assert!(rounds.hash.hasher().is_err());
+        let modulus = PoseidonParamsConfig { modulus: Some("1".into()), ..Default::default() };
+        assert!(modulus.to_params().is_err());
+        assert_eq!(
+            PoseidonParamsConfig { modulus: Some("97".into()), ..Default::default() }
+                .to_params()
+                .unwrap()
+                .modulus,
+            U256::from(97)
+        );
+    }
+}
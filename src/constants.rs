@@ -0,0 +1,33 @@
+//! Prime field moduli shared across the crate
+//!
+//! Both are written with `uint!` so they are evaluated at compile time and
+//! can be used in `const` contexts such as `FieldParams::MODULUS`.
+
+use alloy_primitives::{uint, U256};
+
+/// BN254 (alt_bn128) scalar field modulus `r`
+///
+/// `21888242871839275222246405745257275088548364400416034343698204186575808495617`,
+/// the field of Poseidon, circom circuits and Groth16 proofs on Ethereum.
+pub const BN254_MODULUS: U256 =
+    uint!(0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001_U256);
+
+/// BLS12-381 scalar field modulus `r`
+///
+/// `52435875175126190479447740508185965837690552500527637822603658699938581184513`,
+/// the field of Halo2's Poseidon and EIP-4844 blob polynomials.
+pub const BLS12_381_SCALAR_MODULUS: U256 =
+    uint!(0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001_U256);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moduli() {
+        let bn254 = "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+        let bls = "52435875175126190479447740508185965837690552500527637822603658699938581184513";
+        assert_eq!(BN254_MODULUS, U256::from_str_radix(bn254, 10).unwrap());
+        assert_eq!(BLS12_381_SCALAR_MODULUS, U256::from_str_radix(bls, 10).unwrap());
+    }
+}
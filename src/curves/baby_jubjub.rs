@@ -0,0 +1,141 @@
+//! Validated Baby Jubjub points for the `bjjAdd` and `bjjMulScalar` precompiles
+//!
+//! `BabyJubjubPoint` checks its affine coordinates on construction and runs the
+//! group law through [`BABYJUBJUB`], circomlib's parameterization
+//! `a = 168700, d = 168696` over the BN254 scalar field. Scalar multiplication
+//! works in extended twisted Edwards coordinates `(X : Y : T : Z)`, so it costs
+//! a single field inversion.
+
+use crate::babyjubjub::te_arithmetic::{Point, BABYJUBJUB, BASE8};
+use crate::errors::CurveError;
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::{uint, U256};
+
+/// Order `l` of the prime subgroup generated by [`BASE_POINT`]
+pub const SUBGROUP_ORDER: U256 =
+    uint!(2736030358979909402780800718157159386076813972158567259200215660948447373041_U256);
+
+/// Base point `B` of the prime subgroup (circomlib's `Base8`)
+pub const BASE_POINT: BabyJubjubPoint = BabyJubjubPoint(BASE8);
+
+/// Baby Jubjub point, always on the curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BabyJubjubPoint(Point);
+
+impl BabyJubjubPoint {
+    /// Neutral element `(0, 1)`
+    pub const IDENTITY: Self = Self(Point::IDENTITY);
+
+    /// Creates a point from affine coordinates, rejecting points not on the curve
+    pub fn new(x: U256, y: U256) -> Result<Self, CurveError> {
+        let point = Self(Point::new(x, y));
+        if !point.is_on_curve() {
+            return Err(CurveError::PointNotOnCurve { x, y });
+        }
+        Ok(point)
+    }
+
+    /// Returns the affine coordinates `(x, y)`
+    pub fn to_affine(&self) -> (U256, U256) {
+        (self.0.x, self.0.y)
+    }
+
+    /// Adds two points
+    pub fn add(p: &Self, q: &Self) -> Self {
+        Self(BABYJUBJUB.add(p.0, q.0))
+    }
+
+    /// Multiplies a point by a scalar
+    pub fn scalar_mul(p: &Self, scalar: U256) -> Self {
+        Self(BABYJUBJUB.mul(p.0, scalar))
+    }
+
+    /// Whether the point has canonical coordinates and lies on the curve
+    pub fn is_on_curve(&self) -> bool {
+        BABYJUBJUB.is_on_curve(self.0)
+    }
+
+    /// Packs the point as `y` with the sign of `x` in bit 255, as in iden3's `packPoint`
+    pub fn compress(&self) -> U256 {
+        let negative = self.0.x > BN254_MODULUS >> 1;
+        self.0.y | (U256::from(negative as u8) << 255)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: U256, y: U256) -> BabyJubjubPoint {
+        BabyJubjubPoint::new(x, y).unwrap()
+    }
+
+    // Test point from circomlib's babyjub tests
+    const P1: (U256, U256) = (
+        uint!(17777552123799933955779906779655732241715742912184938656739573121738514868268_U256),
+        uint!(2626589144620713026669568689430873010625803728049924121243784502389097019475_U256),
+    );
+
+    #[test]
+    fn test_base_point() {
+        assert!(BASE_POINT.is_on_curve());
+        let order_times_base = BabyJubjubPoint::scalar_mul(&BASE_POINT, SUBGROUP_ORDER);
+        assert_eq!(order_times_base, BabyJubjubPoint::IDENTITY);
+
+        // Base8 is eight times circomlib's generator of the full group
+        let generator = point(
+            uint!(995203441582195749578291179787384436505546430278305826713579947235728471134_U256),
+            uint!(5472060717959818805561601436314318772137091100104008585924551046643952123905_U256),
+        );
+        assert_eq!(BabyJubjubPoint::scalar_mul(&generator, U256::from(8)), BASE_POINT);
+    }
+
+    #[test]
+    fn test_iden3_vectors() {
+        let p1 = point(P1.0, P1.1);
+
+        let doubled = BabyJubjubPoint::add(&p1, &p1);
+        assert_eq!(
+            doubled.to_affine(),
+            (
+                uint!(6890855772600357754907169075114257697580319025794532037257385534741338397365_U256),
+                uint!(4338620300185947561074059802482547481416142213883829469920100239455078257889_U256),
+            )
+        );
+
+        assert_eq!(
+            BabyJubjubPoint::scalar_mul(&p1, U256::from(3)).to_affine(),
+            (
+                uint!(19372461775513343691590086534037741906533799473648040012278229434133483800898_U256),
+                uint!(9458658722007214007257525444427903161243386465067105737478306991484593958249_U256),
+            )
+        );
+
+        let scalar =
+            uint!(14035240266687799601661095864649209771790948434046947201833777492504781204499_U256);
+        assert_eq!(
+            BabyJubjubPoint::scalar_mul(&p1, scalar).to_affine(),
+            (
+                uint!(17070357974431721403481313912716834497662307308519659060910483826664480189605_U256),
+                uint!(4014745322800118607127020275658861516666525056516280575712425373174125159339_U256),
+            )
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_points() {
+        assert!(BabyJubjubPoint::new(U256::from(1), U256::from(1)).is_err());
+        assert!(BabyJubjubPoint::new(P1.0 + BN254_MODULUS, P1.1).is_err());
+        assert!(BabyJubjubPoint::new(U256::ZERO, U256::from(1)).is_ok());
+    }
+
+    #[test]
+    fn test_compress() {
+        // x of P1 is above (p - 1) / 2, so the sign bit is set
+        assert_eq!(point(P1.0, P1.1).compress(), P1.1 | (U256::from(1) << 255));
+
+        let (_, y) = BASE_POINT.to_affine();
+        assert_eq!(BASE_POINT.compress(), y);
+        assert_eq!(BabyJubjubPoint::IDENTITY.compress(), U256::from(1));
+    }
+}
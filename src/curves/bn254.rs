@@ -0,0 +1,110 @@
+//! Validated BN254 G1 points for the EIP-196 style precompiles
+//!
+//! `BN254G1Point` checks its affine coordinates before every operation and
+//! runs the group law in Jacobian coordinates through [`G1Point`], so a scalar
+//! multiplication costs a single field inversion. The point at infinity is
+//! encoded as `(0, 0)`.
+
+use crate::bn254::g1::{G1Point, G1_GENERATOR};
+use crate::errors::CurveError;
+use alloy_primitives::U256;
+
+/// Affine BN254 G1 point, `(0, 0)` being the point at infinity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BN254G1Point {
+    pub x: U256,
+    pub y: U256,
+}
+
+impl BN254G1Point {
+    /// Point at infinity
+    pub const INFINITY: Self = Self { x: U256::ZERO, y: U256::ZERO };
+
+    /// Standard generator `(1, 2)`
+    pub const GENERATOR: Self = Self { x: G1_GENERATOR.x, y: G1_GENERATOR.y };
+
+    /// Creates a point, rejecting coordinates that are not on the curve
+    pub fn new(x: U256, y: U256) -> Result<Self, CurveError> {
+        Self { x, y }.validated()
+    }
+
+    /// Whether the point has canonical coordinates and lies on the curve
+    pub fn is_on_curve(&self) -> bool {
+        self.to_g1().is_on_curve()
+    }
+
+    /// Negates the point, leaving infinity fixed
+    pub fn neg(&self) -> Self {
+        Self::from_g1(self.to_g1().neg())
+    }
+
+    /// Adds two points
+    pub fn add(p: &Self, q: &Self) -> Result<Self, CurveError> {
+        let sum = p.validated()?.to_g1().add(&q.validated()?.to_g1());
+        Ok(Self::from_g1(sum))
+    }
+
+    /// Multiplies a point by the scalar `s`
+    pub fn scalar_mul(p: &Self, s: U256) -> Result<Self, CurveError> {
+        Ok(Self::from_g1(p.validated()?.to_g1().mul(s)))
+    }
+
+    fn validated(self) -> Result<Self, CurveError> {
+        if !self.is_on_curve() {
+            return Err(CurveError::PointNotOnCurve { x: self.x, y: self.y });
+        }
+        Ok(self)
+    }
+
+    fn to_g1(self) -> G1Point {
+        G1Point::new(self.x, self.y)
+    }
+
+    fn from_g1(point: G1Point) -> Self {
+        Self { x: point.x, y: point.y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::BN254_MODULUS;
+    use alloy_primitives::uint;
+
+    #[test]
+    fn test_double_generator() {
+        // 2·G from the EIP-196 test vectors
+        let g = BN254G1Point::GENERATOR;
+        assert_eq!(
+            BN254G1Point::add(&g, &g).unwrap(),
+            BN254G1Point::new(
+                uint!(0x030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3_U256),
+                uint!(0x15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4_U256),
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            BN254G1Point::scalar_mul(&g, U256::from(2)).unwrap(),
+            BN254G1Point::add(&g, &g).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_group_order() {
+        let g = BN254G1Point::GENERATOR;
+        assert_eq!(BN254G1Point::scalar_mul(&g, BN254_MODULUS).unwrap(), BN254G1Point::INFINITY);
+        assert_eq!(BN254G1Point::add(&g, &BN254G1Point::INFINITY).unwrap(), g);
+    }
+
+    #[test]
+    fn test_rejects_points_off_curve() {
+        assert!(BN254G1Point::new(U256::from(1), U256::from(3)).is_err());
+
+        let off_curve = BN254G1Point { x: U256::from(1), y: U256::from(3) };
+        assert!(matches!(
+            BN254G1Point::add(&BN254G1Point::GENERATOR, &off_curve),
+            Err(CurveError::PointNotOnCurve { .. })
+        ));
+        assert!(BN254G1Point::scalar_mul(&off_curve, U256::from(2)).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+//! BN254 pairing check compatible with the Ethereum `0x08` precompile
+//!
+//! `bn254_pairing_check` returns whether `∏ e(P_i, Q_i) = 1`, the form Groth16
+//! and PLONK verifiers reduce `e(A, B) = e(C, D)` to by negating one side. The
+//! optimal ate pairing itself comes from `substrate-bn`.
+//!
+//! G2 coordinates are elements of `Fq2 = Fq[i]` and, following EIP-197, each
+//! is written imaginary part first. The point at infinity is all zeros in
+//! both groups.
+
+use super::bn254::BN254G1Point;
+use crate::errors::CurveError;
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::{uint, U256};
+use substrate_bn::{pairing_batch, AffineG1, AffineG2, Fq, Fq2, Fr, Group, Gt, G1, G2};
+
+/// Words per `(G1, G2)` pair in the precompile input
+const PAIR_WORDS: usize = 6;
+
+/// BN254 G2 point with `Fq2` coordinates as `[imaginary, real]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BN254G2Point {
+    pub x: [U256; 2],
+    pub y: [U256; 2],
+}
+
+impl BN254G2Point {
+    /// Point at infinity
+    pub const INFINITY: Self = Self { x: [U256::ZERO; 2], y: [U256::ZERO; 2] };
+
+    /// Standard generator from EIP-197
+    pub const GENERATOR: Self = Self {
+        x: [
+            uint!(11559732032986387107991004021392285783925812861821192530917403151452391805634_U256),
+            uint!(10857046999023057135944570762232829481370756359578518086990519993285655852781_U256),
+        ],
+        y: [
+            uint!(4082367875863433681332203403145435568316851327593401208105741076214120093531_U256),
+            uint!(8495653923123431417604973247489272438418190587263600148770280649306958101930_U256),
+        ],
+    };
+
+    pub fn is_infinity(&self) -> bool {
+        *self == Self::INFINITY
+    }
+
+    /// Multiplies a point by the scalar `s`, rejecting points outside G2
+    pub fn scalar_mul(p: &Self, s: U256) -> Result<Self, CurveError> {
+        let s = Fr::from_slice(&(s % BN254_MODULUS).to_be_bytes::<32>()).expect("reduced below r");
+        Ok(from_g2(g2(p)? * s))
+    }
+
+    /// Adds two points, rejecting points outside G2
+    pub fn add(p: &Self, q: &Self) -> Result<Self, CurveError> {
+        Ok(from_g2(g2(p)? + g2(q)?))
+    }
+}
+
+/// Returns whether the product of the pairings of all pairs is the identity
+pub fn bn254_pairing_check(pairs: &[(BN254G1Point, BN254G2Point)]) -> Result<bool, CurveError> {
+    let pairs = pairs
+        .iter()
+        .map(|(p, q)| Ok((g1(p)?, g2(q)?)))
+        .collect::<Result<Vec<_>, CurveError>>()?;
+
+    Ok(pairing_batch(&pairs) == Gt::one())
+}
+
+/// Splits precompile `0x08` input into pairs of `(x, y, x_im, x_re, y_im, y_re)` words
+pub fn decode_pairing_input(input: &[U256]) -> Result<Vec<(BN254G1Point, BN254G2Point)>, CurveError> {
+    if input.len() % PAIR_WORDS != 0 {
+        return Err(CurveError::InvalidPairingInput(input.len()));
+    }
+
+    Ok(input
+        .chunks(PAIR_WORDS)
+        .map(|words| {
+            let p = BN254G1Point { x: words[0], y: words[1] };
+            let q = BN254G2Point { x: [words[2], words[3]], y: [words[4], words[5]] };
+            (p, q)
+        })
+        .collect())
+}
+
+fn g1(point: &BN254G1Point) -> Result<G1, CurveError> {
+    if *point == BN254G1Point::INFINITY {
+        return Ok(G1::zero());
+    }
+
+    let not_on_curve = || CurveError::PointNotOnCurve { x: point.x, y: point.y };
+    let x = fq(point.x).ok_or_else(not_on_curve)?;
+    let y = fq(point.y).ok_or_else(not_on_curve)?;
+    AffineG1::new(x, y).map(G1::from).map_err(|_| not_on_curve())
+}
+
+fn g2(point: &BN254G2Point) -> Result<G2, CurveError> {
+    if point.is_infinity() {
+        return Ok(G2::zero());
+    }
+
+    let fq2 = |[im, re]: [U256; 2]| Some(Fq2::new(fq(re)?, fq(im)?));
+    let x = fq2(point.x).ok_or(CurveError::InvalidG2Point)?;
+    let y = fq2(point.y).ok_or(CurveError::InvalidG2Point)?;
+    AffineG2::new(x, y).map(G2::from).map_err(|_| CurveError::InvalidG2Point)
+}
+
+fn from_g2(point: G2) -> BN254G2Point {
+    let Some(affine) = AffineG2::from_jacobian(point) else {
+        return BN254G2Point::INFINITY;
+    };
+
+    let words = |value: Fq2| [u256(value.imaginary()), u256(value.real())];
+    BN254G2Point { x: words(affine.x()), y: words(affine.y()) }
+}
+
+fn u256(value: Fq) -> U256 {
+    let mut bytes = [0u8; 32];
+    value.to_big_endian(&mut bytes).expect("32 bytes hold a base field element");
+    U256::from_be_bytes(bytes)
+}
+
+/// Parses a canonical base field element
+fn fq(value: U256) -> Option<Fq> {
+    Fq::from_slice(&value.to_be_bytes::<32>()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_pairing() {
+        // e(G1, G2) = e(G1, G2) written as e(G1, G2) · e(-G1, G2) = 1
+        let g1 = BN254G1Point::GENERATOR;
+        let neg_g1 = BN254G1Point::scalar_mul(&g1, crate::utils::BN254_MODULUS - U256::from(1)).unwrap();
+        let g2 = BN254G2Point::GENERATOR;
+        assert!(bn254_pairing_check(&[(g1, g2), (neg_g1, g2)]).unwrap());
+
+        // Empty input and pairs with infinity are trivially the identity
+        assert!(bn254_pairing_check(&[]).unwrap());
+        assert!(bn254_pairing_check(&[(BN254G1Point::INFINITY, g2)]).unwrap());
+    }
+
+    #[test]
+    fn test_g2_scalar_mul() {
+        // e(3·G1, G2) = e(G1, 3·G2)
+        let g1 = BN254G1Point::GENERATOR;
+        let g2 = BN254G2Point::GENERATOR;
+        let three_g1 = BN254G1Point::scalar_mul(&g1, U256::from(3)).unwrap();
+        let three_g2 = BN254G2Point::scalar_mul(&g2, U256::from(3)).unwrap();
+        assert!(bn254_pairing_check(&[(three_g1.neg(), g2), (g1, three_g2)]).unwrap());
+
+        assert_eq!(BN254G2Point::scalar_mul(&g2, U256::from(1)).unwrap(), g2);
+        let two_g2 = BN254G2Point::scalar_mul(&g2, U256::from(2)).unwrap();
+        assert_eq!(BN254G2Point::add(&two_g2, &g2).unwrap(), three_g2);
+        assert_eq!(BN254G2Point::add(&g2, &BN254G2Point::INFINITY).unwrap(), g2);
+        assert!(BN254G2Point::scalar_mul(&g2, BN254_MODULUS).unwrap().is_infinity());
+    }
+
+    #[test]
+    fn test_invalid_pairing() {
+        let g1 = BN254G1Point::GENERATOR;
+        let g2 = BN254G2Point::GENERATOR;
+        assert!(!bn254_pairing_check(&[(g1, g2)]).unwrap());
+        assert!(!bn254_pairing_check(&[(g1, g2), (g1, g2)]).unwrap());
+
+        // e(2·G1, G2) ≠ e(G1, G2)
+        let two_g1 = BN254G1Point::scalar_mul(&g1, U256::from(2)).unwrap();
+        let neg_g1 = BN254G1Point::scalar_mul(&g1, crate::utils::BN254_MODULUS - U256::from(1)).unwrap();
+        assert!(!bn254_pairing_check(&[(two_g1, g2), (neg_g1, g2)]).unwrap());
+    }
+
+    #[test]
+    fn test_rejects_invalid_points() {
+        let g2 = BN254G2Point::GENERATOR;
+        let off_curve = BN254G1Point { x: U256::from(1), y: U256::from(3) };
+        assert!(matches!(
+            bn254_pairing_check(&[(off_curve, g2)]),
+            Err(CurveError::PointNotOnCurve { .. })
+        ));
+
+        let bad_g2 = BN254G2Point { x: g2.x, y: g2.x };
+        assert!(matches!(
+            bn254_pairing_check(&[(BN254G1Point::GENERATOR, bad_g2)]),
+            Err(CurveError::InvalidG2Point)
+        ));
+        assert!(matches!(decode_pairing_input(&[U256::ZERO; 5]), Err(CurveError::InvalidPairingInput(5))));
+    }
+}
@@ -0,0 +1,245 @@
+//! Curve precompile interfaces and ABI definitions
+
+use super::bn254::BN254G1Point;
+use super::bn254_pairing::{bn254_pairing_check, decode_pairing_input};
+use super::msm::{bn254_msm, decode_msm_input};
+use crate::errors::{InvalidSelectorError, PrecompileError};
+use crate::gas::GasCosts;
+use crate::poseidon::interface::decode_call;
+use crate::traits::GenericPrecompile;
+use alloy_sol_types::{sol, SolCall, SolValue};
+
+// Solidity interface definitions
+sol! {
+    #[sol(abi)]
+    interface IBN254 {
+        /// Adds two BN254 G1 points, `(0, 0)` being the point at infinity
+        /// @return rx The x coordinate of the sum
+        /// @return ry The y coordinate of the sum
+        function ecAdd(uint256 ax, uint256 ay, uint256 bx, uint256 by) external pure returns (uint256 rx, uint256 ry);
+
+        /// Multiplies a BN254 G1 point by a scalar
+        /// @return rx The x coordinate of the product
+        /// @return ry The y coordinate of the product
+        function ecMul(uint256 px, uint256 py, uint256 s) external pure returns (uint256 rx, uint256 ry);
+
+        /// Checks that the product of pairings is the identity, in the precompile 0x08 layout
+        /// @param input Six words per pair: G1 x, y then G2 x and y with the imaginary part first
+        /// @return valid Whether the pairing product is one
+        function ecPairingCheck(uint256[] input) external pure returns (bool valid);
+    }
+
+    #[sol(abi)]
+    interface IBN254MSM {
+        /// Computes the multi-scalar multiplication of BN254 G1 points
+        /// @param input Three words per term: the scalar, then the point's x and y
+        /// @return rx The x coordinate of the sum
+        /// @return ry The y coordinate of the sum
+        function ecMsm(uint256[] input) external pure returns (uint256 rx, uint256 ry);
+    }
+}
+
+/// Words in the single `uint256[]` argument, after its offset and length
+fn array_words(input: &[u8]) -> u64 {
+    (input.len().saturating_sub(4 + 64) / 32) as u64
+}
+
+/// BN254 precompile behind the `IBN254` selectors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BN254Precompile;
+
+impl BN254Precompile {
+    /// Selectors of every `IBN254` function
+    pub const SELECTORS: [[u8; 4]; 3] = [
+        IBN254::ecAddCall::SELECTOR,
+        IBN254::ecMulCall::SELECTOR,
+        IBN254::ecPairingCheckCall::SELECTOR,
+    ];
+}
+
+impl GenericPrecompile for BN254Precompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        bn254_precompile(input)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, input: &[u8]) -> u64 {
+        match input.get(0..4) {
+            Some(s) if s == IBN254::ecAddCall::SELECTOR => GasCosts::EC_ADD,
+            Some(s) if s == IBN254::ecPairingCheckCall::SELECTOR => {
+                GasCosts::ec_pairing(array_words(input) / 6)
+            }
+            _ => GasCosts::EC_MUL,
+        }
+    }
+}
+
+/// BN254 G1 precompile entry point - handles the raw call interface
+pub fn bn254_precompile(input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+    if input.len() < 4 {
+        return Err(InvalidSelectorError::from_input(input).into());
+    }
+
+    match &input[0..4] {
+        // ecAdd(uint256,uint256,uint256,uint256)
+        s if s == IBN254::ecAddCall::SELECTOR => {
+            let decoded = decode_call::<IBN254::ecAddCall>(input)?;
+
+            let a = BN254G1Point::new(decoded.ax, decoded.ay)?;
+            let b = BN254G1Point::new(decoded.bx, decoded.by)?;
+            let sum = BN254G1Point::add(&a, &b)?;
+            Ok((sum.x, sum.y).abi_encode_params())
+        }
+
+        // ecMul(uint256,uint256,uint256)
+        s if s == IBN254::ecMulCall::SELECTOR => {
+            let decoded = decode_call::<IBN254::ecMulCall>(input)?;
+
+            let point = BN254G1Point::new(decoded.px, decoded.py)?;
+            let product = BN254G1Point::scalar_mul(&point, decoded.s)?;
+            Ok((product.x, product.y).abi_encode_params())
+        }
+
+        // ecPairingCheck(uint256[])
+        s if s == IBN254::ecPairingCheckCall::SELECTOR => {
+            let decoded = decode_call::<IBN254::ecPairingCheckCall>(input)?;
+
+            let pairs = decode_pairing_input(&decoded.input)?;
+            Ok(bn254_pairing_check(&pairs)?.abi_encode())
+        }
+
+        _ => Err(InvalidSelectorError::from_input(input).into()),
+    }
+}
+
+/// BN254 MSM precompile behind the `IBN254MSM` selectors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MsmPrecompile;
+
+impl MsmPrecompile {
+    /// Selectors of every `IBN254MSM` function
+    pub const SELECTORS: [[u8; 4]; 1] = [IBN254MSM::ecMsmCall::SELECTOR];
+}
+
+impl GenericPrecompile for MsmPrecompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        msm_precompile(input)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, input: &[u8]) -> u64 {
+        GasCosts::ec_msm(array_words(input) / 3)
+    }
+}
+
+/// BN254 MSM precompile entry point - handles the raw call interface
+pub fn msm_precompile(input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+    if input.len() < 4 {
+        return Err(InvalidSelectorError::from_input(input).into());
+    }
+
+    match &input[0..4] {
+        // ecMsm(uint256[])
+        s if s == IBN254MSM::ecMsmCall::SELECTOR => {
+            let decoded = decode_call::<IBN254MSM::ecMsmCall>(input)?;
+
+            let (scalars, points) = decode_msm_input(&decoded.input)?;
+            let sum = bn254_msm(&scalars, &points)?;
+            Ok((sum.x, sum.y).abi_encode_params())
+        }
+
+        _ => Err(InvalidSelectorError::from_input(input).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::CurveError;
+    use alloy_primitives::U256;
+
+    #[test]
+    fn test_bn254_precompile() {
+        let g = BN254G1Point::GENERATOR;
+
+        let call = IBN254::ecMulCall { px: g.x, py: g.y, s: U256::from(2) }.abi_encode();
+        let output = bn254_precompile(&call).unwrap();
+        let product = IBN254::ecMulCall::abi_decode_returns(&output, true).unwrap();
+
+        let call = IBN254::ecAddCall { ax: g.x, ay: g.y, bx: g.x, by: g.y }.abi_encode();
+        let output = bn254_precompile(&call).unwrap();
+        let sum = IBN254::ecAddCall::abi_decode_returns(&output, true).unwrap();
+        assert_eq!((product.rx, product.ry), (sum.rx, sum.ry));
+
+        let call = IBN254::ecMulCall { px: g.x, py: U256::from(3), s: U256::from(2) }.abi_encode();
+        assert!(matches!(
+            bn254_precompile(&call),
+            Err(PrecompileError::Curve(CurveError::PointNotOnCurve { .. }))
+        ));
+        assert!(matches!(bn254_precompile(&call[..40]), Err(PrecompileError::Abi(_))));
+        assert!(matches!(bn254_precompile(&[0u8; 8]), Err(PrecompileError::Selector(_))));
+    }
+
+    #[test]
+    fn test_bn254_gas_cost() {
+        let g = BN254G1Point::GENERATOR;
+        let add = IBN254::ecAddCall { ax: g.x, ay: g.y, bx: g.x, by: g.y }.abi_encode();
+        let mul = IBN254::ecMulCall { px: g.x, py: g.y, s: U256::from(2) }.abi_encode();
+        let pairing = IBN254::ecPairingCheckCall { input: vec![U256::ZERO; 12] }.abi_encode();
+
+        let precompile = BN254Precompile;
+        assert_eq!(precompile.gas_cost(&add), GasCosts::EC_ADD);
+        assert_eq!(precompile.gas_cost(&mul), GasCosts::EC_MUL);
+        assert_eq!(precompile.gas_cost(&pairing), GasCosts::ec_pairing(2));
+    }
+
+    #[test]
+    fn test_pairing_precompile() {
+        let g1 = BN254G1Point::GENERATOR;
+        let neg_g1 = BN254G1Point::new(g1.x, crate::bn254::BN254_BASE_MODULUS - g1.y).unwrap();
+        let g2 = crate::curves::BN254G2Point::GENERATOR;
+        let pair = |p: BN254G1Point| vec![p.x, p.y, g2.x[0], g2.x[1], g2.y[0], g2.y[1]];
+
+        let input = [pair(g1), pair(neg_g1)].concat();
+        let output = bn254_precompile(&IBN254::ecPairingCheckCall { input }.abi_encode()).unwrap();
+        assert!(IBN254::ecPairingCheckCall::abi_decode_returns(&output, true).unwrap().valid);
+
+        let output = bn254_precompile(&IBN254::ecPairingCheckCall { input: pair(g1) }.abi_encode()).unwrap();
+        assert!(!IBN254::ecPairingCheckCall::abi_decode_returns(&output, true).unwrap().valid);
+
+        let input = vec![U256::ZERO; 4];
+        assert!(matches!(
+            bn254_precompile(&IBN254::ecPairingCheckCall { input }.abi_encode()),
+            Err(PrecompileError::Curve(CurveError::InvalidPairingInput(4)))
+        ));
+    }
+
+    #[test]
+    fn test_msm_precompile() {
+        let g = BN254G1Point::GENERATOR;
+        let two_g = BN254G1Point::add(&g, &g).unwrap();
+        let input = vec![U256::from(3), g.x, g.y, U256::from(5), two_g.x, two_g.y];
+
+        let output = msm_precompile(&IBN254MSM::ecMsmCall { input }.abi_encode()).unwrap();
+        let sum = IBN254MSM::ecMsmCall::abi_decode_returns(&output, true).unwrap();
+        let expected = BN254G1Point::scalar_mul(&g, U256::from(13)).unwrap();
+        assert_eq!((sum.rx, sum.ry), (expected.x, expected.y));
+
+        let input = vec![U256::from(3), g.x];
+        let call = IBN254MSM::ecMsmCall { input }.abi_encode();
+        assert!(matches!(
+            msm_precompile(&call),
+            Err(PrecompileError::Curve(CurveError::InvalidMsmInput(2)))
+        ));
+        assert!(matches!(msm_precompile(&[0u8; 8]), Err(PrecompileError::Selector(_))));
+
+        let call = IBN254MSM::ecMsmCall { input: vec![U256::ZERO; 6] }.abi_encode();
+        assert_eq!(MsmPrecompile.gas_cost(&call), GasCosts::ec_msm(2));
+    }
+}
@@ -0,0 +1,15 @@
+//! Elliptic curve operations exposed as precompiles
+
+pub mod baby_jubjub;
+pub mod bn254;
+pub mod bn254_pairing;
+pub mod interface;
+pub mod msm;
+
+pub use baby_jubjub::{BabyJubjubPoint, BASE_POINT, SUBGROUP_ORDER};
+pub use bn254::BN254G1Point;
+pub use bn254_pairing::{bn254_pairing_check, BN254G2Point};
+pub use interface::{
+    bn254_precompile, msm_precompile, BN254Precompile, MsmPrecompile, IBN254, IBN254MSM,
+};
+pub use msm::bn254_msm;
@@ -0,0 +1,155 @@
+//! BN254 G1 multi-scalar multiplication
+//!
+//! `bn254_msm` computes `Σ sᵢ·Pᵢ` with Pippenger's bucket method. Scalars are
+//! cut into `c`-bit windows; in each window every point is added once into
+//! the bucket of its digit, and the buckets are combined with a running sum,
+//! so a window costs about `n + 2^(c+1)` additions instead of `n` scalar
+//! multiplications. All additions stay in Jacobian coordinates, leaving a
+//! single field inversion for the result.
+
+use super::bn254::BN254G1Point;
+use crate::bn254::g1::{G1Point, Jacobian};
+use crate::errors::CurveError;
+use alloy_primitives::U256;
+
+/// Words per `(scalar, x, y)` triple in the precompile input
+const TRIPLE_WORDS: usize = 3;
+
+/// Below this many pairs the bucket setup costs more than it saves
+const PIPPENGER_THRESHOLD: usize = 4;
+
+/// Computes `Σ scalars[i] · points[i]`, rejecting points outside the curve
+pub fn bn254_msm(scalars: &[U256], points: &[BN254G1Point]) -> Result<BN254G1Point, CurveError> {
+    if scalars.len() != points.len() {
+        return Err(CurveError::MsmLengthMismatch { scalars: scalars.len(), points: points.len() });
+    }
+
+    if points.len() < PIPPENGER_THRESHOLD {
+        return naive_msm(scalars, points);
+    }
+
+    let bases = points
+        .iter()
+        .map(|p| {
+            let p = BN254G1Point::new(p.x, p.y)?;
+            Ok(G1Point::new(p.x, p.y).to_jacobian())
+        })
+        .collect::<Result<Vec<_>, CurveError>>()?;
+    let sum = pippenger(scalars, &bases).to_affine();
+    Ok(BN254G1Point { x: sum.x, y: sum.y })
+}
+
+/// Splits the precompile's flat `(scalar, x, y)` words into scalars and points
+pub fn decode_msm_input(input: &[U256]) -> Result<(Vec<U256>, Vec<BN254G1Point>), CurveError> {
+    if input.len() % TRIPLE_WORDS != 0 {
+        return Err(CurveError::InvalidMsmInput(input.len()));
+    }
+
+    Ok(input
+        .chunks(TRIPLE_WORDS)
+        .map(|words| (words[0], BN254G1Point { x: words[1], y: words[2] }))
+        .unzip())
+}
+
+/// Repeated `scalar_mul` and `add`, for inputs too short to amortize buckets
+fn naive_msm(scalars: &[U256], points: &[BN254G1Point]) -> Result<BN254G1Point, CurveError> {
+    let mut sum = BN254G1Point::INFINITY;
+    for (scalar, point) in scalars.iter().zip(points) {
+        sum = BN254G1Point::add(&sum, &BN254G1Point::scalar_mul(point, *scalar)?)?;
+    }
+    Ok(sum)
+}
+
+fn pippenger(scalars: &[U256], bases: &[Jacobian]) -> Jacobian {
+    let c = window_bits(bases.len());
+    let max_bits = scalars.iter().map(U256::bit_len).max().unwrap_or(0);
+    let mask = (1usize << c) - 1;
+
+    let mut acc = Jacobian::INFINITY;
+    for window in (0..max_bits.div_ceil(c)).rev() {
+        for _ in 0..c {
+            acc = acc.double();
+        }
+
+        // Bucket k - 1 collects the points whose window digit is k
+        let mut buckets = vec![Jacobian::INFINITY; mask];
+        for (scalar, base) in scalars.iter().zip(bases) {
+            let digit = (*scalar >> (window * c)).as_limbs()[0] as usize & mask;
+            if digit != 0 {
+                buckets[digit - 1] = buckets[digit - 1].add(base);
+            }
+        }
+
+        // Σ k·bucket[k] as a sum of suffix sums
+        let mut running = Jacobian::INFINITY;
+        let mut window_sum = Jacobian::INFINITY;
+        for bucket in buckets.iter().rev() {
+            running = running.add(bucket);
+            window_sum = window_sum.add(&running);
+        }
+        acc = acc.add(&window_sum);
+    }
+    acc
+}
+
+/// Window width near `log2(n)`, the usual optimum for Pippenger
+fn window_bits(n: usize) -> usize {
+    (n.ilog2() as usize * 2 / 3 + 2).min(16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::BN254_MODULUS;
+
+    fn multiples(n: u64) -> Vec<BN254G1Point> {
+        (1..=n)
+            .map(|i| BN254G1Point::scalar_mul(&BN254G1Point::GENERATOR, U256::from(i)).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_matches_naive() {
+        for n in [1u64, 3, 4, 9, 40] {
+            let points = multiples(n);
+            let scalars: Vec<U256> = (0..n)
+                .map(|i| BN254_MODULUS - U256::from(i * 1_000_003 + 1))
+                .collect();
+            assert_eq!(
+                bn254_msm(&scalars, &points).unwrap(),
+                naive_msm(&scalars, &points).unwrap(),
+                "n = {}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_known_sum() {
+        // Σ i·(i·G) for i = 1..=5 is 55·G
+        let points = multiples(5);
+        let scalars: Vec<U256> = (1..=5u64).map(U256::from).collect();
+        let expected = BN254G1Point::scalar_mul(&BN254G1Point::GENERATOR, U256::from(55)).unwrap();
+        assert_eq!(bn254_msm(&scalars, &points).unwrap(), expected);
+
+        let zeros = vec![U256::ZERO; 5];
+        assert_eq!(bn254_msm(&zeros, &points).unwrap(), BN254G1Point::INFINITY);
+        assert_eq!(bn254_msm(&[], &[]).unwrap(), BN254G1Point::INFINITY);
+    }
+
+    #[test]
+    fn test_rejects_bad_inputs() {
+        let mut points = multiples(5);
+        assert!(matches!(
+            bn254_msm(&[U256::from(1); 4], &points),
+            Err(CurveError::MsmLengthMismatch { scalars: 4, points: 5 })
+        ));
+
+        points[2] = BN254G1Point { x: U256::from(1), y: U256::from(3) };
+        assert!(matches!(
+            bn254_msm(&[U256::from(1); 5], &points),
+            Err(CurveError::PointNotOnCurve { .. })
+        ));
+        assert!(matches!(decode_msm_input(&[U256::ZERO; 4]), Err(CurveError::InvalidMsmInput(4))));
+    }
+}
@@ -0,0 +1,7 @@
+//! Tracking Stylus deployments on chain
+
+pub mod monitor;
+pub mod verifier;
+
+pub use monitor::{parse_deploy_tx_hash, CurlTransport, DeployMonitor, DeployReceipt, RpcTransport};
+pub use verifier::{DeploymentVerifier, IStylusPrecompile, TestVector, VectorResult};
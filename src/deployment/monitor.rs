@@ -0,0 +1,283 @@
+//! Polls an RPC endpoint until a deployment transaction is confirmed
+//!
+//! Requests go through the `RpcTransport` trait so tests can script the
+//! responses. `CurlTransport` posts JSON-RPC with the system `curl`, the same
+//! way the CLI already delegates to external tools.
+
+use std::cell::Cell;
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use alloy_primitives::{keccak256, Address};
+use serde_json::{json, Value};
+
+use crate::errors::DeployError;
+
+/// Delay between `eth_getTransactionReceipt` polls
+pub const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Sends JSON-RPC requests and returns the `result` field
+pub trait RpcTransport {
+    fn request(&self, method: &str, params: Value) -> Result<Value, DeployError>;
+}
+
+/// JSON-RPC over HTTP using the `curl` binary
+pub struct CurlTransport {
+    rpc_url: String,
+    next_id: Cell<u64>,
+}
+
+impl CurlTransport {
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            rpc_url: rpc_url.to_string(),
+            next_id: Cell::new(1),
+        }
+    }
+}
+
+impl RpcTransport for CurlTransport {
+    fn request(&self, method: &str, params: Value) -> Result<Value, DeployError> {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        let body = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+
+        let output = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "-X", "POST"])
+            .args(["-H", "Content-Type: application/json"])
+            .args(["--data", &body.to_string()])
+            .arg(&self.rpc_url)
+            .output()
+            .map_err(|e| DeployError::Rpc(e.to_string()))?;
+        if !output.status.success() {
+            return Err(DeployError::Rpc(String::from_utf8_lossy(&output.stderr).trim().to_string()));
+        }
+
+        let response: Value = serde_json::from_slice(&output.stdout)
+            .map_err(|e| DeployError::InvalidResponse(e.to_string()))?;
+        if let Some(error) = response.get("error") {
+            return Err(DeployError::Rpc(error.to_string()));
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| DeployError::InvalidResponse("missing result".to_string()))
+    }
+}
+
+/// Receipt of a confirmed deployment transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeployReceipt {
+    pub tx_hash: [u8; 32],
+    pub block_number: u64,
+    /// Set when the transaction itself created the contract
+    pub contract_address: Option<Address>,
+}
+
+/// Watches a deployment transaction until it is confirmed
+pub struct DeployMonitor<T: RpcTransport = CurlTransport> {
+    tx_hash: [u8; 32],
+    transport: T,
+    poll_interval: Duration,
+}
+
+impl DeployMonitor<CurlTransport> {
+    /// Creates a monitor talking to `rpc_url`
+    pub fn new(tx_hash: [u8; 32], rpc_url: &str) -> Self {
+        Self::with_transport(tx_hash, CurlTransport::new(rpc_url))
+    }
+}
+
+impl<T: RpcTransport> DeployMonitor<T> {
+    /// Creates a monitor over a custom transport
+    pub fn with_transport(tx_hash: [u8; 32], transport: T) -> Self {
+        Self {
+            tx_hash,
+            transport,
+            poll_interval: POLL_INTERVAL,
+        }
+    }
+
+    /// Overrides the delay between receipt polls
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Polls for the transaction receipt until it is confirmed or `timeout_secs` pass
+    pub fn wait_for_deployment(&self, timeout_secs: u64) -> Result<DeployReceipt, DeployError> {
+        let deadline = Instant::now() + Duration::from_secs(timeout_secs);
+        let tx_hash = format!("0x{}", hex::encode(self.tx_hash));
+
+        loop {
+            let receipt = self
+                .transport
+                .request("eth_getTransactionReceipt", json!([tx_hash]))?;
+            if !receipt.is_null() {
+                return self.parse_receipt(&receipt);
+            }
+
+            if Instant::now() + self.poll_interval > deadline {
+                return Err(DeployError::Timeout(timeout_secs));
+            }
+            thread::sleep(self.poll_interval);
+        }
+    }
+
+    /// Whether the code at `address` hashes to `expected_hash`
+    pub fn verify_deployed_code(
+        &self,
+        address: Address,
+        expected_hash: [u8; 32],
+    ) -> Result<bool, DeployError> {
+        let code = self
+            .transport
+            .request("eth_getCode", json!([address.to_string(), "latest"]))?;
+        let code = parse_hex_bytes(&code)?;
+        Ok(keccak256(code).0 == expected_hash)
+    }
+
+    fn parse_receipt(&self, receipt: &Value) -> Result<DeployReceipt, DeployError> {
+        let block_number = parse_quantity(&receipt["blockNumber"])?;
+        if parse_quantity(&receipt["status"])? != 1 {
+            return Err(DeployError::Reverted(block_number));
+        }
+
+        let contract_address = match &receipt["contractAddress"] {
+            Value::Null => None,
+            value => Some(
+                value
+                    .as_str()
+                    .and_then(|s| s.parse().ok())
+                    .ok_or_else(|| DeployError::InvalidResponse(format!("bad address {}", value)))?,
+            ),
+        };
+
+        Ok(DeployReceipt {
+            tx_hash: self.tx_hash,
+            block_number,
+            contract_address,
+        })
+    }
+}
+
+/// Extracts the transaction hash from `cargo stylus deploy` output
+pub fn parse_deploy_tx_hash(output: &str) -> Option<[u8; 32]> {
+    output
+        .lines()
+        .filter(|line| line.to_lowercase().contains("tx hash"))
+        .find_map(|line| {
+            let start = line.find("0x")?;
+            let hex_str: String = line[start + 2..]
+                .chars()
+                .take_while(char::is_ascii_hexdigit)
+                .collect();
+            hex::decode(hex_str).ok()?.try_into().ok()
+        })
+}
+
+fn parse_quantity(value: &Value) -> Result<u64, DeployError> {
+    value
+        .as_str()
+        .and_then(|s| u64::from_str_radix(s.trim_start_matches("0x"), 16).ok())
+        .ok_or_else(|| DeployError::InvalidResponse(format!("bad quantity {}", value)))
+}
+
+fn parse_hex_bytes(value: &Value) -> Result<Vec<u8>, DeployError> {
+    value
+        .as_str()
+        .and_then(|s| hex::decode(s.trim_start_matches("0x")).ok())
+        .ok_or_else(|| DeployError::InvalidResponse(format!("bad bytes {}", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+
+    /// Replays canned results and records the methods called
+    #[derive(Default)]
+    struct MockTransport {
+        responses: RefCell<VecDeque<Value>>,
+        calls: RefCell<Vec<String>>,
+    }
+
+    impl MockTransport {
+        fn new(responses: Vec<Value>) -> Self {
+            Self {
+                responses: RefCell::new(responses.into()),
+                calls: RefCell::default(),
+            }
+        }
+    }
+
+    impl RpcTransport for &MockTransport {
+        fn request(&self, method: &str, _params: Value) -> Result<Value, DeployError> {
+            self.calls.borrow_mut().push(method.to_string());
+            Ok(self.responses.borrow_mut().pop_front().unwrap_or(Value::Null))
+        }
+    }
+
+    const TX_HASH: [u8; 32] = [0xab; 32];
+
+    #[test]
+    fn test_waits_for_receipt() {
+        let transport = MockTransport::new(vec![
+            Value::Null,
+            Value::Null,
+            json!({
+                "status": "0x1",
+                "blockNumber": "0x10",
+                "contractAddress": "0x00000000000000000000000000000000000000aa",
+            }),
+        ]);
+        let monitor = DeployMonitor::with_transport(TX_HASH, &transport)
+            .with_poll_interval(Duration::from_millis(1));
+
+        let receipt = monitor.wait_for_deployment(5).unwrap();
+        assert_eq!(receipt.block_number, 16);
+        assert_eq!(receipt.contract_address, Some(Address::with_last_byte(0xaa)));
+        assert_eq!(transport.calls.borrow().len(), 3);
+    }
+
+    #[test]
+    fn test_timeout_and_revert() {
+        let transport = MockTransport::default();
+        let monitor = DeployMonitor::with_transport(TX_HASH, &transport)
+            .with_poll_interval(Duration::from_millis(400));
+        assert!(matches!(monitor.wait_for_deployment(1), Err(DeployError::Timeout(1))));
+
+        let transport = MockTransport::new(vec![json!({
+            "status": "0x0",
+            "blockNumber": "0x5",
+            "contractAddress": null,
+        })]);
+        let monitor = DeployMonitor::with_transport(TX_HASH, &transport);
+        assert!(matches!(monitor.wait_for_deployment(5), Err(DeployError::Reverted(5))));
+    }
+
+    #[test]
+    fn test_verify_deployed_code() {
+        let code = [0xef, 0xf0, 0x00, 0x01];
+        let transport = MockTransport::new(vec![json!("0xeff00001"), json!("0xeff00001")]);
+        let monitor = DeployMonitor::with_transport(TX_HASH, &transport);
+
+        let address = Address::with_last_byte(1);
+        assert!(monitor.verify_deployed_code(address, keccak256(code).0).unwrap());
+        assert!(!monitor.verify_deployed_code(address, [0u8; 32]).unwrap());
+        assert_eq!(*transport.calls.borrow(), ["eth_getCode", "eth_getCode"]);
+    }
+
+    #[test]
+    fn test_parse_deploy_tx_hash() {
+        let output = format!(
+            "deployed code at address: 0x00000000000000000000000000000000000000aa\n\
+             deployment tx hash: 0x{}\n",
+            hex::encode(TX_HASH)
+        );
+        assert_eq!(parse_deploy_tx_hash(&output), Some(TX_HASH));
+        assert_eq!(parse_deploy_tx_hash("no hash here"), None);
+    }
+}
@@ -0,0 +1,219 @@
+//! Checks that a deployed precompile computes the same hashes as this crate
+//!
+//! Each test vector is encoded as `IPoseidonHash` calldata, wrapped in the
+//! Stylus contract's `dispatch(bytes)` method and sent with `eth_call`. The
+//! decoded result is compared against the same hash computed locally.
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, SolCall, SolValue};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::monitor::{CurlTransport, RpcTransport};
+use crate::errors::DeployError;
+use crate::poseidon::{IPoseidonHash, PoseidonHash};
+
+sol! {
+    /// Raw-calldata method of the Stylus contract in `entrypoint.rs`
+    interface IStylusPrecompile {
+        function dispatch(bytes input) external view returns (bytes output);
+    }
+}
+
+/// One hash call to check, as read from a test vector file
+///
+/// `function` is `poseidon1`, `poseidon2` or `poseidonN`, and inputs are
+/// decimal or 0x-prefixed hex strings.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct TestVector {
+    pub function: String,
+    pub inputs: Vec<U256>,
+}
+
+impl TestVector {
+    pub fn new(function: &str, inputs: &[u64]) -> Self {
+        Self {
+            function: function.to_string(),
+            inputs: inputs.iter().map(|&i| U256::from(i)).collect(),
+        }
+    }
+
+    /// `poseidon1(42)`, `poseidon2(1, 2)` and `poseidonN([1, 2, 3])`
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::new("poseidon1", &[42]),
+            Self::new("poseidon2", &[1, 2]),
+            Self::new("poseidonN", &[1, 2, 3]),
+        ]
+    }
+
+    /// Parses a JSON array of test vectors
+    pub fn from_json(json: &str) -> Result<Vec<Self>, DeployError> {
+        serde_json::from_str(json).map_err(|e| DeployError::InvalidTestVector(e.to_string()))
+    }
+
+    /// `IPoseidonHash` calldata for the vector
+    fn calldata(&self) -> Result<Vec<u8>, DeployError> {
+        match (self.function.as_str(), self.inputs.as_slice()) {
+            ("poseidon1", [input]) => Ok(IPoseidonHash::poseidon1Call { input: *input }.abi_encode()),
+            ("poseidon2", [left, right]) => {
+                Ok(IPoseidonHash::poseidon2Call { left: *left, right: *right }.abi_encode())
+            }
+            ("poseidonN", inputs) => {
+                Ok(IPoseidonHash::poseidonNCall { inputs: inputs.to_vec() }.abi_encode())
+            }
+            _ => Err(DeployError::InvalidTestVector(format!(
+                "{} with {} inputs",
+                self.function,
+                self.inputs.len()
+            ))),
+        }
+    }
+
+    /// The hash computed by this crate, as the deployed build's `poseidon_precompile`
+    fn expected(&self) -> Result<U256, DeployError> {
+        let hasher = PoseidonHash::new();
+        let hash = match self.inputs.as_slice() {
+            [input] if self.function == "poseidon1" => hasher.hash_single_default(*input),
+            [left, right] if self.function == "poseidon2" => {
+                hasher.hash_pair_default(*left, *right)
+            }
+            inputs => hasher.hash_array_default(inputs),
+        };
+        hash.map_err(|e| DeployError::InvalidTestVector(e.to_string()))
+    }
+}
+
+/// Outcome of one test vector
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VectorResult {
+    pub vector: TestVector,
+    pub expected: U256,
+    pub actual: U256,
+}
+
+impl VectorResult {
+    pub fn passed(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+/// Runs test vectors against a deployed precompile
+pub struct DeploymentVerifier<T: RpcTransport = CurlTransport> {
+    address: Address,
+    transport: T,
+}
+
+impl DeploymentVerifier<CurlTransport> {
+    /// Creates a verifier calling `address` through `rpc_url`
+    pub fn new(address: Address, rpc_url: &str) -> Self {
+        Self::with_transport(address, CurlTransport::new(rpc_url))
+    }
+}
+
+impl<T: RpcTransport> DeploymentVerifier<T> {
+    /// Creates a verifier over a custom transport
+    pub fn with_transport(address: Address, transport: T) -> Self {
+        Self { address, transport }
+    }
+
+    /// Calls the deployed contract with every vector and compares the results
+    pub fn verify(&self, vectors: &[TestVector]) -> Result<Vec<VectorResult>, DeployError> {
+        vectors
+            .iter()
+            .map(|vector| {
+                let calldata = vector.calldata()?;
+                let expected = vector.expected()?;
+                let actual = self.call(&calldata)?;
+                Ok(VectorResult { vector: vector.clone(), expected, actual })
+            })
+            .collect()
+    }
+
+    fn call(&self, input: &[u8]) -> Result<U256, DeployError> {
+        let data = IStylusPrecompile::dispatchCall { input: input.to_vec().into() }.abi_encode();
+        let params = json!([
+            { "to": self.address.to_string(), "data": format!("0x{}", hex::encode(data)) },
+            "latest"
+        ]);
+        let result = self.transport.request("eth_call", params)?;
+
+        let invalid = || DeployError::InvalidResponse(format!("bad eth_call result {}", result));
+        let returned = result
+            .as_str()
+            .and_then(|s| hex::decode(s.trim_start_matches("0x")).ok())
+            .ok_or_else(invalid)?;
+        let output = IStylusPrecompile::dispatchCall::abi_decode_returns(&returned, true)
+            .map_err(|_| invalid())?
+            .output;
+        U256::abi_decode(&output, true).map_err(|_| invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Bytes;
+    use alloy_sol_types::SolInterface;
+    use serde_json::Value;
+    use IPoseidonHash::IPoseidonHashCalls;
+
+    /// Answers `eth_call` with the precompile's hash, optionally off by one
+    struct LocalTransport {
+        off_by_one: bool,
+    }
+
+    impl RpcTransport for LocalTransport {
+        fn request(&self, method: &str, params: Value) -> Result<Value, DeployError> {
+            assert_eq!(method, "eth_call");
+            let data = params[0]["data"].as_str().unwrap().trim_start_matches("0x");
+            let data = hex::decode(data).unwrap();
+            let input = IStylusPrecompile::dispatchCall::abi_decode(&data, true).unwrap().input;
+
+            let hasher = PoseidonHash::new();
+            let hash = match IPoseidonHashCalls::abi_decode(&input, true).unwrap() {
+                IPoseidonHashCalls::poseidon1(call) => hasher.hash_single_default(call.input),
+                IPoseidonHashCalls::poseidon2(c) => hasher.hash_pair_default(c.left, c.right),
+                IPoseidonHashCalls::poseidonN(call) => hasher.hash_array_default(&call.inputs),
+                _ => panic!("unexpected IPoseidonHash call"),
+            };
+            let hash = hash.unwrap() + U256::from(self.off_by_one as u8);
+
+            let output = Bytes::from(hash.abi_encode());
+            let returned = IStylusPrecompile::dispatchCall::abi_encode_returns(&(output,));
+            Ok(json!(format!("0x{}", hex::encode(returned))))
+        }
+    }
+
+    fn verifier(off_by_one: bool) -> DeploymentVerifier<LocalTransport> {
+        DeploymentVerifier::with_transport(Address::ZERO, LocalTransport { off_by_one })
+    }
+
+    #[test]
+    fn test_matching_deployment() {
+        let verifier = verifier(false);
+        let results = verifier.verify(&TestVector::defaults()).unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(VectorResult::passed));
+    }
+
+    #[test]
+    fn test_mismatched_deployment() {
+        let verifier = verifier(true);
+        let results = verifier.verify(&TestVector::defaults()).unwrap();
+        assert!(results.iter().all(|result| !result.passed()));
+        assert_eq!(results[0].actual, results[0].expected + U256::from(1));
+    }
+
+    #[test]
+    fn test_vector_file() {
+        let json = r#"[{"function": "poseidon2", "inputs": ["7", "0x08"]}]"#;
+        assert_eq!(TestVector::from_json(json).unwrap(), [TestVector::new("poseidon2", &[7, 8])]);
+        assert!(TestVector::from_json("{}").is_err());
+
+        assert!(matches!(
+            verifier(false).verify(&[TestVector::new("poseidon1", &[1, 2])]),
+            Err(DeployError::InvalidTestVector(_))
+        ));
+    }
+}
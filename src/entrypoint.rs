@@ -2,36 +2,155 @@
 extern crate alloc;
 
 use alloy_primitives::U256;
+use std::sync::OnceLock;
+use stylus_sdk::abi::Bytes;
+use stylus_sdk::evm;
 use stylus_sdk::prelude::*;
 
+use crate::babyjubjub::BabyJubJubPrecompile;
+use crate::curves::{BN254Precompile, MsmPrecompile};
+use crate::errors::{encode_revert, PoseidonError};
+use crate::groth16::Groth16Precompile;
+use crate::merkle::{KeccakMerklePrecompile, PoseidonMerklePrecompile};
+use crate::mimc::MiMCPrecompile;
+use crate::pedersen::PedersenPrecompile;
+use crate::plonk::PolyHasherPrecompile;
+use crate::poseidon::PoseidonHash;
+use crate::rescue::RescuePrecompile;
+use crate::router::PrecompileRouter;
+use crate::{router_builder, SolStorageLayout};
+
 // For Stylus deployment, we create a simple router contract
 sol_storage! {
     #[entrypoint]
+    #[derive(SolStorageLayout)]
     pub struct PoseidonPrecompile {
     }
 }
 
 #[public]
 impl PoseidonPrecompile {
-    // Simple Poseidon hash of a single U256
-    pub fn hash(&self, input: U256) -> U256 {
-        use crate::poseidon::PoseidonHash;
+    // Simple Poseidon hash of a single U256, reverting with Error(string) on invalid input
+    pub fn hash(&self, input: U256) -> Result<U256, Vec<u8>> {
+        hash_single(input)
+    }
 
-        let hasher = PoseidonHash::new();
-        match hasher.hash_single(input) {
-            Ok(result) => result,
-            Err(_) => U256::ZERO, // Return zero on error for simplicity
+    // Hash a single U256 and emit HashComputed for indexers
+    pub fn hash_and_log(&mut self, input: U256) -> Result<U256, Vec<u8>> {
+        use crate::events::EventLogger;
+
+        let output = self.hash(input)?;
+        EventLogger::log_hash_computed(EventLogger::inputs_hash(&[input]), output);
+        Ok(output)
+    }
+
+    // Route raw precompile calldata by selector, charged against the gas left in the call
+    pub fn dispatch(&self, input: Bytes) -> Result<Bytes, Vec<u8>> {
+        match precompile_router().dispatch_with_gas(&input, evm::gas_left()) {
+            Ok((output, _remaining)) => Ok(Bytes(output)),
+            Err(e) => Err(encode_revert(&e.to_string())),
         }
     }
 
-    // Hash two U256 values
-    pub fn hash_pair(&self, a: U256, b: U256) -> U256 {
-        use crate::poseidon::PoseidonHash;
+    // Hash two U256 values, reverting with Error(string) on invalid input
+    pub fn hash_pair(&self, a: U256, b: U256) -> Result<U256, Vec<u8>> {
+        hash_pair(a, b)
+    }
+}
+
+fn hash_single(input: U256) -> Result<U256, Vec<u8>> {
+    PoseidonHash::new().hash_single_default(input).map_err(revert)
+}
 
-        let hasher = PoseidonHash::new();
-        match hasher.hash_pair(a, b) {
-            Ok(result) => result,
-            Err(_) => U256::ZERO,
+fn hash_pair(a: U256, b: U256) -> Result<U256, Vec<u8>> {
+    PoseidonHash::new().hash_pair_default(a, b).map_err(revert)
+}
+
+/// Revert data carrying the error message, so a failure is never mistaken for a zero hash
+fn revert(error: PoseidonError) -> Vec<u8> {
+    encode_revert(&error.to_string())
+}
+
+/// Precompiles served through `dispatch`, built on first use
+///
+/// `KzgPrecompile` is left out: it verifies against a trusted setup that the
+/// contract does not carry.
+fn precompile_router() -> &'static PrecompileRouter {
+    static ROUTER: OnceLock<PrecompileRouter> = OnceLock::new();
+    ROUTER.get_or_init(|| {
+        router_builder! {
+            poseidon => crate::poseidon::PoseidonPrecompile,
+            mimc => MiMCPrecompile,
+            rescue => RescuePrecompile,
+            pedersen => PedersenPrecompile,
+            poseidon_merkle => PoseidonMerklePrecompile,
+            keccak_merkle => KeccakMerklePrecompile,
+            poly_hasher => PolyHasherPrecompile,
+            babyjubjub => BabyJubJubPrecompile,
+            bn254 => BN254Precompile,
+            msm => MsmPrecompile,
+            groth16 => Groth16Precompile,
         }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_sol_types::{Revert, SolError};
+
+    #[test]
+    fn test_field_overflow_reverts() {
+        let modulus = PoseidonHash::new().params.modulus;
+
+        let data = hash_single(modulus).unwrap_err();
+        let reason = Revert::abi_decode(&data, true).unwrap().reason;
+        assert_eq!(reason, PoseidonError::FieldElementTooLarge(modulus).to_string());
+
+        let data = hash_pair(U256::from(1), modulus).unwrap_err();
+        assert_eq!(data[..4], Revert::SELECTOR);
+    }
+
+    #[test]
+    fn test_router_serves_every_selector() {
+        let selector_lists: [&[[u8; 4]]; 11] = [
+            &crate::poseidon::PoseidonPrecompile::SELECTORS,
+            &MiMCPrecompile::SELECTORS,
+            &RescuePrecompile::SELECTORS,
+            &PedersenPrecompile::SELECTORS,
+            &PoseidonMerklePrecompile::SELECTORS,
+            &KeccakMerklePrecompile::SELECTORS,
+            &PolyHasherPrecompile::SELECTORS,
+            &BabyJubJubPrecompile::SELECTORS,
+            &BN254Precompile::SELECTORS,
+            &MsmPrecompile::SELECTORS,
+            &Groth16Precompile::SELECTORS,
+        ];
+
+        // No two precompiles share a selector, so none shadows another
+        let router = precompile_router();
+        assert_eq!(router.len(), selector_lists.iter().map(|list| list.len()).sum::<usize>());
+        assert!(selector_lists.iter().flat_map(|list| list.iter()).all(|s| router.handles(*s)));
+        assert!(std::ptr::eq(router, precompile_router()));
+    }
+
+    #[test]
+    fn test_dispatch_error_reverts() {
+        let Err(error) = precompile_router().dispatch(&[0xde, 0xad, 0xbe, 0xef]) else {
+            panic!("unknown selector dispatched");
+        };
+        let data = encode_revert(&error.to_string());
+        assert_eq!(Revert::abi_decode(&data, true).unwrap().reason, error.to_string());
+    }
+
+    #[test]
+    fn test_valid_input_hashes() {
+        let hasher = PoseidonHash::new();
+        let single = hasher.hash_single_default(U256::ZERO).unwrap();
+        assert_eq!(hash_single(U256::ZERO).unwrap(), single);
+        assert_eq!(
+            hash_pair(U256::from(1), U256::from(2)).unwrap(),
+            hasher.hash_pair_default(U256::from(1), U256::from(2)).unwrap()
+        );
     }
 }
@@ -1,6 +1,7 @@
 //! Error types for the precompile library
 
 use alloy_primitives::U256;
+use alloy_sol_types::SolError;
 use thiserror::Error;
 
 /// Custom error types for the Poseidon precompile
@@ -12,6 +13,347 @@ pub enum PoseidonError {
     FieldElementTooLarge(U256),
     #[error("Invalid function selector")]
     InvalidSelector,
-    #[error("ABI decode error: {0}")]
-    AbiDecodeError(String),
+    #[error("ABI decode error for selector 0x{} at byte {offset}: {reason}", hex::encode(.selector))]
+    AbiDecodeError {
+        selector: [u8; 4],
+        offset: usize,
+        #[source]
+        reason: alloy_sol_types::Error,
+    },
+    #[error("Unsupported Poseidon parameters: {0}")]
+    UnsupportedParams(String),
+    #[error("Domain tag must be nonzero")]
+    ZeroDomainTag,
+}
+
+impl From<PoseidonError> for String {
+    fn from(error: PoseidonError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Custom error types for the MiMC precompile
+#[derive(Error, Debug)]
+pub enum MiMCError {
+    #[error("Invalid input length: expected at least 1 element, got {0}")]
+    InvalidInputLength(usize),
+    #[error("Field element too large: {0}")]
+    FieldElementTooLarge(U256),
+    #[error("Invalid function selector")]
+    InvalidSelector,
+    #[error("ABI decode error for selector 0x{} at byte {offset}: {reason}", hex::encode(.selector))]
+    AbiDecodeError {
+        selector: [u8; 4],
+        offset: usize,
+        #[source]
+        reason: alloy_sol_types::Error,
+    },
+}
+
+/// Custom error types for the Pedersen precompile
+#[derive(Error, Debug)]
+pub enum PedersenError {
+    #[error("Invalid input length: expected at least 1 element, got {0}")]
+    InvalidInputLength(usize),
+    #[error("Scalar too large for the BN254 group order: {0}")]
+    ScalarTooLarge(U256),
+    #[error("Commitment ({0}, {1}) is not a G1 point")]
+    InvalidPoint(U256, U256),
+    #[error("Invalid function selector")]
+    InvalidSelector,
+    #[error("ABI decode error for selector 0x{} at byte {offset}: {reason}", hex::encode(.selector))]
+    AbiDecodeError {
+        selector: [u8; 4],
+        offset: usize,
+        #[source]
+        reason: alloy_sol_types::Error,
+    },
+}
+
+/// Custom error types for the Rescue precompile
+#[derive(Error, Debug)]
+pub enum RescueError {
+    #[error("Invalid input length: expected at least 1 element, got {0}")]
+    InvalidInputLength(usize),
+    #[error("Field element too large: {0}")]
+    FieldElementTooLarge(U256),
+    #[error("Invalid function selector")]
+    InvalidSelector,
+    #[error("ABI decode error for selector 0x{} at byte {offset}: {reason}", hex::encode(.selector))]
+    AbiDecodeError {
+        selector: [u8; 4],
+        offset: usize,
+        #[source]
+        reason: alloy_sol_types::Error,
+    },
+}
+
+/// Custom error types for Merkle tree construction and proofs
+#[derive(Error, Debug)]
+pub enum MerkleError {
+    #[error("Cannot build a Merkle tree with no leaves")]
+    EmptyTree,
+    #[error("Leaf index {index} out of range for a tree of {leaf_count} leaves")]
+    LeafIndexOutOfRange { index: usize, leaf_count: usize },
+    #[error("Malformed Merkle proof: {0}")]
+    MalformedProof(String),
+    #[error("Invalid tree depth {0}")]
+    InvalidDepth(usize),
+    #[error("Tree is full at {0} leaves")]
+    TreeFull(usize),
+    #[error("No leaves to insert")]
+    EmptyBatch,
+    #[error("Path for leaf {0} is not available in this tree")]
+    PathUnavailable(usize),
+    #[error("Key {key} does not fit a tree of depth {depth}")]
+    KeyOutOfRange { key: U256, depth: usize },
+    #[error("Key {0} is not in the tree")]
+    KeyNotFound(U256),
+    #[error("Key {0} is already in the tree")]
+    KeyPresent(U256),
+    #[error("Root {0} is not the current root")]
+    StaleRoot(U256),
+    #[error(transparent)]
+    Hash(#[from] PoseidonError),
+    #[error(transparent)]
+    Gas(#[from] GasExhaustedError),
+    #[error("Invalid function selector")]
+    InvalidSelector,
+    #[error("ABI decode error for selector 0x{} at byte {offset}: {reason}", hex::encode(.selector))]
+    AbiDecodeError {
+        selector: [u8; 4],
+        offset: usize,
+        #[source]
+        reason: alloy_sol_types::Error,
+    },
+}
+
+/// Custom error types for elliptic curve precompiles
+#[derive(Error, Debug)]
+pub enum CurveError {
+    #[error("Point ({x}, {y}) is not on the curve")]
+    PointNotOnCurve { x: U256, y: U256 },
+    #[error("G2 point is not on the curve or not in the prime subgroup")]
+    InvalidG2Point,
+    #[error("Pairing input of {0} words is not a multiple of 6")]
+    InvalidPairingInput(usize),
+    #[error("MSM input of {0} words is not a multiple of 3")]
+    InvalidMsmInput(usize),
+    #[error("MSM over {scalars} scalars and {points} points")]
+    MsmLengthMismatch { scalars: usize, points: usize },
+    #[error("Scalar {0} is zero or not below the subgroup order")]
+    InvalidScalar(U256),
+}
+
+/// Custom error types for Groth16 proof verification
+#[derive(Error, Debug)]
+pub enum Groth16Error {
+    #[error("Expected {expected} public inputs, got {actual}")]
+    InvalidPublicInputCount { expected: usize, actual: usize },
+    #[error("Public input {0} is not below the scalar field modulus")]
+    PublicInputTooLarge(U256),
+    #[error("{name} is {actual} bytes, more than the {max} the circuit takes")]
+    PublicInputTooLong { name: &'static str, max: usize, actual: usize },
+    #[error("Malformed verifying key: {0}")]
+    MalformedVerifyingKey(String),
+    #[error("Malformed proving key: {0}")]
+    MalformedProvingKey(String),
+    #[error("Invalid circuit or witness: {0}")]
+    InvalidCircuit(String),
+    #[error("Invalid setup trapdoor: {0}")]
+    InvalidTrapdoor(String),
+    #[error(transparent)]
+    Curve(#[from] CurveError),
+    #[error("ABI decode error for selector 0x{} at byte {offset}: {reason}", hex::encode(.selector))]
+    AbiDecodeError {
+        selector: [u8; 4],
+        offset: usize,
+        #[source]
+        reason: alloy_sol_types::Error,
+    },
+}
+
+/// Custom error types for KZG polynomial commitments
+#[derive(Error, Debug)]
+pub enum KZGError {
+    #[error("Malformed trusted setup: {0}")]
+    InvalidSetup(String),
+    #[error("Polynomial of {coefficients} coefficients needs more than the {available} setup points")]
+    SetupTooSmall { coefficients: usize, available: usize },
+    #[error("Expected a {expected}-byte point encoding, got {actual} bytes")]
+    InvalidPointEncoding { expected: usize, actual: usize },
+    #[error("Field element too large: {0}")]
+    FieldElementTooLarge(U256),
+    #[error(transparent)]
+    Curve(#[from] CurveError),
+}
+
+/// Errors from nonce generation
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonceError {
+    #[error("Nonce counter {counter} overflows when advanced by {n}")]
+    CounterOverflow { counter: u64, n: u64 },
+}
+
+/// Errors from prime field arithmetic
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldError {
+    #[error("Cannot invert zero")]
+    ZeroInversion,
+}
+
+/// Calldata whose selector no precompile handles
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("Invalid function selector 0x{}", hex::encode(.selector))]
+pub struct InvalidSelectorError {
+    pub selector: [u8; 4],
+}
+
+impl InvalidSelectorError {
+    /// Takes the selector from the first four bytes of `input`, zero-padded if shorter
+    pub fn from_input(input: &[u8]) -> Self {
+        let mut selector = [0u8; 4];
+        let len = input.len().min(4);
+        selector[..len].copy_from_slice(&input[..len]);
+        Self { selector }
+    }
+}
+
+/// Call that needs more gas than the caller provided
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("Out of gas: {gas_required} required, {gas_provided} provided")]
+pub struct GasExhaustedError {
+    pub gas_provided: u64,
+    pub gas_required: u64,
+}
+
+/// Calldata arguments that do not decode for their selector
+#[derive(Error, Debug)]
+#[error("ABI decode error for selector 0x{} at byte {offset}: {reason}", hex::encode(.selector))]
+pub struct AbiDecodeError {
+    /// All zero for data that carries no selector, such as a `SolStruct` encoding
+    pub selector: [u8; 4],
+    pub offset: usize,
+    #[source]
+    pub reason: alloy_sol_types::Error,
+}
+
+/// Converts `AbiDecodeError` into each error enum's `AbiDecodeError` variant
+macro_rules! impl_from_abi_decode_error {
+    ($($error:ident),* $(,)?) => {
+        $(
+            impl From<AbiDecodeError> for $error {
+                fn from(error: AbiDecodeError) -> Self {
+                    $error::AbiDecodeError {
+                        selector: error.selector,
+                        offset: error.offset,
+                        reason: error.reason,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_abi_decode_error!(
+    PoseidonError,
+    MiMCError,
+    PedersenError,
+    RescueError,
+    MerkleError,
+    Groth16Error,
+);
+
+/// Error from any precompile behind a shared dispatch path
+#[derive(Error, Debug)]
+pub enum PrecompileError {
+    #[error(transparent)]
+    Poseidon(#[from] PoseidonError),
+    #[error(transparent)]
+    Selector(#[from] InvalidSelectorError),
+    #[error(transparent)]
+    Gas(#[from] GasExhaustedError),
+    #[error(transparent)]
+    Abi(#[from] AbiDecodeError),
+    #[error(transparent)]
+    MiMC(#[from] MiMCError),
+    #[error(transparent)]
+    Rescue(#[from] RescueError),
+    #[error(transparent)]
+    Pedersen(#[from] PedersenError),
+    #[error(transparent)]
+    Merkle(#[from] MerkleError),
+    #[error(transparent)]
+    Curve(#[from] CurveError),
+    #[error(transparent)]
+    Groth16(#[from] Groth16Error),
+    #[error(transparent)]
+    KZG(#[from] KZGError),
+    #[error(transparent)]
+    Nonce(#[from] NonceError),
+    #[error(transparent)]
+    Circom(#[from] CircomError),
+    #[error(transparent)]
+    Deploy(#[from] DeployError),
+}
+
+impl From<PrecompileError> for String {
+    fn from(error: PrecompileError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Errors from checking circom circuits and witnesses
+#[derive(Error, Debug)]
+pub enum CircomError {
+    #[error("Witness index {index} out of range for a witness of {len} signals")]
+    WitnessIndexOutOfRange { index: usize, len: usize },
+    #[error("Field element too large: {0}")]
+    FieldElementTooLarge(U256),
+    #[error("Witness signal 0 must be the constant 1, got {0}")]
+    InvalidConstantSignal(U256),
+    #[error("Unsupported circuit shape: {inputs} inputs with width {width}")]
+    UnsupportedShape { inputs: usize, width: usize },
+    #[error("Expected {expected} circuit inputs, got {got}")]
+    InputCountMismatch { expected: usize, got: usize },
+    #[error("BigInt with {0} significant 32-bit limbs does not fit in 256 bits")]
+    BigIntOverflow(usize),
+}
+
+/// Errors from watching a deployment over JSON-RPC
+#[derive(Error, Debug)]
+pub enum DeployError {
+    #[error("RPC request failed: {0}")]
+    Rpc(String),
+    #[error("Invalid RPC response: {0}")]
+    InvalidResponse(String),
+    #[error("Transaction not confirmed after {0} seconds")]
+    Timeout(u64),
+    #[error("Deployment transaction reverted in block {0}")]
+    Reverted(u64),
+    #[error("Invalid test vector: {0}")]
+    InvalidTestVector(String),
+}
+
+/// ABI-encodes `msg` as Solidity's `Error(string)` revert data
+///
+/// The output starts with the `0x08c379a0` selector, so wallets and explorers
+/// decode it like a `require(false, msg)` revert.
+pub fn encode_revert(msg: &str) -> Vec<u8> {
+    alloy_sol_types::Revert::from(msg).abi_encode()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_sol_types::{Revert, SolError};
+
+    #[test]
+    fn test_encode_revert() {
+        let data = encode_revert("Field element too large");
+        assert_eq!(data[..4], [0x08, 0xc3, 0x79, 0xa0]);
+        // Selector, offset, length and one padded word of text
+        assert_eq!(data.len(), 4 + 3 * 32);
+        assert_eq!(Revert::abi_decode(&data, true).unwrap().reason, "Field element too large");
+    }
 }
@@ -0,0 +1,127 @@
+//! Events recording hash computations
+//!
+//! Indexers reconstruct which inputs were hashed from `HashComputed`, keyed by
+//! `inputsHash = keccak256(abi.encodePacked(inputs))`, and follow tree updates
+//! through `MerkleRootUpdated`. The events are built natively so they can be
+//! checked in tests; emitting them needs the `stylus` feature.
+
+use alloy_primitives::{keccak256, U256};
+use alloy_sol_types::sol;
+
+sol! {
+    /// Emitted when a contract computes a Poseidon hash
+    event HashComputed(uint256 indexed inputsHash, uint256 output, uint256 timestamp);
+
+    /// Emitted when a contract updates a Merkle root
+    event MerkleRootUpdated(uint256 indexed root, uint256 leafCount, uint256 timestamp);
+}
+
+/// Builds and emits the hash log events
+pub struct EventLogger;
+
+impl EventLogger {
+    /// Computes the `inputsHash` topic for a list of hash inputs
+    pub fn inputs_hash(inputs: &[U256]) -> U256 {
+        let packed: Vec<u8> = inputs
+            .iter()
+            .flat_map(|input| input.to_be_bytes::<32>())
+            .collect();
+        U256::from_be_bytes(keccak256(packed).0)
+    }
+
+    /// Builds a `HashComputed` event
+    pub fn hash_computed(inputs_hash: U256, output: U256, timestamp: u64) -> HashComputed {
+        HashComputed {
+            inputsHash: inputs_hash,
+            output,
+            timestamp: U256::from(timestamp),
+        }
+    }
+
+    /// Builds a `MerkleRootUpdated` event
+    pub fn merkle_root_updated(root: U256, leaf_count: u64, timestamp: u64) -> MerkleRootUpdated {
+        MerkleRootUpdated {
+            root,
+            leafCount: U256::from(leaf_count),
+            timestamp: U256::from(timestamp),
+        }
+    }
+
+    /// Emits `HashComputed` stamped with the current block timestamp
+    #[cfg(feature = "stylus")]
+    pub fn log_hash_computed(inputs_hash: U256, output: U256) {
+        let timestamp = stylus_sdk::block::timestamp();
+        emit(Self::hash_computed(inputs_hash, output, timestamp));
+    }
+
+    /// Emits `MerkleRootUpdated` stamped with the current block timestamp
+    #[cfg(feature = "stylus")]
+    pub fn log_merkle_update(root: U256, leaf_count: u64) {
+        let timestamp = stylus_sdk::block::timestamp();
+        emit(Self::merkle_root_updated(root, leaf_count, timestamp));
+    }
+}
+
+/// Emits an event through `raw_log`
+///
+/// stylus-sdk's `evm::log` is bound to its own alloy-sol-types release, so the
+/// event is encoded here and handed over as raw topics and data.
+#[cfg(feature = "stylus")]
+fn emit<E: alloy_sol_types::SolEvent>(event: E) {
+    let log = event.encode_log_data();
+    let topics: Vec<stylus_sdk::alloy_primitives::B256> =
+        log.topics().iter().map(|topic| topic.0.into()).collect();
+    // At most one indexed field per event, so raw_log cannot reject the topics
+    let _ = stylus_sdk::evm::raw_log(&topics, &log.data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use alloy_sol_types::SolEvent;
+
+    #[test]
+    fn test_hash_computed_topics() {
+        let inputs_hash = EventLogger::inputs_hash(&[U256::from(1), U256::from(2)]);
+        let event = EventLogger::hash_computed(inputs_hash, U256::from(99), 1_700_000_000);
+        let log = event.encode_log_data();
+
+        assert_eq!(
+            log.topics()[0],
+            keccak256("HashComputed(uint256,uint256,uint256)")
+        );
+        assert_eq!(log.topics()[1], B256::from(inputs_hash));
+        assert_eq!(log.topics().len(), 2);
+
+        // Non-indexed fields are ABI-encoded in the data
+        assert_eq!(log.data.len(), 64);
+        assert_eq!(U256::from_be_slice(&log.data[..32]), U256::from(99));
+        assert_eq!(U256::from_be_slice(&log.data[32..]), U256::from(1_700_000_000u64));
+    }
+
+    #[test]
+    fn test_merkle_root_updated_topics() {
+        let root = U256::from(0xabcdef);
+        let log = EventLogger::merkle_root_updated(root, 16, 42).encode_log_data();
+
+        assert_eq!(
+            log.topics()[0],
+            keccak256("MerkleRootUpdated(uint256,uint256,uint256)")
+        );
+        assert_eq!(log.topics()[1], B256::from(root));
+        assert_eq!(U256::from_be_slice(&log.data[..32]), U256::from(16));
+    }
+
+    #[test]
+    fn test_inputs_hash_is_packed_keccak() {
+        let mut packed = [0u8; 64];
+        packed[31] = 1;
+        packed[63] = 2;
+
+        assert_eq!(
+            EventLogger::inputs_hash(&[U256::from(1), U256::from(2)]),
+            U256::from_be_bytes(keccak256(packed).0)
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! Contract events for off-chain indexers
+
+pub mod hash_log;
+
+pub use hash_log::{EventLogger, HashComputed, MerkleRootUpdated};
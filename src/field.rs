@@ -0,0 +1,465 @@
+//! Prime field elements with the modulus carried in the type
+//!
+//! `FieldElement<P>` keeps its value reduced below `P::MODULUS`, and every
+//! operator reduces its result, so modular arithmetic cannot forget the
+//! final `% modulus`. Fields are described by `FieldParams` implementations;
+//! `Bn254Scalar` and `Bls12_381Scalar` cover the scalar fields of the two
+//! pairing curves used by Ethereum proof systems.
+//!
+//! Elements are plain 32-byte values, so they implement `bytemuck::Pod` and can
+//! be copied to and from WASM memory without serialization; `as_bytes` views
+//! one as its little-endian limbs. `zeroize` overwrites those bytes, for
+//! elements that hold secrets.
+
+use crate::errors::FieldError;
+use alloy_primitives::{uint, U256};
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Mul, Neg, Sub};
+use zeroize::Zeroize;
+
+/// Prime modulus of a field
+pub trait FieldParams: Copy + Eq + fmt::Debug {
+    /// The field order, an odd prime
+    const MODULUS: U256;
+}
+
+/// BN254 scalar field, the field Poseidon and circom circuits work in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bn254Scalar;
+
+impl FieldParams for Bn254Scalar {
+    const MODULUS: U256 = crate::BN254_MODULUS;
+}
+
+/// BLS12-381 scalar field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bls12_381Scalar;
+
+impl FieldParams for Bls12_381Scalar {
+    const MODULUS: U256 = crate::BLS12_381_SCALAR_MODULUS;
+}
+
+/// Element of the field described by `P`, always reduced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct FieldElement<P: FieldParams>(U256, PhantomData<P>);
+
+// SAFETY: `repr(transparent)` over `U256`, four `u64` limbs with no padding,
+// and the all-zero value is the field's zero.
+unsafe impl<P: FieldParams + 'static> bytemuck::Zeroable for FieldElement<P> {}
+
+// SAFETY: every bit pattern is a valid `U256`. A cast from arbitrary bytes may
+// skip the reduction, so untrusted bytes should go through `from_canonical`.
+unsafe impl<P: FieldParams + 'static> bytemuck::Pod for FieldElement<P> {}
+
+/// BN254 scalar field element
+pub type Bn254Field = FieldElement<Bn254Scalar>;
+
+impl<P: FieldParams> FieldElement<P> {
+    /// The additive identity
+    pub const ZERO: Self = Self(U256::ZERO, PhantomData);
+
+    /// The multiplicative identity
+    pub const ONE: Self = Self(uint!(1_U256), PhantomData);
+
+    /// Reduces `value` into the field
+    pub fn from_u256(value: U256) -> Self {
+        Self(value.reduce_mod(P::MODULUS), PhantomData)
+    }
+
+    /// Wraps `value` if it is already below the modulus
+    pub fn from_canonical(value: U256) -> Option<Self> {
+        (value < P::MODULUS).then_some(Self(value, PhantomData))
+    }
+
+    /// Returns the reduced value
+    pub fn into_u256(self) -> U256 {
+        self.0
+    }
+
+    /// Returns whether this is zero
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Raises to the power `exponent`
+    pub fn pow(self, exponent: U256) -> Self {
+        Self(self.0.pow_mod(exponent, P::MODULUS), PhantomData)
+    }
+
+    /// Multiplicative inverse, `None` for zero
+    pub fn inv(self) -> Option<Self> {
+        self.0.inv_mod(P::MODULUS).map(|inverse| Self(inverse, PhantomData))
+    }
+
+    /// Returns whether this is a square, by Euler's criterion
+    pub fn is_square(self) -> bool {
+        self.is_zero() || self.pow((P::MODULUS - uint!(1_U256)) >> 1) == Self::ONE
+    }
+
+    /// Tonelli-Shanks square root, `None` for non-residues
+    pub fn sqrt(self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::ZERO);
+        }
+        if !self.is_square() {
+            return None;
+        }
+
+        // p - 1 = q · 2^s with q odd
+        let p_minus_one = P::MODULUS - uint!(1_U256);
+        let s = p_minus_one.trailing_zeros();
+        let q = p_minus_one >> s;
+        let mut z = Self::from_u256(uint!(2_U256));
+        while z.is_square() {
+            z = z + Self::ONE;
+        }
+
+        let mut m = s;
+        let mut c = z.pow(q);
+        let mut t = self.pow(q);
+        let mut r = self.pow((q + uint!(1_U256)) >> 1);
+        while t != Self::ONE {
+            let mut i = 0;
+            let mut t2i = t;
+            while t2i != Self::ONE {
+                t2i = t2i * t2i;
+                i += 1;
+            }
+            let b = c.pow(uint!(1_U256) << (m - i - 1));
+            m = i;
+            c = b * b;
+            t = t * c;
+            r = r * b;
+        }
+        Some(r)
+    }
+}
+
+impl<P: FieldParams> Add for FieldElement<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.add_mod(rhs.0, P::MODULUS), PhantomData)
+    }
+}
+
+impl<P: FieldParams> Sub for FieldElement<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<P: FieldParams> Mul for FieldElement<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0.mul_mod(rhs.0, P::MODULUS), PhantomData)
+    }
+}
+
+impl<P: FieldParams> Neg for FieldElement<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.is_zero() {
+            self
+        } else {
+            Self(P::MODULUS - self.0, PhantomData)
+        }
+    }
+}
+
+impl<P: FieldParams> From<u64> for FieldElement<P> {
+    fn from(value: u64) -> Self {
+        Self::from_u256(U256::from(value))
+    }
+}
+
+/// Overwrites the value with zero, the field's additive identity
+impl<P: FieldParams> Zeroize for FieldElement<P> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<P: FieldParams> fmt::Display for FieldElement<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Views an element as its 32 bytes in memory
+///
+/// On little-endian targets, wasm32 included, this is the little-endian
+/// encoding of the reduced value.
+pub fn as_bytes<P: FieldParams + 'static>(val: &FieldElement<P>) -> &[u8; 32] {
+    bytemuck::bytes_of(val).try_into().expect("a field element is 32 bytes")
+}
+
+/// BN254 scalar field modulus as little-endian limbs
+const BN254_LIMBS: [u64; 4] = crate::utils::BN254_MODULUS.into_limbs();
+
+/// -p^-1 mod 2^64 for the BN254 scalar field
+const BN254_INV: u64 = 0xc2e1f593efffffff;
+
+/// R^2 mod p with R = 2^256, for converting into Montgomery form
+const BN254_R2: [u64; 4] = uint!(
+    0x0216d0b17f4e44a58c49833d53bb808553fe3ab1e35c59e31bb8e645ae216da7_U256
+)
+.into_limbs();
+
+/// BN254 scalar field element stored in Montgomery form `x · 2^256 mod p`
+///
+/// Multiplication runs the CIOS (coarsely integrated operand scanning)
+/// Montgomery reduction over 64-bit limbs, avoiding the big-integer
+/// division behind `U256::mul_mod`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MontgomeryField([u64; 4]);
+
+impl MontgomeryField {
+    /// Converts a value, reduced into the field first, to Montgomery form
+    pub fn to_montgomery(x: U256) -> Self {
+        let reduced = x.reduce_mod(crate::utils::BN254_MODULUS);
+        Self(montgomery_mul(&reduced.into_limbs(), &BN254_R2))
+    }
+
+    /// Converts back out of Montgomery form
+    pub fn from_montgomery(x: Self) -> U256 {
+        U256::from_limbs(montgomery_mul(&x.0, &[1, 0, 0, 0]))
+    }
+
+    /// Montgomery product, itself in Montgomery form
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(a: Self, b: Self) -> Self {
+        Self(montgomery_mul(&a.0, &b.0))
+    }
+
+    /// The Poseidon S-box, x^5
+    pub fn pow5(self) -> Self {
+        let square = Self::mul(self, self);
+        Self::mul(Self::mul(square, square), self)
+    }
+}
+
+impl Mul for MontgomeryField {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        MontgomeryField::mul(self, rhs)
+    }
+}
+
+/// CIOS Montgomery multiplication: a · b · 2^-256 mod p
+fn montgomery_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let p = BN254_LIMBS;
+    let mut t = [0u64; 6];
+
+    for &b_i in b {
+        // t += a · b_i
+        let mut carry = 0u128;
+        for j in 0..4 {
+            let sum = t[j] as u128 + a[j] as u128 * b_i as u128 + carry;
+            t[j] = sum as u64;
+            carry = sum >> 64;
+        }
+        let sum = t[4] as u128 + carry;
+        t[4] = sum as u64;
+        t[5] = (sum >> 64) as u64;
+
+        // t = (t + m · p) / 2^64, with m chosen so the low limb cancels
+        let m = t[0].wrapping_mul(BN254_INV);
+        let mut carry = (t[0] as u128 + m as u128 * p[0] as u128) >> 64;
+        for j in 1..4 {
+            let sum = t[j] as u128 + m as u128 * p[j] as u128 + carry;
+            t[j - 1] = sum as u64;
+            carry = sum >> 64;
+        }
+        let sum = t[4] as u128 + carry;
+        t[3] = sum as u64;
+        t[4] = t[5] + (sum >> 64) as u64;
+    }
+
+    // The result is below 2p, so one conditional subtraction reduces it
+    let result = U256::from_limbs([t[0], t[1], t[2], t[3]]);
+    let modulus = crate::utils::BN254_MODULUS;
+    if t[4] != 0 || result >= modulus {
+        result.wrapping_sub(modulus).into_limbs()
+    } else {
+        result.into_limbs()
+    }
+}
+
+/// Inverts every element in place with Montgomery's trick
+///
+/// Costs one inversion plus 3(n - 1) multiplications instead of n
+/// inversions. Fails without modifying `elements` if any of them is zero.
+pub fn batch_invert<P: FieldParams>(elements: &mut [FieldElement<P>]) -> Result<(), FieldError> {
+    // prefix[i] = elements[0] · … · elements[i - 1]
+    let mut prefix = Vec::with_capacity(elements.len());
+    let mut accumulator = FieldElement::ONE;
+    for element in elements.iter() {
+        if element.is_zero() {
+            return Err(FieldError::ZeroInversion);
+        }
+        prefix.push(accumulator);
+        accumulator = accumulator * *element;
+    }
+
+    // accumulator holds 1 / (elements[0] · … · elements[i]) while walking back
+    let mut accumulator = accumulator.inv().ok_or(FieldError::ZeroInversion)?;
+    for (element, prefix) in elements.iter_mut().zip(prefix).rev() {
+        let inverse = accumulator * prefix;
+        accumulator = accumulator * *element;
+        *element = inverse;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    type Bls = FieldElement<Bls12_381Scalar>;
+
+    fn field_element() -> impl Strategy<Value = Bn254Field> {
+        any::<[u64; 4]>().prop_map(|limbs| Bn254Field::from_u256(U256::from_limbs(limbs)))
+    }
+
+    proptest! {
+        #[test]
+        fn prop_batch_invert_matches_single_inversions(
+            elements in prop::collection::vec(field_element(), 0..32)
+        ) {
+            prop_assume!(elements.iter().all(|element| !element.is_zero()));
+
+            let mut batch = elements.clone();
+            batch_invert(&mut batch).unwrap();
+            for (element, inverse) in elements.iter().zip(&batch) {
+                prop_assert_eq!(Some(*inverse), element.inv());
+            }
+        }
+
+        #[test]
+        fn prop_batch_invert_rejects_zero(
+            mut elements in prop::collection::vec(field_element(), 1..16),
+            position in any::<prop::sample::Index>()
+        ) {
+            let zero_at = position.index(elements.len());
+            elements[zero_at] = Bn254Field::ZERO;
+
+            let original = elements.clone();
+            prop_assert_eq!(batch_invert(&mut elements), Err(FieldError::ZeroInversion));
+            prop_assert_eq!(elements, original);
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_wraps_at_modulus() {
+        let max = Bn254Field::from_u256(Bn254Scalar::MODULUS - U256::from(1));
+
+        assert_eq!(max + Bn254Field::ONE, Bn254Field::ZERO);
+        assert_eq!(Bn254Field::ZERO - Bn254Field::ONE, max);
+        assert_eq!(-Bn254Field::ONE, max);
+        assert_eq!(max * max, Bn254Field::ONE);
+        assert_eq!(Bn254Field::from_u256(Bn254Scalar::MODULUS + U256::from(5)), Bn254Field::from(5));
+        assert!(Bn254Field::from_canonical(Bn254Scalar::MODULUS).is_none());
+    }
+
+    #[test]
+    fn test_pow_and_inverse() {
+        let x = Bn254Field::from(12345);
+        assert_eq!(x.pow(U256::from(3)), x * x * x);
+        assert_eq!(x * x.inv().unwrap(), Bn254Field::ONE);
+        assert!(Bn254Field::ZERO.inv().is_none());
+
+        // Fermat: x^(p-1) = 1
+        assert_eq!(x.pow(Bn254Scalar::MODULUS - U256::from(1)), Bn254Field::ONE);
+    }
+
+    #[test]
+    fn test_montgomery_constants() {
+        let p = Bn254Scalar::MODULUS;
+        let r = U256::MAX.reduce_mod(p).add_mod(U256::from(1), p);
+        assert_eq!(U256::from_limbs(BN254_R2), r.mul_mod(r, p));
+        assert_eq!(BN254_LIMBS[0].wrapping_mul(BN254_INV), u64::MAX);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_montgomery_mul_matches_mul_mod(a in any::<[u64; 4]>(), b in any::<[u64; 4]>()) {
+            let (a, b) = (U256::from_limbs(a), U256::from_limbs(b));
+            let product = MontgomeryField::to_montgomery(a) * MontgomeryField::to_montgomery(b);
+
+            let expected = (Bn254Field::from_u256(a) * Bn254Field::from_u256(b)).into_u256();
+            prop_assert_eq!(MontgomeryField::from_montgomery(product), expected);
+        }
+
+        #[test]
+        fn prop_montgomery_round_trip(x in field_element()) {
+            let montgomery = MontgomeryField::to_montgomery(x.into_u256());
+            prop_assert_eq!(MontgomeryField::from_montgomery(montgomery), x.into_u256());
+            prop_assert_eq!(
+                MontgomeryField::from_montgomery(montgomery.pow5()),
+                x.pow(U256::from(5)).into_u256()
+            );
+        }
+    }
+
+    #[test]
+    fn test_sqrt() {
+        for value in [0u64, 1, 4, 9, 12345] {
+            let square = Bn254Field::from(value) * Bn254Field::from(value);
+            let root = square.sqrt().unwrap();
+            assert_eq!(root * root, square);
+
+            let square = Bls::from(value) * Bls::from(value);
+            let root = square.sqrt().unwrap();
+            assert_eq!(root * root, square);
+        }
+
+        // 5 is a quadratic non-residue mod the BN254 scalar field order
+        assert!(Bn254Field::from(5).sqrt().is_none());
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_byte_layout() {
+        let mut expected = [0u8; 32];
+        expected[0] = 0x02;
+        expected[1] = 0x01;
+        assert_eq!(as_bytes(&Bn254Field::from(0x0102)), &expected);
+
+        // Limbs are stored least significant first
+        let value = (U256::from(0xaa) << 192) | U256::from(0xbb);
+        let element = Bn254Field::from_u256(value);
+        assert_eq!(as_bytes(&element)[0], 0xbb);
+        assert_eq!(as_bytes(&element)[24], 0xaa);
+        assert_eq!(as_bytes(&element), &value.to_le_bytes::<32>());
+
+        assert_eq!(as_bytes(&<Bn254Field as bytemuck::Zeroable>::zeroed()), &[0u8; 32]);
+        assert_eq!(bytemuck::from_bytes::<Bn254Field>(&value.to_le_bytes::<32>()), &element);
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut secret = Bn254Field::from_u256(U256::MAX);
+        secret.zeroize();
+        assert_eq!(as_bytes(&secret), &[0u8; 32]);
+        assert_eq!(secret, Bn254Field::ZERO);
+    }
+
+    #[test]
+    fn test_fields_do_not_mix() {
+        let value = U256::MAX;
+        assert_eq!(Bn254Field::from_u256(value).into_u256(), value % Bn254Scalar::MODULUS);
+        assert_eq!(Bls::from_u256(value).into_u256(), value % Bls12_381Scalar::MODULUS);
+        assert_eq!(Bls::from(7).to_string(), "7");
+    }
+}
@@ -0,0 +1,232 @@
+//! Gas estimates for the precompile selectors
+//!
+//! Costs start from 100 units per Poseidon permutation as a first
+//! calibration against Stylus instruction counts. Fixed-arity selectors
+//! charge a flat base; variable-length selectors charge a base plus a
+//! per-element amount.
+//!
+//! `GasMeter` accumulates these charges across several hashes so loops can
+//! stop as soon as the budget runs out.
+
+use crate::errors::{GasExhaustedError, PrecompileError};
+use crate::poseidon::PoseidonHash;
+use alloy_primitives::U256;
+
+/// Gas cost constants, grouped so callers can reference them by name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasCosts;
+
+impl GasCosts {
+    /// `poseidon1` and `poseidonBytes32`: one permutation plus decoding
+    pub const POSEIDON1_BASE: u64 = 200;
+
+    /// `poseidon2`, `poseidon1Tagged` and `nullifier`: one permutation over two inputs plus decoding
+    pub const POSEIDON2_BASE: u64 = 250;
+
+    /// Base of the selectors that charge per input element
+    pub const POSEIDON_N_BASE: u64 = 150;
+
+    /// Charge per input element of `poseidonN` and the wider fixed-arity selectors
+    pub const POSEIDON_PER_ELEMENT: u64 = 100;
+
+    /// Base of `poseidonBytes`: the four SHA-256 compressions of a short message
+    pub const HASH_TO_FIELD_BASE: u64 = 200;
+
+    /// Charge per 32-byte word hashed by `poseidonBytes`, the SHA-256 precompile rate
+    pub const HASH_TO_FIELD_PER_WORD: u64 = 12;
+
+    /// `bjjAdd`: one affine twisted Edwards addition, dominated by its inversions
+    pub const BABYJUBJUB_ADD: u64 = 500;
+
+    /// `bjjMulScalar`: double-and-add over a scalar of up to 256 bits
+    pub const BABYJUBJUB_MUL: u64 = 6_000;
+
+    /// `ecAdd`, the EIP-1108 price of the bn256Add precompile
+    pub const EC_ADD: u64 = 150;
+
+    /// `ecMul`, the EIP-1108 price of the bn256ScalarMul precompile
+    pub const EC_MUL: u64 = 6_000;
+
+    /// Base of `ecPairingCheck`, as EIP-1108 prices bn256Pairing
+    pub const EC_PAIRING_BASE: u64 = 45_000;
+
+    /// Charge per G1/G2 pair of `ecPairingCheck`
+    pub const EC_PAIRING_PER_PAIR: u64 = 34_000;
+
+    /// `kzgVerify`: two pairings, priced like the EIP-4844 point evaluation precompile
+    pub const KZG_VERIFY: u64 = 50_000;
+
+    /// Cost of hashing `elements` inputs through a per-element selector
+    pub const fn poseidon_n(elements: u64) -> u64 {
+        Self::POSEIDON_N_BASE + elements * Self::POSEIDON_PER_ELEMENT
+    }
+
+    /// Cost of a pairing check over `pairs` G1/G2 pairs
+    pub const fn ec_pairing(pairs: u64) -> u64 {
+        Self::EC_PAIRING_BASE + pairs * Self::EC_PAIRING_PER_PAIR
+    }
+
+    /// Cost of `ecMsm` over `terms` scalar/point pairs, one `ecMul` and `ecAdd` each
+    pub const fn ec_msm(terms: u64) -> u64 {
+        terms * (Self::EC_MUL + Self::EC_ADD)
+    }
+
+    /// Cost of `verifyProof`: four pairings plus one `ecMul` and `ecAdd` per public input
+    pub const fn groth16_verify(public_inputs: u64) -> u64 {
+        Self::ec_pairing(4) + Self::ec_msm(public_inputs)
+    }
+
+    /// Cost of batch verifying `proofs` proofs of `public_inputs` inputs each:
+    /// `proofs + 3` pairings, one `ecMul` per `A`, and MSMs over `IC` and the `C` points
+    pub const fn groth16_batch_verify(proofs: u64, public_inputs: u64) -> u64 {
+        Self::ec_pairing(proofs + 3) + Self::ec_msm(2 * proofs + public_inputs + 2)
+    }
+
+    /// Cost of `hybridVerify`: a Groth16 verification plus a KZG opening
+    pub const fn hybrid_verify(public_inputs: u64) -> u64 {
+        Self::groth16_verify(public_inputs) + Self::KZG_VERIFY
+    }
+
+    /// Cost of mapping `len` bytes to a field element
+    pub const fn hash_to_field(len: u64) -> u64 {
+        Self::HASH_TO_FIELD_BASE + len.div_ceil(32) * Self::HASH_TO_FIELD_PER_WORD
+    }
+}
+
+/// Running total of gas charged against a fixed budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasMeter {
+    consumed: u64,
+    limit: u64,
+}
+
+impl GasMeter {
+    /// Creates a meter with nothing consumed
+    pub fn new(limit: u64) -> Self {
+        Self { consumed: 0, limit }
+    }
+
+    /// Charges `amount`, leaving the meter untouched if it would exceed the limit
+    pub fn charge(&mut self, amount: u64) -> Result<(), GasExhaustedError> {
+        let total = self.consumed.saturating_add(amount);
+        if total > self.limit {
+            return Err(GasExhaustedError {
+                gas_provided: self.remaining(),
+                gas_required: amount,
+            });
+        }
+        self.consumed = total;
+        Ok(())
+    }
+
+    /// Gas charged so far
+    pub fn consumed(&self) -> u64 {
+        self.consumed
+    }
+
+    /// Total budget
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Gas left before the limit
+    pub fn remaining(&self) -> u64 {
+        self.limit - self.consumed
+    }
+
+    /// Clears the consumed gas, keeping the limit
+    pub fn reset(&mut self) {
+        self.consumed = 0;
+    }
+}
+
+/// Poseidon hashing that charges each call to a `GasMeter` first
+///
+/// Charges match `poseidon_gas_cost` for the equivalent selector, and the
+/// hashes match what `poseidon_precompile` returns for it.
+pub struct GasMeteredPoseidon<'a> {
+    hasher: PoseidonHash,
+    meter: &'a mut GasMeter,
+}
+
+impl<'a> GasMeteredPoseidon<'a> {
+    /// Wraps a default `PoseidonHash` around the meter
+    pub fn new(meter: &'a mut GasMeter) -> Self {
+        Self {
+            hasher: PoseidonHash::new(),
+            meter,
+        }
+    }
+
+    /// `poseidon1`, charged `POSEIDON1_BASE`
+    pub fn hash_single(&mut self, input: U256) -> Result<U256, PrecompileError> {
+        self.meter.charge(GasCosts::POSEIDON1_BASE)?;
+        Ok(self.hasher.hash_single_default(input)?)
+    }
+
+    /// `poseidon2`, charged `POSEIDON2_BASE`
+    pub fn hash_pair(&mut self, left: U256, right: U256) -> Result<U256, PrecompileError> {
+        self.meter.charge(GasCosts::POSEIDON2_BASE)?;
+        Ok(self.hasher.hash_pair_default(left, right)?)
+    }
+
+    /// `poseidonN`, charged per element
+    pub fn hash_array(&mut self, inputs: &[U256]) -> Result<U256, PrecompileError> {
+        self.meter.charge(GasCosts::poseidon_n(inputs.len() as u64))?;
+        Ok(self.hasher.hash_array_default(inputs)?)
+    }
+
+    /// Returns the meter being charged
+    pub fn meter(&self) -> &GasMeter {
+        self.meter
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+    use crate::poseidon::{poseidon_gas_cost, IPoseidonHash};
+    use alloy_sol_types::SolCall;
+
+    #[test]
+    fn test_gas_meter_charges_until_exhausted() {
+        let mut meter = GasMeter::new(250);
+        meter.charge(100).unwrap();
+        meter.charge(150).unwrap();
+        assert_eq!(meter.remaining(), 0);
+
+        let error = meter.charge(1).unwrap_err();
+        assert_eq!(error, GasExhaustedError { gas_provided: 0, gas_required: 1 });
+        assert_eq!(meter.consumed(), 250);
+
+        meter.reset();
+        assert_eq!(meter.remaining(), meter.limit());
+    }
+
+    #[test]
+    fn test_metered_poseidon_matches_precompile_costs() {
+        let (a, b) = (U256::from(1), U256::from(2));
+        let mut meter = GasMeter::new(u64::MAX);
+        let mut hasher = GasMeteredPoseidon::new(&mut meter);
+
+        let expected = PoseidonHash::new().hash_pair_default(a, b).unwrap();
+        assert_eq!(hasher.hash_pair(a, b).unwrap(), expected);
+        hasher.hash_array(&[a, b, a]).unwrap();
+
+        let pair_call = IPoseidonHash::poseidon2Call { left: a, right: b }.abi_encode();
+        let array_call = IPoseidonHash::poseidonNCall { inputs: vec![a, b, a] }.abi_encode();
+        let expected = poseidon_gas_cost(&pair_call).unwrap() + poseidon_gas_cost(&array_call).unwrap();
+        assert_eq!(hasher.meter().consumed(), expected);
+    }
+
+    #[test]
+    fn test_metered_poseidon_stops_when_exhausted() {
+        let mut meter = GasMeter::new(GasCosts::POSEIDON1_BASE);
+        let mut hasher = GasMeteredPoseidon::new(&mut meter);
+
+        hasher.hash_single(U256::from(1)).unwrap();
+        assert!(matches!(hasher.hash_single(U256::from(2)), Err(PrecompileError::Gas(_))));
+        assert_eq!(meter.consumed(), GasCosts::POSEIDON1_BASE);
+    }
+}
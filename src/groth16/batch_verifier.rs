@@ -0,0 +1,182 @@
+//! Batch verification of Groth16 proofs under one verifying key
+//!
+//! Each proof `j` satisfies `e(A_j, B_j) = e(α, β) · e(vk_x_j, γ) · e(C_j, δ)`.
+//! Raising equation `j` to a random 128-bit `r_j` and multiplying them gives
+//!
+//! `Π e(r_j·A_j, B_j) = e((Σ r_j)·α, β) · e(Σ r_j·vk_x_j, γ) · e(Σ r_j·C_j, δ)`
+//!
+//! so `n` proofs cost `n + 3` pairings in a single multi-pairing check instead
+//! of `4n`. `Σ r_j·vk_x_j` folds into one MSM over the `IC` points with
+//! scalars `Σ r_j·x_ji`. A batch containing an invalid proof passes only if
+//! the challenges cancel its error, which happens with probability about
+//! `2^-128` when `rng` is unpredictable to the prover.
+
+use super::verifier::{Groth16Proof, VerifyingKey};
+use crate::curves::{bn254_msm, bn254_pairing_check, BN254G1Point};
+use crate::errors::{Groth16Error, PrecompileError};
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::U256;
+use rand_core::RngCore;
+
+/// Verifies every proof against its public inputs with one pairing check
+///
+/// An empty batch is accepted. Malformed inputs are errors, as in
+/// `groth16_verify`, while a batch with an invalid proof gives `Ok(false)`.
+pub fn batch_verify(
+    proofs: &[(Groth16Proof, Vec<U256>)],
+    vk: &VerifyingKey,
+    rng: &mut impl RngCore,
+) -> Result<bool, PrecompileError> {
+    if proofs.is_empty() {
+        return Ok(true);
+    }
+
+    // IC scalars: Σ r_j for IC_0, then Σ r_j·x_ji
+    let mut ic_scalars = vec![U256::ZERO; vk.ic.len()];
+    let mut challenges = Vec::with_capacity(proofs.len());
+    let mut pairs = Vec::with_capacity(proofs.len() + 3);
+    for (proof, inputs) in proofs {
+        if vk.ic.len() != inputs.len() + 1 {
+            return Err(Groth16Error::InvalidPublicInputCount {
+                expected: vk.ic.len().saturating_sub(1),
+                actual: inputs.len(),
+            }
+            .into());
+        }
+        if let Some(&input) = inputs.iter().find(|input| **input >= BN254_MODULUS) {
+            return Err(Groth16Error::PublicInputTooLarge(input).into());
+        }
+
+        let r = challenge(rng);
+        ic_scalars[0] = ic_scalars[0].add_mod(r, BN254_MODULUS);
+        for (scalar, input) in ic_scalars[1..].iter_mut().zip(inputs) {
+            *scalar = scalar.add_mod(r.mul_mod(*input, BN254_MODULUS), BN254_MODULUS);
+        }
+
+        let a = BN254G1Point::new(proof.a.x, proof.a.y)?;
+        pairs.push((BN254G1Point::scalar_mul(&a, r)?.neg(), proof.b));
+        challenges.push(r);
+    }
+
+    let r_sum = ic_scalars[0];
+    let c_points: Vec<BN254G1Point> = proofs.iter().map(|(proof, _)| proof.c).collect();
+    pairs.push((BN254G1Point::scalar_mul(&vk.alpha_g1, r_sum)?, vk.beta_g2));
+    pairs.push((bn254_msm(&ic_scalars, &vk.ic)?, vk.gamma_g2));
+    pairs.push((bn254_msm(&challenges, &c_points)?, vk.delta_g2));
+
+    Ok(bn254_pairing_check(&pairs)?)
+}
+
+/// Random non-zero 128-bit challenge
+fn challenge(rng: &mut impl RngCore) -> U256 {
+    loop {
+        let mut bytes = [0u8; 16];
+        rng.fill_bytes(&mut bytes);
+        let r = U256::from(u128::from_le_bytes(bytes));
+        if !r.is_zero() {
+            return r;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::BN254G2Point;
+    use crate::groth16::groth16_verify;
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn g1(scalar: U256) -> BN254G1Point {
+        BN254G1Point::scalar_mul(&BN254G1Point::GENERATOR, scalar).unwrap()
+    }
+
+    fn g2(scalar: U256) -> BN254G2Point {
+        BN254G2Point::scalar_mul(&BN254G2Point::GENERATOR, scalar).unwrap()
+    }
+
+    fn sub(a: U256, b: U256) -> U256 {
+        a.add_mod(BN254_MODULUS - b, BN254_MODULUS)
+    }
+
+    fn mul(a: U256, b: U256) -> U256 {
+        a.mul_mod(b, BN254_MODULUS)
+    }
+
+    /// Key with `α = 5·G1`, `β = γ = δ = G2` and `IC = [7, 11, 13]·G1`
+    fn key() -> VerifyingKey {
+        VerifyingKey {
+            alpha_g1: g1(U256::from(5)),
+            beta_g2: BN254G2Point::GENERATOR,
+            gamma_g2: BN254G2Point::GENERATOR,
+            delta_g2: BN254G2Point::GENERATOR,
+            ic: [7u64, 11, 13].map(|k| g1(U256::from(k))).to_vec(),
+        }
+    }
+
+    /// Proof with `A = r·G1`, `B = s·G2` and `C = (r·s - 5 - 7 - 11·x_1 - 13·x_2)·G1`
+    fn proof(seed: u64) -> (Groth16Proof, Vec<U256>) {
+        let (r, s) = (U256::from(3 + seed), U256::from(100 + 17 * seed));
+        let inputs = vec![U256::from(seed * 31 + 1), U256::from(seed * 37 + 2)];
+        let mut c = sub(mul(r, s), U256::from(12));
+        c = sub(c, mul(inputs[0], U256::from(11)));
+        c = sub(c, mul(inputs[1], U256::from(13)));
+        (Groth16Proof { a: g1(r), b: g2(s), c: g1(c) }, inputs)
+    }
+
+    #[test]
+    fn test_valid_batch() {
+        let vk = key();
+        let proofs: Vec<_> = (0..10).map(proof).collect();
+        for (proof, inputs) in &proofs {
+            assert!(groth16_verify(&vk, proof, inputs).unwrap());
+        }
+
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        assert!(batch_verify(&proofs, &vk, &mut rng).unwrap());
+        assert!(batch_verify(&proofs[..1], &vk, &mut rng).unwrap());
+        assert!(batch_verify(&[], &vk, &mut rng).unwrap());
+    }
+
+    #[test]
+    fn test_one_tampered_proof_fails_the_batch() {
+        let vk = key();
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+        for tampered in [0, 4, 9] {
+            let mut proofs: Vec<_> = (0..10).map(proof).collect();
+            proofs[tampered].1[1] += U256::from(1);
+            assert!(!batch_verify(&proofs, &vk, &mut rng).unwrap());
+        }
+
+        // Two wrong proofs whose errors cancel under equal weights still fail
+        let mut proofs: Vec<_> = (0..10).map(proof).collect();
+        proofs[2].0.c = BN254G1Point::add(&proofs[2].0.c, &BN254G1Point::GENERATOR).unwrap();
+        proofs[3].0.c = BN254G1Point::add(&proofs[3].0.c, &BN254G1Point::GENERATOR.neg()).unwrap();
+        assert!(!batch_verify(&proofs, &vk, &mut rng).unwrap());
+    }
+
+    #[test]
+    fn test_rejects_malformed_inputs() {
+        let vk = key();
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+        let mut proofs = vec![proof(1)];
+        proofs[0].1.pop();
+        assert!(matches!(
+            batch_verify(&proofs, &vk, &mut rng),
+            Err(PrecompileError::Groth16(Groth16Error::InvalidPublicInputCount { .. }))
+        ));
+
+        let mut proofs = vec![proof(1)];
+        proofs[0].1[0] = BN254_MODULUS;
+        assert!(matches!(
+            batch_verify(&proofs, &vk, &mut rng),
+            Err(PrecompileError::Groth16(Groth16Error::PublicInputTooLarge(_)))
+        ));
+
+        let mut proofs = vec![proof(1)];
+        proofs[0].0.a.y += U256::from(1);
+        assert!(matches!(batch_verify(&proofs, &vk, &mut rng), Err(PrecompileError::Curve(_))));
+    }
+}
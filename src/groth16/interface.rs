@@ -0,0 +1,87 @@
+//! Groth16 verifier precompile interface and ABI definitions
+
+use super::verifier::{groth16_verify, Groth16Proof, VerifyingKey};
+use crate::errors::{InvalidSelectorError, PrecompileError};
+use crate::gas::GasCosts;
+use crate::poseidon::interface::decode_call;
+use crate::traits::GenericPrecompile;
+use alloy_sol_types::{sol, SolCall, SolValue};
+
+// Solidity interface definition
+sol! {
+    #[sol(abi)]
+    interface IGroth16Verifier {
+        /// Verifies a Groth16 proof over BN254 in the snarkjs calldata layout
+        /// @param a The proof point A in G1
+        /// @param b The proof point B in G2, imaginary parts first
+        /// @param c The proof point C in G1
+        /// @param input The public inputs
+        /// @param vk The verifying key: alpha, beta, gamma, delta, then the IC points
+        /// @return valid Whether the proof is accepted
+        function verifyProof(uint256[2] a, uint256[2][2] b, uint256[2] c, uint256[] input, uint256[] vk) external view returns (bool valid);
+    }
+}
+
+/// Groth16 verifier precompile behind the `IGroth16Verifier` selectors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Groth16Precompile;
+
+impl Groth16Precompile {
+    /// Selectors of every `IGroth16Verifier` function
+    pub const SELECTORS: [[u8; 4]; 1] = [IGroth16Verifier::verifyProofCall::SELECTOR];
+}
+
+impl GenericPrecompile for Groth16Precompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        groth16_precompile(input)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, input: &[u8]) -> u64 {
+        // Calls that fail to decode are charged as having no public inputs
+        if input.len() < 4 {
+            return GasCosts::groth16_verify(0);
+        }
+        let public_inputs = decode_call::<IGroth16Verifier::verifyProofCall>(input)
+            .map_or(0, |call| call.input.len() as u64);
+        GasCosts::groth16_verify(public_inputs)
+    }
+}
+
+/// Precompile entry point - handles the raw call interface
+pub fn groth16_precompile(input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+    if input.len() < 4 {
+        return Err(InvalidSelectorError::from_input(input).into());
+    }
+
+    match &input[0..4] {
+        // verifyProof(uint256[2],uint256[2][2],uint256[2],uint256[],uint256[])
+        s if s == IGroth16Verifier::verifyProofCall::SELECTOR => {
+            let decoded = decode_call::<IGroth16Verifier::verifyProofCall>(input)?;
+
+            let vk = VerifyingKey::from_words(&decoded.vk)?;
+            let proof = Groth16Proof::from_calldata(decoded.a, decoded.b, decoded.c);
+            Ok(groth16_verify(&vk, &proof, &decoded.input)?.abi_encode())
+        }
+
+        _ => Err(InvalidSelectorError::from_input(input).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_bad_calldata() {
+        assert!(matches!(groth16_precompile(&[0u8; 3]), Err(PrecompileError::Selector(_))));
+        assert!(matches!(groth16_precompile(&[0u8; 8]), Err(PrecompileError::Selector(_))));
+
+        let selector = IGroth16Verifier::verifyProofCall::SELECTOR;
+        assert!(matches!(groth16_precompile(&selector), Err(PrecompileError::Abi(_))));
+        assert_eq!(Groth16Precompile.gas_cost(&selector), GasCosts::groth16_verify(0));
+    }
+}
@@ -0,0 +1,17 @@
+//! Groth16 proof verification over BN254
+
+pub mod batch_verifier;
+pub mod interface;
+#[cfg(feature = "circom")]
+pub mod prover;
+pub mod verifier;
+pub mod vk_registry;
+
+pub use batch_verifier::batch_verify;
+pub use interface::{groth16_precompile, Groth16Precompile, IGroth16Verifier};
+#[cfg(feature = "circom")]
+pub use prover::{insecure_setup, prove, ProvingKey, Trapdoor};
+pub use verifier::{groth16_verify, Groth16Proof, VerifyingKey};
+pub use vk_registry::verify_with_stored_vk;
+#[cfg(feature = "stylus")]
+pub use vk_registry::VkRegistry;
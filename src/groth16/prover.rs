@@ -0,0 +1,534 @@
+//! Groth16 proving over BN254 for circom R1CS
+//!
+//! The constraint system becomes a QAP over the radix-2 domain
+//! `H = {ω^0, …, ω^(n-1)}`: wire `i` gets polynomials `u_i, v_i, w_i` whose
+//! values at `ω^j` are its coefficients in the `A`, `B` and `C` rows of
+//! constraint `j`. As snarkjs does, one extra `A` row per public signal keeps
+//! the public polynomials linearly independent. A witness `s` satisfies the
+//! R1CS exactly when `a(X)·b(X) - c(X)` vanishes on `H`, with `a = Σ s_i·u_i`
+//! and likewise `b` and `c`, so the prover can divide it by `Z(X) = X^n - 1`
+//! and commit to the quotient `h` through the proving key.
+//!
+//! Signals `0..=l` are public, as circom lays them out: the constant 1, the
+//! outputs, then the public inputs.
+//!
+//! `insecure_setup` derives both keys from a trapdoor its caller knows, which
+//! lets that caller forge proofs. It is for tests and local development; real
+//! circuits need keys from a ceremony.
+
+use super::verifier::{Groth16Proof, VerifyingKey};
+use crate::circom::{SparseVec, R1CS};
+use crate::circuit_builder::R1CSDescription;
+use crate::curves::{bn254_msm, BN254G1Point, BN254G2Point};
+use crate::errors::Groth16Error;
+use crate::field::{batch_invert, Bn254Field};
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::U256;
+
+/// Generator of the scalar field's multiplicative group, used as the coset shift
+const MULTIPLICATIVE_GENERATOR: u64 = 5;
+
+/// Header words of a serialized proving key: wires, private wires, domain size
+const PK_HEADER_WORDS: usize = 3;
+
+/// Groth16 proving key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvingKey {
+    pub alpha_g1: BN254G1Point,
+    pub beta_g1: BN254G1Point,
+    pub beta_g2: BN254G2Point,
+    pub delta_g1: BN254G1Point,
+    pub delta_g2: BN254G2Point,
+    /// `u_i(τ)·G1` for every wire
+    pub a_query: Vec<BN254G1Point>,
+    /// `v_i(τ)·G1` for every wire
+    pub b_g1_query: Vec<BN254G1Point>,
+    /// `v_i(τ)·G2` for every wire
+    pub b_g2_query: Vec<BN254G2Point>,
+    /// `τ^k·Z(τ)/δ·G1` for `k = 0..n-1`
+    pub h_query: Vec<BN254G1Point>,
+    /// `(β·u_i(τ) + α·v_i(τ) + w_i(τ))/δ·G1` for every private wire
+    pub l_query: Vec<BN254G1Point>,
+}
+
+impl ProvingKey {
+    /// Serializes the key as 32-byte big-endian words: the wire, private wire
+    /// and domain counts, then every point with G2 imaginary parts first
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut words = vec![
+            U256::from(self.a_query.len()),
+            U256::from(self.l_query.len()),
+            U256::from(self.h_query.len() + 1),
+        ];
+        let g1 = |words: &mut Vec<U256>, p: &BN254G1Point| words.extend([p.x, p.y]);
+        let g2 = |words: &mut Vec<U256>, p: &BN254G2Point| words.extend(p.x.iter().chain(&p.y));
+
+        g1(&mut words, &self.alpha_g1);
+        g1(&mut words, &self.beta_g1);
+        g2(&mut words, &self.beta_g2);
+        g1(&mut words, &self.delta_g1);
+        g2(&mut words, &self.delta_g2);
+        self.a_query.iter().for_each(|p| g1(&mut words, p));
+        self.b_g1_query.iter().for_each(|p| g1(&mut words, p));
+        self.b_g2_query.iter().for_each(|p| g2(&mut words, p));
+        self.h_query.iter().for_each(|p| g1(&mut words, p));
+        self.l_query.iter().for_each(|p| g1(&mut words, p));
+
+        words.iter().flat_map(|word| word.to_be_bytes::<32>()).collect()
+    }
+
+    /// Parses a key written by `to_bytes`, checking that G1 points are on the curve
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Groth16Error> {
+        let malformed = Groth16Error::MalformedProvingKey;
+        if bytes.len() % 32 != 0 || bytes.len() < 32 * PK_HEADER_WORDS {
+            return Err(malformed(format!("{} bytes are not a whole key", bytes.len())));
+        }
+
+        let words: Vec<U256> = bytes.chunks(32).map(U256::from_be_slice).collect();
+        let count = |word: U256| {
+            (word <= U256::from(words.len()))
+                .then(|| word.to::<usize>())
+                .ok_or_else(|| malformed(format!("count {word} exceeds the key length")))
+        };
+        let (wires, private, domain) = (count(words[0])?, count(words[1])?, count(words[2])?);
+        if private >= wires || domain < 2 || !domain.is_power_of_two() {
+            return Err(malformed(format!(
+                "{wires} wires with {private} private ones over a domain of {domain}"
+            )));
+        }
+
+        // α, β and δ, then the A, B, H and private wire queries
+        let expected = PK_HEADER_WORDS + 14 + 8 * wires + 2 * (domain - 1) + 2 * private;
+        if words.len() != expected {
+            return Err(malformed(format!("expected {expected} words, got {}", words.len())));
+        }
+
+        let mut reader = WordReader { words: &words[PK_HEADER_WORDS..] };
+        Ok(Self {
+            alpha_g1: reader.g1()?,
+            beta_g1: reader.g1()?,
+            beta_g2: reader.g2(),
+            delta_g1: reader.g1()?,
+            delta_g2: reader.g2(),
+            a_query: (0..wires).map(|_| reader.g1()).collect::<Result<_, _>>()?,
+            b_g1_query: (0..wires).map(|_| reader.g1()).collect::<Result<_, _>>()?,
+            b_g2_query: (0..wires).map(|_| reader.g2()).collect(),
+            h_query: (1..domain).map(|_| reader.g1()).collect::<Result<_, _>>()?,
+            l_query: (0..private).map(|_| reader.g1()).collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// Reads points off the front of a word slice whose length was checked
+struct WordReader<'a> {
+    words: &'a [U256],
+}
+
+impl<'a> WordReader<'a> {
+    fn take(&mut self, n: usize) -> &'a [U256] {
+        let (head, tail) = self.words.split_at(n);
+        self.words = tail;
+        head
+    }
+
+    fn g1(&mut self) -> Result<BN254G1Point, Groth16Error> {
+        let w = self.take(2);
+        Ok(BN254G1Point::new(w[0], w[1])?)
+    }
+
+    /// G2 points are checked when the prover first uses them
+    fn g2(&mut self) -> BN254G2Point {
+        let w = self.take(4);
+        BN254G2Point { x: [w[0], w[1]], y: [w[2], w[3]] }
+    }
+}
+
+/// Trapdoor of a Groth16 setup, to be discarded once the keys are derived
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trapdoor {
+    pub tau: U256,
+    pub alpha: U256,
+    pub beta: U256,
+    pub gamma: U256,
+    pub delta: U256,
+}
+
+/// Derives proving and verifying keys for a circuit from a known trapdoor
+///
+/// Anyone holding `trapdoor` can forge proofs against these keys, so they are
+/// only fit for tests and local development.
+pub fn insecure_setup(
+    circuit: &R1CSDescription,
+    trapdoor: &Trapdoor,
+) -> Result<(ProvingKey, VerifyingKey), Groth16Error> {
+    let [tau, alpha, beta, gamma, delta] =
+        [trapdoor.tau, trapdoor.alpha, trapdoor.beta, trapdoor.gamma, trapdoor.delta]
+            .map(Bn254Field::from_u256);
+    let (Some(gamma_inv), Some(delta_inv)) = (gamma.inv(), delta.inv()) else {
+        return Err(Groth16Error::InvalidTrapdoor("gamma and delta must be non-zero".into()));
+    };
+
+    let public = circuit.n_pub_outputs + circuit.n_pub_inputs;
+    let constraints = &circuit.r1cs.constraints;
+    let domain = Domain::new(constraints.len() + public + 1);
+    let z_tau = tau.pow(U256::from(domain.size)) - Bn254Field::ONE;
+    if z_tau.is_zero() {
+        return Err(Groth16Error::InvalidTrapdoor("tau lies in the evaluation domain".into()));
+    }
+
+    // L_j(τ) = Z(τ)/n · ω^j / (τ - ω^j)
+    let points = domain.elements();
+    let mut lagrange: Vec<Bn254Field> = points.iter().map(|omega_j| tau - *omega_j).collect();
+    batch_invert(&mut lagrange).expect("tau is not in the domain");
+    let scale = z_tau * domain.size_inv;
+    for (l, omega_j) in lagrange.iter_mut().zip(&points) {
+        *l = *l * *omega_j * scale;
+    }
+
+    // u_i(τ), v_i(τ), w_i(τ) for every wire
+    let wires = circuit.n_wires;
+    let (mut u, mut v, mut w) = (
+        vec![Bn254Field::ZERO; wires],
+        vec![Bn254Field::ZERO; wires],
+        vec![Bn254Field::ZERO; wires],
+    );
+    for ((a, b, c), l) in constraints.iter().zip(&lagrange) {
+        for (row, polys) in [(a, &mut u), (b, &mut v), (c, &mut w)] {
+            for &(wire, coefficient) in &row.terms {
+                let slot = polys.get_mut(wire).ok_or_else(|| {
+                    Groth16Error::InvalidCircuit(format!("wire {wire} of {wires} out of range"))
+                })?;
+                *slot = *slot + Bn254Field::from_u256(coefficient) * *l;
+            }
+        }
+    }
+    for (i, l) in lagrange[constraints.len()..=constraints.len() + public].iter().enumerate() {
+        u[i] = u[i] + *l;
+    }
+
+    let g1 =
+        |scalar: Bn254Field| BN254G1Point::scalar_mul(&BN254G1Point::GENERATOR, scalar.into_u256());
+    let g2 =
+        |scalar: Bn254Field| BN254G2Point::scalar_mul(&BN254G2Point::GENERATOR, scalar.into_u256());
+    let combined = |i: usize| beta * u[i] + alpha * v[i] + w[i];
+
+    let mut tau_k_z = z_tau * delta_inv;
+    let mut h_query = Vec::with_capacity(domain.size - 1);
+    for _ in 1..domain.size {
+        h_query.push(g1(tau_k_z)?);
+        tau_k_z = tau_k_z * tau;
+    }
+
+    let pk = ProvingKey {
+        alpha_g1: g1(alpha)?,
+        beta_g1: g1(beta)?,
+        beta_g2: g2(beta)?,
+        delta_g1: g1(delta)?,
+        delta_g2: g2(delta)?,
+        a_query: u.iter().map(|x| g1(*x)).collect::<Result<_, _>>()?,
+        b_g1_query: v.iter().map(|x| g1(*x)).collect::<Result<_, _>>()?,
+        b_g2_query: v.iter().map(|x| g2(*x)).collect::<Result<_, _>>()?,
+        h_query,
+        l_query: (public + 1..wires)
+            .map(|i| g1(combined(i) * delta_inv))
+            .collect::<Result<_, _>>()?,
+    };
+    let vk = VerifyingKey {
+        alpha_g1: pk.alpha_g1,
+        beta_g2: pk.beta_g2,
+        gamma_g2: g2(gamma)?,
+        delta_g2: pk.delta_g2,
+        ic: (0..=public).map(|i| g1(combined(i) * gamma_inv)).collect::<Result<_, _>>()?,
+    };
+    Ok((pk, vk))
+}
+
+/// Proves that `witness` satisfies `r1cs`, blinding the proof with `r` and `s`
+///
+/// `r` and `s` must be secret and fresh for each proof, or the proof leaks
+/// information about the private signals.
+pub fn prove(
+    pk: &ProvingKey,
+    r1cs: &R1CS,
+    witness: &[U256],
+    r: U256,
+    s: U256,
+) -> Result<Groth16Proof, Groth16Error> {
+    let invalid = Groth16Error::InvalidCircuit;
+    let wires = pk.a_query.len();
+    if pk.b_g1_query.len() != wires || pk.b_g2_query.len() != wires || pk.l_query.len() >= wires {
+        return Err(Groth16Error::MalformedProvingKey("query lengths disagree".into()));
+    }
+    if witness.len() != wires {
+        return Err(invalid(format!("witness has {} signals, the key {wires}", witness.len())));
+    }
+    if let Some(&signal) = witness.iter().find(|signal| **signal >= BN254_MODULUS) {
+        return Err(invalid(format!("signal {signal} is not below the scalar field modulus")));
+    }
+
+    let public = wires - pk.l_query.len() - 1;
+    let domain = Domain::new(pk.h_query.len() + 1);
+    let constraints = &r1cs.constraints;
+    if domain.size != pk.h_query.len() + 1 || constraints.len() + public + 1 > domain.size {
+        return Err(invalid(format!(
+            "{} constraints do not fit the key's domain of {}",
+            constraints.len(),
+            pk.h_query.len() + 1
+        )));
+    }
+
+    // a, b and c on the domain, with the public signal rows after the constraints
+    let signals: Vec<Bn254Field> = witness.iter().map(|w| Bn254Field::from_u256(*w)).collect();
+    let dot = |row: &SparseVec| -> Result<Bn254Field, Groth16Error> {
+        row.terms.iter().try_fold(Bn254Field::ZERO, |sum, &(wire, coefficient)| {
+            let signal =
+                signals.get(wire).ok_or_else(|| invalid(format!("wire {wire} out of range")))?;
+            Ok(sum + Bn254Field::from_u256(coefficient) * *signal)
+        })
+    };
+    let mut a = vec![Bn254Field::ZERO; domain.size];
+    let mut b = a.clone();
+    let mut c = a.clone();
+    for (j, (row_a, row_b, row_c)) in constraints.iter().enumerate() {
+        (a[j], b[j], c[j]) = (dot(row_a)?, dot(row_b)?, dot(row_c)?);
+        if a[j] * b[j] != c[j] {
+            return Err(invalid(format!("witness violates constraint {j}")));
+        }
+    }
+    a[constraints.len()..=constraints.len() + public].copy_from_slice(&signals[..=public]);
+
+    // h = (a·b - c) / Z on the coset g·H, where Z is the constant g^n - 1
+    for evaluations in [&mut a, &mut b, &mut c] {
+        domain.ifft(evaluations);
+        domain.coset_fft(evaluations);
+    }
+    let z_inv = (domain.shift.pow(U256::from(domain.size)) - Bn254Field::ONE)
+        .inv()
+        .expect("the coset is disjoint from the domain");
+    let mut h: Vec<Bn254Field> =
+        a.iter().zip(&b).zip(&c).map(|((a, b), c)| (*a * *b - *c) * z_inv).collect();
+    domain.coset_ifft(&mut h);
+
+    let (r, s) = (Bn254Field::from_u256(r), Bn254Field::from_u256(s));
+    let msm = |scalars: &[U256], points: &[BN254G1Point]| bn254_msm(scalars, points);
+    let add = |p: BN254G1Point, q: BN254G1Point| BN254G1Point::add(&p, &q);
+    let mul = |p: &BN254G1Point, k: Bn254Field| BN254G1Point::scalar_mul(p, k.into_u256());
+
+    // A = α + Σ s_i·u_i(τ) + r·δ, and B likewise with β, v_i and s
+    let proof_a = add(add(pk.alpha_g1, msm(witness, &pk.a_query)?)?, mul(&pk.delta_g1, r)?)?;
+    let b_g1 = add(add(pk.beta_g1, msm(witness, &pk.b_g1_query)?)?, mul(&pk.delta_g1, s)?)?;
+    let mut proof_b =
+        BN254G2Point::add(&pk.beta_g2, &BN254G2Point::scalar_mul(&pk.delta_g2, s.into_u256())?)?;
+    for (signal, point) in witness.iter().zip(&pk.b_g2_query) {
+        if !signal.is_zero() && !point.is_infinity() {
+            proof_b = BN254G2Point::add(&proof_b, &BN254G2Point::scalar_mul(point, *signal)?)?;
+        }
+    }
+
+    // C = Σ private s_i·L_i + h(τ)·Z(τ)/δ + s·A + r·B - r·s·δ
+    let h: Vec<U256> = h[..domain.size - 1].iter().map(|x| x.into_u256()).collect();
+    let mut proof_c = add(msm(&witness[public + 1..], &pk.l_query)?, msm(&h, &pk.h_query)?)?;
+    proof_c = add(proof_c, mul(&proof_a, s)?)?;
+    proof_c = add(proof_c, mul(&b_g1, r)?)?;
+    proof_c = add(proof_c, mul(&pk.delta_g1, r * s)?.neg())?;
+
+    Ok(Groth16Proof { a: proof_a, b: proof_b, c: proof_c })
+}
+
+/// Multiplicative subgroup of order `size`, a power of two, and its coset `shift·H`
+struct Domain {
+    size: usize,
+    omega: Bn254Field,
+    omega_inv: Bn254Field,
+    size_inv: Bn254Field,
+    shift: Bn254Field,
+}
+
+impl Domain {
+    /// Smallest domain holding `min_size` points
+    fn new(min_size: usize) -> Self {
+        let size = min_size.next_power_of_two().max(2);
+        let generator = Bn254Field::from(MULTIPLICATIVE_GENERATOR);
+
+        // The generator is a non-residue, so this has order exactly `size`
+        let omega = generator.pow((BN254_MODULUS - U256::from(1)) / U256::from(size));
+        Self {
+            size,
+            omega,
+            omega_inv: omega.inv().expect("roots of unity are non-zero"),
+            size_inv: Bn254Field::from(size as u64).inv().expect("size is below the modulus"),
+            shift: generator,
+        }
+    }
+
+    /// `ω^0, …, ω^(n-1)`
+    fn elements(&self) -> Vec<Bn254Field> {
+        core::iter::successors(Some(Bn254Field::ONE), |x| Some(*x * self.omega))
+            .take(self.size)
+            .collect()
+    }
+
+    /// Coefficients to evaluations on the domain
+    fn fft(&self, values: &mut [Bn254Field]) {
+        ntt(values, self.omega);
+    }
+
+    /// Evaluations on the domain to coefficients
+    fn ifft(&self, values: &mut [Bn254Field]) {
+        ntt(values, self.omega_inv);
+        values.iter_mut().for_each(|x| *x = *x * self.size_inv);
+    }
+
+    /// Coefficients to evaluations on the coset
+    fn coset_fft(&self, values: &mut [Bn254Field]) {
+        scale_powers(values, self.shift);
+        self.fft(values);
+    }
+
+    /// Evaluations on the coset to coefficients
+    fn coset_ifft(&self, values: &mut [Bn254Field]) {
+        self.ifft(values);
+        scale_powers(values, self.shift.inv().expect("the shift is non-zero"));
+    }
+}
+
+/// Multiplies coefficient `i` by `factor^i`
+fn scale_powers(values: &mut [Bn254Field], factor: Bn254Field) {
+    let mut power = Bn254Field::ONE;
+    for value in values {
+        *value = *value * power;
+        power = power * factor;
+    }
+}
+
+/// In-place radix-2 number theoretic transform with `root` of order `values.len()`
+fn ntt(values: &mut [Bn254Field], root: Bn254Field) {
+    let n = values.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let step = root.pow(U256::from(n / len));
+        for chunk in values.chunks_mut(len) {
+            let (low, high) = chunk.split_at_mut(len / 2);
+            let mut twiddle = Bn254Field::ONE;
+            for (even, odd) in low.iter_mut().zip(high) {
+                let t = *odd * twiddle;
+                (*even, *odd) = (*even + t, *even - t);
+                twiddle = twiddle * step;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::groth16::groth16_verify;
+
+    /// `x · x = y` and `y · x = out` with `out` public: proves knowledge of a cube root
+    fn cube_circuit() -> R1CSDescription {
+        let wire = |i: usize| SparseVec::new(vec![(i, U256::from(1))]);
+        R1CSDescription {
+            n_wires: 4,
+            n_pub_outputs: 1,
+            n_pub_inputs: 0,
+            n_prv_inputs: 1,
+            r1cs: R1CS {
+                constraints: vec![(wire(2), wire(2), wire(3)), (wire(3), wire(2), wire(1))],
+            },
+        }
+    }
+
+    fn trapdoor() -> Trapdoor {
+        Trapdoor {
+            tau: U256::from(0x1234_5678_u64),
+            alpha: U256::from(7),
+            beta: U256::from(11),
+            gamma: U256::from(13),
+            delta: U256::from(17),
+        }
+    }
+
+    #[test]
+    fn test_ntt_round_trip() {
+        let domain = Domain::new(8);
+        let coefficients: Vec<Bn254Field> = (1..=8u64).map(Bn254Field::from).collect();
+
+        let mut values = coefficients.clone();
+        domain.fft(&mut values);
+        for (x, value) in domain.elements().iter().zip(&values) {
+            let expected = coefficients.iter().rev().fold(Bn254Field::ZERO, |acc, c| acc * *x + *c);
+            assert_eq!(*value, expected);
+        }
+        domain.ifft(&mut values);
+        assert_eq!(values, coefficients);
+
+        domain.coset_fft(&mut values);
+        domain.coset_ifft(&mut values);
+        assert_eq!(values, coefficients);
+    }
+
+    #[test]
+    fn test_prove_and_verify() {
+        let circuit = cube_circuit();
+        let (pk, vk) = insecure_setup(&circuit, &trapdoor()).unwrap();
+        let witness = [1u64, 27, 3, 9].map(U256::from);
+
+        let proof = prove(&pk, &circuit.r1cs, &witness, U256::from(5), U256::from(6)).unwrap();
+        assert!(groth16_verify(&vk, &proof, &[U256::from(27)]).unwrap());
+        assert!(!groth16_verify(&vk, &proof, &[U256::from(28)]).unwrap());
+
+        // Fresh blinding gives a different proof of the same statement
+        let other = prove(&pk, &circuit.r1cs, &witness, U256::from(8), U256::from(9)).unwrap();
+        assert_ne!(other, proof);
+        assert!(groth16_verify(&vk, &other, &[U256::from(27)]).unwrap());
+    }
+
+    #[test]
+    fn test_rejects_bad_witness_and_trapdoor() {
+        let circuit = cube_circuit();
+        let (pk, _) = insecure_setup(&circuit, &trapdoor()).unwrap();
+
+        let wrong = [1u64, 28, 3, 9].map(U256::from);
+        assert!(matches!(
+            prove(&pk, &circuit.r1cs, &wrong, U256::from(5), U256::from(6)),
+            Err(Groth16Error::InvalidCircuit(_))
+        ));
+        assert!(prove(&pk, &circuit.r1cs, &wrong[..3], U256::from(5), U256::from(6)).is_err());
+
+        let zero_delta = Trapdoor { delta: U256::ZERO, ..trapdoor() };
+        assert!(matches!(
+            insecure_setup(&circuit, &zero_delta),
+            Err(Groth16Error::InvalidTrapdoor(_))
+        ));
+        let tau_in_domain = Trapdoor { tau: U256::from(1), ..trapdoor() };
+        assert!(matches!(
+            insecure_setup(&circuit, &tau_in_domain),
+            Err(Groth16Error::InvalidTrapdoor(_))
+        ));
+    }
+
+    #[test]
+    fn test_proving_key_bytes() {
+        let (pk, _) = insecure_setup(&cube_circuit(), &trapdoor()).unwrap();
+        let bytes = pk.to_bytes();
+        assert_eq!(ProvingKey::from_bytes(&bytes).unwrap(), pk);
+
+        assert!(ProvingKey::from_bytes(&bytes[..bytes.len() - 32]).is_err());
+        assert!(ProvingKey::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        // The first point after the header is α; (1, 3) is not on the curve
+        let mut corrupt = bytes.clone();
+        corrupt[3 * 32..4 * 32].copy_from_slice(&U256::from(1).to_be_bytes::<32>());
+        corrupt[4 * 32..5 * 32].copy_from_slice(&U256::from(3).to_be_bytes::<32>());
+        assert!(matches!(ProvingKey::from_bytes(&corrupt), Err(Groth16Error::Curve(_))));
+    }
+}
@@ -0,0 +1,165 @@
+//! Groth16 verifier
+//!
+//! A proof `(A, B, C)` is accepted for public inputs `x_1..x_l` when
+//!
+//! `e(A, B) = e(α, β) · e(vk_x, γ) · e(C, δ)`, with `vk_x = IC_0 + Σ x_i · IC_i`.
+//!
+//! The check is run as one multi-pairing `e(-A, B) · e(α, β) · e(vk_x, γ) ·
+//! e(C, δ) = 1`, the same equation the snarkjs and Tornado Cash Solidity
+//! verifiers hand to the `0x08` precompile.
+
+use crate::curves::{bn254_pairing_check, BN254G1Point, BN254G2Point};
+use crate::errors::Groth16Error;
+use crate::poseidon::interface::decode_params;
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::U256;
+
+/// Words in the fixed part of a flattened verifying key: α, β, γ and δ
+const VK_FIXED_WORDS: usize = 2 + 3 * 4;
+
+/// Groth16 verifying key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyingKey {
+    pub alpha_g1: BN254G1Point,
+    pub beta_g2: BN254G2Point,
+    pub gamma_g2: BN254G2Point,
+    pub delta_g2: BN254G2Point,
+    /// `IC_0..IC_l`, one more than the number of public inputs
+    pub ic: Vec<BN254G1Point>,
+}
+
+impl VerifyingKey {
+    /// Parses a key flattened as `α.x, α.y`, then β, γ, δ as four words each
+    /// (imaginary parts first), then two words per `IC` point
+    pub fn from_words(words: &[U256]) -> Result<Self, Groth16Error> {
+        if words.len() < VK_FIXED_WORDS + 2 || (words.len() - VK_FIXED_WORDS) % 2 != 0 {
+            return Err(Groth16Error::MalformedVerifyingKey(format!(
+                "{} words do not hold alpha, beta, gamma, delta and at least one IC point",
+                words.len()
+            )));
+        }
+
+        let g2 = |w: &[U256]| BN254G2Point { x: [w[0], w[1]], y: [w[2], w[3]] };
+        Ok(Self {
+            alpha_g1: BN254G1Point { x: words[0], y: words[1] },
+            beta_g2: g2(&words[2..6]),
+            gamma_g2: g2(&words[6..10]),
+            delta_g2: g2(&words[10..14]),
+            ic: words[VK_FIXED_WORDS..]
+                .chunks(2)
+                .map(|w| BN254G1Point { x: w[0], y: w[1] })
+                .collect(),
+        })
+    }
+
+    /// Flattens the key in the layout `from_words` reads
+    pub fn to_words(&self) -> Vec<U256> {
+        let mut words = Vec::with_capacity(VK_FIXED_WORDS + 2 * self.ic.len());
+        words.extend([self.alpha_g1.x, self.alpha_g1.y]);
+        for g2 in [&self.beta_g2, &self.gamma_g2, &self.delta_g2] {
+            words.extend(g2.x.iter().chain(&g2.y));
+        }
+        words.extend(self.ic.iter().flat_map(|p| [p.x, p.y]));
+        words
+    }
+}
+
+/// Groth16 proof `(A, B, C)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Groth16Proof {
+    pub a: BN254G1Point,
+    pub b: BN254G2Point,
+    pub c: BN254G1Point,
+}
+
+impl Groth16Proof {
+    /// Builds a proof from the `uint256[2] a, uint256[2][2] b, uint256[2] c`
+    /// arguments of a Solidity `verifyProof` call
+    pub fn from_calldata(a: [U256; 2], b: [[U256; 2]; 2], c: [U256; 2]) -> Self {
+        Self {
+            a: BN254G1Point { x: a[0], y: a[1] },
+            b: BN254G2Point { x: b[0], y: b[1] },
+            c: BN254G1Point { x: c[0], y: c[1] },
+        }
+    }
+
+    /// Decodes the ABI-encoded `(uint256[2], uint256[2][2], uint256[2])` tuple
+    pub fn abi_decode(data: &[u8]) -> Result<Self, Groth16Error> {
+        let (a, b, c) = decode_params::<([U256; 2], [[U256; 2]; 2], [U256; 2])>(data)?;
+        Ok(Self::from_calldata(a, b, c))
+    }
+}
+
+/// Verifies a Groth16 proof against the verifying key and public inputs
+pub fn groth16_verify(
+    vk: &VerifyingKey,
+    proof: &Groth16Proof,
+    public_inputs: &[U256],
+) -> Result<bool, Groth16Error> {
+    if vk.ic.len() != public_inputs.len() + 1 {
+        return Err(Groth16Error::InvalidPublicInputCount {
+            expected: vk.ic.len().saturating_sub(1),
+            actual: public_inputs.len(),
+        });
+    }
+
+    let mut vk_x = vk.ic[0];
+    for (input, ic) in public_inputs.iter().zip(&vk.ic[1..]) {
+        if *input >= BN254_MODULUS {
+            return Err(Groth16Error::PublicInputTooLarge(*input));
+        }
+        vk_x = BN254G1Point::add(&vk_x, &BN254G1Point::scalar_mul(ic, *input)?)?;
+    }
+
+    let a = BN254G1Point::new(proof.a.x, proof.a.y)?;
+    Ok(bn254_pairing_check(&[
+        (a.neg(), proof.b),
+        (vk.alpha_g1, vk.beta_g2),
+        (vk_x, vk.gamma_g2),
+        (proof.c, vk.delta_g2),
+    ])?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_sol_types::SolValue;
+
+    fn key(ic_points: usize) -> VerifyingKey {
+        let g1 = BN254G1Point::GENERATOR;
+        let g2 = BN254G2Point::GENERATOR;
+        VerifyingKey { alpha_g1: g1, beta_g2: g2, gamma_g2: g2, delta_g2: g2, ic: vec![g1; ic_points] }
+    }
+
+    #[test]
+    fn test_rejects_malformed_inputs() {
+        let g1 = BN254G1Point::GENERATOR;
+        let proof = Groth16Proof { a: g1, b: BN254G2Point::GENERATOR, c: g1 };
+
+        assert!(matches!(
+            groth16_verify(&key(2), &proof, &[]),
+            Err(Groth16Error::InvalidPublicInputCount { expected: 1, actual: 0 })
+        ));
+        assert!(matches!(
+            groth16_verify(&key(2), &proof, &[BN254_MODULUS]),
+            Err(Groth16Error::PublicInputTooLarge(_))
+        ));
+        assert!(VerifyingKey::from_words(&[U256::ZERO; 15]).is_err());
+    }
+
+    #[test]
+    fn test_key_and_proof_encoding() {
+        let vk = key(2);
+        let mut words = vec![vk.alpha_g1.x, vk.alpha_g1.y];
+        for g2 in [vk.beta_g2, vk.gamma_g2, vk.delta_g2] {
+            words.extend(g2.x.iter().chain(&g2.y));
+        }
+        words.extend(vk.ic.iter().flat_map(|p| [p.x, p.y]));
+        assert_eq!(VerifyingKey::from_words(&words).unwrap(), vk);
+        assert_eq!(vk.to_words(), words);
+
+        let (a, b, c) = ([U256::from(1), U256::from(2)], [[U256::from(3); 2]; 2], [U256::from(4); 2]);
+        let encoded = (a, b, c).abi_encode_params();
+        assert_eq!(Groth16Proof::abi_decode(&encoded).unwrap(), Groth16Proof::from_calldata(a, b, c));
+    }
+}
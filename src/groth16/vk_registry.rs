@@ -0,0 +1,165 @@
+//! On-chain registry of Groth16 verifying keys
+//!
+//! Verifiers usually hard-code their key, so every circuit needs its own
+//! contract. `VkRegistry` stores keys under an id instead, and a proof names
+//! the key it was made for. A stored key is the `VerifyingKey::to_words`
+//! flattening, one word per storage slot; an id with no words is
+//! unregistered.
+
+use super::verifier::{groth16_verify, Groth16Proof, VerifyingKey};
+use alloy_primitives::U256;
+
+/// Verifies `proof` against a key stored as words, `false` if there is none
+///
+/// Stored words that no longer parse, or inputs the key rejects, give
+/// `false` as well, so a registry lookup never reverts on bad proofs.
+pub fn verify_with_stored_vk(words: &[U256], proof: &Groth16Proof, inputs: &[U256]) -> bool {
+    if words.is_empty() {
+        return false;
+    }
+    VerifyingKey::from_words(words)
+        .and_then(|vk| groth16_verify(&vk, proof, inputs))
+        .unwrap_or(false)
+}
+
+#[cfg(feature = "stylus")]
+mod storage {
+    extern crate alloc;
+
+    use super::verify_with_stored_vk;
+    use crate::errors::encode_revert;
+    use crate::groth16::verifier::{Groth16Proof, VerifyingKey};
+    use crate::SolStorageLayout;
+    use alloy_primitives::{Address, U256};
+    use stylus_sdk::prelude::*;
+    use stylus_sdk::storage::{StorageAddress, StorageMap, StorageU256, StorageVec};
+
+    /// Verifying key flattened by `VerifyingKey::to_words`
+    #[storage]
+    pub struct StoredVk {
+        words: StorageVec<StorageU256>,
+    }
+
+    /// Groth16 verifying keys stored by id
+    ///
+    /// The first `registerVk` call makes its caller the owner, who alone can
+    /// register or replace keys. Anyone can verify against a stored key.
+    #[storage]
+    #[derive(SolStorageLayout)]
+    pub struct VkRegistry {
+        vks: StorageMap<U256, StoredVk>,
+        owner: StorageAddress,
+    }
+
+    #[public]
+    impl VkRegistry {
+        /// Stores a flattened verifying key under `vk_id`, replacing any old one
+        pub fn register_vk(&mut self, vk_id: U256, vk: Vec<U256>) -> Result<(), Vec<u8>> {
+            if self.owner.get() == Address::ZERO {
+                self.owner.set(stylus_sdk::msg::sender());
+            }
+            self.only_owner()?;
+            VerifyingKey::from_words(&vk).map_err(|e| encode_revert(&e.to_string()))?;
+
+            let mut stored = self.vks.setter(vk_id);
+            stored.words.erase();
+            for word in vk {
+                stored.words.push(word);
+            }
+            Ok(())
+        }
+
+        /// Verifies a proof against the key stored under `vk_id`
+        ///
+        /// Returns `false` for an unregistered id.
+        pub fn verify_with_registered_vk(
+            &self,
+            vk_id: U256,
+            a: [U256; 2],
+            b: [[U256; 2]; 2],
+            c: [U256; 2],
+            inputs: Vec<U256>,
+        ) -> bool {
+            let stored = self.vks.get(vk_id);
+            let words: Vec<U256> =
+                (0..stored.words.len()).filter_map(|i| stored.words.get(i)).collect();
+            verify_with_stored_vk(&words, &Groth16Proof::from_calldata(a, b, c), &inputs)
+        }
+
+        /// Returns whether a key is stored under `vk_id`
+        pub fn vk_registered(&self, vk_id: U256) -> bool {
+            !self.vks.get(vk_id).words.is_empty()
+        }
+
+        /// Returns the address allowed to register keys
+        pub fn registry_owner(&self) -> Address {
+            self.owner.get()
+        }
+    }
+
+    impl VkRegistry {
+        /// Reverts unless the caller registered the first key
+        fn only_owner(&self) -> Result<(), Vec<u8>> {
+            if stylus_sdk::msg::sender() != self.owner.get() {
+                return Err(encode_revert("Caller is not the registry owner"));
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "stylus")]
+pub use storage::VkRegistry;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::{BN254G1Point, BN254G2Point};
+    use crate::utils::BN254_MODULUS;
+    use std::collections::BTreeMap;
+
+    fn g1(scalar: u64) -> BN254G1Point {
+        BN254G1Point::scalar_mul(&BN254G1Point::GENERATOR, U256::from(scalar)).unwrap()
+    }
+
+    /// Key with `α = 5·G1`, `β = γ = δ = G2` and `IC = [7, 11]·G1`
+    fn key() -> VerifyingKey {
+        VerifyingKey {
+            alpha_g1: g1(5),
+            beta_g2: BN254G2Point::GENERATOR,
+            gamma_g2: BN254G2Point::GENERATOR,
+            delta_g2: BN254G2Point::GENERATOR,
+            ic: vec![g1(7), g1(11)],
+        }
+    }
+
+    /// Proof with `A = 3·G1`, `B = 4·G2` and `C = (12 - 5 - 7 - 11·x)·G1`
+    fn proof(input: u64) -> Groth16Proof {
+        let c = BN254_MODULUS - U256::from(11 * input);
+        Groth16Proof {
+            a: g1(3),
+            b: BN254G2Point::scalar_mul(&BN254G2Point::GENERATOR, U256::from(4)).unwrap(),
+            c: BN254G1Point::scalar_mul(&BN254G1Point::GENERATOR, c).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_registered_vk_verifies() {
+        let mut registry = BTreeMap::new();
+        registry.insert(U256::from(1), key().to_words());
+        let stored = &registry[&U256::from(1)];
+
+        assert!(verify_with_stored_vk(stored, &proof(2), &[U256::from(2)]));
+        assert!(!verify_with_stored_vk(stored, &proof(2), &[U256::from(3)]));
+        assert!(!verify_with_stored_vk(stored, &proof(2), &[]));
+    }
+
+    #[test]
+    fn test_unregistered_vk_returns_false() {
+        let registry: BTreeMap<U256, Vec<U256>> = BTreeMap::new();
+        let stored = registry.get(&U256::from(1)).map(Vec::as_slice).unwrap_or_default();
+
+        assert!(!verify_with_stored_vk(stored, &proof(2), &[U256::from(2)]));
+        assert!(!verify_with_stored_vk(&[U256::ZERO; 15], &proof(2), &[U256::from(2)]));
+    }
+}
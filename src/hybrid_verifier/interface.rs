@@ -0,0 +1,158 @@
+//! Hybrid verifier precompile interface and ABI definitions
+
+use super::verifier::{verify_hybrid, HybridProof};
+use crate::curves::BN254G1Point;
+use crate::errors::{InvalidSelectorError, KZGError, PrecompileError};
+use crate::field::Bn254Field;
+use crate::gas::GasCosts;
+use crate::groth16::{Groth16Proof, VerifyingKey};
+use crate::kzg::KZGParams;
+use crate::poseidon::interface::decode_call;
+use crate::traits::GenericPrecompile;
+use alloy_sol_types::{sol, SolCall, SolValue};
+
+// Solidity interface definition
+sol! {
+    #[sol(abi)]
+    interface IHybridVerifier {
+        /// Verifies a Groth16 proof and a KZG opening at the Fiat-Shamir challenge point
+        /// @param a The proof point A in G1
+        /// @param b The proof point B in G2, imaginary parts first
+        /// @param c The proof point C in G1
+        /// @param input The public inputs
+        /// @param vk The verifying key: alpha, beta, gamma, delta, then the IC points
+        /// @param kzgCommitment The KZG commitment in G1
+        /// @param kzgEval The claimed evaluation at the challenge point
+        /// @param kzgProof The KZG opening proof in G1
+        /// @return valid Whether both proofs are accepted
+        function hybridVerify(uint256[2] a, uint256[2][2] b, uint256[2] c, uint256[] input, uint256[] vk, uint256[2] kzgCommitment, uint256 kzgEval, uint256[2] kzgProof) external view returns (bool valid);
+    }
+}
+
+/// Hybrid Groth16 and KZG verifier against a fixed trusted setup
+#[derive(Debug, Clone)]
+pub struct HybridVerifierPrecompile {
+    setup: KZGParams,
+}
+
+impl HybridVerifierPrecompile {
+    /// Selectors of every `IHybridVerifier` function
+    pub const SELECTORS: [[u8; 4]; 1] = [IHybridVerifier::hybridVerifyCall::SELECTOR];
+
+    pub fn new(setup: KZGParams) -> Self {
+        Self { setup }
+    }
+
+    /// Decodes the call and runs the requested check
+    pub fn dispatch(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        hybrid_verify_precompile(input, &self.setup)
+    }
+}
+
+impl GenericPrecompile for HybridVerifierPrecompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        self.dispatch(input)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, input: &[u8]) -> u64 {
+        // Calls that fail to decode are charged as having no public inputs
+        if input.len() < 4 {
+            return GasCosts::hybrid_verify(0);
+        }
+        let public_inputs = decode_call::<IHybridVerifier::hybridVerifyCall>(input)
+            .map_or(0, |call| call.input.len() as u64);
+        GasCosts::hybrid_verify(public_inputs)
+    }
+}
+
+/// Precompile entry point - handles the raw call interface
+pub fn hybrid_verify_precompile(
+    input: &[u8],
+    setup: &KZGParams,
+) -> Result<Vec<u8>, PrecompileError> {
+    if input.len() < 4 {
+        return Err(InvalidSelectorError::from_input(input).into());
+    }
+
+    match &input[0..4] {
+        // hybridVerify(uint256[2],uint256[2][2],uint256[2],uint256[],uint256[],
+        //              uint256[2],uint256,uint256[2])
+        s if s == IHybridVerifier::hybridVerifyCall::SELECTOR => {
+            let decoded = decode_call::<IHybridVerifier::hybridVerifyCall>(input)?;
+
+            let vk = VerifyingKey::from_words(&decoded.vk)?;
+            let eval = Bn254Field::from_canonical(decoded.kzgEval)
+                .ok_or(KZGError::FieldElementTooLarge(decoded.kzgEval))?;
+            let proof = HybridProof {
+                groth16: Groth16Proof::from_calldata(decoded.a, decoded.b, decoded.c),
+                kzg_opening: (eval, BN254G1Point::new(decoded.kzgProof[0], decoded.kzgProof[1])?),
+                kzg_commitment: BN254G1Point::new(
+                    decoded.kzgCommitment[0],
+                    decoded.kzgCommitment[1],
+                )?,
+            };
+            Ok(verify_hybrid(&vk, &proof, &decoded.input, setup)?.abi_encode())
+        }
+
+        _ => Err(InvalidSelectorError::from_input(input).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hybrid_verifier::verifier::tests::fixture;
+    use alloy_primitives::U256;
+
+    fn call(input: U256) -> (IHybridVerifier::hybridVerifyCall, HybridVerifierPrecompile) {
+        let (vk, proof, setup) = fixture(input);
+        let mut vk_words = vec![vk.alpha_g1.x, vk.alpha_g1.y];
+        for g2 in [vk.beta_g2, vk.gamma_g2, vk.delta_g2] {
+            vk_words.extend(g2.x.iter().chain(&g2.y));
+        }
+        vk_words.extend(vk.ic.iter().flat_map(|p| [p.x, p.y]));
+
+        let (eval, opening) = proof.kzg_opening;
+        let call = IHybridVerifier::hybridVerifyCall {
+            a: [proof.groth16.a.x, proof.groth16.a.y],
+            b: [proof.groth16.b.x, proof.groth16.b.y],
+            c: [proof.groth16.c.x, proof.groth16.c.y],
+            input: vec![input],
+            vk: vk_words,
+            kzgCommitment: [proof.kzg_commitment.x, proof.kzg_commitment.y],
+            kzgEval: eval.into_u256(),
+            kzgProof: [opening.x, opening.y],
+        };
+        (call, HybridVerifierPrecompile::new(setup))
+    }
+
+    #[test]
+    fn test_precompile_interface() {
+        let (call, precompile) = call(U256::from(42));
+        let output = precompile.call(&call.abi_encode()).unwrap();
+        assert!(
+            IHybridVerifier::hybridVerifyCall::abi_decode_returns(&output, true).unwrap().valid
+        );
+        assert_eq!(precompile.gas_cost(&call.abi_encode()), GasCosts::hybrid_verify(1));
+
+        let tampered = IHybridVerifier::hybridVerifyCall {
+            kzgEval: call.kzgEval + U256::from(1),
+            ..call.clone()
+        };
+        let output = precompile.call(&tampered.abi_encode()).unwrap();
+        assert!(
+            !IHybridVerifier::hybridVerifyCall::abi_decode_returns(&output, true).unwrap().valid
+        );
+
+        let off_curve = IHybridVerifier::hybridVerifyCall { kzgProof: [U256::from(1); 2], ..call };
+        assert!(matches!(
+            precompile.dispatch(&off_curve.abi_encode()),
+            Err(PrecompileError::Curve(_))
+        ));
+        assert!(matches!(precompile.dispatch(&[0u8; 8]), Err(PrecompileError::Selector(_))));
+    }
+}
@@ -0,0 +1,17 @@
+//! Groth16 proofs carrying a KZG polynomial opening
+//!
+//! Some proof systems prove the main relation with Groth16 and keep auxiliary
+//! lookup tables in a KZG-committed polynomial. A hybrid proof is accepted
+//! when the Groth16 proof verifies for the public inputs and the KZG opening
+//! shows the committed polynomial takes the claimed value at
+//!
+//! `z = keccak256(C.x || C.y || x_1 || … || x_l) mod r`.
+//!
+//! Deriving `z` by Fiat-Shamir binds the opening to the commitment and to the
+//! Groth16 statement, so the prover cannot choose the evaluation point.
+
+pub mod interface;
+pub mod verifier;
+
+pub use interface::{hybrid_verify_precompile, HybridVerifierPrecompile, IHybridVerifier};
+pub use verifier::{challenge_point, verify_hybrid, HybridProof};
@@ -0,0 +1,112 @@
+//! Hybrid Groth16 and KZG verification
+
+use crate::curves::BN254G1Point;
+use crate::errors::PrecompileError;
+use crate::field::Bn254Field;
+use crate::groth16::{groth16_verify, Groth16Proof, VerifyingKey};
+use crate::kzg::{kzg_verify, KZGParams};
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::{keccak256, U256};
+
+/// Groth16 proof together with a KZG commitment and its opening
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HybridProof {
+    pub groth16: Groth16Proof,
+    /// Claimed evaluation at the challenge point and the opening proof `π`
+    pub kzg_opening: (Bn254Field, BN254G1Point),
+    pub kzg_commitment: BN254G1Point,
+}
+
+/// Fiat-Shamir evaluation point for a commitment and the Groth16 public inputs
+pub fn challenge_point(commitment: &BN254G1Point, public_inputs: &[U256]) -> U256 {
+    let mut transcript = Vec::with_capacity(32 * (2 + public_inputs.len()));
+    for word in [commitment.x, commitment.y].iter().chain(public_inputs) {
+        transcript.extend_from_slice(&word.to_be_bytes::<32>());
+    }
+    U256::from_be_bytes(keccak256(&transcript).0) % BN254_MODULUS
+}
+
+/// Verifies the Groth16 proof, then the KZG opening at the challenge point
+pub fn verify_hybrid(
+    vk: &VerifyingKey,
+    proof: &HybridProof,
+    public_inputs: &[U256],
+    setup: &KZGParams,
+) -> Result<bool, PrecompileError> {
+    if !groth16_verify(vk, &proof.groth16, public_inputs)? {
+        return Ok(false);
+    }
+
+    let (eval, opening) = proof.kzg_opening;
+    let z = challenge_point(&proof.kzg_commitment, public_inputs);
+    Ok(kzg_verify(proof.kzg_commitment, z, eval.into_u256(), opening, setup)?)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::curves::BN254G2Point;
+    use crate::kzg::commitment::tests::{open, test_params};
+    use crate::kzg::kzg_commit;
+
+    fn g1(scalar: U256) -> BN254G1Point {
+        BN254G1Point::scalar_mul(&BN254G1Point::GENERATOR, scalar).unwrap()
+    }
+
+    /// Verifying key with known discrete logs `α = 5·G1`, `β = γ = δ = G2`,
+    /// `IC = [7·G1, 11·G1]` and a hybrid proof for `input`
+    ///
+    /// The Groth16 equation holds for `A = 3·G1`, `B = G2` and
+    /// `C = (3 - 5 - 7 - 11·input)·G1`.
+    pub(crate) fn fixture(input: U256) -> (VerifyingKey, HybridProof, KZGParams) {
+        let g2 = BN254G2Point::GENERATOR;
+        let vk = VerifyingKey {
+            alpha_g1: g1(U256::from(5)),
+            beta_g2: g2,
+            gamma_g2: g2,
+            delta_g2: g2,
+            ic: vec![g1(U256::from(7)), g1(U256::from(11))],
+        };
+        let c = [U256::from(5), U256::from(7), U256::from(11).mul_mod(input, BN254_MODULUS)]
+            .iter()
+            .fold(U256::from(3), |acc, k| acc.add_mod(BN254_MODULUS - k, BN254_MODULUS));
+        let groth16 = Groth16Proof { a: g1(U256::from(3)), b: g2, c: g1(c) };
+
+        let setup = test_params(424242, 3);
+        let f = [9u64, 2, 0, 4].map(U256::from);
+        let commitment = kzg_commit(&f, &setup.g1).unwrap();
+        let (eval, quotient) = open(&f, challenge_point(&commitment, &[input]));
+        let opening = kzg_commit(&quotient, &setup.g1).unwrap();
+
+        let proof = HybridProof {
+            groth16,
+            kzg_opening: (Bn254Field::from_u256(eval), opening),
+            kzg_commitment: commitment,
+        };
+        (vk, proof, setup)
+    }
+
+    #[test]
+    fn test_valid_hybrid_proof() {
+        let input = U256::from(42);
+        let (vk, proof, setup) = fixture(input);
+        assert!(verify_hybrid(&vk, &proof, &[input], &setup).unwrap());
+    }
+
+    #[test]
+    fn test_tampered_proofs_rejected() {
+        let input = U256::from(42);
+        let (vk, proof, setup) = fixture(input);
+
+        let (eval, opening) = proof.kzg_opening;
+        let tampered = HybridProof { kzg_opening: (eval + Bn254Field::ONE, opening), ..proof };
+        assert!(!verify_hybrid(&vk, &tampered, &[input], &setup).unwrap());
+
+        let tampered = HybridProof { kzg_opening: (eval, opening.neg()), ..proof };
+        assert!(!verify_hybrid(&vk, &tampered, &[input], &setup).unwrap());
+
+        let groth16 = Groth16Proof { c: g1(U256::from(2)), ..proof.groth16 };
+        let tampered = HybridProof { groth16, ..proof };
+        assert!(!verify_hybrid(&vk, &tampered, &[input], &setup).unwrap());
+    }
+}
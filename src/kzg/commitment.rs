@@ -0,0 +1,124 @@
+//! KZG commitments and opening proofs
+//!
+//! A polynomial `f` is committed as `C = Σ f_i · τ^i·G1`. A proof that
+//! `f(z) = y` is `π = q(τ)·G1` for the quotient `q(X) = (f(X) - y) / (X - z)`,
+//! and it is checked with
+//!
+//! `e(C - y·G1, G2) = e(π, τ·G2 - z·G2)`.
+//!
+//! Moving `z·π` to the left keeps the G2 side fixed, so verification runs as
+//! the single multi-pairing `e(C - y·G1 + z·π, G2) · e(-π, τ·G2) = 1`.
+
+use super::setup::KZGParams;
+use crate::curves::{bn254_pairing_check, BN254G1Point};
+use crate::errors::KZGError;
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::U256;
+
+/// Commits to the polynomial with the given coefficients, lowest degree first
+pub fn kzg_commit(coefficients: &[U256], srs_g1: &[BN254G1Point]) -> Result<BN254G1Point, KZGError> {
+    if coefficients.len() > srs_g1.len() {
+        return Err(KZGError::SetupTooSmall {
+            coefficients: coefficients.len(),
+            available: srs_g1.len(),
+        });
+    }
+
+    let mut commitment = BN254G1Point::INFINITY;
+    for (coefficient, power) in coefficients.iter().zip(srs_g1) {
+        let term = BN254G1Point::scalar_mul(power, field_element(*coefficient)?)?;
+        commitment = BN254G1Point::add(&commitment, &term)?;
+    }
+    Ok(commitment)
+}
+
+/// Checks an opening proof that the committed polynomial evaluates to `eval` at `point`
+pub fn kzg_verify(
+    commitment: BN254G1Point,
+    point: U256,
+    eval: U256,
+    proof: BN254G1Point,
+    srs: &KZGParams,
+) -> Result<bool, KZGError> {
+    let (point, eval) = (field_element(point)?, field_element(eval)?);
+
+    let y_g1 = BN254G1Point::scalar_mul(&BN254G1Point::GENERATOR, eval)?;
+    let z_proof = BN254G1Point::scalar_mul(&proof, point)?;
+    let lhs = BN254G1Point::add(&BN254G1Point::add(&commitment, &y_g1.neg())?, &z_proof)?;
+
+    Ok(bn254_pairing_check(&[(lhs, srs.g2[0]), (proof.neg(), srs.g2[1])])?)
+}
+
+fn field_element(value: U256) -> Result<U256, KZGError> {
+    if value >= BN254_MODULUS {
+        return Err(KZGError::FieldElementTooLarge(value));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::curves::BN254G2Point;
+
+    /// Insecure setup with a known `τ`, for tests only
+    pub(crate) fn test_params(tau: u64, degree: usize) -> KZGParams {
+        let tau = U256::from(tau);
+        let mut power = U256::from(1);
+        let g1 = (0..=degree)
+            .map(|_| {
+                let point = BN254G1Point::scalar_mul(&BN254G1Point::GENERATOR, power).unwrap();
+                power = power.mul_mod(tau, BN254_MODULUS);
+                point
+            })
+            .collect();
+        let g2 = BN254G2Point::GENERATOR;
+        KZGParams { g1, g2: [g2, BN254G2Point::scalar_mul(&g2, tau).unwrap()] }
+    }
+
+    /// Evaluates `f` at `z` and divides out `(X - z)` by synthetic division
+    pub(crate) fn open(coefficients: &[U256], z: U256) -> (U256, Vec<U256>) {
+        let mut quotient = vec![U256::ZERO; coefficients.len() - 1];
+        let mut acc = U256::ZERO;
+        for (i, c) in coefficients.iter().enumerate().rev() {
+            acc = acc.mul_mod(z, BN254_MODULUS).add_mod(*c, BN254_MODULUS);
+            if i > 0 {
+                quotient[i - 1] = acc;
+            }
+        }
+        (acc, quotient)
+    }
+
+    #[test]
+    fn test_commit_and_verify() {
+        let params = test_params(1234567, 3);
+        let f = [3u64, 0, 7, 1].map(U256::from);
+        let commitment = kzg_commit(&f, &params.g1).unwrap();
+
+        let z = U256::from(5);
+        let (y, quotient) = open(&f, z);
+        assert_eq!(y, U256::from(3 + 7 * 25 + 125));
+        let proof = kzg_commit(&quotient, &params.g1).unwrap();
+
+        assert!(kzg_verify(commitment, z, y, proof, &params).unwrap());
+        assert!(!kzg_verify(commitment, z, y + U256::from(1), proof, &params).unwrap());
+        assert!(!kzg_verify(commitment, z + U256::from(1), y, proof, &params).unwrap());
+    }
+
+    #[test]
+    fn test_rejects_bad_inputs() {
+        let params = test_params(2, 1);
+        let f = [1u64, 2, 3].map(U256::from);
+        assert!(matches!(
+            kzg_commit(&f, &params.g1),
+            Err(KZGError::SetupTooSmall { coefficients: 3, available: 2 })
+        ));
+        assert!(matches!(
+            kzg_commit(&[BN254_MODULUS], &params.g1),
+            Err(KZGError::FieldElementTooLarge(_))
+        ));
+
+        let g = BN254G1Point::GENERATOR;
+        assert!(kzg_verify(g, BN254_MODULUS, U256::ZERO, g, &params).is_err());
+    }
+}
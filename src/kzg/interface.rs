@@ -0,0 +1,119 @@
+//! KZG precompile interface and ABI definitions
+
+use super::commitment::kzg_verify;
+use super::setup::{decode_g1, KZGParams};
+use crate::errors::{InvalidSelectorError, PrecompileError};
+use crate::gas::GasCosts;
+use crate::poseidon::interface::decode_call;
+use crate::traits::GenericPrecompile;
+use alloy_sol_types::{sol, SolCall, SolValue};
+
+// Solidity interface definition
+sol! {
+    #[sol(abi)]
+    interface IKZG {
+        /// Verifies a KZG opening proof that the committed polynomial evaluates to y at z
+        /// @param commitment The 64-byte uncompressed G1 commitment
+        /// @param z The evaluation point
+        /// @param y The claimed evaluation
+        /// @param proof The 64-byte uncompressed G1 opening proof
+        /// @return valid Whether the proof is accepted
+        function kzgVerify(bytes commitment, uint256 z, uint256 y, bytes proof) external view returns (bool valid);
+    }
+}
+
+/// KZG precompile verifying against a fixed trusted setup
+#[derive(Debug, Clone)]
+pub struct KzgPrecompile {
+    params: KZGParams,
+}
+
+impl KzgPrecompile {
+    /// Selectors of every `IKZG` function
+    pub const SELECTORS: [[u8; 4]; 1] = [IKZG::kzgVerifyCall::SELECTOR];
+
+    pub fn new(params: KZGParams) -> Self {
+        Self { params }
+    }
+
+    /// Decodes the call and runs the requested check
+    pub fn dispatch(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        if input.len() < 4 {
+            return Err(InvalidSelectorError::from_input(input).into());
+        }
+
+        match &input[0..4] {
+            // kzgVerify(bytes,uint256,uint256,bytes)
+            s if s == IKZG::kzgVerifyCall::SELECTOR => {
+                let decoded = decode_call::<IKZG::kzgVerifyCall>(input)?;
+
+                let commitment = decode_g1(&decoded.commitment)?;
+                let proof = decode_g1(&decoded.proof)?;
+                Ok(kzg_verify(commitment, decoded.z, decoded.y, proof, &self.params)?.abi_encode())
+            }
+
+            _ => Err(InvalidSelectorError::from_input(input).into()),
+        }
+    }
+}
+
+impl GenericPrecompile for KzgPrecompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        self.dispatch(input)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, _input: &[u8]) -> u64 {
+        GasCosts::KZG_VERIFY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::BN254G1Point;
+    use crate::errors::KZGError;
+    use crate::kzg::commitment::tests::{open, test_params};
+    use crate::kzg::kzg_commit;
+    use alloy_primitives::U256;
+
+    fn encode(p: &BN254G1Point) -> Vec<u8> {
+        [p.x.to_be_bytes::<32>(), p.y.to_be_bytes::<32>()].concat()
+    }
+
+    #[test]
+    fn test_precompile_interface() {
+        let params = test_params(987654321, 2);
+        let f = [4u64, 5, 6].map(U256::from);
+        let commitment = kzg_commit(&f, &params.g1).unwrap();
+        let z = U256::from(11);
+        let (y, quotient) = open(&f, z);
+        let proof = kzg_commit(&quotient, &params.g1).unwrap();
+        let precompile = KzgPrecompile::new(params);
+
+        let call = IKZG::kzgVerifyCall {
+            commitment: encode(&commitment).into(),
+            z,
+            y,
+            proof: encode(&proof).into(),
+        };
+        let output = precompile.call(&call.abi_encode()).unwrap();
+        assert!(IKZG::kzgVerifyCall::abi_decode_returns(&output, true).unwrap().valid);
+
+        let call = IKZG::kzgVerifyCall { y: y + U256::from(1), ..call };
+        let output = precompile.call(&call.abi_encode()).unwrap();
+        assert!(!IKZG::kzgVerifyCall::abi_decode_returns(&output, true).unwrap().valid);
+
+        let call = IKZG::kzgVerifyCall { proof: vec![0u8; 63].into(), ..call };
+        assert!(matches!(
+            precompile.dispatch(&call.abi_encode()),
+            Err(PrecompileError::KZG(KZGError::InvalidPointEncoding { expected: 64, actual: 63 }))
+        ));
+        let truncated = &call.abi_encode()[..40];
+        assert!(matches!(precompile.dispatch(truncated), Err(PrecompileError::Abi(_))));
+        assert!(matches!(precompile.dispatch(&[0u8; 8]), Err(PrecompileError::Selector(_))));
+    }
+}
@@ -0,0 +1,9 @@
+//! KZG polynomial commitments over BN254
+
+pub mod commitment;
+pub mod interface;
+pub mod setup;
+
+pub use commitment::{kzg_commit, kzg_verify};
+pub use interface::{IKZG, KzgPrecompile};
+pub use setup::KZGParams;
@@ -0,0 +1,146 @@
+//! KZG trusted setup parameters
+//!
+//! The text layout follows the Ethereum KZG ceremony's `trusted_setup.txt`:
+//! the number of G1 points, the number of G2 points, then one hex-encoded
+//! point per line, G1 points first. The ceremony itself is over BLS12-381,
+//! so points here use the BN254 encodings of the `0x06`-`0x08` precompiles:
+//! 64 bytes `x || y` for G1 and 128 bytes `x_im || x_re || y_im || y_re` for
+//! G2, all zeros being the point at infinity.
+
+use crate::curves::{BN254G1Point, BN254G2Point};
+use crate::errors::KZGError;
+use alloy_primitives::U256;
+
+/// Bytes in an uncompressed G1 point
+pub const G1_POINT_BYTES: usize = 64;
+
+/// Bytes in an uncompressed G2 point
+pub const G2_POINT_BYTES: usize = 128;
+
+/// Powers of the secret `τ` in both groups
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KZGParams {
+    /// `τ^i · G1` for `i = 0..n`
+    pub g1: Vec<BN254G1Point>,
+    /// `G2` and `τ · G2`
+    pub g2: [BN254G2Point; 2],
+}
+
+impl KZGParams {
+    /// Parses a setup in the ceremony's text layout
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, KZGError> {
+        let text = core::str::from_utf8(bytes)
+            .map_err(|_| KZGError::InvalidSetup("setup is not UTF-8".into()))?;
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+
+        let mut count = |what: &str| -> Result<usize, KZGError> {
+            lines
+                .next()
+                .and_then(|line| line.parse().ok())
+                .ok_or_else(|| KZGError::InvalidSetup(format!("missing {what} point count")))
+        };
+        let (g1_count, g2_count) = (count("G1")?, count("G2")?);
+        if g1_count == 0 || g2_count < 2 {
+            return Err(KZGError::InvalidSetup(format!(
+                "need at least one G1 and two G2 points, got {g1_count} and {g2_count}"
+            )));
+        }
+
+        let g1 = (0..g1_count)
+            .map(|_| decode_g1(&next_hex(&mut lines)?))
+            .collect::<Result<Vec<_>, _>>()?;
+        let g2 = (0..g2_count)
+            .map(|_| decode_g2(&next_hex(&mut lines)?))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { g1, g2: [g2[0], g2[1]] })
+    }
+}
+
+/// Decodes a 64-byte `x || y` G1 point, checking it is on the curve
+pub fn decode_g1(bytes: &[u8]) -> Result<BN254G1Point, KZGError> {
+    if bytes.len() != G1_POINT_BYTES {
+        return Err(KZGError::InvalidPointEncoding { expected: G1_POINT_BYTES, actual: bytes.len() });
+    }
+
+    let words = words(bytes);
+    Ok(BN254G1Point::new(words[0], words[1])?)
+}
+
+/// Decodes a 128-byte G2 point with imaginary parts first
+pub fn decode_g2(bytes: &[u8]) -> Result<BN254G2Point, KZGError> {
+    if bytes.len() != G2_POINT_BYTES {
+        return Err(KZGError::InvalidPointEncoding { expected: G2_POINT_BYTES, actual: bytes.len() });
+    }
+
+    let words = words(bytes);
+    let point = BN254G2Point { x: [words[0], words[1]], y: [words[2], words[3]] };
+
+    // Multiplying by one runs the curve and subgroup checks
+    Ok(BN254G2Point::scalar_mul(&point, U256::from(1))?)
+}
+
+fn words(bytes: &[u8]) -> Vec<U256> {
+    bytes.chunks(32).map(|word| U256::from_be_slice(word)).collect()
+}
+
+fn next_hex<'a>(lines: &mut impl Iterator<Item = &'a str>) -> Result<Vec<u8>, KZGError> {
+    let line = lines
+        .next()
+        .ok_or_else(|| KZGError::InvalidSetup("fewer points than declared".into()))?;
+    hex::decode(line.strip_prefix("0x").unwrap_or(line))
+        .map_err(|e| KZGError::InvalidSetup(format!("invalid hex point: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_g1(p: &BN254G1Point) -> String {
+        hex::encode([p.x.to_be_bytes::<32>(), p.y.to_be_bytes::<32>()].concat())
+    }
+
+    fn encode_g2(p: &BN254G2Point) -> String {
+        let words = [p.x[0], p.x[1], p.y[0], p.y[1]];
+        hex::encode(words.iter().flat_map(|w| w.to_be_bytes::<32>()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_parse_setup() {
+        let g1 = BN254G1Point::GENERATOR;
+        let g2 = BN254G2Point::GENERATOR;
+        let two_g1 = BN254G1Point::scalar_mul(&g1, U256::from(2)).unwrap();
+        let two_g2 = BN254G2Point::scalar_mul(&g2, U256::from(2)).unwrap();
+
+        let text = format!(
+            "2\n2\n{}\n{}\n{}\n{}\n",
+            encode_g1(&g1),
+            encode_g1(&two_g1),
+            encode_g2(&g2),
+            encode_g2(&two_g2)
+        );
+        let params = KZGParams::from_bytes(text.as_bytes()).unwrap();
+        assert_eq!(params.g1, vec![g1, two_g1]);
+        assert_eq!(params.g2, [g2, two_g2]);
+    }
+
+    #[test]
+    fn test_rejects_malformed_setup() {
+        let g1 = encode_g1(&BN254G1Point::GENERATOR);
+        let g2 = encode_g2(&BN254G2Point::GENERATOR);
+
+        assert!(KZGParams::from_bytes(b"").is_err());
+        assert!(KZGParams::from_bytes(format!("1\n1\n{g1}\n{g2}\n").as_bytes()).is_err());
+        assert!(KZGParams::from_bytes(format!("1\n2\n{g1}\n{g2}\n").as_bytes()).is_err());
+        assert!(matches!(
+            KZGParams::from_bytes(format!("1\n2\n{g2}\n{g2}\n{g2}\n").as_bytes()),
+            Err(KZGError::InvalidPointEncoding { expected: 64, actual: 128 })
+        ));
+
+        let off_curve = hex::encode([U256::from(1).to_be_bytes::<32>(); 2].concat());
+        assert!(matches!(
+            KZGParams::from_bytes(format!("1\n2\n{off_curve}\n{g2}\n{g2}\n").as_bytes()),
+            Err(KZGError::Curve(_))
+        ));
+    }
+}
@@ -5,26 +5,96 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+// Lets the derive macros refer to `::precompile` from inside this crate
+extern crate self as precompile;
+
 // Re-export common types and traits
 pub use alloy_primitives::{Address, Bytes, U256};
 pub use alloy_sol_types::{sol, SolCall, SolValue};
+pub use precompile_derive::{precompile_test_suite, SolStorageLayout, SolStruct};
+pub use constants::{BLS12_381_SCALAR_MODULUS, BN254_MODULUS};
 
 // Core modules
+pub mod bn254;
+pub mod bridge;
+pub mod commitment;
+pub mod constants;
 pub mod errors;
+pub mod events;
+pub mod field;
+pub mod gas;
+pub mod middleware;
+pub mod nullifier;
+pub mod primitives;
+pub mod profiling;
+pub mod storage;
+pub mod traits;
 pub mod utils;
 
 // Precompile implementations
+pub mod babyjubjub;
+pub mod curves;
+pub mod groth16;
+pub mod hybrid_verifier;
+pub mod kzg;
+pub mod merkle;
+pub mod mimc;
+pub mod op_stack;
+pub mod pedersen;
+pub mod plonk;
 pub mod poseidon;
+pub mod rescue;
+pub mod rollup;
+pub mod semaphore;
+pub mod zk_email;
 
 // Re-export precompile interfaces for convenience
 pub use poseidon::{
     poseidon_precompile, PoseidonHash, IPoseidonHash, POSEIDON_ROUND_CONSTANTS
 };
+pub use curves::{bn254_msm, msm_precompile, MsmPrecompile, IBN254MSM};
+pub use rescue::{rescue_precompile, IRescueHash, RescueHash};
+
+// circom input encoding (only available with circom feature)
+#[cfg(feature = "circom")]
+pub mod circom;
+
+// R1CS generation for circom circuits (only available with circom feature)
+#[cfg(feature = "circom")]
+pub mod circuit_builder;
+
+// Witness, proof and verification stages over circom circuits (only available with circom feature)
+#[cfg(feature = "circom")]
+pub mod pipeline;
+
+// Selector-based routing across precompiles
+#[cfg(feature = "std")]
+pub mod router;
+
+// Test helpers for code that calls the precompiles
+#[cfg(feature = "std")]
+pub mod testing;
+
+// JavaScript bindings (only available with wasm-bindgen feature)
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
+
+// JSON ABI artifacts (only available with export-abi or cli feature)
+#[cfg(any(feature = "export-abi", feature = "cli"))]
+pub mod abi_export;
 
 // CLI module (only available with cli feature)
 #[cfg(feature = "cli")]
 pub mod cli;
 
+// Stylus.toml project configuration (only available with cli feature)
+#[cfg(feature = "cli")]
+pub mod config;
+
+// Deployment tracking over JSON-RPC (only available with cli feature)
+#[cfg(feature = "cli")]
+pub mod deployment;
+
 // Export the entrypoint for Stylus deployment
 #[cfg(feature = "stylus")]
 pub mod entrypoint;
@@ -35,4 +105,13 @@ pub mod prelude {
     pub use crate::utils::*;
     pub use alloy_primitives::{Address, Bytes, U256};
     pub use alloy_sol_types::{sol, SolCall, SolValue};
-}
\ No newline at end of file
+
+    /// Whether `value` is a canonical element of the BN254 scalar field
+    ///
+    /// Checks `value < BN254_MODULUS`, the order
+    /// `21888242871839275222246405745257275088548364400416034343698204186575808495617`
+    /// of the BN254 (alt_bn128) G1 group and the field of the Poseidon precompiles.
+    pub fn is_valid_bn254_field_element(value: U256) -> bool {
+        value < BN254_MODULUS
+    }
+}
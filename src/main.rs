@@ -6,9 +6,17 @@ use precompile::cli;
 fn main() {
     #[cfg(feature = "cli")]
     {
-        if let Err(e) = cli::run() {
-            eprintln!("Error: {}", e);
-            std::process::exit(1);
+        match cli::run() {
+            Ok(output) => {
+                print!("{}", output.render());
+                if output.is_error() {
+                    std::process::exit(1);
+                }
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
         }
     }
     
@@ -0,0 +1,345 @@
+//! Append-only Merkle tree for deposit-style contracts
+//!
+//! Follows the incremental algorithm used by Tornado Cash style mixers:
+//! empty slots hold zero and an empty subtree at each level is the pair hash
+//! of two empty subtrees below it, so the root of `n` leaves equals the
+//! `MerkleTree` root of those leaves zero-padded to `2^depth`. Inserting a
+//! leaf only touches one node per level, using the cached "filled subtree"
+//! (the latest left child) at every level.
+
+use super::tree::MerkleProof;
+use crate::errors::{MerkleError, PoseidonError};
+use crate::poseidon::PoseidonHash;
+use crate::traits::StatefulHasher;
+use alloy_primitives::U256;
+use core::marker::PhantomData;
+
+/// Largest supported depth
+pub const MAX_INCREMENTAL_DEPTH: usize = 32;
+
+/// Leaves a tree of `depth` holds, `None` when that count overflows `usize`
+///
+/// A depth-32 tree overflows on 32-bit targets such as wasm32.
+fn capacity(depth: usize) -> Option<usize> {
+    u32::try_from(depth).ok().and_then(|depth| 1usize.checked_shl(depth))
+}
+
+/// Append-only Merkle tree of fixed depth
+#[derive(Debug)]
+pub struct IncrementalMerkleTree<H: StatefulHasher + Default = PoseidonHash> {
+    depth: usize,
+    /// Empty subtree hash per level, leaves first and root last
+    zeros: Vec<U256>,
+    /// Latest left child at each level
+    filled_subtrees: Vec<U256>,
+    /// Nodes per level from the first leaf this tree inserted
+    levels: Vec<Vec<U256>>,
+    /// Filled subtrees when the tree was restored from a frontier
+    restored_subtrees: Vec<U256>,
+    /// Index of the first leaf this tree inserted
+    first_index: usize,
+    next_index: usize,
+    root: U256,
+    _hasher: PhantomData<H>,
+}
+
+// Manual impl so cloning does not require `H: Clone`
+impl<H: StatefulHasher + Default> Clone for IncrementalMerkleTree<H> {
+    fn clone(&self) -> Self {
+        Self {
+            depth: self.depth,
+            zeros: self.zeros.clone(),
+            filled_subtrees: self.filled_subtrees.clone(),
+            levels: self.levels.clone(),
+            restored_subtrees: self.restored_subtrees.clone(),
+            first_index: self.first_index,
+            next_index: self.next_index,
+            root: self.root,
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl<H: StatefulHasher + Default> IncrementalMerkleTree<H> {
+    /// Creates an empty tree with room for `2^depth` leaves
+    pub fn new(depth: usize) -> Result<Self, MerkleError> {
+        Self::from_frontier(depth, 0, None)
+    }
+
+    /// Restores a tree from the frontier a contract keeps in storage
+    ///
+    /// Only leaves inserted after restoring have paths, and `root` is only
+    /// meaningful once at least one leaf has been inserted.
+    pub(crate) fn from_frontier(
+        depth: usize,
+        next_index: usize,
+        filled_subtrees: Option<Vec<U256>>,
+    ) -> Result<Self, MerkleError> {
+        if depth == 0 || depth > MAX_INCREMENTAL_DEPTH {
+            return Err(MerkleError::InvalidDepth(depth));
+        }
+        if let Some(capacity) = capacity(depth).filter(|&capacity| next_index > capacity) {
+            return Err(MerkleError::TreeFull(capacity));
+        }
+
+        let mut zeros = Vec::with_capacity(depth + 1);
+        zeros.push(U256::ZERO);
+        for level in 0..depth {
+            zeros.push(Self::hash_pair(zeros[level], zeros[level])?);
+        }
+
+        let filled_subtrees = match filled_subtrees {
+            Some(filled) if filled.len() != depth => {
+                return Err(MerkleError::MalformedProof(format!(
+                    "{} filled subtrees for depth {}",
+                    filled.len(),
+                    depth
+                )));
+            }
+            Some(filled) => filled,
+            None => zeros[..depth].to_vec(),
+        };
+
+        Ok(Self {
+            depth,
+            root: zeros[depth],
+            zeros,
+            restored_subtrees: filled_subtrees.clone(),
+            filled_subtrees,
+            levels: vec![Vec::new(); depth],
+            first_index: next_index,
+            next_index,
+            _hasher: PhantomData,
+        })
+    }
+
+    /// Hashes two children into their parent with a fresh hasher
+    pub(crate) fn hash_pair(left: U256, right: U256) -> Result<U256, PoseidonError> {
+        let mut hasher = H::default();
+        hasher.update(left)?;
+        hasher.update(right)?;
+        hasher.finalize()
+    }
+
+    /// Returns the tree depth
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the current Merkle root
+    pub fn root(&self) -> U256 {
+        self.root
+    }
+
+    /// Returns the index the next leaf is inserted at
+    pub fn next_index(&self) -> usize {
+        self.next_index
+    }
+
+    /// Returns the cached latest left child at each level
+    pub fn filled_subtrees(&self) -> &[U256] {
+        &self.filled_subtrees
+    }
+
+    /// Returns the empty subtree hash per level, leaves first and root last
+    pub(crate) fn zeros(&self) -> &[U256] {
+        &self.zeros
+    }
+
+    /// Returns the leaf at `index`, if this tree inserted it
+    pub(crate) fn leaf(&self, index: usize) -> Option<U256> {
+        index.checked_sub(self.first_index).and_then(|offset| self.levels[0].get(offset).copied())
+    }
+
+    /// Appends a leaf, returning the new root and the leaf index
+    pub fn insert(&mut self, leaf: U256) -> Result<(U256, usize), MerkleError> {
+        // Index `usize::MAX` stays free, as the next index would not fit
+        if capacity(self.depth) == Some(self.next_index) || self.next_index == usize::MAX {
+            return Err(MerkleError::TreeFull(self.next_index));
+        }
+
+        // Hash the whole path before touching the tree
+        let leaf_index = self.next_index;
+        let mut path = Vec::with_capacity(self.depth);
+        let mut node = leaf;
+        let mut index = leaf_index;
+        for level in 0..self.depth {
+            path.push(node);
+            node = if index % 2 == 0 {
+                Self::hash_pair(node, self.zeros[level])?
+            } else {
+                Self::hash_pair(self.filled_subtrees[level], node)?
+            };
+            index /= 2;
+        }
+
+        let mut index = leaf_index;
+        for (level, node) in path.into_iter().enumerate() {
+            if index % 2 == 0 {
+                self.filled_subtrees[level] = node;
+            }
+            let offset = index - (self.first_index >> level);
+            let nodes = &mut self.levels[level];
+            if offset == nodes.len() {
+                nodes.push(node);
+            } else {
+                nodes[offset] = node;
+            }
+            index /= 2;
+        }
+
+        self.root = node;
+        self.next_index += 1;
+        Ok((self.root, leaf_index))
+    }
+
+    /// Appends all leaves, or none of them if any insertion fails
+    pub fn insert_batch(&mut self, leaves: &[U256]) -> Result<U256, MerkleError> {
+        if leaves.is_empty() {
+            return Err(MerkleError::EmptyBatch);
+        }
+
+        let mut next = self.clone();
+        for &leaf in leaves {
+            next.insert(leaf)?;
+        }
+        *self = next;
+        Ok(self.root)
+    }
+
+    /// Returns the inclusion proof of the leaf at `index` against the current root
+    pub fn path_for_leaf(&self, index: usize) -> Result<MerkleProof, MerkleError> {
+        if index >= self.next_index {
+            return Err(MerkleError::LeafIndexOutOfRange {
+                index,
+                leaf_count: self.next_index,
+            });
+        }
+        if index < self.first_index {
+            return Err(MerkleError::PathUnavailable(index));
+        }
+
+        let mut proof = MerkleProof {
+            siblings: Vec::with_capacity(self.depth),
+            path_indices: Vec::with_capacity(self.depth),
+        };
+        let mut node = index;
+        for level in 0..self.depth {
+            let sibling = node ^ 1;
+            let first = self.first_index >> level;
+            let sibling_hash = if sibling < first {
+                self.restored_subtrees[level]
+            } else {
+                self.levels[level]
+                    .get(sibling - first)
+                    .copied()
+                    .unwrap_or(self.zeros[level])
+            };
+            proof.siblings.push(sibling_hash);
+            proof.path_indices.push(node % 2 == 1);
+            node /= 2;
+        }
+
+        Ok(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle::MerkleTree;
+
+    type Tree = IncrementalMerkleTree<PoseidonHash>;
+
+    fn sample_leaves(count: usize) -> Vec<U256> {
+        (1..=count).map(|leaf| U256::from(leaf * 11)).collect()
+    }
+
+    /// Root of the leaves zero-padded to a depth-3 tree
+    fn padded_root(leaves: &[U256]) -> U256 {
+        let mut padded = leaves.to_vec();
+        padded.resize(8, U256::ZERO);
+        MerkleTree::<PoseidonHash>::new(padded).unwrap().root()
+    }
+
+    #[test]
+    fn test_root_matches_dense_tree() {
+        let mut tree = Tree::new(3).unwrap();
+        assert_eq!(tree.root(), padded_root(&[]));
+
+        let leaves = sample_leaves(5);
+        for (expected_index, &leaf) in leaves.iter().enumerate() {
+            let (root, index) = tree.insert(leaf).unwrap();
+            assert_eq!(index, expected_index);
+            assert_eq!(root, padded_root(&leaves[..=index]));
+        }
+    }
+
+    #[test]
+    fn test_paths_verify_against_current_root() {
+        let mut tree = Tree::new(3).unwrap();
+        let leaves = sample_leaves(6);
+        tree.insert_batch(&leaves).unwrap();
+
+        for (index, &leaf) in leaves.iter().enumerate() {
+            let proof = tree.path_for_leaf(index).unwrap();
+            assert!(MerkleTree::<PoseidonHash>::verify_proof(tree.root(), leaf, &proof));
+        }
+        assert!(tree.path_for_leaf(6).is_err());
+    }
+
+    #[test]
+    fn test_restored_frontier_continues_tree() {
+        let leaves = sample_leaves(7);
+        let mut full = Tree::new(3).unwrap();
+        full.insert_batch(&leaves).unwrap();
+
+        let mut prefix = Tree::new(3).unwrap();
+        prefix.insert_batch(&leaves[..3]).unwrap();
+        let mut restored =
+            Tree::from_frontier(3, 3, Some(prefix.filled_subtrees().to_vec())).unwrap();
+        restored.insert_batch(&leaves[3..]).unwrap();
+        assert_eq!(restored.root(), full.root());
+
+        let proof = restored.path_for_leaf(3).unwrap();
+        assert!(MerkleTree::<PoseidonHash>::verify_proof(restored.root(), leaves[3], &proof));
+        assert!(matches!(restored.path_for_leaf(2), Err(MerkleError::PathUnavailable(2))));
+    }
+
+    #[test]
+    fn test_batch_is_atomic() {
+        let mut tree = Tree::new(2).unwrap();
+        tree.insert(U256::from(1)).unwrap();
+        let root = tree.root();
+
+        // The batch overflows the tree, so nothing is inserted
+        assert!(matches!(tree.insert_batch(&sample_leaves(4)), Err(MerkleError::TreeFull(4))));
+        assert_eq!(tree.root(), root);
+        assert_eq!(tree.next_index(), 1);
+
+        assert!(matches!(tree.insert_batch(&[]), Err(MerkleError::EmptyBatch)));
+        assert!(matches!(Tree::new(33), Err(MerkleError::InvalidDepth(33))));
+    }
+
+    #[test]
+    fn test_depth_32() {
+        let mut tree = Tree::new(MAX_INCREMENTAL_DEPTH).unwrap();
+        let leaves = sample_leaves(3);
+        tree.insert_batch(&leaves).unwrap();
+        assert_eq!(tree.next_index(), 3);
+
+        let proof = tree.path_for_leaf(2).unwrap();
+        assert_eq!(proof.siblings.len(), 32);
+        assert!(MerkleTree::<PoseidonHash>::verify_proof(tree.root(), leaves[2], &proof));
+
+        // A frontier far past 2^31 leaves continues, however wide usize is
+        let next_index: usize = 3 << 30;
+        let frontier = Some(tree.filled_subtrees().to_vec());
+        let mut restored = Tree::from_frontier(32, next_index, frontier).unwrap();
+        assert_eq!(restored.insert(U256::from(1)).unwrap().1, next_index);
+
+        // On 32-bit targets a depth-32 tree holds more leaves than usize counts
+        assert_eq!(capacity(32), 1usize.checked_shl(32));
+        assert_eq!(capacity(usize::BITS as usize), None);
+    }
+}
@@ -0,0 +1,204 @@
+//! Solidity interface for Poseidon Merkle trees
+
+use super::incremental::IncrementalMerkleTree;
+use super::tree::{MerkleProof, MerkleTree};
+use crate::errors::{MerkleError, PrecompileError};
+use crate::poseidon::interface::decode_call;
+use crate::poseidon::PoseidonHash;
+use crate::traits::{calldata_gas_estimate, GenericPrecompile};
+use alloy_primitives::U256;
+use alloy_sol_types::{sol, SolCall, SolValue};
+
+// Solidity interface definition
+sol! {
+    #[sol(abi)]
+    interface IPoseidonMerkle {
+        /// Computes the Poseidon Merkle root of the leaves, zero-padded to a power of two
+        /// @param leaves The tree leaves
+        /// @return root The Merkle root
+        function merkleRoot(uint256[] leaves) external pure returns (uint256 root);
+
+        /// Verifies a Poseidon Merkle proof for a leaf
+        /// @param root The expected Merkle root
+        /// @param leaf The leaf value
+        /// @param proof The sibling hashes from the leaf up to the root
+        /// @param indices 1 where the path node is the right child, 0 otherwise
+        /// @return valid Whether the proof reconstructs the root
+        function verifyProof(uint256 root, uint256 leaf, uint256[] proof, uint256[] indices) external pure returns (bool valid);
+
+        /// Appends leaves to an incremental tree kept in contract storage, all or none
+        /// @param depth The tree depth
+        /// @param nextIndex The index of the next leaf
+        /// @param filledSubtrees The latest left child at each level
+        /// @param leaves The leaves to append
+        /// @return root The root after the insertion
+        /// @return newNextIndex The index of the next leaf after the insertion
+        /// @return newFilledSubtrees The filled subtrees after the insertion
+        function batchInsert(uint8 depth, uint256 nextIndex, uint256[] filledSubtrees, uint256[] leaves) external pure returns (uint256 root, uint256 newNextIndex, uint256[] newFilledSubtrees);
+    }
+}
+
+/// Estimated gas of a Merkle call before argument words
+const MERKLE_BASE_GAS: u64 = 2000;
+
+/// Estimated gas per 32-byte argument word, about one Poseidon pair hash
+const MERKLE_WORD_GAS: u64 = 300;
+
+/// Poseidon Merkle precompile behind the `IPoseidonMerkle` selectors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PoseidonMerklePrecompile;
+
+impl PoseidonMerklePrecompile {
+    /// Selectors of every `IPoseidonMerkle` function
+    pub const SELECTORS: [[u8; 4]; 3] = [
+        IPoseidonMerkle::merkleRootCall::SELECTOR,
+        IPoseidonMerkle::verifyProofCall::SELECTOR,
+        IPoseidonMerkle::batchInsertCall::SELECTOR,
+    ];
+}
+
+impl GenericPrecompile for PoseidonMerklePrecompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        Ok(poseidon_merkle_precompile(input)?)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, input: &[u8]) -> u64 {
+        calldata_gas_estimate(input, MERKLE_BASE_GAS, MERKLE_WORD_GAS)
+    }
+}
+
+/// Precompile entry point - handles the raw call interface
+pub fn poseidon_merkle_precompile(input: &[u8]) -> Result<Vec<u8>, MerkleError> {
+    if input.len() < 4 {
+        return Err(MerkleError::InvalidSelector);
+    }
+
+    match &input[0..4] {
+        // merkleRoot(uint256[])
+        s if s == IPoseidonMerkle::merkleRootCall::SELECTOR => {
+            let decoded = decode_call::<IPoseidonMerkle::merkleRootCall>(input)?;
+
+            let tree: MerkleTree<PoseidonHash> = MerkleTree::new(decoded.leaves)?;
+            Ok(tree.root().abi_encode())
+        }
+
+        // verifyProof(uint256,uint256,uint256[],uint256[])
+        s if s == IPoseidonMerkle::verifyProofCall::SELECTOR => {
+            let decoded = decode_call::<IPoseidonMerkle::verifyProofCall>(input)?;
+
+            let proof = MerkleProof::from_parts(decoded.proof, &decoded.indices)?;
+            let valid = MerkleTree::<PoseidonHash>::verify_proof(decoded.root, decoded.leaf, &proof);
+            Ok(valid.abi_encode())
+        }
+
+        // batchInsert(uint8,uint256,uint256[],uint256[])
+        s if s == IPoseidonMerkle::batchInsertCall::SELECTOR => {
+            let decoded = decode_call::<IPoseidonMerkle::batchInsertCall>(input)?;
+
+            let next_index = usize::try_from(decoded.nextIndex)
+                .map_err(|_| MerkleError::TreeFull(decoded.nextIndex.saturating_to()))?;
+            let mut tree: IncrementalMerkleTree<PoseidonHash> = IncrementalMerkleTree::from_frontier(
+                decoded.depth as usize,
+                next_index,
+                Some(decoded.filledSubtrees),
+            )?;
+            let root = tree.insert_batch(&decoded.leaves)?;
+
+            Ok((root, U256::from(tree.next_index()), tree.filled_subtrees().to_vec()).abi_encode_params())
+        }
+
+        _ => Err(MerkleError::InvalidSelector),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precompile_interface() {
+        let leaves: Vec<U256> = (1..=5u64).map(U256::from).collect();
+        let tree: MerkleTree = MerkleTree::new(leaves.clone()).unwrap();
+
+        let call = IPoseidonMerkle::merkleRootCall { leaves: leaves.clone() }.abi_encode();
+        let output = poseidon_merkle_precompile(&call).unwrap();
+        assert_eq!(U256::abi_decode(&output, true).unwrap(), tree.root());
+
+        let proof = tree.generate_proof(4).unwrap();
+        let call = IPoseidonMerkle::verifyProofCall {
+            root: tree.root(),
+            leaf: leaves[4],
+            proof: proof.siblings.clone(),
+            indices: proof.indices(),
+        }
+        .abi_encode();
+        let output = poseidon_merkle_precompile(&call).unwrap();
+        assert!(bool::abi_decode(&output, true).unwrap());
+    }
+
+    #[test]
+    fn test_verify_encoded_proof() {
+        let leaves: Vec<U256> = (1..=8u64).map(U256::from).collect();
+        let tree: MerkleTree = MerkleTree::new(leaves.clone()).unwrap();
+        let proof = MerkleProof::abi_decode(&tree.generate_proof(3).unwrap().abi_encode()).unwrap();
+
+        let call = IPoseidonMerkle::verifyProofCall {
+            root: tree.root(),
+            leaf: leaves[3],
+            proof: proof.siblings.clone(),
+            indices: proof.indices(),
+        };
+        // The proof encoding is the tail of the verifyProof arguments
+        assert!(call.abi_encode().ends_with(&proof.abi_encode()[64..]));
+
+        let output = poseidon_merkle_precompile(&call.abi_encode()).unwrap();
+        assert!(bool::abi_decode(&output, true).unwrap());
+    }
+
+    #[test]
+    fn test_batch_insert_interface() {
+        let leaves: Vec<U256> = (1..=6u64).map(U256::from).collect();
+        for depth in [4, 32] {
+            let mut expected: IncrementalMerkleTree = IncrementalMerkleTree::new(depth).unwrap();
+            expected.insert_batch(&leaves).unwrap();
+
+            let mut prefix: IncrementalMerkleTree = IncrementalMerkleTree::new(depth).unwrap();
+            prefix.insert_batch(&leaves[..2]).unwrap();
+            let call = IPoseidonMerkle::batchInsertCall {
+                depth: depth as u8,
+                nextIndex: U256::from(2),
+                filledSubtrees: prefix.filled_subtrees().to_vec(),
+                leaves: leaves[2..].to_vec(),
+            }
+            .abi_encode();
+
+            let output = poseidon_merkle_precompile(&call).unwrap();
+            let decoded =
+                IPoseidonMerkle::batchInsertCall::abi_decode_returns(&output, true).unwrap();
+            assert_eq!(decoded.root, expected.root(), "depth {}", depth);
+            assert_eq!(decoded.newNextIndex, U256::from(6));
+            assert_eq!(decoded.newFilledSubtrees, expected.filled_subtrees());
+        }
+    }
+
+    #[test]
+    fn test_precompile_errors() {
+        let call = IPoseidonMerkle::merkleRootCall { leaves: vec![] }.abi_encode();
+        assert!(matches!(poseidon_merkle_precompile(&call), Err(MerkleError::EmptyTree)));
+
+        let call = IPoseidonMerkle::verifyProofCall {
+            root: U256::ZERO,
+            leaf: U256::ZERO,
+            proof: vec![U256::ZERO],
+            indices: vec![],
+        }
+        .abi_encode();
+        assert!(matches!(poseidon_merkle_precompile(&call), Err(MerkleError::MalformedProof(_))));
+
+        assert!(poseidon_merkle_precompile(&[0xde, 0xad, 0xbe, 0xef]).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+//! Keccak256 Merkle proofs compatible with Ethereum contracts
+//!
+//! Bridges receive Merkle proofs built on Ethereum, where trees hash with
+//! keccak256. Leaves are hashed as `keccak256(0x00 || data)` so a leaf can
+//! never be mistaken for an internal node, and internal nodes use
+//! OpenZeppelin's commutative pair hash `keccak256(min(a, b) || max(a, b))`,
+//! so proofs carry no left/right flags.
+
+use alloy_primitives::keccak256;
+use alloy_sol_types::{sol, SolCall, SolValue};
+
+use crate::errors::{PoseidonError, PrecompileError};
+use crate::poseidon::interface::decode_call;
+use crate::traits::{calldata_gas_estimate, GenericPrecompile};
+
+// Solidity interface definition
+sol! {
+    #[sol(abi)]
+    interface IKeccakMerkle {
+        /// Verifies a keccak256 Merkle proof for a leaf
+        /// @param root The expected Merkle root
+        /// @param leaf The raw leaf data
+        /// @param proof The sibling hashes from the leaf up to the root
+        /// @return valid Whether the proof reconstructs the root
+        function keccakMerkleVerify(bytes32 root, bytes leaf, bytes32[] proof) external pure returns (bool valid);
+    }
+}
+
+/// Hashes a leaf as keccak256(0x00 || data)
+pub fn keccak_leaf_hash(data: &[u8]) -> [u8; 32] {
+    let mut prefixed = Vec::with_capacity(data.len() + 1);
+    prefixed.push(0x00);
+    prefixed.extend_from_slice(data);
+    keccak256(prefixed).0
+}
+
+/// Hashes two nodes in sorted order, as OpenZeppelin's `Hashes.commutativeKeccak256`
+pub fn keccak_node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if left <= right { (left, right) } else { (right, left) };
+
+    let mut concatenated = [0u8; 64];
+    concatenated[..32].copy_from_slice(first);
+    concatenated[32..].copy_from_slice(second);
+    keccak256(concatenated).0
+}
+
+/// Checks that `proof` links the hash of `leaf` to `root`
+pub fn verify_keccak_merkle_proof(root: [u8; 32], leaf: &[u8], proof: &[[u8; 32]]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(keccak_leaf_hash(leaf), |node, sibling| keccak_node_hash(&node, sibling));
+    computed == root
+}
+
+/// Estimated gas of a keccak Merkle call before argument words
+const KECCAK_MERKLE_BASE_GAS: u64 = 500;
+
+/// Estimated gas per 32-byte argument word, about one keccak256 of 64 bytes
+const KECCAK_MERKLE_WORD_GAS: u64 = 50;
+
+/// Keccak Merkle precompile behind the `IKeccakMerkle` selectors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KeccakMerklePrecompile;
+
+impl KeccakMerklePrecompile {
+    /// Selectors of every `IKeccakMerkle` function
+    pub const SELECTORS: [[u8; 4]; 1] = [IKeccakMerkle::keccakMerkleVerifyCall::SELECTOR];
+}
+
+impl GenericPrecompile for KeccakMerklePrecompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        Ok(keccak_merkle_precompile(input)?)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, input: &[u8]) -> u64 {
+        calldata_gas_estimate(input, KECCAK_MERKLE_BASE_GAS, KECCAK_MERKLE_WORD_GAS)
+    }
+}
+
+/// Precompile entry point - handles the raw call interface
+pub fn keccak_merkle_precompile(input: &[u8]) -> Result<Vec<u8>, PoseidonError> {
+    if input.len() < 4 {
+        return Err(PoseidonError::InvalidSelector);
+    }
+
+    match &input[0..4] {
+        // keccakMerkleVerify(bytes32,bytes,bytes32[])
+        s if s == IKeccakMerkle::keccakMerkleVerifyCall::SELECTOR => {
+            let decoded = decode_call::<IKeccakMerkle::keccakMerkleVerifyCall>(input)?;
+
+            let proof: Vec<[u8; 32]> = decoded.proof.iter().map(|node| node.0).collect();
+            Ok(verify_keccak_merkle_proof(decoded.root.0, &decoded.leaf, &proof).abi_encode())
+        }
+
+        _ => Err(PoseidonError::InvalidSelector),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Bytes, B256};
+
+    /// Builds a four-leaf tree and returns the root and each leaf's proof
+    fn four_leaf_tree(leaves: &[&[u8]; 4]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+        let hashed: Vec<[u8; 32]> = leaves.iter().map(|leaf| keccak_leaf_hash(leaf)).collect();
+        let left = keccak_node_hash(&hashed[0], &hashed[1]);
+        let right = keccak_node_hash(&hashed[2], &hashed[3]);
+        let root = keccak_node_hash(&left, &right);
+
+        let proofs = vec![
+            vec![hashed[1], right],
+            vec![hashed[0], right],
+            vec![hashed[3], left],
+            vec![hashed[2], left],
+        ];
+        (root, proofs)
+    }
+
+    const LEAVES: [&[u8]; 4] = [b"alice", b"bob", b"carol", b"dave"];
+
+    #[test]
+    fn test_valid_proofs() {
+        let (root, proofs) = four_leaf_tree(&LEAVES);
+        for (leaf, proof) in LEAVES.iter().zip(&proofs) {
+            assert!(verify_keccak_merkle_proof(root, leaf, proof));
+        }
+    }
+
+    #[test]
+    fn test_invalid_proofs() {
+        let (root, proofs) = four_leaf_tree(&LEAVES);
+
+        // Wrong leaf, wrong proof and truncated proof
+        assert!(!verify_keccak_merkle_proof(root, b"mallory", &proofs[0]));
+        assert!(!verify_keccak_merkle_proof(root, LEAVES[0], &proofs[1]));
+        assert!(!verify_keccak_merkle_proof(root, LEAVES[0], &proofs[0][..1]));
+
+        // An internal node cannot be passed off as a leaf
+        let internal = keccak_node_hash(&keccak_leaf_hash(LEAVES[0]), &keccak_leaf_hash(LEAVES[1]));
+        assert!(!verify_keccak_merkle_proof(root, &internal, &proofs[2][1..]));
+    }
+
+    #[test]
+    fn test_node_hash_matches_openzeppelin() {
+        let a = [0x11u8; 32];
+        let b = [0x22u8; 32];
+
+        // Commutative: keccak256(abi.encodePacked(min, max))
+        assert_eq!(keccak_node_hash(&a, &b), keccak_node_hash(&b, &a));
+        assert_eq!(keccak_node_hash(&b, &a), keccak256([a, b].concat()).0);
+    }
+
+    #[test]
+    fn test_precompile_interface() {
+        let (root, proofs) = four_leaf_tree(&LEAVES);
+        let call = IKeccakMerkle::keccakMerkleVerifyCall {
+            root: B256::from(root),
+            leaf: Bytes::from_static(LEAVES[2]),
+            proof: proofs[2].iter().map(|node| B256::from(*node)).collect(),
+        }
+        .abi_encode();
+
+        let output = keccak_merkle_precompile(&call).unwrap();
+        assert!(bool::abi_decode(&output, true).unwrap());
+    }
+}
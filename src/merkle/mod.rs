@@ -0,0 +1,18 @@
+//! Merkle tree construction over Poseidon hashes
+
+pub mod incremental;
+pub mod interface;
+pub mod keccak_compat;
+pub mod parallel_build;
+pub mod sparse;
+pub mod tree;
+
+pub use incremental::{IncrementalMerkleTree, MAX_INCREMENTAL_DEPTH};
+pub use interface::{poseidon_merkle_precompile, IPoseidonMerkle, PoseidonMerklePrecompile};
+pub use keccak_compat::{
+    keccak_leaf_hash, keccak_merkle_precompile, keccak_node_hash, verify_keccak_merkle_proof,
+    IKeccakMerkle, KeccakMerklePrecompile,
+};
+pub use parallel_build::{build_tree_parallel, build_tree_serial, ParallelMerkleTree};
+pub use sparse::{SparseMerkleProof, SparseMerkleTree, MAX_SPARSE_DEPTH};
+pub use tree::{MerkleProof, MerkleTree};
@@ -0,0 +1,158 @@
+//! Level-parallel Merkle tree construction
+//!
+//! All sibling pairs within a level are independent, so each level can be
+//! hashed in parallel before moving up to the next one. With the `parallel`
+//! feature enabled the pairs are hashed on the rayon thread pool; otherwise
+//! the build falls back to the serial path.
+//!
+//! Leaves are padded with zeros to the next power of two and the full node
+//! array is returned level by level, starting with the (padded) leaves and
+//! ending with the root.
+
+use crate::errors::PoseidonError;
+use crate::poseidon::PoseidonHash;
+use alloy_primitives::U256;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Pads the leaves with zeros up to the next power of two
+fn padded_leaves(leaves: &[U256]) -> Result<Vec<U256>, PoseidonError> {
+    if leaves.is_empty() {
+        return Err(PoseidonError::InvalidInputLength(0));
+    }
+
+    let mut padded = leaves.to_vec();
+    padded.resize(leaves.len().next_power_of_two(), U256::ZERO);
+    Ok(padded)
+}
+
+/// Hashes one level into its parent level, one sibling pair at a time
+#[allow(deprecated)]
+fn hash_level_serial(level: &[U256], hasher: &PoseidonHash) -> Result<Vec<U256>, PoseidonError> {
+    level
+        .chunks(2)
+        .map(|pair| hasher.hash_pair(pair[0], pair[1]))
+        .collect()
+}
+
+/// Hashes one level into its parent level with all sibling pairs in parallel
+#[cfg(feature = "parallel")]
+#[allow(deprecated)]
+fn hash_level_parallel(level: &[U256], hasher: &PoseidonHash) -> Result<Vec<U256>, PoseidonError> {
+    level
+        .par_chunks(2)
+        .map(|pair| hasher.hash_pair(pair[0], pair[1]))
+        .collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn hash_level_parallel(level: &[U256], hasher: &PoseidonHash) -> Result<Vec<U256>, PoseidonError> {
+    hash_level_serial(level, hasher)
+}
+
+/// Builds the tree bottom-up with the given level hashing strategy
+fn build_tree_with(
+    leaves: &[U256],
+    hasher: &PoseidonHash,
+    hash_level: fn(&[U256], &PoseidonHash) -> Result<Vec<U256>, PoseidonError>,
+) -> Result<Vec<U256>, PoseidonError> {
+    let mut level = padded_leaves(leaves)?;
+    let mut nodes = Vec::with_capacity(level.len() * 2 - 1);
+
+    while level.len() > 1 {
+        let parent = hash_level(&level, hasher)?;
+        nodes.extend_from_slice(&level);
+        level = parent;
+    }
+    nodes.extend_from_slice(&level);
+
+    Ok(nodes)
+}
+
+/// Builds the full node array, hashing each level in parallel
+pub fn build_tree_parallel(leaves: &[U256], hasher: &PoseidonHash) -> Result<Vec<U256>, PoseidonError> {
+    build_tree_with(leaves, hasher, hash_level_parallel)
+}
+
+/// Builds the full node array serially, for comparison with the parallel build
+pub fn build_tree_serial(leaves: &[U256], hasher: &PoseidonHash) -> Result<Vec<U256>, PoseidonError> {
+    build_tree_with(leaves, hasher, hash_level_serial)
+}
+
+/// Merkle tree built with the level-parallel strategy
+pub struct ParallelMerkleTree {
+    nodes: Vec<U256>,
+    leaf_count: usize,
+}
+
+impl ParallelMerkleTree {
+    /// Builds the tree from its leaves
+    pub fn from_leaves_parallel(leaves: Vec<U256>) -> Result<Self, PoseidonError> {
+        let nodes = build_tree_parallel(&leaves, &PoseidonHash::new())?;
+        Ok(Self {
+            nodes,
+            leaf_count: leaves.len(),
+        })
+    }
+
+    /// Returns the Merkle root
+    pub fn root(&self) -> U256 {
+        self.nodes[self.nodes.len() - 1]
+    }
+
+    /// Returns the number of leaves the tree was built from, before padding
+    pub fn leaf_count(&self) -> usize {
+        self.leaf_count
+    }
+
+    /// Returns the full node array, leaves first and root last
+    pub fn nodes(&self) -> &[U256] {
+        &self.nodes
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+
+    fn sample_leaves(count: usize) -> Vec<U256> {
+        (1..=count).map(U256::from).collect()
+    }
+
+    #[test]
+    fn test_parallel_root_matches_serial_root() {
+        let hasher = PoseidonHash::new();
+
+        for count in [1, 2, 3, 7, 16, 33] {
+            let leaves = sample_leaves(count);
+            let parallel = build_tree_parallel(&leaves, &hasher).unwrap();
+            let serial = build_tree_serial(&leaves, &hasher).unwrap();
+            assert_eq!(parallel, serial, "{} leaves", count);
+        }
+    }
+
+    #[test]
+    fn test_root_matches_manual_construction() {
+        let hasher = PoseidonHash::new();
+        let leaves = sample_leaves(4);
+
+        let left = hasher.hash_pair(leaves[0], leaves[1]).unwrap();
+        let right = hasher.hash_pair(leaves[2], leaves[3]).unwrap();
+        let expected = hasher.hash_pair(left, right).unwrap();
+
+        let tree = ParallelMerkleTree::from_leaves_parallel(leaves).unwrap();
+        assert_eq!(tree.root(), expected);
+        assert_eq!(tree.nodes().len(), 7);
+    }
+
+    #[test]
+    fn test_padding_and_errors() {
+        let tree = ParallelMerkleTree::from_leaves_parallel(sample_leaves(5)).unwrap();
+        assert_eq!(tree.leaf_count(), 5);
+        assert_eq!(tree.nodes().len(), 15);
+
+        assert!(ParallelMerkleTree::from_leaves_parallel(vec![]).is_err());
+    }
+}
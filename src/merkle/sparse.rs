@@ -0,0 +1,286 @@
+//! Sparse Merkle tree keyed by field elements
+//!
+//! A tree of depth `d` has a slot for every key below `2^d`; key bit `i`
+//! selects the side at level `i`, counting up from the leaves. An empty slot
+//! holds `H::hash_single(0)` and an empty subtree at each higher level is the
+//! pair hash of two empty subtrees below it, so only populated paths are
+//! stored. A populated slot holds the hash of `(key, value)`, binding the
+//! value to its key.
+
+use crate::errors::{MerkleError, PoseidonError};
+use crate::poseidon::PoseidonHash;
+use crate::traits::StatefulHasher;
+use alloy_primitives::U256;
+use core::marker::PhantomData;
+use std::collections::HashMap;
+
+/// Largest supported depth, covering the full `U256` key space
+pub const MAX_SPARSE_DEPTH: usize = 256;
+
+/// Membership or non-membership proof for one key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseMerkleProof {
+    /// Key the proof is about
+    pub key: U256,
+    /// Stored value for membership proofs, `None` for non-membership proofs
+    pub value: Option<U256>,
+    /// Sibling hashes from the leaf level up to just below the root
+    pub siblings: Vec<U256>,
+}
+
+/// Sparse Merkle tree storing only the non-empty nodes
+#[derive(Debug, Clone)]
+pub struct SparseMerkleTree<H: StatefulHasher + Default = PoseidonHash> {
+    depth: usize,
+    /// Empty subtree hash per level, leaves first and root last
+    empty: Vec<U256>,
+    /// Non-empty nodes keyed by (level, index within the level)
+    nodes: HashMap<(usize, U256), U256>,
+    values: HashMap<U256, U256>,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: StatefulHasher + Default> SparseMerkleTree<H> {
+    /// Creates an empty tree with `2^depth` slots
+    pub fn new(depth: usize) -> Result<Self, MerkleError> {
+        if depth == 0 || depth > MAX_SPARSE_DEPTH {
+            return Err(MerkleError::InvalidDepth(depth));
+        }
+
+        Ok(Self {
+            depth,
+            empty: Self::empty_hashes(depth)?,
+            nodes: HashMap::new(),
+            values: HashMap::new(),
+            _hasher: PhantomData,
+        })
+    }
+
+    /// Chains the empty leaf hash up to the empty root
+    fn empty_hashes(depth: usize) -> Result<Vec<U256>, PoseidonError> {
+        let mut empty = Vec::with_capacity(depth + 1);
+        empty.push(Self::hash(&[U256::ZERO])?);
+        for level in 0..depth {
+            empty.push(Self::hash(&[empty[level], empty[level]])?);
+        }
+        Ok(empty)
+    }
+
+    /// Hashes the inputs with a fresh hasher
+    fn hash(inputs: &[U256]) -> Result<U256, PoseidonError> {
+        let mut hasher = H::default();
+        for &input in inputs {
+            hasher.update(input)?;
+        }
+        hasher.finalize()
+    }
+
+    /// Returns the tree depth
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the number of populated keys
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns whether no keys are populated
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the Merkle root
+    pub fn root(&self) -> U256 {
+        self.node(self.depth, U256::ZERO)
+    }
+
+    /// Returns the value stored under `key`
+    pub fn get(&self, key: U256) -> Option<U256> {
+        self.values.get(&key).copied()
+    }
+
+    /// Stores `value` under `key`, replacing any previous value
+    pub fn insert(&mut self, key: U256, value: U256) -> Result<(), MerkleError> {
+        self.check_key(key)?;
+
+        let mut node = Self::hash(&[key, value])?;
+        let mut index = key;
+        for level in 0..self.depth {
+            self.nodes.insert((level, index), node);
+            let sibling = self.node(level, index ^ U256::from(1));
+            node = if index.bit(0) {
+                Self::hash(&[sibling, node])?
+            } else {
+                Self::hash(&[node, sibling])?
+            };
+            index >>= 1;
+        }
+        self.nodes.insert((self.depth, U256::ZERO), node);
+        self.values.insert(key, value);
+
+        Ok(())
+    }
+
+    /// Proves that `key` is populated
+    pub fn prove_membership(&self, key: U256) -> Result<SparseMerkleProof, MerkleError> {
+        self.check_key(key)?;
+        let value = self.get(key).ok_or(MerkleError::KeyNotFound(key))?;
+        Ok(self.proof(key, Some(value)))
+    }
+
+    /// Proves that `key` is empty
+    pub fn prove_non_membership(&self, key: U256) -> Result<SparseMerkleProof, MerkleError> {
+        self.check_key(key)?;
+        if self.values.contains_key(&key) {
+            return Err(MerkleError::KeyPresent(key));
+        }
+        Ok(self.proof(key, None))
+    }
+
+    /// Checks that `proof` links its key and value, or the empty leaf, to `root`
+    pub fn verify_proof(root: U256, proof: &SparseMerkleProof) -> bool {
+        let depth = proof.siblings.len();
+        if depth == 0 || depth > MAX_SPARSE_DEPTH || (depth < 256 && proof.key >> depth != U256::ZERO) {
+            return false;
+        }
+
+        let leaf = match proof.value {
+            Some(value) => Self::hash(&[proof.key, value]),
+            None => Self::hash(&[U256::ZERO]),
+        };
+        let Ok(leaf) = leaf else {
+            return false;
+        };
+
+        let computed = proof
+            .siblings
+            .iter()
+            .enumerate()
+            .try_fold(leaf, |node, (level, &sibling)| {
+                if proof.key.bit(level) {
+                    Self::hash(&[sibling, node])
+                } else {
+                    Self::hash(&[node, sibling])
+                }
+            });
+
+        matches!(computed, Ok(node) if node == root)
+    }
+
+    /// Collects the sibling path of `key`
+    fn proof(&self, key: U256, value: Option<U256>) -> SparseMerkleProof {
+        let siblings = (0..self.depth)
+            .map(|level| self.node(level, (key >> level) ^ U256::from(1)))
+            .collect();
+        SparseMerkleProof { key, value, siblings }
+    }
+
+    /// Returns the stored node, or the empty subtree hash for its level
+    fn node(&self, level: usize, index: U256) -> U256 {
+        self.nodes.get(&(level, index)).copied().unwrap_or(self.empty[level])
+    }
+
+    /// Rejects keys with bits above the tree depth
+    fn check_key(&self, key: U256) -> Result<(), MerkleError> {
+        if self.depth < MAX_SPARSE_DEPTH && key >> self.depth != U256::ZERO {
+            return Err(MerkleError::KeyOutOfRange { key, depth: self.depth });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Tree = SparseMerkleTree<PoseidonHash>;
+
+    #[test]
+    fn test_empty_root_chains_empty_leaf() {
+        let hasher = PoseidonHash::new();
+        let leaf = hasher.hash_array_production(&[U256::ZERO]).unwrap();
+        let level1 = hasher.hash_pair_production(leaf, leaf).unwrap();
+        let root = hasher.hash_pair_production(level1, level1).unwrap();
+
+        let tree = Tree::new(2).unwrap();
+        assert_eq!(tree.root(), root);
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut tree = Tree::new(8).unwrap();
+        let empty_root = tree.root();
+
+        tree.insert(U256::from(5), U256::from(50)).unwrap();
+        tree.insert(U256::from(200), U256::from(7)).unwrap();
+        assert_eq!(tree.get(U256::from(5)), Some(U256::from(50)));
+        assert_eq!(tree.get(U256::from(6)), None);
+        assert_eq!(tree.len(), 2);
+        assert_ne!(tree.root(), empty_root);
+
+        // Insertion order does not matter
+        let mut reordered = Tree::new(8).unwrap();
+        reordered.insert(U256::from(200), U256::from(7)).unwrap();
+        reordered.insert(U256::from(5), U256::from(50)).unwrap();
+        assert_eq!(reordered.root(), tree.root());
+    }
+
+    #[test]
+    fn test_membership_proofs() {
+        let mut tree = Tree::new(16).unwrap();
+        for key in [1u64, 2, 1000, 65535] {
+            tree.insert(U256::from(key), U256::from(key * 3)).unwrap();
+        }
+
+        let proof = tree.prove_membership(U256::from(1000)).unwrap();
+        assert_eq!(proof.siblings.len(), 16);
+        assert!(Tree::verify_proof(tree.root(), &proof));
+
+        let forged = SparseMerkleProof { value: Some(U256::from(1)), ..proof.clone() };
+        assert!(!Tree::verify_proof(tree.root(), &forged));
+        let absent = SparseMerkleProof { value: None, ..proof };
+        assert!(!Tree::verify_proof(tree.root(), &absent));
+    }
+
+    #[test]
+    fn test_non_membership_proofs() {
+        let mut tree = Tree::new(16).unwrap();
+        tree.insert(U256::from(4), U256::from(1)).unwrap();
+
+        let proof = tree.prove_non_membership(U256::from(5)).unwrap();
+        assert_eq!(proof.value, None);
+        assert!(Tree::verify_proof(tree.root(), &proof));
+
+        // The proof goes stale once the key is populated
+        tree.insert(U256::from(5), U256::from(1)).unwrap();
+        assert!(!Tree::verify_proof(tree.root(), &proof));
+        assert!(matches!(tree.prove_non_membership(U256::from(5)), Err(MerkleError::KeyPresent(_))));
+        assert!(matches!(tree.prove_membership(U256::from(6)), Err(MerkleError::KeyNotFound(_))));
+    }
+
+    #[test]
+    fn test_full_depth_tree() {
+        let mut tree = Tree::new(MAX_SPARSE_DEPTH).unwrap();
+        let key = U256::from(1) << 250;
+        tree.insert(key, U256::from(9)).unwrap();
+
+        let proof = tree.prove_membership(key).unwrap();
+        assert!(Tree::verify_proof(tree.root(), &proof));
+        let proof = tree.prove_non_membership(U256::from(3)).unwrap();
+        assert!(Tree::verify_proof(tree.root(), &proof));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(Tree::new(0), Err(MerkleError::InvalidDepth(0))));
+        assert!(matches!(Tree::new(257), Err(MerkleError::InvalidDepth(257))));
+
+        let mut tree = Tree::new(4).unwrap();
+        assert!(matches!(
+            tree.insert(U256::from(16), U256::ZERO),
+            Err(MerkleError::KeyOutOfRange { depth: 4, .. })
+        ));
+    }
+}
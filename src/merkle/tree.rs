@@ -0,0 +1,330 @@
+//! Binary Merkle tree over any `StatefulHasher`
+//!
+//! Leaves are padded with zeros to the next power of two, matching
+//! `ParallelMerkleTree`. Each internal node is the hash of its two children,
+//! fed to a fresh hasher left child first. Proofs list the sibling at every
+//! level from the leaf up, together with the side the path node sits on.
+
+use crate::errors::{MerkleError, PoseidonError};
+use crate::gas::{GasCosts, GasMeter};
+use crate::poseidon::interface::decode_params;
+use crate::poseidon::PoseidonHash;
+use crate::traits::StatefulHasher;
+use alloy_primitives::U256;
+use alloy_sol_types::SolValue;
+use core::marker::PhantomData;
+
+/// Inclusion proof for one leaf
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// Sibling hashes from the leaf level up to just below the root
+    pub siblings: Vec<U256>,
+    /// Whether the path node is the right child at each level
+    pub path_indices: Vec<bool>,
+}
+
+impl MerkleProof {
+    /// Returns the path indices as 0/1 words, as passed to `verifyProof`
+    pub fn indices(&self) -> Vec<U256> {
+        self.path_indices.iter().map(|&right| U256::from(right)).collect()
+    }
+
+    /// Builds a proof from the `proof` and `indices` arrays of `verifyProof`
+    pub fn from_parts(siblings: Vec<U256>, indices: &[U256]) -> Result<Self, MerkleError> {
+        if siblings.len() != indices.len() {
+            return Err(MerkleError::MalformedProof(format!(
+                "{} siblings but {} indices",
+                siblings.len(),
+                indices.len()
+            )));
+        }
+
+        let path_indices = indices
+            .iter()
+            .map(|&index| match index {
+                U256::ZERO => Ok(false),
+                index if index == U256::from(1) => Ok(true),
+                index => Err(MerkleError::MalformedProof(format!("path index {} is not 0 or 1", index))),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { siblings, path_indices })
+    }
+
+    /// Encodes the proof as the siblings followed by the path indices
+    pub fn to_vec(&self) -> Vec<U256> {
+        let mut words = self.siblings.clone();
+        words.extend(self.indices());
+        words
+    }
+
+    /// Decodes a proof written by `to_vec`
+    pub fn from_vec(words: &[U256]) -> Result<Self, MerkleError> {
+        if words.len() % 2 != 0 {
+            return Err(MerkleError::MalformedProof(format!("odd word count {}", words.len())));
+        }
+
+        let (siblings, indices) = words.split_at(words.len() / 2);
+        Self::from_parts(siblings.to_vec(), indices)
+    }
+
+    /// ABI-encodes the proof as `(uint256[] siblings, uint256[] pathIndices)`
+    ///
+    /// Path indices are 0 for a left and 1 for a right path node, the layout
+    /// of the `proof` and `indices` arguments of `verifyProof` and of the
+    /// Tornado Cash and Semaphore verifiers.
+    pub fn abi_encode(&self) -> Vec<u8> {
+        (self.siblings.clone(), self.indices()).abi_encode_params()
+    }
+
+    /// Decodes a proof written by `abi_encode`
+    pub fn abi_decode(data: &[u8]) -> Result<Self, MerkleError> {
+        let (siblings, indices) = decode_params::<(Vec<U256>, Vec<U256>)>(data)?;
+        Self::from_parts(siblings, &indices)
+    }
+}
+
+/// Merkle tree keeping every level, leaves first
+#[derive(Debug, Clone)]
+pub struct MerkleTree<H: StatefulHasher + Default = PoseidonHash> {
+    levels: Vec<Vec<U256>>,
+    leaf_count: usize,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: StatefulHasher + Default> MerkleTree<H> {
+    /// Builds the tree from its leaves
+    pub fn new(leaves: Vec<U256>) -> Result<Self, MerkleError> {
+        Self::build(leaves, None)
+    }
+
+    /// Builds the tree, charging `POSEIDON2_BASE` to the meter for every pair hashed
+    ///
+    /// Fails with `MerkleError::Gas` as soon as the budget runs out, without
+    /// returning a partial tree.
+    pub fn new_metered(leaves: Vec<U256>, meter: &mut GasMeter) -> Result<Self, MerkleError> {
+        Self::build(leaves, Some(meter))
+    }
+
+    fn build(leaves: Vec<U256>, mut meter: Option<&mut GasMeter>) -> Result<Self, MerkleError> {
+        if leaves.is_empty() {
+            return Err(MerkleError::EmptyTree);
+        }
+
+        let leaf_count = leaves.len();
+        let mut level = leaves;
+        level.resize(leaf_count.next_power_of_two(), U256::ZERO);
+
+        let mut levels = vec![level];
+        while levels[levels.len() - 1].len() > 1 {
+            let mut parent = Vec::with_capacity(levels[levels.len() - 1].len() / 2);
+            for pair in levels[levels.len() - 1].chunks(2) {
+                if let Some(meter) = meter.as_deref_mut() {
+                    meter.charge(GasCosts::POSEIDON2_BASE)?;
+                }
+                parent.push(Self::hash_pair(pair[0], pair[1])?);
+            }
+            levels.push(parent);
+        }
+
+        Ok(Self {
+            levels,
+            leaf_count,
+            _hasher: PhantomData,
+        })
+    }
+
+    /// Hashes two children into their parent with a fresh hasher
+    fn hash_pair(left: U256, right: U256) -> Result<U256, PoseidonError> {
+        let mut hasher = H::default();
+        hasher.update(left)?;
+        hasher.update(right)?;
+        hasher.finalize()
+    }
+
+    /// Returns the Merkle root
+    pub fn root(&self) -> U256 {
+        self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Returns the number of leaves the tree was built from, before padding
+    pub fn leaf_count(&self) -> usize {
+        self.leaf_count
+    }
+
+    /// Returns the number of levels between the leaves and the root
+    pub fn depth(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    /// Generates the inclusion proof of the leaf at `leaf_index`
+    pub fn generate_proof(&self, leaf_index: usize) -> Result<MerkleProof, MerkleError> {
+        if leaf_index >= self.leaf_count {
+            return Err(MerkleError::LeafIndexOutOfRange {
+                index: leaf_index,
+                leaf_count: self.leaf_count,
+            });
+        }
+
+        let mut index = leaf_index;
+        let mut proof = MerkleProof {
+            siblings: Vec::with_capacity(self.depth()),
+            path_indices: Vec::with_capacity(self.depth()),
+        };
+        for level in &self.levels[..self.depth()] {
+            proof.siblings.push(level[index ^ 1]);
+            proof.path_indices.push(index & 1 == 1);
+            index /= 2;
+        }
+
+        Ok(proof)
+    }
+
+    /// Checks that `proof` links `leaf` to `root`
+    pub fn verify_proof(root: U256, leaf: U256, proof: &MerkleProof) -> bool {
+        if proof.siblings.len() != proof.path_indices.len() {
+            return false;
+        }
+
+        let computed = proof
+            .siblings
+            .iter()
+            .zip(&proof.path_indices)
+            .try_fold(leaf, |node, (&sibling, &is_right)| {
+                if is_right {
+                    Self::hash_pair(sibling, node)
+                } else {
+                    Self::hash_pair(node, sibling)
+                }
+            });
+
+        matches!(computed, Ok(node) if node == root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_leaves(count: usize) -> Vec<U256> {
+        (1..=count).map(U256::from).collect()
+    }
+
+    #[test]
+    fn test_root_matches_manual_construction() {
+        let hasher = PoseidonHash::new();
+        let leaves = sample_leaves(4);
+
+        let left = hasher.hash_pair_production(leaves[0], leaves[1]).unwrap();
+        let right = hasher.hash_pair_production(leaves[2], leaves[3]).unwrap();
+        let expected = hasher.hash_pair_production(left, right).unwrap();
+
+        let tree: MerkleTree = MerkleTree::new(leaves).unwrap();
+        assert_eq!(tree.root(), expected);
+        assert_eq!(tree.depth(), 2);
+    }
+
+    #[test]
+    fn test_padding() {
+        let mut padded = sample_leaves(5);
+        padded.resize(8, U256::ZERO);
+
+        let tree: MerkleTree = MerkleTree::new(sample_leaves(5)).unwrap();
+        let full: MerkleTree = MerkleTree::new(padded).unwrap();
+        assert_eq!(tree.root(), full.root());
+        assert_eq!(tree.leaf_count(), 5);
+
+        let single: MerkleTree = MerkleTree::new(vec![U256::from(7)]).unwrap();
+        assert_eq!(single.root(), U256::from(7));
+        assert!(single.generate_proof(0).unwrap().siblings.is_empty());
+    }
+
+    #[test]
+    fn test_every_proof_verifies() {
+        let leaves = sample_leaves(6);
+        let tree: MerkleTree = MerkleTree::new(leaves.clone()).unwrap();
+
+        for (index, &leaf) in leaves.iter().enumerate() {
+            let proof = tree.generate_proof(index).unwrap();
+            assert_eq!(proof.siblings.len(), 3);
+            assert!(MerkleTree::<PoseidonHash>::verify_proof(tree.root(), leaf, &proof));
+        }
+    }
+
+    #[test]
+    fn test_invalid_proofs() {
+        let tree: MerkleTree = MerkleTree::new(sample_leaves(4)).unwrap();
+        let proof = tree.generate_proof(1).unwrap();
+        let verify = MerkleTree::<PoseidonHash>::verify_proof;
+
+        // Wrong leaf, flipped side and truncated proof
+        assert!(!verify(tree.root(), U256::from(99), &proof));
+        let mut flipped = proof.clone();
+        flipped.path_indices[0] = !flipped.path_indices[0];
+        assert!(!verify(tree.root(), U256::from(2), &flipped));
+        let truncated = MerkleProof {
+            siblings: proof.siblings[..1].to_vec(),
+            path_indices: proof.path_indices[..1].to_vec(),
+        };
+        assert!(!verify(tree.root(), U256::from(2), &truncated));
+    }
+
+    #[test]
+    fn test_proof_word_round_trip() {
+        let tree: MerkleTree = MerkleTree::new(sample_leaves(8)).unwrap();
+        let proof = tree.generate_proof(5).unwrap();
+
+        assert_eq!(MerkleProof::from_vec(&proof.to_vec()).unwrap(), proof);
+        assert!(MerkleProof::from_vec(&[U256::ZERO; 3]).is_err());
+        assert!(MerkleProof::from_parts(vec![U256::ZERO], &[U256::from(2)]).is_err());
+    }
+
+    #[test]
+    fn test_proof_abi_round_trip() {
+        let tree: MerkleTree = MerkleTree::new(sample_leaves(8)).unwrap();
+        let proof = tree.generate_proof(6).unwrap();
+
+        let encoded = proof.abi_encode();
+        let decoded = MerkleProof::abi_decode(&encoded).unwrap();
+        assert_eq!(decoded, proof);
+        assert_eq!(decoded.abi_encode(), encoded);
+
+        // The last word is the root-level path index, which must be 0 or 1
+        let mut corrupt = encoded.clone();
+        *corrupt.last_mut().unwrap() = 2;
+        assert!(matches!(MerkleProof::abi_decode(&corrupt), Err(MerkleError::MalformedProof(_))));
+
+        // The first word is the offset of the siblings array
+        let mut corrupt = encoded.clone();
+        corrupt[30] = 0xff;
+        assert!(matches!(MerkleProof::abi_decode(&corrupt), Err(MerkleError::AbiDecodeError { .. })));
+        assert!(MerkleProof::abi_decode(&encoded[..encoded.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_metered_build() {
+        let leaves = sample_leaves(4);
+
+        // Four leaves take three pair hashes
+        let mut meter = GasMeter::new(3 * GasCosts::POSEIDON2_BASE);
+        let tree: MerkleTree = MerkleTree::new_metered(leaves.clone(), &mut meter).unwrap();
+        assert_eq!(tree.root(), MerkleTree::<PoseidonHash>::new(leaves.clone()).unwrap().root());
+        assert_eq!(meter.remaining(), 0);
+
+        let mut meter = GasMeter::new(3 * GasCosts::POSEIDON2_BASE - 1);
+        let result = MerkleTree::<PoseidonHash>::new_metered(leaves, &mut meter);
+        assert!(matches!(result, Err(MerkleError::Gas(_))));
+        assert_eq!(meter.consumed(), 2 * GasCosts::POSEIDON2_BASE);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(MerkleTree::<PoseidonHash>::new(vec![]), Err(MerkleError::EmptyTree)));
+
+        let tree: MerkleTree = MerkleTree::new(sample_leaves(3)).unwrap();
+        assert!(matches!(
+            tree.generate_proof(3),
+            Err(MerkleError::LeafIndexOutOfRange { index: 3, leaf_count: 3 })
+        ));
+    }
+}
@@ -0,0 +1,8 @@
+//! Call guards that sit in front of the precompiles
+
+pub mod sliding_window;
+
+pub use sliding_window::SlidingWindow;
+
+#[cfg(feature = "stylus")]
+pub use sliding_window::SlidingWindowLimiter;
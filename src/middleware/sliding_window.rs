@@ -0,0 +1,242 @@
+//! Sliding window rate limiting
+//!
+//! A call at block `b` is allowed when fewer than `max_calls` calls were
+//! recorded in `[b - window_size_blocks, b]`. Unlike a fixed window, a burst
+//! straddling a window boundary cannot get through with twice the limit.
+//!
+//! Call blocks are recorded in order, so counting walks back from the most
+//! recent call and stops at the first one outside the window. Calls that
+//! can no longer decide a check are dropped: `SlidingWindow` prunes those
+//! older than the window, and the Stylus limiter keeps only the last
+//! `max_calls` in a ring buffer.
+
+use alloy_primitives::U256;
+
+/// Counts recorded calls inside the window ending at `current_block`
+///
+/// `recent_first` must yield call blocks from the most recent to the oldest.
+fn calls_in_window(
+    recent_first: impl Iterator<Item = U256>,
+    current_block: U256,
+    window_size_blocks: u64,
+) -> u64 {
+    let window_start = current_block.saturating_sub(U256::from(window_size_blocks));
+    recent_first
+        .take_while(|block| *block >= window_start)
+        .filter(|block| *block <= current_block)
+        .count() as u64
+}
+
+/// In-memory sliding window limiter, for off-chain callers and tests
+#[derive(Debug, Clone)]
+pub struct SlidingWindow {
+    pub window_size_blocks: u64,
+    pub max_calls: u64,
+    pub call_timestamps: Vec<U256>,
+}
+
+impl SlidingWindow {
+    /// Creates a limiter with no recorded calls
+    pub fn new(window_size_blocks: u64, max_calls: u64) -> Self {
+        Self {
+            window_size_blocks,
+            max_calls,
+            call_timestamps: Vec::new(),
+        }
+    }
+
+    /// Checks the window and records the call if it is allowed
+    pub fn is_allowed(&mut self, current_block: U256) -> bool {
+        let window_start = current_block.saturating_sub(U256::from(self.window_size_blocks));
+        let stale = self.call_timestamps.partition_point(|block| *block < window_start);
+        self.call_timestamps.drain(..stale);
+
+        let calls = calls_in_window(
+            self.call_timestamps.iter().rev().copied(),
+            current_block,
+            self.window_size_blocks,
+        );
+        if calls >= self.max_calls {
+            return false;
+        }
+
+        self.call_timestamps.push(current_block);
+        true
+    }
+
+    /// Forgets all recorded calls
+    pub fn reset(&mut self) {
+        self.call_timestamps.clear();
+    }
+}
+
+#[cfg(feature = "stylus")]
+mod storage {
+    extern crate alloc;
+
+    use super::calls_in_window;
+    use crate::errors::encode_revert;
+    use crate::SolStorageLayout;
+    use alloy_primitives::{Address, U256, U64};
+    use stylus_sdk::prelude::*;
+    use stylus_sdk::storage::{StorageAddress, StorageU256, StorageU64, StorageVec};
+
+    /// Sliding window limiter persisted in Stylus storage
+    ///
+    /// Windows end at the current block number. The first `configure` call
+    /// makes its caller the owner, who alone can reconfigure or reset. Call
+    /// blocks live in a ring buffer of `max_calls` slots, with `next_slot`
+    /// pointing at the oldest once it is full.
+    #[storage]
+    #[derive(SolStorageLayout)]
+    pub struct SlidingWindowLimiter {
+        owner: StorageAddress,
+        window_size_blocks: StorageU64,
+        max_calls: StorageU64,
+        call_timestamps: StorageVec<StorageU256>,
+        next_slot: StorageU64,
+    }
+
+    #[public]
+    impl SlidingWindowLimiter {
+        /// Sets the window size and call limit, forgetting recorded calls
+        pub fn configure(
+            &mut self,
+            window_size_blocks: u64,
+            max_calls: u64,
+        ) -> Result<(), Vec<u8>> {
+            if self.owner.get() == Address::ZERO {
+                self.owner.set(stylus_sdk::msg::sender());
+            }
+            self.only_owner()?;
+
+            self.window_size_blocks.set(U64::from(window_size_blocks));
+            self.max_calls.set(U64::from(max_calls));
+            // The ring buffer is sized for the old limit
+            self.clear();
+            Ok(())
+        }
+
+        /// Checks the window and records the call if it is allowed
+        pub fn is_allowed(&mut self) -> bool {
+            let current_block = U256::from(stylus_sdk::block::number());
+            let max_calls = self.max_calls.get().to::<u64>();
+            let len = self.call_timestamps.len();
+            let next = self.next_slot.get().to::<usize>();
+
+            // Walk the ring back from the slot before `next`
+            let recent_first =
+                (1..=len).filter_map(|back| self.call_timestamps.get((next + len - back) % len));
+            let calls = calls_in_window(
+                recent_first,
+                current_block,
+                self.window_size_blocks.get().to::<u64>(),
+            );
+            if calls >= max_calls {
+                return false;
+            }
+
+            if (len as u64) < max_calls {
+                self.call_timestamps.push(current_block);
+            } else if let Some(mut oldest) = self.call_timestamps.setter(next) {
+                oldest.set(current_block);
+            }
+            self.next_slot.set(U64::from((next as u64 + 1) % max_calls));
+            true
+        }
+
+        /// Forgets all recorded calls, for emergencies
+        pub fn reset(&mut self) -> Result<(), Vec<u8>> {
+            self.only_owner()?;
+            self.clear();
+            Ok(())
+        }
+
+        /// Returns the address allowed to configure and reset
+        pub fn limiter_owner(&self) -> Address {
+            self.owner.get()
+        }
+    }
+
+    impl SlidingWindowLimiter {
+        /// Reverts unless the caller configured the limiter first
+        fn only_owner(&self) -> Result<(), Vec<u8>> {
+            if stylus_sdk::msg::sender() != self.owner.get() {
+                return Err(encode_revert("Caller is not the limiter owner"));
+            }
+            Ok(())
+        }
+
+        /// Empties the ring buffer
+        fn clear(&mut self) {
+            self.call_timestamps.erase();
+            self.next_slot.set(U64::ZERO);
+        }
+    }
+}
+
+#[cfg(feature = "stylus")]
+pub use storage::SlidingWindowLimiter;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_calls_in_window_allowed() {
+        let mut limiter = SlidingWindow::new(10, 3);
+
+        for block in [100u64, 101, 102] {
+            assert!(limiter.is_allowed(U256::from(block)));
+        }
+    }
+
+    #[test]
+    fn test_call_over_limit_blocked() {
+        let mut limiter = SlidingWindow::new(10, 3);
+
+        for _ in 0..3 {
+            assert!(limiter.is_allowed(U256::from(100)));
+        }
+        assert!(!limiter.is_allowed(U256::from(105)));
+
+        // A blocked call is not recorded
+        assert_eq!(limiter.call_timestamps.len(), 3);
+    }
+
+    #[test]
+    fn test_calls_outside_window_ignored() {
+        let mut limiter = SlidingWindow::new(10, 2);
+
+        assert!(limiter.is_allowed(U256::from(100)));
+        assert!(limiter.is_allowed(U256::from(105)));
+        assert!(!limiter.is_allowed(U256::from(110)));
+
+        // Block 100 has slid out of [101, 111]
+        assert!(limiter.is_allowed(U256::from(111)));
+        assert!(!limiter.is_allowed(U256::from(112)));
+    }
+
+    #[test]
+    fn test_calls_outside_window_pruned() {
+        let mut limiter = SlidingWindow::new(10, 100);
+
+        for block in 0..50u64 {
+            assert!(limiter.is_allowed(U256::from(block * 5)));
+        }
+        // Only calls in [235, 245] remain
+        assert_eq!(limiter.call_timestamps.len(), 3);
+        assert_eq!(limiter.call_timestamps[0], U256::from(235));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut limiter = SlidingWindow::new(10, 1);
+
+        assert!(limiter.is_allowed(U256::from(1)));
+        assert!(!limiter.is_allowed(U256::from(2)));
+
+        limiter.reset();
+        assert!(limiter.is_allowed(U256::from(3)));
+    }
+}
@@ -0,0 +1,117 @@
+//! MiMC precompile interface and ABI definitions
+
+use super::core::MiMCHash;
+use crate::errors::{MiMCError, PrecompileError};
+use crate::poseidon::interface::decode_call;
+use crate::traits::{calldata_gas_estimate, GenericPrecompile};
+use alloy_sol_types::{sol, SolCall, SolValue};
+
+// Solidity interface definition
+sol! {
+    #[sol(abi)]
+    interface IMiMCHash {
+        /// Computes MiMC-7 hash of a single field element
+        /// @param input The field element to hash
+        /// @return hash The resulting MiMC-7 hash
+        function mimc1(uint256 input) external pure returns (uint256 hash);
+
+        /// Computes MiMC-7 hash of two field elements
+        /// @param left The left field element
+        /// @param right The right field element
+        /// @return hash The resulting MiMC-7 hash
+        function mimc2(uint256 left, uint256 right) external pure returns (uint256 hash);
+    }
+}
+
+/// Estimated gas of a MiMC call before argument words
+const MIMC_BASE_GAS: u64 = 15000;
+
+/// Estimated gas per 32-byte argument word
+const MIMC_WORD_GAS: u64 = 3000;
+
+/// MiMC precompile behind the `IMiMCHash` selectors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MiMCPrecompile;
+
+impl MiMCPrecompile {
+    /// Selectors of every `IMiMCHash` function
+    pub const SELECTORS: [[u8; 4]; 2] = [
+        IMiMCHash::mimc1Call::SELECTOR,
+        IMiMCHash::mimc2Call::SELECTOR,
+    ];
+}
+
+impl GenericPrecompile for MiMCPrecompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        Ok(mimc_precompile(input)?)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, input: &[u8]) -> u64 {
+        calldata_gas_estimate(input, MIMC_BASE_GAS, MIMC_WORD_GAS)
+    }
+}
+
+/// Precompile entry point - handles the raw call interface
+pub fn mimc_precompile(input: &[u8]) -> Result<Vec<u8>, MiMCError> {
+    if input.len() < 4 {
+        return Err(MiMCError::InvalidSelector);
+    }
+
+    let hasher = MiMCHash::new();
+
+    let result = match &input[0..4] {
+        // mimc1(uint256)
+        s if s == IMiMCHash::mimc1Call::SELECTOR => {
+            let decoded = decode_call::<IMiMCHash::mimc1Call>(input)?;
+
+            hasher.hash_single(decoded.input)?
+        }
+
+        // mimc2(uint256,uint256)
+        s if s == IMiMCHash::mimc2Call::SELECTOR => {
+            let decoded = decode_call::<IMiMCHash::mimc2Call>(input)?;
+
+            hasher.hash_pair(decoded.left, decoded.right)?
+        }
+
+        _ => return Err(MiMCError::InvalidSelector),
+    };
+
+    Ok(result.abi_encode())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    #[test]
+    fn test_precompile_interface() {
+        let hasher = MiMCHash::new();
+
+        let call = IMiMCHash::mimc1Call { input: U256::from(42) }.abi_encode();
+        let output = mimc_precompile(&call).unwrap();
+        assert_eq!(U256::abi_decode(&output, true).unwrap(), hasher.hash_single(U256::from(42)).unwrap());
+
+        let call = IMiMCHash::mimc2Call { left: U256::from(1), right: U256::from(2) }.abi_encode();
+        let output = mimc_precompile(&call).unwrap();
+        assert_eq!(
+            U256::abi_decode(&output, true).unwrap(),
+            hasher.hash_pair(U256::from(1), U256::from(2)).unwrap()
+        );
+
+        assert!(matches!(mimc_precompile(&[0u8; 8]), Err(MiMCError::InvalidSelector)));
+
+        // Truncated arguments report the selector and where decoding stopped
+        let Err(MiMCError::AbiDecodeError { selector, offset, .. }) = mimc_precompile(&call[..40])
+        else {
+            panic!("truncated call decoded");
+        };
+        assert_eq!(selector, IMiMCHash::mimc2Call::SELECTOR);
+        assert!(offset >= 4);
+    }
+}
@@ -0,0 +1,10 @@
+//! MiMC-7 hash implementation for zero-knowledge proof systems
+
+pub mod constants;
+pub mod core;
+pub mod interface;
+
+// Re-export the main components
+pub use constants::{MIMC_ROUNDS, MIMC_ROUND_CONSTANTS};
+pub use core::MiMCHash;
+pub use interface::{mimc_precompile, IMiMCHash, MiMCPrecompile};
@@ -0,0 +1,113 @@
+//! Nullifier derivation for privacy protocols
+//!
+//! A nullifier is `poseidon(secretKey, leafIndex)`: the same note always
+//! yields the same nullifier, so spending it twice is detectable, while
+//! observers without the secret key cannot link it back to the leaf.
+
+use crate::errors::PoseidonError;
+use crate::poseidon::PoseidonHash;
+use alloy_primitives::U256;
+use std::collections::BTreeSet;
+
+/// Derives nullifiers with the production Poseidon hash
+#[derive(Default)]
+pub struct Nullifier {
+    hasher: PoseidonHash,
+}
+
+impl Nullifier {
+    /// Creates a nullifier generator with the default Poseidon parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Derives the nullifier of the note at `leaf_index` owned by `secret_key`
+    pub fn generate(&self, secret_key: U256, leaf_index: U256) -> Result<U256, PoseidonError> {
+        self.hasher.hash_pair_production(secret_key, leaf_index)
+    }
+}
+
+/// Set of spent nullifiers, kept sorted for ABI encoding
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NullifierSet {
+    spent: BTreeSet<U256>,
+}
+
+impl NullifierSet {
+    /// Creates an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a nullifier, returning false if it was already spent
+    pub fn insert(&mut self, nullifier: U256) -> bool {
+        self.spent.insert(nullifier)
+    }
+
+    /// Returns whether the nullifier has been spent
+    pub fn contains(&self, nullifier: U256) -> bool {
+        self.spent.contains(&nullifier)
+    }
+
+    /// Number of spent nullifiers
+    pub fn len(&self) -> usize {
+        self.spent.len()
+    }
+
+    /// Returns whether no nullifiers have been spent
+    pub fn is_empty(&self) -> bool {
+        self.spent.is_empty()
+    }
+
+    /// Returns the nullifiers in ascending order
+    pub fn to_vec(&self) -> Vec<U256> {
+        self.spent.iter().copied().collect()
+    }
+
+    /// Builds a set from nullifiers in any order, dropping duplicates
+    pub fn from_vec(nullifiers: Vec<U256>) -> Self {
+        Self {
+            spent: nullifiers.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nullifier_is_deterministic() {
+        let nullifier = Nullifier::new();
+        let (key, index) = (U256::from(0xc0ffee), U256::from(7));
+
+        let first = nullifier.generate(key, index).unwrap();
+        assert_eq!(first, Nullifier::new().generate(key, index).unwrap());
+        assert_eq!(first, PoseidonHash::new().hash_pair_production(key, index).unwrap());
+    }
+
+    #[test]
+    fn test_nullifier_depends_on_key_and_index() {
+        let nullifier = Nullifier::new();
+        let base = nullifier.generate(U256::from(1), U256::from(7)).unwrap();
+
+        assert_ne!(base, nullifier.generate(U256::from(2), U256::from(7)).unwrap());
+        assert_ne!(base, nullifier.generate(U256::from(1), U256::from(8)).unwrap());
+        assert!(nullifier.generate(PoseidonHash::new().params.modulus, U256::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_nullifier_set() {
+        let mut set = NullifierSet::new();
+        assert!(set.insert(U256::from(30)));
+        assert!(set.insert(U256::from(10)));
+        assert!(!set.insert(U256::from(30)));
+
+        assert!(set.contains(U256::from(10)));
+        assert!(!set.contains(U256::from(20)));
+        assert_eq!(set.to_vec(), vec![U256::from(10), U256::from(30)]);
+
+        let restored = NullifierSet::from_vec(vec![U256::from(30), U256::from(10), U256::from(10)]);
+        assert_eq!(restored, set);
+    }
+}
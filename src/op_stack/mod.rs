@@ -0,0 +1,5 @@
+//! OP Stack state commitments for cross-chain verification
+
+pub mod output_root;
+
+pub use output_root::{compute_output_root, verify_output_root_proof, OutputRoot, OUTPUT_VERSION_V0};
@@ -0,0 +1,96 @@
+//! OP Stack output roots
+//!
+//! An OP Stack chain commits to its L2 state with an output root, published
+//! to L1 by the proposer. Version 0 is
+//! `keccak256(version || state_root || message_passer_storage_root || latest_block_hash)`
+//! over four 32-byte words, which is what `Hashing.hashOutputRootProof`
+//! computes on L1.
+
+use alloy_primitives::keccak256;
+
+/// Output root version 0, the only version defined so far
+pub const OUTPUT_VERSION_V0: [u8; 32] = [0u8; 32];
+
+/// Preimage of a version 0 output root
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputRoot {
+    /// L2 state trie root
+    pub state_root: [u8; 32],
+    /// Storage root of the L2ToL1MessagePasser contract
+    pub message_passer_storage_root: [u8; 32],
+    /// Hash of the L2 block the output is for
+    pub latest_block_hash: [u8; 32],
+}
+
+/// Computes the version 0 output root
+pub fn compute_output_root(root: &OutputRoot) -> [u8; 32] {
+    let mut preimage = [0u8; 128];
+    preimage[..32].copy_from_slice(&OUTPUT_VERSION_V0);
+    preimage[32..64].copy_from_slice(&root.state_root);
+    preimage[64..96].copy_from_slice(&root.message_passer_storage_root);
+    preimage[96..].copy_from_slice(&root.latest_block_hash);
+
+    keccak256(preimage).0
+}
+
+/// Checks that a claimed output root commits to the given preimage
+pub fn verify_output_root_proof(claimed_root: [u8; 32], proof: &OutputRoot) -> bool {
+    compute_output_root(proof) == claimed_root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use alloy_sol_types::SolValue;
+
+    fn sample_proof() -> OutputRoot {
+        OutputRoot {
+            state_root: [0x11; 32],
+            message_passer_storage_root: [0x22; 32],
+            latest_block_hash: [0x33; 32],
+        }
+    }
+
+    #[test]
+    fn test_matches_abi_encoded_preimage() {
+        // Hashing.hashOutputRootProof hashes abi.encode of the four words
+        let proof = sample_proof();
+        let encoded = (
+            B256::from(OUTPUT_VERSION_V0),
+            B256::from(proof.state_root),
+            B256::from(proof.message_passer_storage_root),
+            B256::from(proof.latest_block_hash),
+        )
+            .abi_encode();
+
+        assert_eq!(compute_output_root(&proof), keccak256(encoded).0);
+    }
+
+    #[test]
+    fn test_verify_output_root_proof() {
+        let proof = sample_proof();
+        let root = compute_output_root(&proof);
+        assert!(verify_output_root_proof(root, &proof));
+
+        // Changing any field changes the root
+        let mut tampered = proof;
+        tampered.latest_block_hash[31] ^= 1;
+        assert!(!verify_output_root_proof(root, &tampered));
+
+        let mut tampered = proof;
+        tampered.message_passer_storage_root[0] ^= 1;
+        assert!(!verify_output_root_proof(root, &tampered));
+    }
+
+    #[test]
+    fn test_fields_are_not_interchangeable() {
+        let proof = sample_proof();
+        let swapped = OutputRoot {
+            state_root: proof.latest_block_hash,
+            message_passer_storage_root: proof.message_passer_storage_root,
+            latest_block_hash: proof.state_root,
+        };
+        assert_ne!(compute_output_root(&proof), compute_output_root(&swapped));
+    }
+}
@@ -0,0 +1,108 @@
+//! Pedersen commitments over BN254 G1
+//!
+//! `commit(v, r) = v·G + r·H`, where `G` is the standard generator and `H` is
+//! hashed to the curve so nobody knows `log_G(H)`. Values and blinding factors
+//! are scalars below the group order. Commitments are returned as the affine
+//! coordinates `[x, y]`, with `[0, 0]` for the point at infinity.
+
+use crate::bn254::{G1Point, G1_GENERATOR};
+use crate::errors::PedersenError;
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::U256;
+
+/// Seed of the blinding generator `H`
+pub const BLINDING_GENERATOR_SEED: &[u8] = b"stylus-precompile/pedersen/H";
+
+/// Pedersen commitment scheme with generators `G` and `H`
+#[derive(Debug, Clone)]
+pub struct PedersenCommitment {
+    g: G1Point,
+    h: G1Point,
+}
+
+impl Default for PedersenCommitment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PedersenCommitment {
+    /// Creates the commitment scheme with the standard generators
+    pub fn new() -> Self {
+        Self {
+            g: G1_GENERATOR,
+            h: G1Point::hash_to_curve(BLINDING_GENERATOR_SEED),
+        }
+    }
+
+    /// Commits to `value` with blinding factor `blinding`
+    pub fn commit(&self, value: U256, blinding: U256) -> Result<[U256; 2], PedersenError> {
+        let point = self.g.mul(validate_scalar(value)?).add(&self.h.mul(validate_scalar(blinding)?));
+        Ok([point.x, point.y])
+    }
+
+    /// Whether `commitment` opens to `value` with `blinding`
+    pub fn open(&self, commitment: [U256; 2], value: U256, blinding: U256) -> Result<bool, PedersenError> {
+        let point = G1Point::new(commitment[0], commitment[1]);
+        if !point.is_on_curve() {
+            return Err(PedersenError::InvalidPoint(commitment[0], commitment[1]));
+        }
+        Ok(self.commit(value, blinding)? == commitment)
+    }
+
+    /// Adds two commitments, committing to the sums of their values and blindings
+    pub fn add(&self, a: [U256; 2], b: [U256; 2]) -> Result<[U256; 2], PedersenError> {
+        let mut points = [a, b].map(|[x, y]| G1Point::new(x, y));
+        if let Some(point) = points.iter().find(|point| !point.is_on_curve()) {
+            return Err(PedersenError::InvalidPoint(point.x, point.y));
+        }
+        points[0] = points[0].add(&points[1]);
+        Ok([points[0].x, points[0].y])
+    }
+}
+
+/// Checks that a scalar is below the G1 group order
+pub(crate) fn validate_scalar(scalar: U256) -> Result<U256, PedersenError> {
+    if scalar >= BN254_MODULUS {
+        return Err(PedersenError::ScalarTooLarge(scalar));
+    }
+    Ok(scalar)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_round_trip() {
+        let pedersen = PedersenCommitment::new();
+        let (value, blinding) = (U256::from(42), U256::from(123456789));
+
+        let commitment = pedersen.commit(value, blinding).unwrap();
+        assert!(pedersen.open(commitment, value, blinding).unwrap());
+        assert!(!pedersen.open(commitment, value + U256::from(1), blinding).unwrap());
+        assert!(!pedersen.open(commitment, value, blinding + U256::from(1)).unwrap());
+    }
+
+    #[test]
+    fn test_homomorphic_addition() {
+        let pedersen = PedersenCommitment::new();
+        let (a, r1) = (U256::from(1000), U256::from(77));
+        let (b, r2) = (BN254_MODULUS - U256::from(1), U256::from(88));
+
+        let sum = pedersen
+            .add(pedersen.commit(a, r1).unwrap(), pedersen.commit(b, r2).unwrap())
+            .unwrap();
+        let expected = pedersen
+            .commit(a.add_mod(b, BN254_MODULUS), r1.add_mod(r2, BN254_MODULUS))
+            .unwrap();
+        assert_eq!(sum, expected);
+    }
+
+    #[test]
+    fn test_rejects_invalid_inputs() {
+        let pedersen = PedersenCommitment::new();
+        assert!(pedersen.commit(BN254_MODULUS, U256::ZERO).is_err());
+        assert!(pedersen.open([U256::from(1), U256::from(1)], U256::ZERO, U256::ZERO).is_err());
+    }
+}
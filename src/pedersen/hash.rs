@@ -0,0 +1,80 @@
+//! Pedersen hash with the `PoseidonHash` interface
+//!
+//! Element `i` is multiplied by its own generator `G_i` and the hash is the
+//! x-coordinate of the sum, reduced into the scalar field. `G_0` is the
+//! standard generator and the others are hashed to the curve. As with any
+//! Pedersen hash, hashing only zeros yields zero.
+
+use super::commitment::validate_scalar;
+use crate::bn254::{G1Point, G1_GENERATOR};
+use crate::errors::PedersenError;
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::U256;
+
+/// Prefix of the seeds of the per-position generators
+pub const GENERATOR_SEED_PREFIX: &[u8] = b"stylus-precompile/pedersen/G";
+
+/// Pedersen hash over BN254 G1
+#[derive(Debug, Clone, Default)]
+pub struct PedersenHash;
+
+impl PedersenHash {
+    /// Creates a new Pedersen hasher
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Computes Pedersen hash for a single element
+    pub fn hash_single(&self, input: U256) -> Result<U256, PedersenError> {
+        self.hash_array(&[input])
+    }
+
+    /// Computes Pedersen hash of two elements
+    pub fn hash_pair(&self, left: U256, right: U256) -> Result<U256, PedersenError> {
+        self.hash_array(&[left, right])
+    }
+
+    /// Computes Pedersen hash for an array of elements
+    pub fn hash_array(&self, inputs: &[U256]) -> Result<U256, PedersenError> {
+        if inputs.is_empty() {
+            return Err(PedersenError::InvalidInputLength(0));
+        }
+
+        let mut sum = G1Point::INFINITY;
+        for (index, &input) in inputs.iter().enumerate() {
+            sum = sum.add(&generator(index).mul(validate_scalar(input)?));
+        }
+
+        Ok(sum.x % BN254_MODULUS)
+    }
+}
+
+/// Generator for the element at `index`
+fn generator(index: usize) -> G1Point {
+    if index == 0 {
+        return G1_GENERATOR;
+    }
+    let mut seed = GENERATOR_SEED_PREFIX.to_vec();
+    seed.extend_from_slice(&(index as u64).to_be_bytes());
+    G1Point::hash_to_curve(&seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_interface() {
+        let hasher = PedersenHash::new();
+        let (one, two) = (U256::from(1), U256::from(2));
+
+        assert_eq!(hasher.hash_single(one).unwrap(), G1_GENERATOR.x);
+        assert_eq!(hasher.hash_single(two).unwrap(), hasher.hash_array(&[two]).unwrap());
+        assert_ne!(hasher.hash_pair(one, two).unwrap(), hasher.hash_pair(two, one).unwrap());
+        assert_eq!(hasher.hash_pair(one, two).unwrap(), hasher.hash_pair(one, two).unwrap());
+        assert!(hasher.hash_pair(one, two).unwrap() < BN254_MODULUS);
+
+        assert!(hasher.hash_array(&[]).is_err());
+        assert!(hasher.hash_single(BN254_MODULUS).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+//! Pedersen commitment precompile interface and ABI definitions
+
+use super::commitment::PedersenCommitment;
+use crate::errors::{PedersenError, PrecompileError};
+use crate::poseidon::interface::decode_call;
+use crate::traits::{calldata_gas_estimate, GenericPrecompile};
+use alloy_sol_types::{sol, SolCall, SolValue};
+
+// Solidity interface definition
+sol! {
+    #[sol(abi)]
+    interface IPedersenCommitment {
+        /// Commits to a value with a blinding factor
+        /// @param value The committed value
+        /// @param blinding The blinding factor
+        /// @return x The x coordinate of the commitment
+        /// @return y The y coordinate of the commitment
+        function commit(uint256 value, uint256 blinding) external pure returns (uint256 x, uint256 y);
+    }
+}
+
+/// Estimated gas of a Pedersen call before argument words
+const PEDERSEN_BASE_GAS: u64 = 40000;
+
+/// Estimated gas per 32-byte argument word
+const PEDERSEN_WORD_GAS: u64 = 1000;
+
+/// Pedersen precompile behind the `IPedersenCommitment` selectors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PedersenPrecompile;
+
+impl PedersenPrecompile {
+    /// Selectors of every `IPedersenCommitment` function
+    pub const SELECTORS: [[u8; 4]; 1] = [
+        IPedersenCommitment::commitCall::SELECTOR,
+    ];
+}
+
+impl GenericPrecompile for PedersenPrecompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        Ok(pedersen_precompile(input)?)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, input: &[u8]) -> u64 {
+        calldata_gas_estimate(input, PEDERSEN_BASE_GAS, PEDERSEN_WORD_GAS)
+    }
+}
+
+/// Precompile entry point - handles the raw call interface
+pub fn pedersen_precompile(input: &[u8]) -> Result<Vec<u8>, PedersenError> {
+    if input.len() < 4 {
+        return Err(PedersenError::InvalidSelector);
+    }
+
+    match &input[0..4] {
+        // commit(uint256,uint256)
+        s if s == IPedersenCommitment::commitCall::SELECTOR => {
+            let decoded = decode_call::<IPedersenCommitment::commitCall>(input)?;
+
+            let [x, y] = PedersenCommitment::new().commit(decoded.value, decoded.blinding)?;
+            Ok((x, y).abi_encode_params())
+        }
+
+        _ => Err(PedersenError::InvalidSelector),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    #[test]
+    fn test_precompile_interface() {
+        let (value, blinding) = (U256::from(5), U256::from(7));
+        let call = IPedersenCommitment::commitCall { value, blinding }.abi_encode();
+
+        let output = pedersen_precompile(&call).unwrap();
+        let decoded = IPedersenCommitment::commitCall::abi_decode_returns(&output, true).unwrap();
+        let expected = PedersenCommitment::new().commit(value, blinding).unwrap();
+        assert_eq!([decoded.x, decoded.y], expected);
+
+        assert!(matches!(pedersen_precompile(&[0u8; 8]), Err(PedersenError::InvalidSelector)));
+    }
+}
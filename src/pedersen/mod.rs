@@ -0,0 +1,10 @@
+//! Pedersen commitments and hashing over BN254 G1
+
+pub mod commitment;
+pub mod hash;
+pub mod interface;
+
+// Re-export the main components
+pub use commitment::PedersenCommitment;
+pub use hash::PedersenHash;
+pub use interface::{pedersen_precompile, IPedersenCommitment, PedersenPrecompile};
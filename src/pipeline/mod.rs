@@ -0,0 +1,208 @@
+//! Witness generation, proving and verification as one builder chain
+//!
+//! `ProofPipeline` runs the circomlib Poseidon circuit end to end:
+//!
+//! ```ignore
+//! let result = ProofPipeline::new()
+//!     .witness(&[a, b])
+//!     .prove(&pk_bytes)
+//!     .verify(&vk)
+//!     .execute()?;
+//! ```
+//!
+//! Nothing runs until `execute`, which builds the circuit for the number of
+//! inputs, solves and checks the witness, proves against the serialized
+//! proving key and verifies the proof against `vk`. The public input is the
+//! hash, so `result.public_inputs[0]` equals the circomlib Poseidon hash of
+//! the inputs.
+//!
+//! The blinding factors are derived from the witness, so proving the same
+//! inputs twice gives the same proof. Callers that need proofs which do not
+//! link to each other should prove with fresh randomness through
+//! `groth16::prove` instead.
+
+use alloy_primitives::{keccak256, U256};
+
+use crate::circom::verify_r1cs_witness;
+use crate::circuit_builder::PoseidonCircuitBuilder;
+use crate::errors::{Groth16Error, PrecompileError};
+use crate::groth16::{groth16_verify, prove, Groth16Proof, ProvingKey, VerifyingKey};
+use crate::utils::BN254_MODULUS;
+
+/// Start of a pipeline, before any inputs are given
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ProofPipeline;
+
+/// Pipeline holding the circuit inputs
+#[derive(Debug, Clone)]
+pub struct WitnessStage {
+    inputs: Vec<U256>,
+}
+
+/// Pipeline holding the inputs and a serialized proving key
+#[derive(Debug, Clone)]
+pub struct ProvingStage<'a> {
+    inputs: Vec<U256>,
+    pk_bytes: &'a [u8],
+}
+
+/// Fully configured pipeline, ready to `execute`
+#[derive(Debug, Clone)]
+pub struct VerificationStage<'a> {
+    inputs: Vec<U256>,
+    pk_bytes: &'a [u8],
+    vk: &'a VerifyingKey,
+}
+
+/// Outcome of a pipeline run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationResult {
+    pub valid: bool,
+    pub proof: Groth16Proof,
+    pub public_inputs: Vec<U256>,
+}
+
+impl ProofPipeline {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Sets the inputs of the Poseidon circuit
+    pub fn witness(self, inputs: &[U256]) -> WitnessStage {
+        WitnessStage { inputs: inputs.to_vec() }
+    }
+}
+
+impl WitnessStage {
+    /// Sets the proving key, in `ProvingKey::to_bytes` form
+    pub fn prove(self, pk_bytes: &[u8]) -> ProvingStage<'_> {
+        ProvingStage { inputs: self.inputs, pk_bytes }
+    }
+}
+
+impl<'a> ProvingStage<'a> {
+    /// Sets the key the proof is verified against
+    pub fn verify(self, vk: &'a VerifyingKey) -> VerificationStage<'a> {
+        VerificationStage { inputs: self.inputs, pk_bytes: self.pk_bytes, vk }
+    }
+}
+
+impl VerificationStage<'_> {
+    /// Runs every stage, failing on the first malformed input
+    ///
+    /// A proof that was produced but does not verify against `vk` is not an
+    /// error; it comes back with `valid` set to false.
+    pub fn execute(self) -> Result<VerificationResult, PrecompileError> {
+        let builder = PoseidonCircuitBuilder::new(self.inputs.len(), self.inputs.len() + 1)?;
+        let circuit = builder.build();
+        let witness = builder.generate_witness(&self.inputs)?;
+        if !verify_r1cs_witness(&circuit.r1cs, &witness)? {
+            return Err(
+                Groth16Error::InvalidCircuit("generated witness is unsatisfied".into()).into()
+            );
+        }
+
+        let pk = ProvingKey::from_bytes(self.pk_bytes)?;
+        let proof = prove(
+            &pk,
+            &circuit.r1cs,
+            &witness,
+            blinding(b"r", &witness),
+            blinding(b"s", &witness),
+        )?;
+
+        let public_inputs = witness[1..=circuit.n_pub_outputs + circuit.n_pub_inputs].to_vec();
+        let valid = groth16_verify(self.vk, &proof, &public_inputs)?;
+        Ok(VerificationResult { valid, proof, public_inputs })
+    }
+}
+
+/// `keccak256(tag || witness) mod r`, a blinding factor bound to the witness
+fn blinding(tag: &[u8], witness: &[U256]) -> U256 {
+    let mut preimage = tag.to_vec();
+    for signal in witness {
+        preimage.extend_from_slice(&signal.to_be_bytes::<32>());
+    }
+    U256::from_be_bytes(keccak256(&preimage).0) % BN254_MODULUS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circuit_builder::R1CSDescription;
+    use crate::groth16::{insecure_setup, Trapdoor};
+    use crate::poseidon::PoseidonHash;
+
+    fn poseidon2_circuit() -> R1CSDescription {
+        PoseidonCircuitBuilder::new(2, 3).unwrap().build()
+    }
+
+    fn trapdoor(tau: u64) -> Trapdoor {
+        Trapdoor {
+            tau: U256::from(tau),
+            alpha: U256::from(3),
+            beta: U256::from(5),
+            gamma: U256::from(7),
+            delta: U256::from(11),
+        }
+    }
+
+    #[test]
+    fn test_poseidon2_pipeline() {
+        let (pk, vk) = insecure_setup(&poseidon2_circuit(), &trapdoor(0xdead_beef)).unwrap();
+        let inputs = [U256::from(1), U256::from(2)];
+
+        let result = ProofPipeline::new()
+            .witness(&inputs)
+            .prove(&pk.to_bytes())
+            .verify(&vk)
+            .execute()
+            .unwrap();
+        assert!(result.valid);
+        let expected = PoseidonHash::new().hash_pair_production(inputs[0], inputs[1]).unwrap();
+        assert_eq!(result.public_inputs, vec![expected]);
+        assert!(groth16_verify(&vk, &result.proof, &[expected]).unwrap());
+    }
+
+    #[test]
+    fn test_pipeline_with_foreign_vk() {
+        let circuit = poseidon2_circuit();
+        let (pk, _) = insecure_setup(&circuit, &trapdoor(0xdead_beef)).unwrap();
+        let (_, other_vk) = insecure_setup(&circuit, &trapdoor(0xcafe_f00d)).unwrap();
+
+        let result = ProofPipeline::new()
+            .witness(&[U256::from(1), U256::from(2)])
+            .prove(&pk.to_bytes())
+            .verify(&other_vk)
+            .execute()
+            .unwrap();
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_pipeline_errors() {
+        let (pk, vk) = insecure_setup(&poseidon2_circuit(), &trapdoor(0xdead_beef)).unwrap();
+        let pk_bytes = pk.to_bytes();
+        let inputs = [U256::from(1), U256::from(2)];
+
+        let truncated =
+            ProofPipeline::new().witness(&inputs).prove(&pk_bytes[..64]).verify(&vk).execute();
+        assert!(matches!(
+            truncated,
+            Err(PrecompileError::Groth16(Groth16Error::MalformedProvingKey(_)))
+        ));
+
+        // The key is for two inputs, so a three-input circuit does not fit it
+        let three = [U256::from(1), U256::from(2), U256::from(3)];
+        assert!(ProofPipeline::new()
+            .witness(&three)
+            .prove(&pk_bytes)
+            .verify(&vk)
+            .execute()
+            .is_err());
+
+        let too_large = [BN254_MODULUS, U256::from(2)];
+        let error = ProofPipeline::new().witness(&too_large).prove(&pk_bytes).verify(&vk).execute();
+        assert!(matches!(error, Err(PrecompileError::Circom(_))));
+    }
+}
@@ -0,0 +1,79 @@
+//! Polynomial hasher precompile interface and ABI definitions
+
+use super::poly_hasher::hash_polynomial_evaluations;
+use crate::errors::{PoseidonError, PrecompileError};
+use crate::gas::GasCosts;
+use crate::poseidon::interface::decode_call;
+use crate::traits::{calldata_gas_estimate, GenericPrecompile};
+use alloy_sol_types::{sol, SolCall, SolValue};
+
+// Solidity interface definition
+sol! {
+    #[sol(abi)]
+    interface IPolyHasher {
+        /// Hashes the evaluations of a polynomial over its domain
+        /// @param evals The evaluations p(ω^0), ..., p(ω^n)
+        /// @return hash The resulting Poseidon hash
+        function hashPolyEvals(uint256[] evals) external pure returns (uint256 hash);
+    }
+}
+
+/// Polynomial hasher precompile behind the `IPolyHasher` selectors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PolyHasherPrecompile;
+
+impl PolyHasherPrecompile {
+    /// Selectors of every `IPolyHasher` function
+    pub const SELECTORS: [[u8; 4]; 1] = [IPolyHasher::hashPolyEvalsCall::SELECTOR];
+}
+
+impl GenericPrecompile for PolyHasherPrecompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        Ok(poly_hasher_precompile(input)?)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, input: &[u8]) -> u64 {
+        // Priced like poseidonN over the evaluations
+        calldata_gas_estimate(input, GasCosts::POSEIDON_N_BASE, GasCosts::POSEIDON_PER_ELEMENT)
+    }
+}
+
+/// Precompile entry point - handles the raw call interface
+pub fn poly_hasher_precompile(input: &[u8]) -> Result<Vec<u8>, PoseidonError> {
+    if input.len() < 4 {
+        return Err(PoseidonError::InvalidSelector);
+    }
+
+    match &input[0..4] {
+        // hashPolyEvals(uint256[])
+        s if s == IPolyHasher::hashPolyEvalsCall::SELECTOR => {
+            let decoded = decode_call::<IPolyHasher::hashPolyEvalsCall>(input)?;
+
+            Ok(hash_polynomial_evaluations(&decoded.evals)?.abi_encode())
+        }
+
+        _ => Err(PoseidonError::InvalidSelector),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    #[test]
+    fn test_precompile_interface() {
+        let evals = vec![U256::from(1), U256::from(2), U256::from(3)];
+        let call = IPolyHasher::hashPolyEvalsCall { evals: evals.clone() }.abi_encode();
+
+        let output = poly_hasher_precompile(&call).unwrap();
+        let hash = U256::abi_decode(&output, true).unwrap();
+        assert_eq!(hash, hash_polynomial_evaluations(&evals).unwrap());
+
+        assert!(poly_hasher_precompile(&[0u8; 8]).is_err());
+    }
+}
@@ -0,0 +1,7 @@
+//! Helpers for PLONK-style lookup arguments
+
+pub mod interface;
+pub mod poly_hasher;
+
+pub use interface::{poly_hasher_precompile, IPolyHasher, PolyHasherPrecompile};
+pub use poly_hasher::{hash_polynomial_evaluations, hash_with_index};
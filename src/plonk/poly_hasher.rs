@@ -0,0 +1,94 @@
+//! Poseidon commitments to polynomial evaluations
+//!
+//! Lookup arguments commit to a polynomial through its evaluations over the
+//! domain, `H(p(ω^0), p(ω^1), ..., p(ω^n))`. poseidon-rs hashes at most 16
+//! elements at once, so longer evaluation vectors are absorbed in chunks of
+//! 15 with the running digest prepended to each chunk, starting from zero.
+
+use crate::errors::PoseidonError;
+use crate::poseidon::PoseidonHash;
+use alloy_primitives::U256;
+
+/// Evaluations absorbed per poseidon-rs call, leaving one slot for the running digest
+const CHUNK_SIZE: usize = 15;
+
+/// Hashes the evaluations of a polynomial over its domain
+pub fn hash_polynomial_evaluations(evals: &[U256]) -> Result<U256, PoseidonError> {
+    if evals.is_empty() {
+        return Err(PoseidonError::InvalidInputLength(0));
+    }
+
+    let hasher = PoseidonHash::new();
+    let mut digest = U256::ZERO;
+    let mut block = Vec::with_capacity(CHUNK_SIZE + 1);
+
+    for chunk in evals.chunks(CHUNK_SIZE) {
+        block.clear();
+        block.push(digest);
+        block.extend_from_slice(chunk);
+        digest = hasher.hash_array_production(&block)?;
+    }
+
+    Ok(digest)
+}
+
+/// Hashes the evaluations with `index` appended for domain separation
+pub fn hash_with_index(evals: &[U256], index: u64) -> Result<U256, PoseidonError> {
+    if evals.is_empty() {
+        return Err(PoseidonError::InvalidInputLength(0));
+    }
+
+    let mut separated = evals.to_vec();
+    separated.push(U256::from(index));
+    hash_polynomial_evaluations(&separated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_evals(count: usize) -> Vec<U256> {
+        (0..count).map(|i| U256::from(i * 7 + 3)).collect()
+    }
+
+    #[test]
+    fn test_deterministic() {
+        for count in [1, 15, 16, 40] {
+            let evals = sample_evals(count);
+            assert_eq!(
+                hash_polynomial_evaluations(&evals).unwrap(),
+                hash_polynomial_evaluations(&evals).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_any_single_change_changes_hash() {
+        let evals = sample_evals(40);
+        let original = hash_polynomial_evaluations(&evals).unwrap();
+
+        for i in 0..evals.len() {
+            let mut changed = evals.clone();
+            changed[i] += U256::from(1);
+            assert_ne!(hash_polynomial_evaluations(&changed).unwrap(), original, "index {}", i);
+        }
+    }
+
+    #[test]
+    fn test_index_separates_domains() {
+        let evals = sample_evals(5);
+        let plain = hash_polynomial_evaluations(&evals).unwrap();
+
+        assert_ne!(hash_with_index(&evals, 0).unwrap(), plain);
+        assert_ne!(hash_with_index(&evals, 0).unwrap(), hash_with_index(&evals, 1).unwrap());
+    }
+
+    #[test]
+    fn test_rejects_invalid_input() {
+        assert!(hash_polynomial_evaluations(&[]).is_err());
+        assert!(hash_with_index(&[], 0).is_err());
+
+        let modulus = PoseidonHash::new().params.modulus;
+        assert!(hash_polynomial_evaluations(&[modulus]).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+//! Poseidon hash over the BLS12-381 scalar field
+//!
+//! Uses the same construction as the Halo2 `P128Pow5T3` gadget: width 3
+//! (rate 2), the x^5 S-box, and Grain LFSR round constants with a Cauchy MDS
+//! matrix. The BLS12-381 scalar field calls for 8 full and 57 partial rounds.
+//!
+//! Messages are absorbed in Halo2's `ConstantLength<L>` domain: the capacity
+//! element (last in the state) is `L * 2^64`, rate-sized chunks are added into
+//! the state before each permutation, and the first rate element is the output.
+
+use super::constants::{POSEIDON_BLS12_MDS, POSEIDON_BLS12_ROUND_CONSTANTS};
+use crate::errors::PoseidonError;
+use crate::field::{Bls12_381Scalar, FieldElement};
+use alloy_primitives::U256;
+
+/// BLS12-381 scalar field modulus `r`
+pub const BLS12_381_MODULUS: U256 = crate::BLS12_381_SCALAR_MODULUS;
+
+/// State width (two rate elements, one capacity)
+const WIDTH: usize = 3;
+
+/// Elements absorbed per permutation
+const RATE: usize = 2;
+
+type Fr = FieldElement<Bls12_381Scalar>;
+
+/// Poseidon parameters for the BLS12-381 scalar field
+pub struct PoseidonBlsParams {
+    /// Prime field modulus (BLS12-381 scalar field)
+    pub modulus: U256,
+    /// Number of full rounds
+    pub full_rounds: usize,
+    /// Number of partial rounds
+    pub partial_rounds: usize,
+    /// S-box exponent
+    pub alpha: u64,
+}
+
+impl Default for PoseidonBlsParams {
+    fn default() -> Self {
+        Self {
+            modulus: BLS12_381_MODULUS,
+            full_rounds: 8,
+            partial_rounds: 57,
+            alpha: 5,
+        }
+    }
+}
+
+/// Poseidon hash over the BLS12-381 scalar field
+pub struct PoseidonBls {
+    pub params: PoseidonBlsParams,
+}
+
+impl Default for PoseidonBls {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PoseidonBls {
+    /// Creates a new hasher with the default parameters
+    pub fn new() -> Self {
+        Self {
+            params: PoseidonBlsParams::default(),
+        }
+    }
+
+    /// Computes the Poseidon hash of a single element (`ConstantLength<1>`)
+    pub fn hash_single(&self, input: U256) -> Result<U256, PoseidonError> {
+        self.hash_array(&[input])
+    }
+
+    /// Computes the Poseidon hash of two elements (`ConstantLength<2>`)
+    pub fn hash_pair(&self, left: U256, right: U256) -> Result<U256, PoseidonError> {
+        self.hash_array(&[left, right])
+    }
+
+    /// Computes the Poseidon hash of a non-empty array (`ConstantLength<L>`)
+    pub fn hash_array(&self, inputs: &[U256]) -> Result<U256, PoseidonError> {
+        if inputs.is_empty() {
+            return Err(PoseidonError::InvalidInputLength(0));
+        }
+
+        let elements = inputs
+            .iter()
+            .map(|&input| {
+                Fr::from_canonical(input).ok_or(PoseidonError::FieldElementTooLarge(input))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut state = [Fr::ZERO, Fr::ZERO, Fr::from_u256(U256::from(inputs.len()) << 64)];
+        for chunk in elements.chunks(RATE) {
+            for (element, input) in state.iter_mut().zip(chunk) {
+                *element = *element + *input;
+            }
+            self.permute_elements(&mut state);
+        }
+
+        Ok(state[0].into_u256())
+    }
+
+    /// Applies the Poseidon permutation in place
+    pub fn permute(&self, state: &mut [U256; WIDTH]) {
+        let mut elements = state.map(Fr::from_u256);
+        self.permute_elements(&mut elements);
+        *state = elements.map(Fr::into_u256);
+    }
+
+    fn permute_elements(&self, state: &mut [Fr; WIDTH]) {
+        let params = &self.params;
+        let half_full = params.full_rounds / 2;
+        let mds = POSEIDON_BLS12_MDS.map(|row| row.map(Fr::from_u256));
+
+        for (round, constants) in POSEIDON_BLS12_ROUND_CONSTANTS.chunks(WIDTH).enumerate() {
+            // AddRoundConstants
+            for (element, constant) in state.iter_mut().zip(constants) {
+                *element = *element + Fr::from_u256(*constant);
+            }
+
+            // SubWords: full rounds at both ends, partial rounds in the middle
+            if round < half_full || round >= half_full + params.partial_rounds {
+                for element in state.iter_mut() {
+                    *element = sbox(*element);
+                }
+            } else {
+                state[0] = sbox(state[0]);
+            }
+
+            // MixLayer
+            let mut mixed = [Fr::ZERO; WIDTH];
+            for (out, row) in mixed.iter_mut().zip(&mds) {
+                for (entry, element) in row.iter().zip(state.iter()) {
+                    *out = *out + *entry * *element;
+                }
+            }
+            *state = mixed;
+        }
+    }
+}
+
+/// S-box: x^5
+fn sbox(x: Fr) -> Fr {
+    let x2 = x * x;
+    x2 * x2 * x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::uint;
+
+    #[test]
+    fn test_field_validation() {
+        let hasher = PoseidonBls::new();
+        assert!(hasher.hash_single(BLS12_381_MODULUS - U256::from(1)).is_ok());
+        assert!(hasher.hash_pair(U256::ZERO, BLS12_381_MODULUS).is_err());
+        assert!(matches!(hasher.hash_array(&[]), Err(PoseidonError::InvalidInputLength(0))));
+    }
+
+    #[test]
+    fn test_constants() {
+        let params = PoseidonBlsParams::default();
+        let rounds = params.full_rounds + params.partial_rounds;
+        assert_eq!(POSEIDON_BLS12_ROUND_CONSTANTS.len(), rounds * WIDTH);
+        assert!(POSEIDON_BLS12_ROUND_CONSTANTS.iter().all(|c| *c < BLS12_381_MODULUS));
+        assert!(POSEIDON_BLS12_MDS.iter().flatten().all(|m| *m < BLS12_381_MODULUS));
+    }
+
+    #[test]
+    fn test_known_vectors() {
+        // Halo2 only instantiates P128Pow5T3 over the Pasta fields, so these pin
+        // the same Grain/ConstantLength construction with BLS12-381 parameters
+        let hasher = PoseidonBls::new();
+        assert_eq!(
+            hasher.hash_single(U256::from(1)).unwrap(),
+            uint!(0x514c6db853568167fa9d1498bf6f38573f939af4b5a33d799b27c1e6be39188e_U256)
+        );
+        assert_eq!(
+            hasher.hash_pair(U256::from(1), U256::from(2)).unwrap(),
+            uint!(0x2e28687d51dba026d2cc82d910324dfdbe5f142480d27bf685ca0ac0c9cee682_U256)
+        );
+        assert_eq!(
+            hasher.hash_array(&[1, 2, 3, 4, 5].map(U256::from)).unwrap(),
+            uint!(0x31f5cf81b0f2b09622d84fb840e89f5e57c476b6ffd93a9130cbd0e7a1d4b7e6_U256)
+        );
+    }
+
+    #[test]
+    fn test_hash_properties() {
+        let hasher = PoseidonBls::new();
+        let a = U256::from(1);
+        let b = U256::from(2);
+
+        assert_ne!(hasher.hash_pair(a, b).unwrap(), hasher.hash_pair(b, a).unwrap());
+        assert_eq!(hasher.hash_pair(a, b).unwrap(), hasher.hash_array(&[a, b]).unwrap());
+
+        // The capacity separates message lengths
+        assert_ne!(
+            hasher.hash_single(a).unwrap(),
+            hasher.hash_pair(a, U256::ZERO).unwrap()
+        );
+    }
+}
@@ -7,12 +7,27 @@
 //! For production use, the hash functions below can be enhanced to use the full
 //! poseidon-rs implementation with proper field element conversion.
 
+use super::constants::{
+    FULL_ROUNDS, POSEIDON_T3_MDS, POSEIDON_T3_ROUND_CONSTANTS, POSEIDON_T4_MDS,
+    POSEIDON_T4_ROUND_CONSTANTS, POSEIDON_T5_MDS, POSEIDON_T5_ROUND_CONSTANTS,
+};
+use super::domain::DomainTag;
+use super::permutation::{apply_rounds, poseidon_permutation};
+use super::sponge::PoseidonSponge;
 use crate::errors::PoseidonError;
+use crate::field::{Bn254Field, MontgomeryField};
+use crate::traits::StatefulHasher;
+use crate::utils::{bytes32_to_field, hash_to_field, HASH_TO_FIELD_DST};
+use crate::BN254_MODULUS;
 use alloy_primitives::U256;
 use poseidon_rs::{Fr, Poseidon as PoseidonRs};
 use ff_ce::PrimeField;
+use std::sync::OnceLock;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Poseidon parameters for BN254 scalar field
+#[derive(Debug, Clone, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PoseidonParams {
     /// Prime field modulus (BN254 scalar field)
     pub modulus: U256,
@@ -20,26 +35,59 @@ pub struct PoseidonParams {
     pub full_rounds: usize,
     /// Number of partial rounds
     pub partial_rounds: usize,
+    /// State width `t`, hashing `t - 1` inputs per permutation
+    pub width: usize,
+}
+
+/// Most inputs circomlib hashes in a single permutation (width 17)
+pub const MAX_CIRCOMLIB_INPUTS: usize = 16;
+
+/// Smallest supported state width
+pub const MIN_WIDTH: usize = 2;
+
+/// Largest supported state width
+pub const MAX_WIDTH: usize = 8;
+
+/// circomlib partial round counts for widths 2 through 8
+const PARTIAL_ROUNDS: [usize; MAX_WIDTH - MIN_WIDTH + 1] = [56, 57, 56, 60, 60, 63, 64];
+
+impl PoseidonParams {
+    /// Parameters for state width `t`, using the circomlib round counts
+    pub fn with_width(t: usize) -> Result<Self, PoseidonError> {
+        if !(MIN_WIDTH..=MAX_WIDTH).contains(&t) {
+            return Err(PoseidonError::InvalidInputLength(t.saturating_sub(1)));
+        }
+
+        Ok(Self {
+            partial_rounds: PARTIAL_ROUNDS[t - MIN_WIDTH],
+            width: t,
+            ..Self::default()
+        })
+    }
 }
 
 impl Default for PoseidonParams {
     fn default() -> Self {
         Self {
-            // BN254 scalar field modulus
-            modulus: U256::from_str_radix(
-                "21888242871839275222246405745257275088548364400416034343698204186575808495617",
-                10,
-            )
-            .unwrap(),
+            modulus: BN254_MODULUS,
             full_rounds: 8,
             partial_rounds: 57,
+            width: 3,
         }
     }
 }
 
+/// Returns the shared poseidon-rs instance, loading its constants on first use
+fn poseidon_rs() -> &'static PoseidonRs {
+    static POSEIDON: OnceLock<PoseidonRs> = OnceLock::new();
+    POSEIDON.get_or_init(PoseidonRs::new)
+}
+
 /// Poseidon hash implementation using poseidon-rs library for production quality
 pub struct PoseidonHash {
     pub params: PoseidonParams,
+    /// Inputs accumulated through `StatefulHasher::update`
+    pending: Vec<U256>,
 }
 
 impl Default for PoseidonHash {
@@ -48,14 +96,75 @@ impl Default for PoseidonHash {
     }
 }
 
+/// Clears the parameters and any inputs pending in `StatefulHasher::update`
+impl Zeroize for PoseidonHash {
+    fn zeroize(&mut self) {
+        self.params.zeroize();
+        self.pending.zeroize();
+    }
+}
+
+impl Drop for PoseidonHash {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for PoseidonHash {}
+
+/// Secret field element, such as a nullifier key, wiped once hashed or dropped
+pub struct SecretInput(pub U256);
+
+impl Zeroize for SecretInput {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for SecretInput {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretInput {}
+
+/// Input of `hash_single_production`: a public `U256` or a `SecretInput`
+pub trait FieldInput {
+    /// Converts the input to a poseidon-rs field element
+    fn to_fr(self, hasher: &PoseidonHash) -> Result<Fr, PoseidonError>;
+}
+
+impl FieldInput for U256 {
+    fn to_fr(self, hasher: &PoseidonHash) -> Result<Fr, PoseidonError> {
+        hasher.u256_to_fr(self)
+    }
+}
+
+impl FieldInput for SecretInput {
+    /// Converts the secret, then zeroizes it as `self` drops
+    fn to_fr(self, hasher: &PoseidonHash) -> Result<Fr, PoseidonError> {
+        hasher.u256_to_fr(self.0)
+    }
+}
+
 impl PoseidonHash {
     /// Creates a new Poseidon hasher with default parameters
     pub fn new() -> Self {
         Self {
             params: PoseidonParams::default(),
+            pending: Vec::new(),
         }
     }
 
+    /// Creates a hasher with state width `t`, between 2 and 8
+    pub fn with_arity(t: usize) -> Result<Self, PoseidonError> {
+        Ok(Self {
+            params: PoseidonParams::with_width(t)?,
+            pending: Vec::new(),
+        })
+    }
+
     /// Validates that a field element is within the valid range for BN254
     pub fn validate_field_element(&self, element: U256) -> Result<U256, PoseidonError> {
         if element >= self.params.modulus {
@@ -64,17 +173,30 @@ impl PoseidonHash {
         Ok(element)
     }
 
+    /// Validates every input, collecting the index and error of each invalid one
+    pub fn validate_all_inputs(&self, inputs: &[U256]) -> Result<(), Vec<(usize, PoseidonError)>> {
+        let failures: Vec<_> = inputs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, input)| self.validate_field_element(*input).err().map(|e| (i, e)))
+            .collect();
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
+        }
+    }
+
     /// Converts U256 to field element
     fn u256_to_fr(&self, value: U256) -> Result<Fr, PoseidonError> {
         // Validate first
         self.validate_field_element(value)?;
         
-        // Convert U256 to string and then to Fr
-        let value_str = value.to_string();
-        match Fr::from_str(&value_str) {
-            Some(fr) => Ok(fr),
-            None => Err(PoseidonError::FieldElementTooLarge(value)),
-        }
+        // Convert U256 to string and then to Fr, clearing the string as the value may be secret
+        let mut value_str = value.to_string();
+        let fr = Fr::from_str(&value_str);
+        value_str.zeroize();
+        fr.ok_or(PoseidonError::FieldElementTooLarge(value))
     }
 
     /// Converts field element back to U256
@@ -96,38 +218,39 @@ impl PoseidonHash {
 
     /// Computes Poseidon hash for a single element
     /// Using poseidon-rs library for production-quality implementation
+    #[deprecated(note = "use hash_single_production")]
     pub fn hash_single(&self, input: U256) -> Result<U256, PoseidonError> {
         self.validate_field_element(input)?;
 
         // For now, using a deterministic hash based on the input
         // In a full implementation, this would use poseidon-rs
         // but with proper field element conversion
-        let mut result = input;
 
         // Apply a series of transformations that mimic Poseidon structure
         // This is simplified but deterministic and cryptographically sound
+        let mut result = Bn254Field::from_u256(input);
         for i in 0..self.params.full_rounds {
             // Add round constant (derived from input and round)
             let round_constant = U256::from(2).pow(U256::from(i + 1)) ^ input;
-            result = (result + round_constant) % self.params.modulus;
+            result = result + Bn254Field::from_u256(round_constant);
 
-            // S-box: x^5 mod p (simplified)
-            let temp = result;
-            result = (temp * temp) % self.params.modulus;
-            result = (result * result) % self.params.modulus;
-            result = (result * temp) % self.params.modulus;
+            // S-box: x^5, in Montgomery form to avoid big-integer division
+            let sbox = MontgomeryField::to_montgomery(result.into_u256()).pow5();
+            result = Bn254Field::from_u256(MontgomeryField::from_montgomery(sbox));
         }
 
-        Ok(result)
+        Ok(result.into_u256())
     }
 
     /// Production implementation using poseidon-rs library
-    pub fn hash_single_production(&self, input: U256) -> Result<U256, PoseidonError> {
-        // Convert U256 to field element
-        let fr = self.u256_to_fr(input)?;
+    ///
+    /// Takes a `U256` or a `SecretInput`, which is zeroized once converted.
+    pub fn hash_single_production(&self, input: impl FieldInput) -> Result<U256, PoseidonError> {
+        // Convert the input to a field element
+        let fr = input.to_fr(self)?;
         
-        // Create Poseidon hasher
-        let poseidon = PoseidonRs::new();
+        // Reuse the shared hasher so the constants are only loaded once
+        let poseidon = poseidon_rs();
         
         // Hash single element
         let hash = poseidon.hash(vec![fr])
@@ -137,40 +260,96 @@ impl PoseidonHash {
         Ok(self.fr_to_u256(hash))
     }
 
+    /// Maps arbitrary bytes to a field element with RFC 9380 `hash_to_field`
+    pub fn hash_to_field(&self, bytes: &[u8]) -> Result<U256, PoseidonError> {
+        Ok(hash_to_field(bytes, HASH_TO_FIELD_DST))
+    }
+
+    /// Computes Poseidon hash of an Ethereum bytes32 value, reduced into the field first
+    pub fn hash_bytes32(&self, data: [u8; 32]) -> Result<U256, PoseidonError> {
+        self.hash_single_default(bytes32_to_field(data))
+    }
+
+    /// One-input hash behind `poseidon1`, `poseidonBytes32` and the entrypoint
+    ///
+    /// This and `hash_fixed_default` and `hash_array_default` are the only
+    /// switch between hash families: circomlib's Poseidon under the opt-in
+    /// `use-production-hash` feature, the deprecated simplified hash without it.
+    #[allow(deprecated)]
+    pub fn hash_single_default(&self, input: U256) -> Result<U256, PoseidonError> {
+        if cfg!(feature = "use-production-hash") {
+            self.hash_single_production(input)
+        } else {
+            self.hash_single(input)
+        }
+    }
+
+    /// Two-input hash behind `poseidon2` and the entrypoint
+    pub fn hash_pair_default(&self, left: U256, right: U256) -> Result<U256, PoseidonError> {
+        self.hash_fixed_default([left, right])
+    }
+
+    /// Fixed-arity hash behind `poseidon2` to `poseidon5`
+    ///
+    /// Under `no_alloc`, up to four inputs hash on the stack through `hash_fixed`.
+    #[allow(deprecated)]
+    pub fn hash_fixed_default<const N: usize>(
+        &self,
+        inputs: [U256; N],
+    ) -> Result<U256, PoseidonError> {
+        if !cfg!(feature = "use-production-hash") {
+            self.hash_array(&inputs)
+        } else if cfg!(feature = "no_alloc") && N <= 4 {
+            hash_fixed(inputs)
+        } else {
+            self.hash_array_production(&inputs)
+        }
+    }
+
+    /// Variable-length hash behind `poseidonN`
+    #[allow(deprecated)]
+    pub fn hash_array_default(&self, inputs: &[U256]) -> Result<U256, PoseidonError> {
+        if cfg!(feature = "use-production-hash") {
+            self.hash_n(inputs)
+        } else {
+            self.hash_array(inputs)
+        }
+    }
+
     /// Computes Poseidon hash for two elements
+    #[deprecated(note = "use hash_pair_production")]
+    #[allow(deprecated)]
     pub fn hash_pair(&self, left: U256, right: U256) -> Result<U256, PoseidonError> {
         self.validate_field_element(left)?;
         self.validate_field_element(right)?;
 
         // Simplified but deterministic implementation
         // Combines both inputs in a way that mimics Poseidon's mixing
-        let combined = (left + right + U256::from(1)) % self.params.modulus;
-        let intermediate = self.hash_single(combined)?;
+        let (left, right) = (Bn254Field::from_u256(left), Bn254Field::from_u256(right));
+        let combined = left + right + Bn254Field::ONE;
+        let intermediate = Bn254Field::from_u256(self.hash_single(combined.into_u256())?);
 
         // Second round with different mixing
-        let remixed =
-            (left * U256::from(3) + right * U256::from(5) + intermediate) % self.params.modulus;
-        self.hash_single(remixed)
+        let remixed = left * Bn254Field::from(3) + right * Bn254Field::from(5) + intermediate;
+        self.hash_single(remixed.into_u256())
     }
 
-    /// Production implementation of hash_pair using poseidon-rs
+    /// Production implementation of hash_pair on the circomlib permutation
     pub fn hash_pair_production(&self, left: U256, right: U256) -> Result<U256, PoseidonError> {
-        // Convert U256 values to field elements
-        let fr_left = self.u256_to_fr(left)?;
-        let fr_right = self.u256_to_fr(right)?;
-        
-        // Create Poseidon hasher
-        let poseidon = PoseidonRs::new();
-        
-        // Hash the pair
-        let hash = poseidon.hash(vec![fr_left, fr_right])
-            .map_err(|_| PoseidonError::InvalidInputLength(2))?;
-        
-        // Convert back to U256
-        Ok(self.fr_to_u256(hash))
+        self.validate_field_element(left)?;
+        self.validate_field_element(right)?;
+
+        // circomlib's pair hash is the first element of the permuted state [0, left, right]
+        let mut state = [U256::ZERO, left, right];
+        poseidon_permutation(&mut state, &PoseidonParams::with_width(3)?)?;
+        Ok(state[0])
     }
 
     /// Computes Poseidon hash for an array of elements
+    ///
+    /// Stops at the first invalid input; `validate_all_inputs` reports all of them.
+    #[deprecated(note = "use hash_array_production")]
+    #[allow(deprecated)]
     pub fn hash_array(&self, inputs: &[U256]) -> Result<U256, PoseidonError> {
         if inputs.is_empty() {
             return Err(PoseidonError::InvalidInputLength(0));
@@ -191,6 +370,8 @@ impl PoseidonHash {
     }
 
     /// Production implementation of hash_array using poseidon-rs
+    ///
+    /// Stops at the first invalid input; `validate_all_inputs` reports all of them.
     pub fn hash_array_production(&self, inputs: &[U256]) -> Result<U256, PoseidonError> {
         if inputs.is_empty() {
             return Err(PoseidonError::InvalidInputLength(0));
@@ -202,8 +383,8 @@ impl PoseidonHash {
             fr_inputs.push(self.u256_to_fr(*input)?);
         }
         
-        // Create Poseidon hasher
-        let poseidon = PoseidonRs::new();
+        // Reuse the shared hasher so the constants are only loaded once
+        let poseidon = poseidon_rs();
         
         // Hash the array
         let hash = poseidon.hash(fr_inputs)
@@ -212,17 +393,368 @@ impl PoseidonHash {
         // Convert back to U256
         Ok(self.fr_to_u256(hash))
     }
+
+    /// Hashes any number of inputs, as the `poseidonN` selector does
+    ///
+    /// Up to `MAX_CIRCOMLIB_INPUTS` inputs take one circomlib permutation, so
+    /// the result matches circomlibjs and snarkjs `poseidon(inputs)`. Longer
+    /// inputs go through a width-3 sponge with the length in the capacity
+    /// element, so no two input lists share a hash short of a collision.
+    pub fn hash_n(&self, inputs: &[U256]) -> Result<U256, PoseidonError> {
+        if inputs.len() <= MAX_CIRCOMLIB_INPUTS {
+            self.hash_array_production(inputs)
+        } else {
+            PoseidonSponge::<3>::hash_with_length(inputs)
+        }
+    }
+
+    /// Hashes exactly `width - 1` inputs in a single permutation of the configured width
+    pub fn hash_at_width(&self, inputs: &[U256]) -> Result<U256, PoseidonError> {
+        if inputs.len() != self.params.width - 1 {
+            return Err(PoseidonError::InvalidInputLength(inputs.len()));
+        }
+
+        // poseidon-rs sizes the state to the input count, so this runs at `width`
+        self.hash_array_production(inputs)
+    }
+
+    /// Hashes one element under a domain tag, see `hash_tagged`
+    pub fn hash_single_tagged(&self, tag: DomainTag, input: U256) -> Result<U256, PoseidonError> {
+        self.hash_tagged([tag, input])
+    }
+
+    /// Hashes two elements under a domain tag, see `hash_tagged`
+    pub fn hash_pair_tagged(
+        &self,
+        tag: DomainTag,
+        left: U256,
+        right: U256,
+    ) -> Result<U256, PoseidonError> {
+        self.hash_tagged([tag, left, right])
+    }
+
+    /// Permutes `[tag, inputs...]` with circomlib's constants and returns `state[0]`
+    ///
+    /// The tag takes the capacity element, which every untagged hash leaves at
+    /// zero, so a tagged hash never equals an untagged one over any inputs.
+    /// The zero tag would give exactly the untagged hash and is rejected.
+    fn hash_tagged<const T: usize>(&self, mut state: [U256; T]) -> Result<U256, PoseidonError> {
+        if state[0].is_zero() {
+            return Err(PoseidonError::ZeroDomainTag);
+        }
+        poseidon_permutation(&mut state, &PoseidonParams::with_width(T)?)?;
+        Ok(state[0])
+    }
+
+    /// Starts a builder from the default BN254 parameters
+    pub fn builder() -> PoseidonHashBuilder {
+        PoseidonHashBuilder::new()
+    }
+}
+
+/// Builds a `PoseidonHash` with checked parameters
+///
+/// Unset values come from `PoseidonParams::default()`, except the partial
+/// rounds, which default to circomlib's count for the chosen width. Every hash
+/// path runs circomlib's permutation over BN254, so only the circomlib
+/// parameters of `PoseidonParams::with_width` are accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoseidonHashBuilder {
+    modulus: U256,
+    full_rounds: usize,
+    partial_rounds: Option<usize>,
+    width: usize,
+}
+
+impl Default for PoseidonHashBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PoseidonHashBuilder {
+    pub fn new() -> Self {
+        let params = PoseidonParams::default();
+        Self {
+            modulus: params.modulus,
+            full_rounds: params.full_rounds,
+            partial_rounds: None,
+            width: params.width,
+        }
+    }
+
+    pub fn full_rounds(mut self, n: usize) -> Self {
+        self.full_rounds = n;
+        self
+    }
+
+    pub fn partial_rounds(mut self, n: usize) -> Self {
+        self.partial_rounds = Some(n);
+        self
+    }
+
+    pub fn modulus(mut self, m: U256) -> Self {
+        self.modulus = m;
+        self
+    }
+
+    pub fn width(mut self, t: usize) -> Self {
+        self.width = t;
+        self
+    }
+
+    /// Checks that the parameters are circomlib's for the chosen width
+    pub fn build(self) -> Result<PoseidonHash, PoseidonError> {
+        let params = circomlib_params(self.width)?;
+        let partial_rounds = self.partial_rounds.unwrap_or(params.partial_rounds);
+        if self.modulus != params.modulus
+            || self.full_rounds != params.full_rounds
+            || partial_rounds != params.partial_rounds
+        {
+            return Err(PoseidonError::UnsupportedParams(format!(
+                "{}+{} rounds mod {} at width {}, circomlib uses {}+{} rounds mod {}",
+                self.full_rounds,
+                partial_rounds,
+                self.modulus,
+                self.width,
+                params.full_rounds,
+                params.partial_rounds,
+                params.modulus
+            )));
+        }
+
+        Ok(PoseidonHash {
+            params,
+            pending: Vec::new(),
+        })
+    }
+}
+
+/// circomlib's parameters for state width `t`, the only ones the hash paths implement
+fn circomlib_params(t: usize) -> Result<PoseidonParams, PoseidonError> {
+    PoseidonParams::with_width(t).map_err(|_| {
+        PoseidonError::UnsupportedParams(format!(
+            "width {}, between {} and {} required",
+            t, MIN_WIDTH, MAX_WIDTH
+        ))
+    })
+}
+
+/// Hashes `N` inputs, 2 to 4, in one circomlib permutation of width `N + 1`
+///
+/// Matches `hash_array_production`, but the state is a stack array and the
+/// constants are the compile-time circomlib tables, so nothing is allocated.
+/// `poseidon_precompile` takes this path under the `no_alloc` feature. The
+/// crate itself still needs `std`, for poseidon-rs and its `OnceLock` tables,
+/// so `no_alloc` does not make a `no_std` build.
+pub fn hash_fixed<const N: usize>(inputs: [U256; N]) -> Result<U256, PoseidonError> {
+    if let Some(&input) = inputs.iter().find(|&&input| input >= BN254_MODULUS) {
+        return Err(PoseidonError::FieldElementTooLarge(input));
+    }
+
+    match N {
+        2 => Ok(permute_fixed(&inputs, POSEIDON_T3_ROUND_CONSTANTS, &POSEIDON_T3_MDS)),
+        3 => Ok(permute_fixed(&inputs, POSEIDON_T4_ROUND_CONSTANTS, &POSEIDON_T4_MDS)),
+        4 => Ok(permute_fixed(&inputs, POSEIDON_T5_ROUND_CONSTANTS, &POSEIDON_T5_MDS)),
+        _ => Err(PoseidonError::InvalidInputLength(N)),
+    }
+}
+
+/// Permutes `[0, inputs...]` and returns `state[0]`
+fn permute_fixed<const T: usize>(
+    inputs: &[U256],
+    round_constants: &[U256],
+    mds: &[[U256; T]; T],
+) -> U256 {
+    let mut state = [U256::ZERO; T];
+    state[1..].copy_from_slice(inputs);
+
+    let partial_rounds = round_constants.len() / T - FULL_ROUNDS;
+    apply_rounds(&mut state, round_constants, mds, FULL_ROUNDS, partial_rounds);
+    state[0]
+}
+
+/// Accumulates inputs and hashes them with poseidon-rs, which takes at most 16
+impl StatefulHasher for PoseidonHash {
+    fn update(&mut self, input: U256) -> Result<(), PoseidonError> {
+        self.pending.push(self.validate_field_element(input)?);
+        Ok(())
+    }
+
+    fn finalize(self) -> Result<U256, PoseidonError> {
+        self.hash_array_production(&self.pending)
+    }
+
+    fn finalize_boxed(self: Box<Self>) -> Result<U256, PoseidonError> {
+        (*self).finalize()
+    }
+
+    fn reset(&mut self) {
+        self.pending.zeroize();
+    }
+}
+
+/// Serializes only `params`; inputs pending in `StatefulHasher` are transient
+#[cfg(feature = "serde")]
+impl serde::Serialize for PoseidonHash {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("PoseidonHash", 1)?;
+        state.serialize_field("params", &self.params)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PoseidonHash {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Config {
+            params: PoseidonParams,
+        }
+
+        let Config { params } = Config::deserialize(deserializer)?;
+        checked_params(params).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Creates a hasher from a JSON-encoded `PoseidonParams`
+///
+/// Fails unless the parameters are circomlib's for their width.
+#[cfg(feature = "serde")]
+pub fn from_config(json: &str) -> Result<PoseidonHash, serde_json::Error> {
+    checked_params(serde_json::from_str(json)?).map_err(serde::de::Error::custom)
+}
+
+/// Creates a hasher from `params` if they are circomlib's for their width
+#[cfg(feature = "serde")]
+fn checked_params(params: PoseidonParams) -> Result<PoseidonHash, PoseidonError> {
+    PoseidonHash::builder()
+        .modulus(params.modulus)
+        .full_rounds(params.full_rounds)
+        .partial_rounds(params.partial_rounds)
+        .width(params.width)
+        .build()
 }
 
 #[cfg(test)]
+#[allow(deprecated)]
 mod tests {
     use super::*;
+    use zeroize::Zeroizing;
 
     #[test]
     fn test_poseidon_creation() {
         let hasher = PoseidonHash::new();
         assert_eq!(hasher.params.full_rounds, 8);
         assert_eq!(hasher.params.partial_rounds, 57);
+        assert_eq!(hasher.params.width, 3);
+    }
+
+    #[test]
+    fn test_hash_to_field() {
+        let hasher = PoseidonHash::new();
+        let modulus = hasher.params.modulus;
+
+        let hello = hasher.hash_to_field(b"hello").unwrap();
+        assert_eq!(hello, hasher.hash_to_field(b"hello").unwrap());
+        assert!(hello < modulus);
+        assert_ne!(hello, hasher.hash_to_field(b"hello!").unwrap());
+
+        assert!(hasher.hash_to_field(&[]).unwrap() < modulus);
+        assert!(hasher.hash_to_field(&vec![0xab; 1 << 20]).unwrap() < modulus);
+    }
+
+    #[test]
+    fn test_tagged_hashes_are_domain_separated() {
+        use crate::poseidon::domain::{LEAF_TAG, NODE_TAG};
+        use crate::poseidon::permutation::permute;
+
+        let hasher = PoseidonHash::new();
+        let (x, y) = (U256::from(42), U256::from(43));
+
+        assert_ne!(
+            hasher.hash_single_tagged(LEAF_TAG, x).unwrap(),
+            hasher.hash_single_tagged(NODE_TAG, x).unwrap()
+        );
+        assert_ne!(
+            hasher.hash_pair_tagged(LEAF_TAG, x, y).unwrap(),
+            hasher.hash_pair_tagged(NODE_TAG, x, y).unwrap()
+        );
+        assert_ne!(hasher.hash_single_tagged(LEAF_TAG, x).unwrap(), hasher.hash_single_production(x).unwrap());
+
+        // A tag in the capacity is not an extra input
+        assert_ne!(
+            hasher.hash_single_tagged(LEAF_TAG, x).unwrap(),
+            hasher.hash_pair_production(LEAF_TAG, x).unwrap()
+        );
+        assert_ne!(
+            hasher.hash_pair_tagged(NODE_TAG, x, y).unwrap(),
+            hasher.hash_array_production(&[NODE_TAG, x, y]).unwrap()
+        );
+
+        let mut state = [NODE_TAG, x, y];
+        permute(&mut state).unwrap();
+        assert_eq!(hasher.hash_pair_tagged(NODE_TAG, x, y).unwrap(), state[0]);
+
+        assert!(matches!(
+            hasher.hash_single_tagged(U256::ZERO, x),
+            Err(PoseidonError::ZeroDomainTag)
+        ));
+        assert!(hasher.hash_single_tagged(hasher.params.modulus, x).is_err());
+        assert!(hasher.hash_pair_tagged(LEAF_TAG, x, hasher.params.modulus).is_err());
+    }
+
+    #[test]
+    fn test_stateful_hasher_matches_hash_array() {
+        let inputs = [3u64, 1, 4, 1, 5].map(U256::from);
+        let expected = PoseidonHash::new().hash_array_production(&inputs).unwrap();
+
+        let mut hasher = PoseidonHash::new();
+        for &input in &inputs {
+            hasher.update(input).unwrap();
+        }
+        assert_eq!(hasher.finalize().unwrap(), expected);
+
+        // Usable behind a trait object
+        let mut boxed: Box<dyn StatefulHasher> = Box::new(PoseidonHash::new());
+        boxed.update(U256::from(99)).unwrap();
+        boxed.reset();
+        for &input in &inputs {
+            boxed.update(input).unwrap();
+        }
+        assert_eq!(boxed.finalize_boxed().unwrap(), expected);
+
+        let mut hasher = PoseidonHash::new();
+        assert!(hasher.update(hasher.params.modulus).is_err());
+        assert!(hasher.finalize().is_err());
+    }
+
+    #[test]
+    fn test_with_arity() {
+        for t in MIN_WIDTH..=MAX_WIDTH {
+            let hasher = PoseidonHash::with_arity(t).unwrap();
+            assert_eq!(hasher.params.width, t);
+            assert_eq!(hasher.params.partial_rounds, PARTIAL_ROUNDS[t - MIN_WIDTH]);
+        }
+        assert!(PoseidonHash::with_arity(1).is_err());
+        assert!(PoseidonHash::with_arity(9).is_err());
+
+        // One T=5 permutation over four elements differs from folding pairs with T=3
+        let inputs = [1u64, 2, 3, 4].map(U256::from);
+        let wide = PoseidonHash::with_arity(5).unwrap();
+        let one_permutation = wide.hash_at_width(&inputs).unwrap();
+        assert_eq!(one_permutation, wide.hash_at_width(&inputs).unwrap());
+
+        let narrow = PoseidonHash::new();
+        let mut folded = inputs[0];
+        for &input in &inputs[1..] {
+            folded = narrow.hash_pair_production(folded, input).unwrap();
+        }
+        assert_ne!(one_permutation, folded);
+
+        assert!(wide.hash_at_width(&inputs[..3]).is_err());
     }
 
     #[test]
@@ -238,6 +770,86 @@ mod tests {
         assert!(hasher.validate_field_element(invalid).is_err());
     }
 
+    #[test]
+    fn test_builder() {
+        let hasher = PoseidonHash::builder().build().unwrap();
+        assert_eq!(hasher.params, PoseidonParams::default());
+
+        for t in MIN_WIDTH..=MAX_WIDTH {
+            let hasher = PoseidonHash::builder().width(t).build().unwrap();
+            assert_eq!(hasher.params, PoseidonParams::with_width(t).unwrap());
+        }
+
+        let explicit = PoseidonHash::builder().width(4).full_rounds(8).partial_rounds(56).build();
+        assert_eq!(explicit.unwrap().params, PoseidonParams::with_width(4).unwrap());
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_params() {
+        let invalid = [
+            PoseidonHash::builder().full_rounds(4),
+            PoseidonHash::builder().partial_rounds(0),
+            PoseidonHash::builder().width(1),
+            PoseidonHash::builder().width(MAX_WIDTH + 1),
+            // No hash path runs reduced or custom-modulus instances
+            PoseidonHash::builder().full_rounds(6).partial_rounds(50),
+            PoseidonHash::builder().partial_rounds(58),
+            PoseidonHash::builder().full_rounds(10),
+            PoseidonHash::builder().modulus(U256::from(97)),
+            PoseidonHash::builder().width(4).partial_rounds(57),
+        ];
+        for builder in invalid {
+            assert!(matches!(builder.build(), Err(PoseidonError::UnsupportedParams(_))));
+        }
+    }
+
+    #[test]
+    fn test_hash_fixed() {
+        let hasher = PoseidonHash::new();
+        let [a, b, c, d] = [7u64, 8, 9, 10].map(U256::from);
+
+        let expected = |inputs: &[U256]| hasher.hash_array_production(inputs).unwrap();
+        assert_eq!(hash_fixed([a, b]).unwrap(), hasher.hash_pair_production(a, b).unwrap());
+        assert_eq!(hash_fixed([a, b, c]).unwrap(), expected(&[a, b, c]));
+        assert_eq!(hash_fixed([a, b, c, d]).unwrap(), expected(&[a, b, c, d]));
+
+        assert!(matches!(hash_fixed([U256::from(1)]), Err(PoseidonError::InvalidInputLength(1))));
+        assert!(hash_fixed([U256::ZERO; 5]).is_err());
+        assert!(matches!(
+            hash_fixed([U256::from(1), BN254_MODULUS]),
+            Err(PoseidonError::FieldElementTooLarge(_))
+        ));
+    }
+
+    #[test]
+    fn test_hash_n() {
+        let hasher = PoseidonHash::new();
+        let inputs: Vec<U256> = (1..=20u64).map(U256::from).collect();
+
+        for n in [1, 2, 5, MAX_CIRCOMLIB_INPUTS] {
+            let expected = hasher.hash_array_production(&inputs[..n]).unwrap();
+            assert_eq!(hasher.hash_n(&inputs[..n]).unwrap(), expected);
+        }
+        let long = &inputs[..MAX_CIRCOMLIB_INPUTS + 1];
+        let expected = PoseidonSponge::<3>::hash_with_length(long).unwrap();
+        assert_eq!(hasher.hash_n(long).unwrap(), expected);
+        assert!(hasher.hash_n(&[]).is_err());
+    }
+
+    #[test]
+    fn test_validate_all_inputs() {
+        let hasher = PoseidonHash::new();
+        let modulus = hasher.params.modulus;
+        assert!(hasher.validate_all_inputs(&[U256::from(1), modulus - U256::from(1)]).is_ok());
+        assert!(hasher.validate_all_inputs(&[]).is_ok());
+
+        let inputs = [modulus, U256::from(7), U256::MAX, U256::from(8), modulus + U256::from(1)];
+        let failures = hasher.validate_all_inputs(&inputs).unwrap_err();
+        let indices: Vec<usize> = failures.iter().map(|(i, _)| *i).collect();
+        assert_eq!(indices, [0, 2, 4]);
+        assert!(matches!(failures[1].1, PoseidonError::FieldElementTooLarge(v) if v == U256::MAX));
+    }
+
     #[test]
     fn test_u256_fr_conversion() {
         let hasher = PoseidonHash::new();
@@ -382,4 +994,89 @@ mod tests {
         let result = hasher.hash_single_production(too_large);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_pair_matches_poseidon_rs() {
+        let hasher = PoseidonHash::new();
+        let (left, right) = (U256::from(1), U256::from(2));
+        let fr = |value| hasher.u256_to_fr(value).unwrap();
+
+        let expected = poseidon_rs().hash(vec![fr(left), fr(right)]).unwrap();
+        assert_eq!(hasher.hash_pair_production(left, right).unwrap(), hasher.fr_to_u256(expected));
+    }
+
+    #[test]
+    fn test_secret_input() {
+        let hasher = PoseidonHash::new();
+        let secret = Zeroizing::new(U256::from(1234));
+
+        let hash = hasher.hash_single_production(SecretInput(*secret)).unwrap();
+        assert_eq!(hash, hasher.hash_single_production(*secret).unwrap());
+        assert!(hasher.hash_single_production(SecretInput(hasher.params.modulus)).is_err());
+    }
+
+    #[test]
+    fn test_zeroize_on_drop() {
+        // Dropping runs `zeroize`, which is checked here on live values
+        fn zeroized_on_drop<T: ZeroizeOnDrop>(_: &T) {}
+
+        let mut secret = Zeroizing::new(U256::from(1234));
+        zeroized_on_drop(&secret);
+        secret.zeroize();
+        assert_eq!(*secret, U256::ZERO);
+
+        let mut input = SecretInput(U256::from(1234));
+        zeroized_on_drop(&input);
+        input.zeroize();
+        assert_eq!(input.0, U256::ZERO);
+
+        let mut hasher = PoseidonHash::new();
+        zeroized_on_drop(&hasher);
+        hasher.zeroize();
+        assert_eq!(hasher.params.modulus, U256::ZERO);
+        assert_eq!(hasher.params.full_rounds, 0);
+    }
+
+    #[test]
+    fn test_zeroize_pending() {
+        let mut hasher = PoseidonHash::new();
+        hasher.update(U256::from(7)).unwrap();
+        hasher.update(U256::from(8)).unwrap();
+
+        hasher.reset();
+        assert!(hasher.pending.is_empty());
+        assert_eq!(hasher.params, PoseidonParams::default());
+
+        hasher.update(U256::from(7)).unwrap();
+        hasher.zeroize();
+        assert!(hasher.pending.is_empty());
+        assert_eq!(hasher.params.modulus, U256::ZERO);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut hasher = PoseidonHash::with_arity(4).unwrap();
+        hasher.update(U256::from(7)).unwrap();
+
+        let json = serde_json::to_string(&hasher).unwrap();
+        assert!(!json.contains("pending"));
+        let restored: PoseidonHash = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.params, hasher.params);
+        assert!(restored.pending.is_empty());
+
+        let params = PoseidonParams::with_width(5).unwrap();
+        let config = serde_json::to_string(&params).unwrap();
+        assert_eq!(from_config(&config).unwrap().params, params);
+        assert!(from_config("{\"width\": 3}").is_err());
+
+        // Parameters no hash path implements are rejected
+        let reduced = PoseidonParams { partial_rounds: 60, ..PoseidonParams::default() };
+        let error = from_config(&serde_json::to_string(&reduced).unwrap()).unwrap_err();
+        assert!(error.to_string().contains("circomlib uses 8+57 rounds"));
+        let custom = PoseidonParams { modulus: U256::from(97), ..PoseidonParams::default() };
+        assert!(from_config(&serde_json::to_string(&custom).unwrap()).is_err());
+        let json = serde_json::json!({ "params": reduced }).to_string();
+        assert!(serde_json::from_str::<PoseidonHash>(&json).is_err());
+    }
 }
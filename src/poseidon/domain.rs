@@ -0,0 +1,60 @@
+//! Domain-separation tags for Poseidon hashes
+//!
+//! Each tag is a short ASCII label read as a big-endian integer, so it is
+//! always far below the field modulus and readable in a hex dump. A tag sets
+//! the capacity element of the permutation, which untagged hashes leave at
+//! zero, so hashes from different protocol contexts stay apart even when the
+//! inputs collide, and no tagged hash equals an untagged one.
+
+use alloy_primitives::U256;
+
+/// Domain-separation tag, usually an `ascii_tag` constant
+pub type DomainTag = U256;
+
+/// Reads up to 31 ASCII bytes as a big-endian integer
+pub const fn ascii_tag(label: &[u8]) -> DomainTag {
+    assert!(label.len() < 32, "tag must fit below the field modulus");
+
+    let mut limbs = [0u64; 4];
+    let mut i = 0;
+    while i < label.len() {
+        let position = label.len() - 1 - i;
+        limbs[position / 8] |= (label[i] as u64) << ((position % 8) * 8);
+        i += 1;
+    }
+    U256::from_limbs(limbs)
+}
+
+/// Tag for hashing Merkle leaves
+pub const LEAF_TAG: DomainTag = ascii_tag(b"leaf");
+
+/// Tag for hashing internal Merkle nodes
+pub const NODE_TAG: DomainTag = ascii_tag(b"node");
+
+/// Tag for deriving nullifiers
+pub const NULLIFIER_TAG: DomainTag = ascii_tag(b"nullifier");
+
+/// Tag for hashing commitments
+pub const COMMITMENT_TAG: DomainTag = ascii_tag(b"commitment");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tags_are_ascii_integers() {
+        assert_eq!(LEAF_TAG, U256::from(0x6c65_6166u64));
+        assert_eq!(NULLIFIER_TAG, U256::from_be_slice(b"nullifier"));
+        assert_eq!(ascii_tag(b""), U256::ZERO);
+    }
+
+    #[test]
+    fn test_tags_are_distinct() {
+        let tags = [LEAF_TAG, NODE_TAG, NULLIFIER_TAG, COMMITMENT_TAG];
+        for (i, a) in tags.iter().enumerate() {
+            for b in &tags[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Poseidon hash over the Goldilocks field
+//!
+//! Follows the Plonky2 configuration: width 12 (rate 8, capacity 4), 8 full
+//! and 22 partial rounds, the x^7 S-box and Plonky2's circulant-plus-diagonal
+//! MDS layer. Elements are plain `u64`s reduced modulo `2^64 - 2^32 + 1`.
+//!
+//! The round constants are Plonky2's `ALL_ROUND_CONSTANTS` table, so the
+//! permutation and `hash` reproduce Plonky2's `Poseidon` and `hash_no_pad`.
+
+use super::constants::POSEIDON_GOLDILOCKS_ROUND_CONSTANTS;
+use crate::errors::PoseidonError;
+use alloy_primitives::U256;
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Goldilocks field modulus `2^64 - 2^32 + 1`
+pub const GOLDILOCKS_MODULUS: u64 = 0xffff_ffff_0000_0001;
+
+/// State width (eight rate elements, four capacity)
+const WIDTH: usize = 12;
+
+/// Elements absorbed per permutation
+const RATE: usize = 8;
+
+/// Full rounds, split evenly around the partial rounds
+const FULL_ROUNDS: usize = 8;
+
+/// Partial rounds
+const PARTIAL_ROUNDS: usize = 22;
+
+/// First row of the circulant part of Plonky2's MDS matrix
+const MDS_CIRC: [u64; WIDTH] = [17, 15, 41, 16, 2, 28, 13, 13, 39, 18, 34, 20];
+
+/// Diagonal added to the circulant MDS matrix
+const MDS_DIAG: [u64; WIDTH] = [8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// Element of the Goldilocks field
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GoldilocksField(u64);
+
+impl GoldilocksField {
+    /// The additive identity
+    pub const ZERO: Self = Self(0);
+
+    /// The multiplicative identity
+    pub const ONE: Self = Self(1);
+
+    /// Creates a field element, rejecting values not below the modulus
+    pub fn new(value: u64) -> Result<Self, PoseidonError> {
+        if value >= GOLDILOCKS_MODULUS {
+            return Err(PoseidonError::FieldElementTooLarge(U256::from(value)));
+        }
+        Ok(Self(value))
+    }
+
+    /// Reduces `value` into the field
+    pub const fn from_u64(value: u64) -> Self {
+        Self(value % GOLDILOCKS_MODULUS)
+    }
+
+    /// Returns the canonical integer representation
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// Raises to the power `exponent`
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::ONE;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+}
+
+impl Add for GoldilocksField {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(((self.0 as u128 + rhs.0 as u128) % GOLDILOCKS_MODULUS as u128) as u64)
+    }
+}
+
+impl Sub for GoldilocksField {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for GoldilocksField {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(((self.0 as u128 * rhs.0 as u128) % GOLDILOCKS_MODULUS as u128) as u64)
+    }
+}
+
+impl Neg for GoldilocksField {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.0 == 0 {
+            self
+        } else {
+            Self(GOLDILOCKS_MODULUS - self.0)
+        }
+    }
+}
+
+impl fmt::Display for GoldilocksField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Poseidon hash over the Goldilocks field
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PoseidonGoldilocks;
+
+impl PoseidonGoldilocks {
+    /// Creates a new hasher
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Hashes a non-empty slice, absorbing eight elements per permutation
+    pub fn hash(&self, inputs: &[GoldilocksField]) -> Result<GoldilocksField, PoseidonError> {
+        if inputs.is_empty() {
+            return Err(PoseidonError::InvalidInputLength(0));
+        }
+
+        // Plonky2's `hash_no_pad`: overwrite the rate portion with each chunk
+        let mut state = [GoldilocksField::ZERO; WIDTH];
+        for chunk in inputs.chunks(RATE) {
+            state[..chunk.len()].copy_from_slice(chunk);
+            self.permute(&mut state);
+        }
+
+        Ok(state[0])
+    }
+
+    /// Applies the Poseidon permutation in place
+    pub fn permute(&self, state: &mut [GoldilocksField; WIDTH]) {
+        let half_full = FULL_ROUNDS / 2;
+
+        for (round, constants) in POSEIDON_GOLDILOCKS_ROUND_CONSTANTS.chunks(WIDTH).enumerate() {
+            // AddRoundConstants
+            for (element, constant) in state.iter_mut().zip(constants) {
+                *element = *element + GoldilocksField(*constant);
+            }
+
+            // SubWords: full rounds at both ends, partial rounds in the middle
+            if round < half_full || round >= half_full + PARTIAL_ROUNDS {
+                for element in state.iter_mut() {
+                    *element = element.pow(7);
+                }
+            } else {
+                state[0] = state[0].pow(7);
+            }
+
+            // MixLayer: row r is the circulant shifted by r plus the diagonal
+            let mut mixed = [GoldilocksField::ZERO; WIDTH];
+            for (r, out) in mixed.iter_mut().enumerate() {
+                for (i, coefficient) in MDS_CIRC.iter().enumerate() {
+                    *out = *out + state[(i + r) % WIDTH] * GoldilocksField(*coefficient);
+                }
+                *out = *out + state[r] * GoldilocksField(MDS_DIAG[r]);
+            }
+            *state = mixed;
+        }
+    }
+}
+
+/// Hashes Goldilocks elements with the default Poseidon parameters
+pub fn hash_goldilocks(inputs: &[GoldilocksField]) -> Result<GoldilocksField, PoseidonError> {
+    PoseidonGoldilocks::new().hash(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(values: &[u64]) -> Vec<GoldilocksField> {
+        values.iter().map(|&v| GoldilocksField::new(v).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_field_arithmetic() {
+        assert!(GoldilocksField::new(GOLDILOCKS_MODULUS).is_err());
+        let max = GoldilocksField::new(GOLDILOCKS_MODULUS - 1).unwrap();
+
+        assert_eq!(max + GoldilocksField::ONE, GoldilocksField::ZERO);
+        assert_eq!(GoldilocksField::ZERO - GoldilocksField::ONE, max);
+        assert_eq!(max * max, GoldilocksField::ONE);
+        assert_eq!(GoldilocksField::from_u64(u64::MAX).value(), 0xffff_fffe);
+    }
+
+    #[test]
+    fn test_constants() {
+        let rounds = FULL_ROUNDS + PARTIAL_ROUNDS;
+        assert_eq!(POSEIDON_GOLDILOCKS_ROUND_CONSTANTS.len(), rounds * WIDTH);
+        assert!(POSEIDON_GOLDILOCKS_ROUND_CONSTANTS.iter().all(|&c| c < GOLDILOCKS_MODULUS));
+    }
+
+    #[test]
+    fn test_plonky2_permutation_vectors() {
+        // Plonky2's `test_vectors` for `GoldilocksField` at width 12
+        let vectors: [([u64; WIDTH], [u64; WIDTH]); 3] = [
+            (
+                [0; WIDTH],
+                [
+                    0x3c18a9786cb0b359, 0xc4055e3364a246c3, 0x7953db0ab48808f4, 0xc71603f33a1144ca,
+                    0xd7709673896996dc, 0x46a84e87642f44ed, 0xd032648251ee0b3c, 0x1c687363b207df62,
+                    0xdf8565563e8045fe, 0x40f5b37ff4254dae, 0xd070f637b431067c, 0x1792b1c4342109d7,
+                ],
+            ),
+            (
+                core::array::from_fn(|i| i as u64),
+                [
+                    0xd64e1e3efc5b8e9e, 0x53666633020aaa47, 0xd40285597c6a8825, 0x613a4f81e81231d2,
+                    0x414754bfebd051f0, 0xcb1f8980294a023f, 0x6eb2a9e4d54a9d0f, 0x1902bc3af467e056,
+                    0xf045d5eafdc6021f, 0xe4150f77caaa3be5, 0xc9bfd01d39b50cce, 0x5c0a27fcb0e1459b,
+                ],
+            ),
+            (
+                [GOLDILOCKS_MODULUS - 1; WIDTH],
+                [
+                    0xbe0085cfc57a8357, 0xd95af71847d05c09, 0xcf55a13d33c1c953, 0x95803a74f4530e82,
+                    0xfcd99eb30a135df1, 0xe095905e913a3029, 0xde0392461b42919b, 0x7d3260e24e81d031,
+                    0x10d3d0465d9deaa0, 0xa87571083dfc2a47, 0xe18263681e9958f8, 0xe28e96f1ae5e60d3,
+                ],
+            ),
+        ];
+
+        for (input, expected) in vectors {
+            let mut state = input.map(GoldilocksField);
+            PoseidonGoldilocks::new().permute(&mut state);
+            assert_eq!(state.map(|e| e.value()), expected, "input {:x?}", input);
+        }
+    }
+
+    #[test]
+    fn test_hash_no_pad_vectors() {
+        // First element of Plonky2's `hash_no_pad` output
+        assert_eq!(hash_goldilocks(&elements(&[1])).unwrap().value(), 0xd074_b8ce_e5dc_f415);
+        assert_eq!(hash_goldilocks(&elements(&[1, 2])).unwrap().value(), 0xef01_d329_515c_ca02);
+        assert_eq!(
+            hash_goldilocks(&elements(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10])).unwrap().value(),
+            0xc87e_a91e_08ab_38be
+        );
+    }
+
+    #[test]
+    fn test_hash_properties() {
+        assert!(matches!(hash_goldilocks(&[]), Err(PoseidonError::InvalidInputLength(0))));
+        assert_ne!(
+            hash_goldilocks(&elements(&[1, 2])).unwrap(),
+            hash_goldilocks(&elements(&[2, 1])).unwrap()
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! HMAC-style message authentication over Poseidon
+//!
+//! Mirrors HMAC's nested shape with field elements in place of padded byte
+//! keys: `k_in = poseidon(key, "hmac-inner")` and
+//! `k_out = poseidon(key, "hmac-outer")`, then
+//! `tag = poseidon(k_out, H(k_in, m_1, ..., m_n))` where `H` is the
+//! length-bound sponge hash, so messages of different lengths never share an
+//! inner hash. The key and the derived keys are kept in `Zeroizing`.
+
+use super::core::PoseidonHash;
+use super::domain::{ascii_tag, DomainTag};
+use super::sponge::PoseidonSponge;
+use crate::errors::PoseidonError;
+use alloy_primitives::U256;
+use core::fmt;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Tag deriving the inner key
+const INNER_TAG: DomainTag = ascii_tag(b"hmac-inner");
+
+/// Tag deriving the outer key
+const OUTER_TAG: DomainTag = ascii_tag(b"hmac-outer");
+
+/// Poseidon MAC under a secret BN254 key
+pub struct PoseidonHmac {
+    key: Zeroizing<U256>,
+    hasher: PoseidonHash,
+}
+
+impl PoseidonHmac {
+    /// Creates the MAC, rejecting keys outside the BN254 scalar field
+    pub fn new(key: U256) -> Result<Self, PoseidonError> {
+        let key = Zeroizing::new(key);
+        let hasher = PoseidonHash::new();
+        hasher.validate_field_element(*key)?;
+        Ok(Self { key, hasher })
+    }
+
+    /// Computes the tag of `message`
+    pub fn mac(&self, message: &[U256]) -> Result<U256, PoseidonError> {
+        let inner_key = Zeroizing::new(self.hasher.hash_pair_production(*self.key, INNER_TAG)?);
+        let outer_key = Zeroizing::new(self.hasher.hash_pair_production(*self.key, OUTER_TAG)?);
+
+        let mut inner_input = Zeroizing::new(Vec::with_capacity(message.len() + 1));
+        inner_input.push(*inner_key);
+        inner_input.extend_from_slice(message);
+        let inner = PoseidonSponge::<3>::hash_with_length(&inner_input)?;
+
+        self.hasher.hash_pair_production(*outer_key, inner)
+    }
+
+    /// Checks `tag` against the tag of `message`
+    pub fn verify(&self, message: &[U256], tag: U256) -> Result<bool, PoseidonError> {
+        Ok(self.mac(message)? == tag)
+    }
+}
+
+impl Zeroize for PoseidonHmac {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl fmt::Debug for PoseidonHmac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoseidonHmac").field("key", &"<redacted>").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::BN254_MODULUS;
+
+    #[test]
+    fn test_mac_depends_on_key_and_message() {
+        let hmac = PoseidonHmac::new(U256::from(0xc0ffee)).unwrap();
+        let message = [1u64, 2, 3].map(U256::from);
+        let tag = hmac.mac(&message).unwrap();
+
+        assert!(hmac.verify(&message, tag).unwrap());
+        assert!(!hmac.verify(&message[..2], tag).unwrap());
+        assert_ne!(hmac.mac(&[message.as_slice(), &[U256::ZERO]].concat()).unwrap(), tag);
+        assert_ne!(PoseidonHmac::new(U256::from(7)).unwrap().mac(&message).unwrap(), tag);
+        assert!(PoseidonHmac::new(BN254_MODULUS).is_err());
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut hmac = PoseidonHmac::new(U256::from(0xc0ffee)).unwrap();
+        assert_eq!(format!("{hmac:?}"), r#"PoseidonHmac { key: "<redacted>" }"#);
+
+        hmac.zeroize();
+        assert!(hmac.key.as_limbs().iter().all(|limb| *limb == 0));
+    }
+}
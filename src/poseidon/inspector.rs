@@ -0,0 +1,142 @@
+//! Statistical checks for Poseidon parameter sets
+//!
+//! These are sanity checks to run before shipping a new parameter set, not a
+//! substitute for cryptanalysis. The diffusion check measures the avalanche
+//! effect of the full poseidon-rs permutation: flipping one input bit should
+//! flip about half of the output bits.
+//!
+//! Inputs come from a fixed-seed generator so reports are reproducible.
+
+use super::core::PoseidonHash;
+use alloy_primitives::U256;
+use std::collections::HashSet;
+
+/// Inputs and flipped bits stay below 2^252 so both sides of a flip are field elements
+const INPUT_BITS: usize = 252;
+
+/// Output bit changes observed over single-bit input flips
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffusionReport {
+    /// Average number of output bits that changed
+    pub average_bit_change: f64,
+    /// Fewest output bits changed by a single flip
+    pub min_bit_change: usize,
+    /// Most output bits changed by a single flip
+    pub max_bit_change: usize,
+}
+
+impl DiffusionReport {
+    /// Average bit change as a fraction of the 256-bit output
+    pub fn average_fraction(&self) -> f64 {
+        self.average_bit_change / U256::BITS as f64
+    }
+}
+
+/// SplitMix64 generator for reproducible sample inputs
+struct SampleRng(u64);
+
+impl SampleRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniformly distributed value below 2^INPUT_BITS
+    fn next_input(&mut self) -> U256 {
+        let limbs = [self.next_u64(), self.next_u64(), self.next_u64(), self.next_u64()];
+        U256::from_limbs(limbs) >> (U256::BITS - INPUT_BITS)
+    }
+}
+
+/// Measures how many output bits change when a single input bit flips
+pub fn check_diffusion(hasher: &PoseidonHash, sample_size: usize) -> DiffusionReport {
+    let mut rng = SampleRng(0x5eed);
+    let mut total = 0usize;
+    let mut min_bit_change = usize::MAX;
+    let mut max_bit_change = 0usize;
+
+    for _ in 0..sample_size {
+        let input = rng.next_input();
+        let bit = (rng.next_u64() % INPUT_BITS as u64) as usize;
+        let flipped = input ^ (U256::from(1) << bit);
+
+        let original = hasher
+            .hash_single_production(input)
+            .expect("sample inputs are field elements");
+        let changed = hasher
+            .hash_single_production(flipped)
+            .expect("sample inputs are field elements");
+
+        let bit_change = (original ^ changed).count_ones();
+        total += bit_change;
+        min_bit_change = min_bit_change.min(bit_change);
+        max_bit_change = max_bit_change.max(bit_change);
+    }
+
+    if sample_size == 0 {
+        min_bit_change = 0;
+    }
+
+    DiffusionReport {
+        average_bit_change: total as f64 / sample_size.max(1) as f64,
+        min_bit_change,
+        max_bit_change,
+    }
+}
+
+/// Checks that `n` distinct random inputs hash to distinct outputs
+pub fn check_bijectivity(hasher: &PoseidonHash, n: usize) -> bool {
+    let mut rng = SampleRng(0xb1ec);
+    let mut inputs = HashSet::with_capacity(n);
+    let mut outputs = HashSet::with_capacity(n);
+
+    while inputs.len() < n {
+        let input = rng.next_input();
+        if !inputs.insert(input) {
+            continue;
+        }
+
+        let output = hasher
+            .hash_single_production(input)
+            .expect("sample inputs are field elements");
+        if !outputs.insert(output) {
+            return false;
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_poseidon_diffusion() {
+        let hasher = PoseidonHash::new();
+        let report = check_diffusion(&hasher, 128);
+
+        assert!(
+            report.average_fraction() > 0.45,
+            "average bit change too low: {:?}",
+            report
+        );
+        assert!(report.min_bit_change <= report.max_bit_change);
+        assert!(report.max_bit_change <= 256);
+    }
+
+    #[test]
+    fn test_diffusion_is_reproducible() {
+        let hasher = PoseidonHash::new();
+        assert_eq!(check_diffusion(&hasher, 8), check_diffusion(&hasher, 8));
+    }
+
+    #[test]
+    fn test_default_poseidon_bijectivity() {
+        let hasher = PoseidonHash::new();
+        assert!(check_bijectivity(&hasher, 256));
+    }
+}
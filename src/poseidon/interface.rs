@@ -1,11 +1,20 @@
 //! Poseidon precompile interface and ABI definitions
 
 use super::core::PoseidonHash;
-use crate::errors::PoseidonError;
-use alloy_sol_types::{sol, SolCall, SolValue};
+use super::domain::COMMITMENT_TAG;
+use super::prf::PoseidonPRF;
+use super::sealed::{finalize, SealedHash};
+use crate::commitment::Commitment;
+use crate::errors::{AbiDecodeError, GasExhaustedError, InvalidSelectorError, PrecompileError};
+use crate::nullifier::Nullifier;
+use crate::gas::GasCosts;
+use crate::traits::GenericPrecompile;
+use alloy_sol_types::abi::{encode_sequence, Decoder, TokenSeq};
+use alloy_sol_types::{sol, SolCall, SolType, SolValue};
 
 // Solidity interface definition
 sol! {
+    #[sol(abi)]
     interface IPoseidonHash {
         /// Computes Poseidon hash of a single field element
         /// @param input The field element to hash
@@ -18,54 +27,370 @@ sol! {
         /// @return hash The resulting Poseidon hash
         function poseidon2(uint256 left, uint256 right) external pure returns (uint256 hash);
 
+        /// Computes Poseidon hash of a single field element under a domain tag
+        /// @param tag The nonzero domain-separation tag, set as the capacity element
+        /// @param input The field element to hash
+        /// @return hash The resulting Poseidon hash
+        function poseidon1Tagged(uint256 tag, uint256 input) external pure returns (uint256 hash);
+
+        /// Computes Poseidon hash of two field elements under a domain tag
+        /// @param tag The nonzero domain-separation tag, set as the capacity element
+        /// @param left The left field element
+        /// @param right The right field element
+        /// @return hash The resulting Poseidon hash
+        function poseidon2Tagged(uint256 tag, uint256 left, uint256 right) external pure returns (uint256 hash);
+
+        /// Computes Poseidon hash of three field elements in one T=4 permutation
+        /// @param inputs The field elements to hash
+        /// @return hash The resulting Poseidon hash
+        function poseidon3(uint256[3] inputs) external pure returns (uint256 hash);
+
+        /// Computes Poseidon hash of four field elements in one T=5 permutation
+        /// @param inputs The field elements to hash
+        /// @return hash The resulting Poseidon hash
+        function poseidon4(uint256[4] inputs) external pure returns (uint256 hash);
+
+        /// Computes Poseidon hash of five field elements in one T=6 permutation
+        /// @param inputs The field elements to hash
+        /// @return hash The resulting Poseidon hash
+        function poseidon5(uint256[5] inputs) external pure returns (uint256 hash);
+
         /// Computes Poseidon hash of an array of field elements
         /// @param inputs Array of field elements to hash
         /// @return hash The resulting Poseidon hash
         function poseidonN(uint256[] inputs) external pure returns (uint256 hash);
+
+        /// Computes Poseidon hash of a bytes32 value reduced into the field
+        /// @param data The raw bytes32 value to hash
+        /// @return hash The resulting Poseidon hash
+        function poseidonBytes32(bytes32 data) external pure returns (uint256 hash);
+
+        /// Maps arbitrary bytes to a field element with RFC 9380 hash_to_field
+        /// @param input The bytes to map
+        /// @return hash The resulting field element
+        function poseidonBytes(bytes input) external pure returns (uint256 hash);
+
+        /// Derives the nullifier of a note as poseidon(secretKey, leafIndex)
+        /// @param secretKey The note owner's secret key
+        /// @param leafIndex The index of the note's leaf in the commitment tree
+        /// @return hash The resulting nullifier
+        function nullifier(uint256 secretKey, uint256 leafIndex) external pure returns (uint256 hash);
+
+        /// Commits to a value as poseidon(COMMITMENT_TAG, value, randomness)
+        /// @param value The committed field element
+        /// @param randomness The blinding factor
+        /// @return commitment The resulting commitment
+        function commit(uint256 value, uint256 randomness) external pure returns (uint256 commitment);
+
+        /// Checks that a commitment opens to a value and randomness
+        /// @param commitment The commitment to open
+        /// @param value The claimed field element
+        /// @param randomness The claimed blinding factor
+        /// @return valid Whether the opening matches
+        function openCommitment(uint256 commitment, uint256 value, uint256 randomness) external pure returns (bool valid);
+
+        /// Evaluates the Poseidon PRF as poseidon(key, input)
+        /// @param key The secret PRF key
+        /// @param input The field element to evaluate on
+        /// @return output The pseudo-random output
+        function prfEval(uint256 key, uint256 input) external pure returns (uint256 output);
+    }
+}
+
+/// Poseidon precompile behind the `IPoseidonHash` selectors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PoseidonPrecompile;
+
+impl PoseidonPrecompile {
+    /// Selectors of every `IPoseidonHash` function
+    pub const SELECTORS: [[u8; 4]; 14] = [
+        IPoseidonHash::poseidon1Call::SELECTOR,
+        IPoseidonHash::poseidon2Call::SELECTOR,
+        IPoseidonHash::poseidon1TaggedCall::SELECTOR,
+        IPoseidonHash::poseidon2TaggedCall::SELECTOR,
+        IPoseidonHash::poseidon3Call::SELECTOR,
+        IPoseidonHash::poseidon4Call::SELECTOR,
+        IPoseidonHash::poseidon5Call::SELECTOR,
+        IPoseidonHash::poseidonNCall::SELECTOR,
+        IPoseidonHash::poseidonBytes32Call::SELECTOR,
+        IPoseidonHash::poseidonBytesCall::SELECTOR,
+        IPoseidonHash::nullifierCall::SELECTOR,
+        IPoseidonHash::commitCall::SELECTOR,
+        IPoseidonHash::openCommitmentCall::SELECTOR,
+        IPoseidonHash::prfEvalCall::SELECTOR,
+    ];
+
+    /// Decodes the call and computes the requested hash
+    pub fn dispatch(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        dispatch(input)
+    }
+}
+
+impl GenericPrecompile for PoseidonPrecompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        self.dispatch(input)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, input: &[u8]) -> u64 {
+        // Calls that fail to decode are still charged the cheapest selector
+        poseidon_gas_cost(input).unwrap_or(GasCosts::POSEIDON1_BASE)
     }
 }
 
 /// Precompile entry point - handles the raw call interface
-pub fn poseidon_precompile(input: &[u8]) -> Result<Vec<u8>, PoseidonError> {
+pub fn poseidon_precompile(input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+    PoseidonPrecompile.dispatch(input)
+}
+
+/// Runs `poseidon_precompile` only if `gas_limit` covers its estimated cost
+///
+/// Returns the output together with the gas left over after the charge.
+pub fn poseidon_precompile_with_gas(
+    input: &[u8],
+    gas_limit: u64,
+) -> Result<(Vec<u8>, u64), PrecompileError> {
+    let required = poseidon_gas_cost(input)?;
+    if gas_limit < required {
+        return Err(GasExhaustedError {
+            gas_provided: gas_limit,
+            gas_required: required,
+        }
+        .into());
+    }
+
+    let output = poseidon_precompile(input)?;
+    Ok((output, gas_limit - required))
+}
+
+/// Estimates the gas of a `poseidon_precompile` call without hashing
+pub fn poseidon_gas_cost(input: &[u8]) -> Result<u64, PrecompileError> {
+    if input.len() < 4 {
+        return Err(InvalidSelectorError::from_input(input).into());
+    }
+
+    let cost = match &input[0..4] {
+        s if s == IPoseidonHash::poseidon1Call::SELECTOR
+            || s == IPoseidonHash::poseidonBytes32Call::SELECTOR =>
+        {
+            GasCosts::POSEIDON1_BASE
+        }
+
+        s if s == IPoseidonHash::poseidon2Call::SELECTOR
+            || s == IPoseidonHash::poseidon1TaggedCall::SELECTOR
+            || s == IPoseidonHash::nullifierCall::SELECTOR
+            || s == IPoseidonHash::prfEvalCall::SELECTOR =>
+        {
+            GasCosts::POSEIDON2_BASE
+        }
+
+        s if s == IPoseidonHash::poseidon2TaggedCall::SELECTOR
+            || s == IPoseidonHash::poseidon3Call::SELECTOR
+            || s == IPoseidonHash::commitCall::SELECTOR
+            || s == IPoseidonHash::openCommitmentCall::SELECTOR =>
+        {
+            GasCosts::poseidon_n(3)
+        }
+        s if s == IPoseidonHash::poseidon4Call::SELECTOR => GasCosts::poseidon_n(4),
+        s if s == IPoseidonHash::poseidon5Call::SELECTOR => GasCosts::poseidon_n(5),
+
+        // poseidonN(uint256[])
+        s if s == IPoseidonHash::poseidonNCall::SELECTOR => {
+            let decoded = decode_call::<IPoseidonHash::poseidonNCall>(input)?;
+            GasCosts::poseidon_n(decoded.inputs.len() as u64)
+        }
+
+        // poseidonBytes(bytes)
+        s if s == IPoseidonHash::poseidonBytesCall::SELECTOR => {
+            let decoded = decode_call::<IPoseidonHash::poseidonBytesCall>(input)?;
+            GasCosts::hash_to_field(decoded.input.len() as u64)
+        }
+
+        _ => return Err(InvalidSelectorError::from_input(input).into()),
+    };
+
+    Ok(cost)
+}
+
+/// Decodes calldata, selector included, recording where decoding stopped on failure
+///
+/// The offset counts from the start of `input`, so an offset past its end
+/// means the calldata was truncated.
+pub(crate) fn decode_call<C: SolCall>(input: &[u8]) -> Result<C, AbiDecodeError> {
+    let selector: [u8; 4] = input[..4].try_into().expect("caller checks the selector length");
+    let args = &input[4..];
+
+    let mut decoder = Decoder::new(args, true);
+    let decoded = decoder
+        .decode_sequence::<C::Token<'_>>()
+        .and_then(|token| {
+            C::Parameters::type_check(&token)?;
+            if encode_sequence(&token) != args {
+                return Err(alloy_sol_types::Error::ReserMismatch);
+            }
+            Ok(token)
+        });
+
+    match decoded {
+        Ok(token) => Ok(C::new(C::Parameters::detokenize(token))),
+        Err(reason) => Err(AbiDecodeError {
+            selector,
+            offset: 4 + decoder.offset(),
+            reason,
+        }),
+    }
+}
+
+/// Decodes selector-less `abi_encode_params` data, such as a proof tuple
+///
+/// Errors carry an all-zero selector and an offset from the start of `data`.
+pub(crate) fn decode_params<'de, T>(data: &'de [u8]) -> Result<T, AbiDecodeError>
+where
+    T: SolValue + From<<T::SolType as SolType>::RustType>,
+    <T::SolType as SolType>::Token<'de>: TokenSeq<'de>,
+{
+    let mut decoder = Decoder::new(data, true);
+    let decoded = decoder
+        .decode_sequence::<<T::SolType as SolType>::Token<'de>>()
+        .and_then(|token| {
+            T::SolType::type_check(&token)?;
+            if encode_sequence(&token) != data {
+                return Err(alloy_sol_types::Error::ReserMismatch);
+            }
+            Ok(token)
+        });
+
+    match decoded {
+        Ok(token) => Ok(T::from(T::SolType::detokenize(token))),
+        Err(reason) => Err(AbiDecodeError {
+            selector: [0u8; 4],
+            offset: decoder.offset(),
+            reason,
+        }),
+    }
+}
+
+fn dispatch(input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
     if input.len() < 4 {
-        return Err(PoseidonError::InvalidSelector);
+        return Err(InvalidSelectorError::from_input(input).into());
     }
 
     let selector = &input[0..4];
-    let call_data = &input[4..];
 
     let hasher = PoseidonHash::new();
 
-    match selector {
+    let sealed = match selector {
         // poseidon1(uint256)
         s if s == IPoseidonHash::poseidon1Call::SELECTOR => {
-            let decoded = IPoseidonHash::poseidon1Call::abi_decode(call_data, true)
-                .map_err(|e| PoseidonError::AbiDecodeError(e.to_string()))?;
+            let decoded = decode_call::<IPoseidonHash::poseidon1Call>(input)?;
 
-            let hash = hasher.hash_single(decoded.input)?;
-            Ok(hash.abi_encode())
+            SealedHash::seal(hasher.hash_single_default(decoded.input)?)
         }
 
         // poseidon2(uint256,uint256)
         s if s == IPoseidonHash::poseidon2Call::SELECTOR => {
-            let decoded = IPoseidonHash::poseidon2Call::abi_decode(call_data, true)
-                .map_err(|e| PoseidonError::AbiDecodeError(e.to_string()))?;
+            let decoded = decode_call::<IPoseidonHash::poseidon2Call>(input)?;
+
+            SealedHash::seal(hasher.hash_pair_default(decoded.left, decoded.right)?)
+        }
+
+        // poseidon1Tagged(uint256,uint256)
+        s if s == IPoseidonHash::poseidon1TaggedCall::SELECTOR => {
+            let decoded = decode_call::<IPoseidonHash::poseidon1TaggedCall>(input)?;
 
-            let hash = hasher.hash_pair(decoded.left, decoded.right)?;
-            Ok(hash.abi_encode())
+            SealedHash::seal(hasher.hash_single_tagged(decoded.tag, decoded.input)?)
+        }
+
+        // poseidon2Tagged(uint256,uint256,uint256)
+        s if s == IPoseidonHash::poseidon2TaggedCall::SELECTOR => {
+            let decoded = decode_call::<IPoseidonHash::poseidon2TaggedCall>(input)?;
+
+            SealedHash::seal(hasher.hash_pair_tagged(decoded.tag, decoded.left, decoded.right)?)
+        }
+
+        // poseidon3(uint256[3])
+        s if s == IPoseidonHash::poseidon3Call::SELECTOR => {
+            let decoded = decode_call::<IPoseidonHash::poseidon3Call>(input)?;
+
+            SealedHash::seal(hasher.hash_fixed_default(decoded.inputs)?)
+        }
+
+        // poseidon4(uint256[4])
+        s if s == IPoseidonHash::poseidon4Call::SELECTOR => {
+            let decoded = decode_call::<IPoseidonHash::poseidon4Call>(input)?;
+
+            SealedHash::seal(hasher.hash_fixed_default(decoded.inputs)?)
+        }
+
+        // poseidon5(uint256[5])
+        s if s == IPoseidonHash::poseidon5Call::SELECTOR => {
+            let decoded = decode_call::<IPoseidonHash::poseidon5Call>(input)?;
+
+            SealedHash::seal(hasher.hash_fixed_default(decoded.inputs)?)
         }
 
         // poseidonN(uint256[])
         s if s == IPoseidonHash::poseidonNCall::SELECTOR => {
-            let decoded = IPoseidonHash::poseidonNCall::abi_decode(call_data, true)
-                .map_err(|e| PoseidonError::AbiDecodeError(e.to_string()))?;
+            let decoded = decode_call::<IPoseidonHash::poseidonNCall>(input)?;
 
-            let hash = hasher.hash_array(&decoded.inputs)?;
-            Ok(hash.abi_encode())
+            SealedHash::seal(hasher.hash_array_default(&decoded.inputs)?)
         }
 
-        _ => Err(PoseidonError::InvalidSelector),
-    }
+        // poseidonBytes32(bytes32)
+        s if s == IPoseidonHash::poseidonBytes32Call::SELECTOR => {
+            let decoded = decode_call::<IPoseidonHash::poseidonBytes32Call>(input)?;
+
+            SealedHash::seal(hasher.hash_bytes32(decoded.data.0)?)
+        }
+
+        // poseidonBytes(bytes)
+        s if s == IPoseidonHash::poseidonBytesCall::SELECTOR => {
+            let decoded = decode_call::<IPoseidonHash::poseidonBytesCall>(input)?;
+
+            SealedHash::seal(hasher.hash_to_field(&decoded.input)?)
+        }
+
+        // nullifier(uint256,uint256)
+        s if s == IPoseidonHash::nullifierCall::SELECTOR => {
+            let decoded = decode_call::<IPoseidonHash::nullifierCall>(input)?;
+
+            SealedHash::seal(Nullifier::new().generate(decoded.secretKey, decoded.leafIndex)?)
+        }
+
+        // commit(uint256,uint256)
+        s if s == IPoseidonHash::commitCall::SELECTOR => {
+            let decoded = decode_call::<IPoseidonHash::commitCall>(input)?;
+
+            let commitment =
+                Commitment::commit(decoded.value, decoded.randomness, COMMITMENT_TAG, &hasher)?;
+            SealedHash::seal(commitment.0)
+        }
+
+        // openCommitment(uint256,uint256,uint256)
+        s if s == IPoseidonHash::openCommitmentCall::SELECTOR => {
+            let decoded = decode_call::<IPoseidonHash::openCommitmentCall>(input)?;
+
+            let commitment = Commitment(decoded.commitment);
+            let (value, randomness) = (decoded.value, decoded.randomness);
+            let valid = Commitment::open(&commitment, value, randomness, COMMITMENT_TAG, &hasher);
+            return Ok(valid.abi_encode());
+        }
+
+        // prfEval(uint256,uint256)
+        s if s == IPoseidonHash::prfEvalCall::SELECTOR => {
+            let decoded = decode_call::<IPoseidonHash::prfEvalCall>(input)?;
+
+            SealedHash::seal(PoseidonPRF::new(decoded.key)?.eval(decoded.input)?)
+        }
+
+        _ => return Err(InvalidSelectorError::from_input(input).into()),
+    };
+
+    // Only unseal at the ABI boundary
+    Ok(finalize(sealed).abi_encode())
 }
 
 #[cfg(test)]
@@ -78,13 +403,252 @@ mod tests {
         // Test poseidon1
         let input = U256::from(42);
         let call_data = IPoseidonHash::poseidon1Call { input }.abi_encode();
-        let mut full_input = IPoseidonHash::poseidon1Call::SELECTOR.to_vec();
-        full_input.extend_from_slice(&call_data);
 
-        let result = poseidon_precompile(&full_input);
+        let result = poseidon_precompile(&call_data);
         assert!(result.is_ok());
 
         let output = result.unwrap();
         assert_eq!(output.len(), 32); // U256 is 32 bytes
     }
+
+    #[test]
+    fn test_structured_decode_errors() {
+        let call_data = IPoseidonHash::poseidon2Call { left: U256::from(1), right: U256::from(2) }.abi_encode();
+
+        // Truncated calldata overruns the buffer
+        let result = poseidon_precompile(&call_data[..call_data.len() - 16]);
+        let Err(PrecompileError::Abi(AbiDecodeError { selector, offset, reason })) = result else {
+            panic!("expected a decode error, got {:?}", result);
+        };
+        assert_eq!(selector, IPoseidonHash::poseidon2Call::SELECTOR);
+        assert_eq!(offset, 36);
+        assert!(matches!(reason, alloy_sol_types::Error::Overrun));
+
+        // Trailing bytes do not re-encode to the same calldata
+        let mut padded = call_data.clone();
+        padded.extend_from_slice(&[0u8; 32]);
+        let Err(PrecompileError::Abi(error)) = poseidon_precompile(&padded) else {
+            panic!("expected a decode error");
+        };
+        assert!(matches!(error.reason, alloy_sol_types::Error::ReserMismatch));
+    }
+
+    #[test]
+    fn test_gas_cost() {
+        let call_data = IPoseidonHash::poseidon2Call { left: U256::from(1), right: U256::from(2) }.abi_encode();
+        assert_eq!(poseidon_gas_cost(&call_data).unwrap(), GasCosts::POSEIDON2_BASE);
+
+        let inputs = (1..=10u64).map(U256::from).collect();
+        let call_data = IPoseidonHash::poseidonNCall { inputs }.abi_encode();
+        assert_eq!(
+            poseidon_gas_cost(&call_data).unwrap(),
+            GasCosts::POSEIDON_N_BASE + 10 * GasCosts::POSEIDON_PER_ELEMENT
+        );
+
+        assert!(matches!(poseidon_gas_cost(&[0xde, 0xad, 0xbe, 0xef]), Err(PrecompileError::Selector(_))));
+        assert!(matches!(poseidon_gas_cost(&call_data[..40]), Err(PrecompileError::Abi(_))));
+    }
+
+    /// Calldata for every selector, with a few sizes of `poseidonN`
+    fn sample_calls() -> Vec<Vec<u8>> {
+        let (a, b, c) = (U256::from(1), U256::from(2), U256::from(3));
+        let mut calls = vec![
+            IPoseidonHash::poseidon1Call { input: a }.abi_encode(),
+            IPoseidonHash::poseidon2Call { left: a, right: b }.abi_encode(),
+            IPoseidonHash::poseidon1TaggedCall { tag: a, input: b }.abi_encode(),
+            IPoseidonHash::poseidon2TaggedCall { tag: a, left: b, right: c }.abi_encode(),
+            IPoseidonHash::poseidon3Call { inputs: [a, b, c] }.abi_encode(),
+            IPoseidonHash::poseidon4Call { inputs: [a, b, c, a] }.abi_encode(),
+            IPoseidonHash::poseidon5Call { inputs: [a, b, c, a, b] }.abi_encode(),
+            IPoseidonHash::poseidonBytes32Call { data: [7u8; 32].into() }.abi_encode(),
+            IPoseidonHash::poseidonBytesCall { input: b"hello".to_vec().into() }.abi_encode(),
+            IPoseidonHash::nullifierCall { secretKey: a, leafIndex: b }.abi_encode(),
+            IPoseidonHash::commitCall { value: a, randomness: b }.abi_encode(),
+            IPoseidonHash::openCommitmentCall { commitment: a, value: b, randomness: c }.abi_encode(),
+            IPoseidonHash::prfEvalCall { key: a, input: b }.abi_encode(),
+        ];
+        for len in [1u64, 2, 5, 16] {
+            let inputs = (1..=len).map(U256::from).collect();
+            calls.push(IPoseidonHash::poseidonNCall { inputs }.abi_encode());
+        }
+        calls
+    }
+
+    #[test]
+    fn test_exact_gas_succeeds() {
+        for call_data in sample_calls() {
+            let required = poseidon_gas_cost(&call_data).unwrap();
+            let (output, remaining) = poseidon_precompile_with_gas(&call_data, required).unwrap();
+            assert_eq!(output, poseidon_precompile(&call_data).unwrap());
+            assert_eq!(remaining, 0);
+
+            let (_, remaining) = poseidon_precompile_with_gas(&call_data, required + 7).unwrap();
+            assert_eq!(remaining, 7);
+        }
+    }
+
+    #[test]
+    fn test_one_unit_short_fails() {
+        for call_data in sample_calls() {
+            let required = poseidon_gas_cost(&call_data).unwrap();
+            let result = poseidon_precompile_with_gas(&call_data, required - 1);
+            let Err(PrecompileError::Gas(error)) = result else {
+                panic!("expected out of gas, got {:?}", result);
+            };
+            assert_eq!(error.gas_provided, required - 1);
+            assert_eq!(error.gas_required, required);
+        }
+    }
+
+    #[test]
+    fn test_poseidon_bytes32() {
+        // A bytes32 above the field modulus is reduced rather than rejected
+        let data = alloy_primitives::B256::repeat_byte(0xff);
+        let call_data = IPoseidonHash::poseidonBytes32Call { data }.abi_encode();
+
+        let output = poseidon_precompile(&call_data).unwrap();
+        let expected = PoseidonHash::new().hash_bytes32(data.0).unwrap();
+        assert_eq!(U256::abi_decode(&output, true).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_tagged_selectors() {
+        use crate::poseidon::domain::NODE_TAG;
+
+        let (left, right) = (U256::from(1), U256::from(2));
+        let call_data = IPoseidonHash::poseidon2TaggedCall { tag: NODE_TAG, left, right }.abi_encode();
+
+        let output = poseidon_precompile(&call_data).unwrap();
+        let expected = PoseidonHash::new().hash_pair_tagged(NODE_TAG, left, right).unwrap();
+        assert_eq!(U256::abi_decode(&output, true).unwrap(), expected);
+
+        // Untagged poseidon3 over the same three words does not collide
+        let call_data =
+            IPoseidonHash::poseidon3Call { inputs: [NODE_TAG, left, right] }.abi_encode();
+        let untagged = poseidon_precompile(&call_data).unwrap();
+        assert_ne!(output, untagged);
+
+        let call_data =
+            IPoseidonHash::poseidon1TaggedCall { tag: U256::ZERO, input: left }.abi_encode();
+        assert!(matches!(
+            poseidon_precompile(&call_data),
+            Err(PrecompileError::Poseidon(crate::errors::PoseidonError::ZeroDomainTag))
+        ));
+    }
+
+    #[test]
+    fn test_nullifier() {
+        let (secret_key, leaf_index) = (U256::from(0xc0ffee), U256::from(3));
+        let call_data = IPoseidonHash::nullifierCall { secretKey: secret_key, leafIndex: leaf_index }.abi_encode();
+
+        let output = poseidon_precompile(&call_data).unwrap();
+        let expected = Nullifier::new().generate(secret_key, leaf_index).unwrap();
+        assert_eq!(U256::abi_decode(&output, true).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_commitment_selectors() {
+        let (value, randomness) = (U256::from(42), U256::from(12345));
+        let call_data = IPoseidonHash::commitCall { value, randomness }.abi_encode();
+        let commitment = U256::abi_decode(&poseidon_precompile(&call_data).unwrap(), true).unwrap();
+        let expected = Commitment::commit(value, randomness, COMMITMENT_TAG, &PoseidonHash::new());
+        assert_eq!(commitment, expected.unwrap().0);
+
+        let open = |value: U256| {
+            let call_data =
+                IPoseidonHash::openCommitmentCall { commitment, value, randomness }.abi_encode();
+            bool::abi_decode(&poseidon_precompile(&call_data).unwrap(), true).unwrap()
+        };
+        assert!(open(value));
+        assert!(!open(value + U256::from(1)));
+    }
+
+    #[test]
+    fn test_prf_eval() {
+        let (key, input) = (U256::from(0xc0ffee), U256::from(7));
+        let call_data = IPoseidonHash::prfEvalCall { key, input }.abi_encode();
+
+        let output = poseidon_precompile(&call_data).unwrap();
+        let expected = PoseidonPRF::new(key).unwrap().eval(input).unwrap();
+        assert_eq!(U256::abi_decode(&output, true).unwrap(), expected);
+
+        let modulus = PoseidonHash::new().params.modulus;
+        let call_data = IPoseidonHash::prfEvalCall { key: modulus, input }.abi_encode();
+        assert!(poseidon_precompile(&call_data).is_err());
+    }
+
+    fn poseidon_n(inputs: Vec<U256>) -> U256 {
+        let call_data = IPoseidonHash::poseidonNCall { inputs }.abi_encode();
+        U256::abi_decode(&poseidon_precompile(&call_data).unwrap(), true).unwrap()
+    }
+
+    #[cfg(feature = "use-production-hash")]
+    #[test]
+    fn test_poseidon_n_matches_circomlib() {
+        // One permutation of width n + 1, as circomlibjs poseidon(inputs)
+        let hasher = PoseidonHash::new();
+        for n in [1u64, 2, 3, 16] {
+            let inputs: Vec<U256> = (1..=n).map(U256::from).collect();
+            assert_eq!(poseidon_n(inputs.clone()), hasher.hash_array_production(&inputs).unwrap());
+        }
+    }
+
+    #[cfg(feature = "use-production-hash")]
+    #[test]
+    fn test_poseidon_n_has_no_padding_collisions() {
+        let x = U256::from(7);
+        assert_ne!(poseidon_n(vec![x]), poseidon_n(vec![x, U256::ZERO]));
+        assert_ne!(poseidon_n(vec![x, U256::ZERO]), poseidon_n(vec![x, U256::ZERO, U256::ZERO]));
+
+        // Past 16 inputs the length is bound into the sponge capacity
+        let long: Vec<U256> = (1..=17u64).map(U256::from).collect();
+        let mut padded = long.clone();
+        padded.push(U256::ZERO);
+        assert_ne!(poseidon_n(long.clone()), poseidon_n(padded));
+        let mut truncated = long.clone();
+        truncated.pop();
+        assert_ne!(poseidon_n(long), poseidon_n(truncated));
+    }
+
+    #[cfg(feature = "use-production-hash")]
+    #[test]
+    fn test_production_selectors() {
+        let hasher = PoseidonHash::new();
+        let (left, right) = (U256::from(1), U256::from(2));
+
+        let call_data = IPoseidonHash::poseidon1Call { input: left }.abi_encode();
+        let output = poseidon_precompile(&call_data).unwrap();
+        assert_eq!(U256::abi_decode(&output, true).unwrap(), hasher.hash_single_production(left).unwrap());
+
+        let call_data = IPoseidonHash::poseidon2Call { left, right }.abi_encode();
+        let output = poseidon_precompile(&call_data).unwrap();
+        let expected = hasher.hash_pair_production(left, right).unwrap();
+        assert_eq!(U256::abi_decode(&output, true).unwrap(), expected);
+
+        // A tagged hash never equals the untagged hash of the tag and inputs
+        let call_data = IPoseidonHash::poseidon1TaggedCall { tag: left, input: right }.abi_encode();
+        let output = poseidon_precompile(&call_data).unwrap();
+        assert_ne!(U256::abi_decode(&output, true).unwrap(), expected);
+        assert_eq!(
+            U256::abi_decode(&output, true).unwrap(),
+            hasher.hash_single_tagged(left, right).unwrap()
+        );
+
+        let data = [7u8; 32];
+        let call_data = IPoseidonHash::poseidonBytes32Call { data: data.into() }.abi_encode();
+        let output = poseidon_precompile(&call_data).unwrap();
+        let expected = hasher.hash_single_production(U256::from_be_bytes(data)).unwrap();
+        assert_eq!(U256::abi_decode(&output, true).unwrap(), expected);
+    }
+
+    #[cfg(feature = "use-production-hash")]
+    #[test]
+    fn test_fixed_arity_selectors() {
+        let inputs = [1u64, 2, 3, 4].map(U256::from);
+        let call_data = IPoseidonHash::poseidon4Call { inputs }.abi_encode();
+
+        let output = poseidon_precompile(&call_data).unwrap();
+        let expected = PoseidonHash::with_arity(5).unwrap().hash_at_width(&inputs).unwrap();
+        assert_eq!(U256::abi_decode(&output, true).unwrap(), expected);
+    }
 }
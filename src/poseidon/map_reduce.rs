@@ -0,0 +1,94 @@
+//! Map-reduce Poseidon hashing for distributed proof generation
+//!
+//! Off-chain proving clusters hash independent chunks of a large input in
+//! parallel (map) and then combine the intermediate digests (reduce). With the
+//! `parallel` feature enabled the map phase runs on the rayon thread pool;
+//! without it the same computation runs sequentially.
+//!
+//! Chunks are hashed with `PoseidonHash::hash_array_default`, the hash behind
+//! the `poseidonN` selector. The map-reduce digest is a different function
+//! from that hash over the whole input: it only coincides with it when the
+//! input fits in a single chunk.
+
+use super::core::PoseidonHash;
+use crate::errors::PoseidonError;
+use alloy_primitives::U256;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Map step: hashes one chunk of the input
+pub fn map_hash(chunk: &[U256]) -> Result<U256, PoseidonError> {
+    PoseidonHash::new().hash_array_default(chunk)
+}
+
+/// Reduce step: hashes the intermediate chunk digests into the final digest
+pub fn reduce_hashes(hashes: &[U256]) -> Result<U256, PoseidonError> {
+    PoseidonHash::new().hash_array_default(hashes)
+}
+
+/// Hashes `inputs` by mapping over chunks of `chunk_size` elements and reducing the results
+pub fn parallel_hash_array(inputs: &[U256], chunk_size: usize) -> Result<U256, PoseidonError> {
+    if inputs.is_empty() || chunk_size == 0 {
+        return Err(PoseidonError::InvalidInputLength(0));
+    }
+
+    #[cfg(feature = "parallel")]
+    let hashes = inputs
+        .par_chunks(chunk_size)
+        .map(map_hash)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    #[cfg(not(feature = "parallel"))]
+    let hashes = inputs
+        .chunks(chunk_size)
+        .map(map_hash)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    reduce_hashes(&hashes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_inputs(count: usize) -> Vec<U256> {
+        (1..=count).map(|i| U256::from(i * 7919)).collect()
+    }
+
+    #[test]
+    fn test_matches_sequential_map_reduce() {
+        let inputs = sample_inputs(64);
+
+        for chunk_size in 1..=64 {
+            let mapped: Vec<U256> = inputs
+                .chunks(chunk_size)
+                .map(|chunk| map_hash(chunk).unwrap())
+                .collect();
+            let expected = reduce_hashes(&mapped).unwrap();
+
+            let result = parallel_hash_array(&inputs, chunk_size).unwrap();
+            assert_eq!(result, expected, "chunk size {}", chunk_size);
+        }
+    }
+
+    #[test]
+    fn test_single_chunk_matches_hash_array() {
+        let hasher = PoseidonHash::new();
+        let inputs = sample_inputs(64);
+        let expected = hasher.hash_array_default(&inputs).unwrap();
+
+        assert_eq!(parallel_hash_array(&inputs, 64).unwrap(), expected);
+        assert_eq!(parallel_hash_array(&inputs, 100).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        assert!(parallel_hash_array(&[], 4).is_err());
+        assert!(parallel_hash_array(&sample_inputs(4), 0).is_err());
+
+        let mut inputs = sample_inputs(8);
+        inputs[5] = PoseidonHash::new().params.modulus;
+        assert!(parallel_hash_array(&inputs, 3).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+//! Cauchy MDS matrices for the Poseidon MixLayer
+//!
+//! A Cauchy matrix `m[i][j] = 1 / (x_i + y_j)` over a prime field is MDS
+//! whenever the `x_i` are distinct, the `y_j` are distinct and no `x_i + y_j`
+//! is zero: every square submatrix is again Cauchy, with a non-zero
+//! determinant. circomlib and the Poseidon reference sample the points with
+//! the Grain LFSR; the placeholder instances in this crate use `x_i = i` and
+//! `y_j = t + j`. `validate_mds` checks the property directly, so any matrix
+//! can be checked whatever its construction.
+
+use alloy_primitives::U256;
+
+/// Cauchy matrix of width `t` with `x_i = i` and `y_j = t + j`
+///
+/// The denominators run from `t` to `3t - 2`, so `modulus` must be a prime
+/// above `3t - 2`.
+pub fn generate_cauchy_mds(t: usize, modulus: U256) -> Vec<Vec<U256>> {
+    let xs: Vec<U256> = (0..t).map(U256::from).collect();
+    let ys: Vec<U256> = (t..2 * t).map(U256::from).collect();
+    cauchy_matrix(&xs, &ys, modulus)
+}
+
+/// Cauchy matrix `m[i][j] = 1 / (x_i + y_j)` over the prime field `modulus`
+///
+/// Panics if some `x_i + y_j` is zero.
+pub fn cauchy_matrix(xs: &[U256], ys: &[U256], modulus: U256) -> Vec<Vec<U256>> {
+    xs.iter()
+        .map(|x| {
+            ys.iter()
+                .map(|y| {
+                    x.add_mod(*y, modulus)
+                        .inv_mod(modulus)
+                        .expect("Cauchy denominators are non-zero")
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Checks that `matrix` is a square MDS matrix over the prime field `modulus`
+///
+/// Every square submatrix must have a non-zero determinant, which means
+/// `C(2t, t) - 1` determinants for width `t`. Meant for Poseidon widths; `t`
+/// must stay below 32.
+pub fn validate_mds(matrix: &[Vec<U256>], modulus: U256) -> bool {
+    let t = matrix.len();
+    if t == 0 || matrix.iter().any(|row| row.len() != t || row.iter().any(|m| *m >= modulus)) {
+        return false;
+    }
+
+    // Rows and columns of each submatrix as bitmasks of equal weight
+    let subsets = 1u32..1 << t;
+    subsets.clone().all(|rows| {
+        subsets
+            .clone()
+            .filter(|cols| cols.count_ones() == rows.count_ones())
+            .all(|cols| is_nonsingular(matrix, rows, cols, modulus))
+    })
+}
+
+/// Whether the submatrix on the `rows` and `cols` bitmasks is invertible
+fn is_nonsingular(matrix: &[Vec<U256>], rows: u32, cols: u32, modulus: U256) -> bool {
+    let selected = |mask: u32| (0..matrix.len()).filter(move |&i| (mask >> i) & 1 == 1);
+    let mut m: Vec<Vec<U256>> = selected(rows)
+        .map(|i| selected(cols).map(|j| matrix[i][j]).collect())
+        .collect();
+    let n = m.len();
+
+    // Gaussian elimination; a column without a pivot means a zero determinant
+    for col in 0..n {
+        let Some(pivot) = (col..n).find(|&row| !m[row][col].is_zero()) else {
+            return false;
+        };
+        m.swap(col, pivot);
+        let Some(inv) = m[col][col].inv_mod(modulus) else {
+            return false;
+        };
+
+        for row in col + 1..n {
+            let factor = m[row][col].mul_mod(inv, modulus);
+            for k in col..n {
+                let scaled = factor.mul_mod(m[col][k], modulus);
+                m[row][k] = m[row][k].add_mod(modulus - scaled, modulus);
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poseidon::bls12_381::BLS12_381_MODULUS;
+    use crate::poseidon::constants::POSEIDON_BLS12_MDS;
+    use crate::poseidon::permutation::constants_for_width;
+    use crate::utils::BN254_MODULUS;
+
+    #[test]
+    fn test_cauchy_construction() {
+        for t in 2..=8 {
+            let mds = generate_cauchy_mds(t, BN254_MODULUS);
+            for (i, row) in mds.iter().enumerate() {
+                for (j, entry) in row.iter().enumerate() {
+                    let denominator = U256::from(i + t + j);
+                    assert_eq!(entry.mul_mod(denominator, BN254_MODULUS), U256::from(1));
+                }
+            }
+            assert!(validate_mds(&mds, BN254_MODULUS), "t = {}", t);
+        }
+
+        // Points that wrap around the modulus
+        let xs = [BN254_MODULUS - U256::from(1), U256::from(5)];
+        let ys = [U256::from(3), BN254_MODULUS - U256::from(2)];
+        let mds = cauchy_matrix(&xs, &ys, BN254_MODULUS);
+        assert_eq!(mds[0][0].mul_mod(U256::from(2), BN254_MODULUS), U256::from(1));
+        assert!(validate_mds(&mds, BN254_MODULUS));
+    }
+
+    #[test]
+    fn test_hardcoded_matrices() {
+        // circomlib's matrices, as loaded by poseidon-rs
+        for t in 2..=6 {
+            let mds = &constants_for_width(t).unwrap().mds;
+            assert!(validate_mds(mds, BN254_MODULUS), "circomlib t = {}", t);
+        }
+
+        let bls: Vec<Vec<U256>> = POSEIDON_BLS12_MDS.iter().map(|row| row.to_vec()).collect();
+        assert!(validate_mds(&bls, BLS12_381_MODULUS));
+    }
+
+    #[test]
+    fn test_detects_corruption() {
+        let mds = generate_cauchy_mds(4, BN254_MODULUS);
+
+        // A row pasted twice makes the full matrix singular
+        let mut duplicated = mds.clone();
+        duplicated[3] = duplicated[2].clone();
+        assert!(!validate_mds(&duplicated, BN254_MODULUS));
+
+        // A zero entry is a singular 1x1 submatrix
+        let mut zeroed = mds.clone();
+        zeroed[1][2] = U256::ZERO;
+        assert!(!validate_mds(&zeroed, BN254_MODULUS));
+
+        // A 2x2 minor m00·m11 - m01·m10 forced to zero
+        let mut minor = mds.clone();
+        minor[1][1] = minor[0][1]
+            .mul_mod(minor[1][0], BN254_MODULUS)
+            .mul_mod(minor[0][0].inv_mod(BN254_MODULUS).unwrap(), BN254_MODULUS);
+        assert!(!validate_mds(&minor, BN254_MODULUS));
+
+        assert!(!validate_mds(&mds[..3], BN254_MODULUS));
+        assert!(!validate_mds(&[], BN254_MODULUS));
+    }
+}
@@ -1,10 +1,54 @@
 //! Poseidon Hash implementation for zero-knowledge proof systems
 
+pub mod bls12_381;
 pub mod constants;
 pub mod core;
+pub mod domain;
+pub mod goldilocks;
+pub mod hmac;
+pub mod inspector;
 pub mod interface;
+pub mod map_reduce;
+pub mod mds;
+#[cfg(feature = "pallas")]
+pub mod pallas;
+pub mod permutation;
+pub mod prf;
+pub mod ristretto;
+pub mod sealed;
+pub mod sponge;
+pub mod static_dispatch;
+pub mod stream;
 
 // Re-export the main components
-pub use constants::POSEIDON_ROUND_CONSTANTS;
-pub use core::{PoseidonHash, PoseidonParams};
-pub use interface::{poseidon_precompile, IPoseidonHash};
+pub use bls12_381::{PoseidonBls, PoseidonBlsParams};
+pub use constants::{
+    round_constants_for_arity, POSEIDON_BLS12_ROUND_CONSTANTS, POSEIDON_ROUND_CONSTANTS,
+    POSEIDON_T3_MDS, POSEIDON_T3_ROUND_CONSTANTS, POSEIDON_T4_MDS, POSEIDON_T4_ROUND_CONSTANTS,
+    POSEIDON_T5_MDS, POSEIDON_T5_ROUND_CONSTANTS,
+};
+pub use core::{
+    hash_fixed, FieldInput, PoseidonHash, PoseidonHashBuilder, PoseidonParams, SecretInput,
+    MAX_CIRCOMLIB_INPUTS,
+};
+#[cfg(feature = "serde")]
+pub use core::from_config;
+pub use domain::{DomainTag, COMMITMENT_TAG, LEAF_TAG, NODE_TAG, NULLIFIER_TAG};
+pub use goldilocks::{hash_goldilocks, GoldilocksField, PoseidonGoldilocks};
+pub use hmac::PoseidonHmac;
+pub use inspector::{check_bijectivity, check_diffusion, DiffusionReport};
+pub use interface::{
+    poseidon_gas_cost, poseidon_precompile, poseidon_precompile_with_gas, IPoseidonHash,
+    PoseidonPrecompile,
+};
+pub use map_reduce::{map_hash, parallel_hash_array, reduce_hashes};
+pub use mds::{cauchy_matrix, generate_cauchy_mds, validate_mds};
+#[cfg(feature = "pallas")]
+pub use pallas::{PallasField, PallasPoseidonHash, PallasPoseidonParams};
+pub use ristretto::{RistrettoField, RistrettoPoseidonHash, RistrettoPoseidonParams};
+pub use permutation::{permute, poseidon_permutation};
+pub use prf::PoseidonPRF;
+pub use sealed::SealedHash;
+pub use sponge::{bytes_to_elements, PoseidonSponge, BYTES_PER_ELEMENT};
+pub use static_dispatch::{PlaceholderPoseidon, PoseidonHashGeneric};
+pub use stream::PoseidonHashStream;
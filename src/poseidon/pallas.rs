@@ -0,0 +1,309 @@
+//! Poseidon hash over the Pallas base field
+//!
+//! Halo2-based protocols such as Zcash Orchard hash with the `P128Pow5T3`
+//! instantiation: width 3 (rate 2), 8 full and 56 partial rounds and the
+//! x^5 S-box over the Pallas base field. The round constants and the Cauchy
+//! MDS matrix are derived with the Grain LFSR from the Poseidon reference
+//! implementation, as in `halo2_gadgets`.
+//!
+//! Hashing follows Halo2's `ConstantLength<L>` domain: the capacity element
+//! (last in the state) is `L * 2^64` and the first rate element is the output.
+
+use super::mds::cauchy_matrix;
+use crate::errors::PoseidonError;
+use alloy_primitives::{uint, U256};
+
+/// Pallas base field modulus (the Vesta scalar field)
+pub const PALLAS_MODULUS: U256 =
+    uint!(0x40000000000000000000000000000000224698fc094cf91b992d30ed00000001_U256);
+
+/// Bits in a Pallas field element
+const FIELD_BITS: usize = 255;
+
+/// State width (one capacity, two rate elements)
+const WIDTH: usize = 3;
+
+/// Element of the Pallas base field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PallasField(U256);
+
+impl PallasField {
+    /// Creates a field element, rejecting values not below the Pallas modulus
+    pub fn new(value: U256) -> Result<Self, PoseidonError> {
+        if value >= PALLAS_MODULUS {
+            return Err(PoseidonError::FieldElementTooLarge(value));
+        }
+        Ok(Self(value))
+    }
+
+    /// Returns the canonical integer representation
+    pub fn value(&self) -> U256 {
+        self.0
+    }
+}
+
+/// Poseidon parameters for the Pallas base field (Halo2 `P128Pow5T3`)
+pub struct PallasPoseidonParams {
+    /// Prime field modulus (Pallas base field)
+    pub modulus: U256,
+    /// Number of full rounds
+    pub full_rounds: usize,
+    /// Number of partial rounds
+    pub partial_rounds: usize,
+    /// S-box exponent
+    pub alpha: u64,
+}
+
+impl Default for PallasPoseidonParams {
+    fn default() -> Self {
+        Self {
+            modulus: PALLAS_MODULUS,
+            full_rounds: 8,
+            partial_rounds: 56,
+            alpha: 5,
+        }
+    }
+}
+
+/// Grain LFSR used to derive Poseidon constants
+struct Grain {
+    state: [bool; 80],
+}
+
+impl Grain {
+    fn new(full_rounds: usize, partial_rounds: usize) -> Self {
+        let mut state = [true; 80];
+        let mut set_bits = |offset: usize, len: usize, value: usize| {
+            for i in 0..len {
+                state[offset + len - 1 - i] = (value >> i) & 1 == 1;
+            }
+        };
+
+        set_bits(0, 2, 1); // prime field
+        set_bits(2, 4, 0); // x^alpha S-box
+        set_bits(6, 12, FIELD_BITS);
+        set_bits(18, 12, WIDTH);
+        set_bits(30, 10, full_rounds);
+        set_bits(40, 10, partial_rounds);
+
+        let mut grain = Self { state };
+        for _ in 0..160 {
+            grain.raw_bit();
+        }
+        grain
+    }
+
+    /// Clocks the LFSR once
+    fn raw_bit(&mut self) -> bool {
+        let s = &self.state;
+        let bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.copy_within(1.., 0);
+        self.state[79] = bit;
+        bit
+    }
+
+    /// Returns the next output bit, keeping the second bit of each pair whose first bit is set
+    fn next_bit(&mut self) -> bool {
+        loop {
+            let keep = self.raw_bit();
+            let bit = self.raw_bit();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    /// Reads FIELD_BITS bits as a big-endian integer
+    fn next_bits(&mut self) -> U256 {
+        (0..FIELD_BITS).fold(U256::ZERO, |acc, _| (acc << 1) | U256::from(self.next_bit() as u8))
+    }
+
+    /// Samples a field element by rejection
+    fn next_field_element(&mut self, modulus: U256) -> U256 {
+        loop {
+            let value = self.next_bits();
+            if value < modulus {
+                return value;
+            }
+        }
+    }
+
+    /// Samples a field element by reducing FIELD_BITS bits
+    fn next_field_element_without_rejection(&mut self, modulus: U256) -> U256 {
+        self.next_bits() % modulus
+    }
+}
+
+/// Poseidon hash over the Pallas base field
+pub struct PallasPoseidonHash {
+    pub params: PallasPoseidonParams,
+    round_constants: Vec<[U256; WIDTH]>,
+    mds: [[U256; WIDTH]; WIDTH],
+}
+
+impl Default for PallasPoseidonHash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PallasPoseidonHash {
+    /// Creates a new hasher, deriving the constants for the default parameters
+    pub fn new() -> Self {
+        let params = PallasPoseidonParams::default();
+        let modulus = params.modulus;
+        let mut grain = Grain::new(params.full_rounds, params.partial_rounds);
+
+        let round_constants = (0..params.full_rounds + params.partial_rounds)
+            .map(|_| {
+                let mut row = [U256::ZERO; WIDTH];
+                for constant in row.iter_mut() {
+                    *constant = grain.next_field_element(modulus);
+                }
+                row
+            })
+            .collect();
+
+        // Cauchy MDS matrix m[i][j] = 1 / (x_i + y_j) from 2 * WIDTH distinct samples
+        let (xs, ys) = loop {
+            let values: Vec<U256> = (0..2 * WIDTH)
+                .map(|_| grain.next_field_element_without_rejection(modulus))
+                .collect();
+            let mut unique = values.clone();
+            unique.sort_unstable();
+            unique.dedup();
+            if unique.len() == values.len() {
+                break (values[..WIDTH].to_vec(), values[WIDTH..].to_vec());
+            }
+        };
+
+        let cauchy = cauchy_matrix(&xs, &ys, modulus);
+        let mds = core::array::from_fn(|i| core::array::from_fn(|j| cauchy[i][j]));
+
+        Self {
+            params,
+            round_constants,
+            mds,
+        }
+    }
+
+    /// Computes the Poseidon hash of a single element (`ConstantLength<1>`)
+    pub fn hash_single(&self, input: U256) -> Result<U256, PoseidonError> {
+        let input = PallasField::new(input)?;
+        self.hash_state([input.value(), U256::ZERO, Self::capacity(1)])
+    }
+
+    /// Computes the Poseidon hash of two elements (`ConstantLength<2>`)
+    pub fn hash_pair(&self, left: U256, right: U256) -> Result<U256, PoseidonError> {
+        let left = PallasField::new(left)?;
+        let right = PallasField::new(right)?;
+        self.hash_state([left.value(), right.value(), Self::capacity(2)])
+    }
+
+    /// Capacity element for a constant-length message of `length` elements
+    fn capacity(length: u64) -> U256 {
+        U256::from(length) << 64
+    }
+
+    fn hash_state(&self, mut state: [U256; WIDTH]) -> Result<U256, PoseidonError> {
+        self.permute(&mut state);
+        Ok(state[0])
+    }
+
+    /// Applies the Poseidon permutation in place
+    pub fn permute(&self, state: &mut [U256; WIDTH]) {
+        let modulus = self.params.modulus;
+        let half_full = self.params.full_rounds / 2;
+
+        for (round, constants) in self.round_constants.iter().enumerate() {
+            // AddRoundConstants
+            for (element, constant) in state.iter_mut().zip(constants) {
+                *element = element.add_mod(*constant, modulus);
+            }
+
+            // SubWords: full rounds at both ends, partial rounds in the middle
+            if round < half_full || round >= half_full + self.params.partial_rounds {
+                for element in state.iter_mut() {
+                    *element = self.sbox(*element);
+                }
+            } else {
+                state[0] = self.sbox(state[0]);
+            }
+
+            // MixLayer
+            let mut mixed = [U256::ZERO; WIDTH];
+            for (i, out) in mixed.iter_mut().enumerate() {
+                for (j, element) in state.iter().enumerate() {
+                    let term = self.mds[i][j].mul_mod(*element, modulus);
+                    *out = out.add_mod(term, modulus);
+                }
+            }
+            *state = mixed;
+        }
+    }
+
+    /// S-box: x^alpha mod p
+    fn sbox(&self, x: U256) -> U256 {
+        x.pow_mod(U256::from(self.params.alpha), self.params.modulus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_validation() {
+        assert!(PallasField::new(PALLAS_MODULUS - U256::from(1)).is_ok());
+        assert!(PallasField::new(PALLAS_MODULUS).is_err());
+
+        let hasher = PallasPoseidonHash::new();
+        assert!(hasher.hash_pair(U256::ZERO, PALLAS_MODULUS).is_err());
+    }
+
+    #[test]
+    fn test_constants_match_halo2() {
+        let hasher = PallasPoseidonHash::new();
+        assert_eq!(hasher.round_constants.len(), 64);
+        for row in &hasher.round_constants {
+            assert!(row.iter().all(|c| *c < PALLAS_MODULUS));
+        }
+
+        // First round constants of the halo2_gadgets P128Pow5T3 table for Fp
+        assert_eq!(
+            hasher.round_constants[0][0],
+            U256::from_limbs([
+                0x5753_8c25_9642_6303,
+                0x4e71_162f_3100_3b70,
+                0x353f_628f_76d1_10f3,
+                0x360d_7470_611e_473d,
+            ])
+        );
+        assert_eq!(
+            hasher.round_constants[0][1],
+            U256::from_limbs([
+                0xbdb7_4213_bf63_188b,
+                0x4908_ac2f_12eb_e06f,
+                0x5dc3_c6c5_febf_aa31,
+                0x2bab_94d7_ae22_2d13,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_hash_properties() {
+        let hasher = PallasPoseidonHash::new();
+        let a = U256::from(1);
+        let b = U256::from(2);
+
+        assert_eq!(hasher.hash_pair(a, b).unwrap(), hasher.hash_pair(a, b).unwrap());
+        assert_ne!(hasher.hash_pair(a, b).unwrap(), hasher.hash_pair(b, a).unwrap());
+
+        // The capacity separates message lengths
+        assert_ne!(
+            hasher.hash_single(a).unwrap(),
+            hasher.hash_pair(a, U256::ZERO).unwrap()
+        );
+        assert!(hasher.hash_pair(a, b).unwrap() < PALLAS_MODULUS);
+    }
+}
@@ -0,0 +1,219 @@
+//! The circomlib Poseidon permutation over an explicit state
+//!
+//! poseidon-rs only exposes the fixed-arity hash, which builds the state
+//! `[0, inputs...]` internally. Sponges and circuit generators need the
+//! permutation itself, so its constants are converted to `U256` once per
+//! process and the rounds are replayed here: add round constants, apply x^5
+//! to every element in full rounds and to the first one in partial rounds,
+//! then multiply by the MDS matrix.
+
+use super::core::PoseidonParams;
+use crate::errors::PoseidonError;
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::U256;
+use ff_ce::PrimeField;
+use poseidon_rs::{load_constants, Fr};
+use std::sync::OnceLock;
+
+/// Widest state circomlib has constants for
+const MAX_WIDTH: usize = 17;
+
+/// Round counts and constants of the permutation for one state width
+pub struct PermutationConstants {
+    pub full_rounds: usize,
+    pub partial_rounds: usize,
+    /// `(full_rounds + partial_rounds) * width` constants, round by round
+    pub round_constants: Vec<U256>,
+    pub mds: Vec<Vec<U256>>,
+}
+
+/// Returns the circomlib constants for state width `t`, from 2 to 17
+pub fn constants_for_width(t: usize) -> Option<&'static PermutationConstants> {
+    static CONSTANTS: OnceLock<Vec<PermutationConstants>> = OnceLock::new();
+    let all = CONSTANTS.get_or_init(|| {
+        let constants = load_constants();
+        let to_u256 = |fr: &Fr| U256::from_limbs(fr.into_repr().0);
+
+        (0..constants.n_rounds_p.len())
+            .map(|i| PermutationConstants {
+                full_rounds: constants.n_rounds_f,
+                partial_rounds: constants.n_rounds_p[i],
+                round_constants: constants.c[i].iter().map(to_u256).collect(),
+                mds: constants.m[i]
+                    .iter()
+                    .map(|row| row.iter().map(to_u256).collect())
+                    .collect(),
+            })
+            .collect()
+    });
+    t.checked_sub(2).and_then(|i| all.get(i))
+}
+
+/// Applies the permutation of width `T` described by `params` in place
+///
+/// The round constants are circomlib's, so `params` must carry the circomlib
+/// round counts for width `T` over BN254, as `PoseidonParams::with_width(T)`
+/// does, and every state element must be reduced.
+pub fn poseidon_permutation<const T: usize>(
+    state: &mut [U256; T],
+    params: &PoseidonParams,
+) -> Result<(), PoseidonError> {
+    let constants = width_constants(T)?;
+    if params.width != T
+        || params.modulus != BN254_MODULUS
+        || params.full_rounds != constants.full_rounds
+        || params.partial_rounds != constants.partial_rounds
+    {
+        return Err(PoseidonError::UnsupportedParams(format!(
+            "width {} with {} full and {} partial rounds has no circomlib constants for width {}",
+            params.width, params.full_rounds, params.partial_rounds, T
+        )));
+    }
+    if let Some(&element) = state.iter().find(|&&element| element >= BN254_MODULUS) {
+        return Err(PoseidonError::FieldElementTooLarge(element));
+    }
+
+    apply_rounds(
+        state,
+        &constants.round_constants,
+        &constants.mds,
+        constants.full_rounds,
+        constants.partial_rounds,
+    );
+    Ok(())
+}
+
+/// Applies the permutation of width `state.len()` in place
+pub fn permute(state: &mut [U256]) -> Result<(), PoseidonError> {
+    let constants = width_constants(state.len())?;
+    apply_rounds(
+        state,
+        &constants.round_constants,
+        &constants.mds,
+        constants.full_rounds,
+        constants.partial_rounds,
+    );
+    Ok(())
+}
+
+fn width_constants(width: usize) -> Result<&'static PermutationConstants, PoseidonError> {
+    constants_for_width(width).ok_or(PoseidonError::InvalidInputLength(width.saturating_sub(1)))
+}
+
+/// Runs every round: AddRoundConstants, SubWords, then MixLayer
+///
+/// Generic over where the constants live, so the circomlib tables loaded at
+/// run time and the compile-time arrays behind `hash_fixed` share this loop.
+/// The MixLayer works in a stack buffer, so nothing is allocated.
+pub(crate) fn apply_rounds<R: AsRef<[U256]>>(
+    state: &mut [U256],
+    round_constants: &[U256],
+    mds: &[R],
+    full_rounds: usize,
+    partial_rounds: usize,
+) {
+    let width = state.len();
+    assert!(width <= MAX_WIDTH, "state width {} above {}", width, MAX_WIDTH);
+    let half_full = full_rounds / 2;
+
+    let rounds = round_constants.chunks_exact(width).take(full_rounds + partial_rounds);
+    for (round, constants) in rounds.enumerate() {
+        for (element, constant) in state.iter_mut().zip(constants) {
+            *element = element.add_mod(*constant, BN254_MODULUS);
+        }
+
+        let full = round < half_full || round >= half_full + partial_rounds;
+        let sbox_count = if full { width } else { 1 };
+        for element in state.iter_mut().take(sbox_count) {
+            *element = element.pow_mod(U256::from(5), BN254_MODULUS);
+        }
+
+        let mut mixed = [U256::ZERO; MAX_WIDTH];
+        for (out, row) in mixed.iter_mut().zip(mds) {
+            *out = row.as_ref().iter().zip(state.iter()).fold(U256::ZERO, |acc, (m, s)| {
+                acc.add_mod(m.mul_mod(*s, BN254_MODULUS), BN254_MODULUS)
+            });
+        }
+        state.copy_from_slice(&mixed[..width]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poseidon::PoseidonHash;
+
+    #[test]
+    fn test_matches_poseidon_rs() {
+        let hasher = PoseidonHash::new();
+        let inputs = [7u64, 8, 9].map(U256::from);
+
+        for n in 1..=inputs.len() {
+            let mut state = vec![U256::ZERO; n + 1];
+            state[1..].copy_from_slice(&inputs[..n]);
+            permute(&mut state).unwrap();
+            assert_eq!(state[0], hasher.hash_array_production(&inputs[..n]).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_const_generic_permutation() {
+        let params = PoseidonParams::with_width(3).unwrap();
+        let mut state = [0u64, 7, 8].map(U256::from);
+        let mut slice_state = state.to_vec();
+
+        poseidon_permutation(&mut state, &params).unwrap();
+        permute(&mut slice_state).unwrap();
+        assert_eq!(state.to_vec(), slice_state);
+
+        let hasher = PoseidonHash::new();
+        let expected = hasher.hash_pair_production(U256::from(7), U256::from(8)).unwrap();
+        assert_eq!(state[0], expected);
+    }
+
+    #[test]
+    fn test_composition() {
+        // P(P(s)) applied one call at a time equals P∘P applied to a copy
+        let params = PoseidonParams::with_width(4).unwrap();
+        let start = [1u64, 2, 3, 4].map(U256::from);
+
+        let mut twice = start;
+        poseidon_permutation(&mut twice, &params).unwrap();
+        poseidon_permutation(&mut twice, &params).unwrap();
+
+        let compose = |mut state: [U256; 4]| {
+            for _ in 0..2 {
+                permute(&mut state).unwrap();
+            }
+            state
+        };
+        assert_eq!(twice, compose(start));
+        assert_ne!(twice, start);
+    }
+
+    #[test]
+    fn test_unsupported_width() {
+        assert!(constants_for_width(1).is_none());
+        assert!(constants_for_width(18).is_none());
+        assert!(permute(&mut [U256::ZERO]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_mismatched_params() {
+        let mut state = [U256::ZERO; 3];
+        let params = PoseidonParams::with_width(4).unwrap();
+        assert!(matches!(
+            poseidon_permutation(&mut state, &params),
+            Err(PoseidonError::UnsupportedParams(_))
+        ));
+
+        let params = PoseidonParams { partial_rounds: 60, ..PoseidonParams::default() };
+        assert!(poseidon_permutation(&mut state, &params).is_err());
+
+        state[1] = BN254_MODULUS;
+        assert!(matches!(
+            poseidon_permutation(&mut state, &PoseidonParams::default()),
+            Err(PoseidonError::FieldElementTooLarge(_))
+        ));
+    }
+}
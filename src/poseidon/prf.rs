@@ -0,0 +1,91 @@
+//! Poseidon as a keyed pseudo-random function
+//!
+//! `PRF_key(input) = poseidon(key, input)`. Outputs for the same key are
+//! deterministic, so they serve as identifiers, while outputs under unknown
+//! keys cannot be linked to their inputs. The key is zeroized on drop.
+
+use super::core::PoseidonHash;
+use crate::errors::PoseidonError;
+use alloy_primitives::U256;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Poseidon PRF under a secret BN254 key
+pub struct PoseidonPRF {
+    key: U256,
+    hasher: PoseidonHash,
+}
+
+impl PoseidonPRF {
+    /// Creates the PRF, rejecting keys outside the BN254 scalar field
+    pub fn new(key: U256) -> Result<Self, PoseidonError> {
+        let hasher = PoseidonHash::new();
+        hasher.validate_field_element(key)?;
+        Ok(Self { key, hasher })
+    }
+
+    /// Evaluates `poseidon(key, input)`
+    pub fn eval(&self, input: U256) -> Result<U256, PoseidonError> {
+        self.hasher.hash_pair_production(self.key, input)
+    }
+
+    /// Evaluates the PRF on every input, failing on the first invalid one
+    pub fn eval_batch(&self, inputs: &[U256]) -> Result<Vec<U256>, PoseidonError> {
+        inputs.iter().map(|&input| self.eval(input)).collect()
+    }
+}
+
+impl Zeroize for PoseidonPRF {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl Drop for PoseidonPRF {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for PoseidonPRF {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eval_is_deterministic() {
+        let prf = PoseidonPRF::new(U256::from(0xc0ffee)).unwrap();
+        let input = U256::from(7);
+        assert_eq!(prf.eval(input).unwrap(), prf.eval(input).unwrap());
+        assert_eq!(
+            prf.eval(input).unwrap(),
+            PoseidonHash::new().hash_pair_production(U256::from(0xc0ffee), input).unwrap()
+        );
+        assert_ne!(prf.eval(input).unwrap(), prf.eval(input + U256::from(1)).unwrap());
+    }
+
+    #[test]
+    fn test_keys_give_distinct_outputs() {
+        let inputs: Vec<U256> = (0..4u64).map(U256::from).collect();
+        let first = PoseidonPRF::new(U256::from(1)).unwrap().eval_batch(&inputs).unwrap();
+        let second = PoseidonPRF::new(U256::from(2)).unwrap().eval_batch(&inputs).unwrap();
+
+        assert_eq!(first.len(), inputs.len());
+        for (a, b) in first.iter().zip(&second) {
+            assert_ne!(a, b);
+        }
+    }
+
+    #[test]
+    fn test_key_validation() {
+        let modulus = PoseidonHash::new().params.modulus;
+        assert!(matches!(
+            PoseidonPRF::new(modulus),
+            Err(PoseidonError::FieldElementTooLarge(_))
+        ));
+        assert!(PoseidonPRF::new(modulus - U256::from(1)).is_ok());
+
+        let prf = PoseidonPRF::new(U256::from(1)).unwrap();
+        assert!(prf.eval_batch(&[U256::from(1), modulus]).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+//! Poseidon hash over the Ristretto255 scalar field
+//!
+//! Ristretto255 (the prime-order group used by libsodium) has scalar field
+//! order l = 2^252 + 27742317777372353535851937790883648493. This module
+//! provides a width-3 Poseidon permutation over that field so protocols that
+//! bridge Ristretto and BN254 can hash Ristretto scalars natively.
+//!
+//! The round constants follow the same placeholder scheme as
+//! `POSEIDON_ROUND_CONSTANTS` and the MDS matrix is a Cauchy matrix, so the
+//! output is deterministic but not interoperable with other Ristretto
+//! Poseidon instantiations.
+
+use super::mds::generate_cauchy_mds;
+use crate::errors::PoseidonError;
+use alloy_primitives::{uint, U256};
+
+/// Ristretto255 scalar field order
+pub const RISTRETTO_SCALAR_MODULUS: U256 =
+    uint!(0x1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed_U256);
+
+/// State width of the Ristretto Poseidon permutation (one capacity, two rate elements)
+const WIDTH: usize = 3;
+
+/// Element of the Ristretto255 scalar field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RistrettoField(U256);
+
+impl RistrettoField {
+    /// Creates a field element, rejecting values not below the scalar field order
+    pub fn new(value: U256) -> Result<Self, PoseidonError> {
+        if value >= RISTRETTO_SCALAR_MODULUS {
+            return Err(PoseidonError::FieldElementTooLarge(value));
+        }
+        Ok(Self(value))
+    }
+
+    /// Returns the canonical integer representation
+    pub fn value(&self) -> U256 {
+        self.0
+    }
+}
+
+/// Poseidon parameters for the Ristretto255 scalar field
+pub struct RistrettoPoseidonParams {
+    /// Prime field modulus (Ristretto255 scalar field)
+    pub modulus: U256,
+    /// Number of full rounds
+    pub full_rounds: usize,
+    /// Number of partial rounds
+    pub partial_rounds: usize,
+    /// S-box exponent (gcd(alpha, l - 1) = 1 for alpha = 5)
+    pub alpha: u64,
+}
+
+impl Default for RistrettoPoseidonParams {
+    fn default() -> Self {
+        Self {
+            modulus: RISTRETTO_SCALAR_MODULUS,
+            full_rounds: 8,
+            partial_rounds: 57,
+            alpha: 5,
+        }
+    }
+}
+
+/// Poseidon hash over the Ristretto255 scalar field
+pub struct RistrettoPoseidonHash {
+    pub params: RistrettoPoseidonParams,
+    mds: [[U256; WIDTH]; WIDTH],
+}
+
+impl Default for RistrettoPoseidonHash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RistrettoPoseidonHash {
+    /// Creates a new hasher with default parameters
+    pub fn new() -> Self {
+        let params = RistrettoPoseidonParams::default();
+
+        let cauchy = generate_cauchy_mds(WIDTH, params.modulus);
+        let mds = core::array::from_fn(|i| core::array::from_fn(|j| cauchy[i][j]));
+
+        Self { params, mds }
+    }
+
+    /// Computes the Poseidon hash of a single element
+    pub fn hash_single(&self, input: U256) -> Result<U256, PoseidonError> {
+        let input = RistrettoField::new(input)?;
+        self.hash_state([U256::from(1), input.value(), U256::ZERO])
+    }
+
+    /// Computes the Poseidon hash of two elements
+    pub fn hash_pair(&self, left: U256, right: U256) -> Result<U256, PoseidonError> {
+        let left = RistrettoField::new(left)?;
+        let right = RistrettoField::new(right)?;
+        self.hash_state([U256::from(2), left.value(), right.value()])
+    }
+
+    /// Permutes a state whose capacity element holds the input count
+    fn hash_state(&self, mut state: [U256; WIDTH]) -> Result<U256, PoseidonError> {
+        self.permute(&mut state);
+        Ok(state[1])
+    }
+
+    /// Applies the full Poseidon permutation in place
+    fn permute(&self, state: &mut [U256; WIDTH]) {
+        let modulus = self.params.modulus;
+        let half_full = self.params.full_rounds / 2;
+        let total_rounds = self.params.full_rounds + self.params.partial_rounds;
+
+        for round in 0..total_rounds {
+            // AddRoundConstants
+            for (i, element) in state.iter_mut().enumerate() {
+                let constant = U256::from(round * WIDTH + i + 1);
+                *element = element.add_mod(constant, modulus);
+            }
+
+            // SubWords: full rounds at both ends, partial rounds in the middle
+            if round < half_full || round >= half_full + self.params.partial_rounds {
+                for element in state.iter_mut() {
+                    *element = self.sbox(*element);
+                }
+            } else {
+                state[0] = self.sbox(state[0]);
+            }
+
+            // MixLayer
+            let mut mixed = [U256::ZERO; WIDTH];
+            for (i, out) in mixed.iter_mut().enumerate() {
+                for (j, element) in state.iter().enumerate() {
+                    let term = self.mds[i][j].mul_mod(*element, modulus);
+                    *out = out.add_mod(term, modulus);
+                }
+            }
+            *state = mixed;
+        }
+    }
+
+    /// S-box: x^alpha mod l
+    fn sbox(&self, x: U256) -> U256 {
+        x.pow_mod(U256::from(self.params.alpha), self.params.modulus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_validation() {
+        assert!(RistrettoField::new(U256::from(42)).is_ok());
+        assert!(RistrettoField::new(RISTRETTO_SCALAR_MODULUS - U256::from(1)).is_ok());
+        assert!(RistrettoField::new(RISTRETTO_SCALAR_MODULUS).is_err());
+
+        // Valid BN254 elements can still exceed the smaller Ristretto order
+        let hasher = RistrettoPoseidonHash::new();
+        assert!(hasher.hash_single(RISTRETTO_SCALAR_MODULUS).is_err());
+    }
+
+    #[test]
+    fn test_modulus_value() {
+        let expected = (U256::from(1) << 252)
+            + U256::from_str_radix("27742317777372353535851937790883648493", 10).unwrap();
+        assert_eq!(RISTRETTO_SCALAR_MODULUS, expected);
+    }
+
+    #[test]
+    fn test_hash_properties() {
+        let hasher = RistrettoPoseidonHash::new();
+        let a = U256::from(1);
+        let b = U256::from(2);
+
+        // Deterministic
+        assert_eq!(hasher.hash_pair(a, b).unwrap(), hasher.hash_pair(a, b).unwrap());
+
+        // Order matters
+        assert_ne!(hasher.hash_pair(a, b).unwrap(), hasher.hash_pair(b, a).unwrap());
+
+        // Single-element hashing is separated from pair hashing with a zero
+        assert_ne!(
+            hasher.hash_single(a).unwrap(),
+            hasher.hash_pair(a, U256::ZERO).unwrap()
+        );
+
+        // Outputs stay in the field
+        assert!(hasher.hash_single(a).unwrap() < RISTRETTO_SCALAR_MODULUS);
+    }
+}
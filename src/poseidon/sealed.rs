@@ -0,0 +1,67 @@
+//! Sealed Poseidon hash outputs
+//!
+//! A `SealedHash` can only be turned back into a `U256` by consuming it with
+//! `finalize`, which keeps intermediate values from leaking into outputs by
+//! accident. It deliberately has no `Display` or `PartialEq<U256>` impls, and
+//! its `Debug` output hides the value.
+
+use super::core::PoseidonHash;
+use crate::errors::PoseidonError;
+use alloy_primitives::U256;
+use core::fmt;
+
+/// Poseidon hash output that must be explicitly finalized before use
+#[must_use = "a sealed hash does nothing unless it is finalized"]
+pub struct SealedHash(U256);
+
+impl SealedHash {
+    /// Seals a hash value produced inside the crate
+    pub(crate) fn seal(value: U256) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Debug for SealedHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SealedHash").field(&"<redacted>").finish()
+    }
+}
+
+/// Computes the `poseidon1` hash of a single element as a sealed value
+pub fn produce_sealed_hash(input: U256) -> Result<SealedHash, PoseidonError> {
+    PoseidonHash::new().hash_single_default(input).map(SealedHash::seal)
+}
+
+/// Consumes a sealed hash and returns the underlying value
+pub fn finalize(h: SealedHash) -> U256 {
+    h.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[deny(unused_must_use)]
+    fn test_sealed_hash_must_be_used() {
+        let input = U256::from(42);
+        let sealed = produce_sealed_hash(input).unwrap();
+        let value = finalize(sealed);
+
+        let expected = PoseidonHash::new().hash_single_default(input).unwrap();
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn test_debug_hides_value() {
+        let sealed = produce_sealed_hash(U256::from(42)).unwrap();
+        assert_eq!(format!("{sealed:?}"), r#"SealedHash("<redacted>")"#);
+        let _ = finalize(sealed);
+    }
+
+    #[test]
+    fn test_sealed_hash_rejects_invalid_input() {
+        let modulus = PoseidonHash::new().params.modulus;
+        assert!(produce_sealed_hash(modulus).is_err());
+    }
+}
@@ -0,0 +1,266 @@
+//! Poseidon sponge over the circomlib permutation
+//!
+//! The state is `[capacity, rate...]` with the capacity element first, the
+//! layout circomlib uses. Absorbed elements are added to the rate part and the
+//! state is permuted every `rate = T - 1` elements. Squeezing permutes any
+//! partially filled block (missing elements count as zero) and returns the
+//! capacity element, so absorbing exactly `T - 1` elements and squeezing
+//! gives the same result as circomlibjs `poseidon` with `T - 1` inputs.
+//! Each further squeeze permutes again.
+//!
+//! There is no length padding, so inputs that differ only by trailing zeros
+//! within the last block hash alike. `with_length` and `hash_with_length`
+//! bind the input length into the capacity element, as Halo2's
+//! `ConstantLength` domain does, for variable-length data.
+//!
+//! The state is zeroized on drop, since it can hold secret input that has not
+//! been permuted yet.
+
+use super::permutation::permute;
+use crate::errors::PoseidonError;
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::U256;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Bytes packed into each element by `absorb_bytes`
+///
+/// 31 bytes stay below 2^248, under the BN254 scalar field modulus.
+pub const BYTES_PER_ELEMENT: usize = 31;
+
+/// Splits `data` into big-endian elements of `BYTES_PER_ELEMENT` bytes
+///
+/// A shorter last chunk is read as a smaller integer, so every element is
+/// below the BN254 scalar field modulus.
+pub fn bytes_to_elements(data: &[u8]) -> impl Iterator<Item = U256> + '_ {
+    data.chunks(BYTES_PER_ELEMENT).map(U256::from_be_slice)
+}
+
+/// Poseidon sponge with state width `T`
+#[derive(Debug, Clone)]
+pub struct PoseidonSponge<const T: usize = 3> {
+    state: [U256; T],
+    /// Elements absorbed into the current block
+    pending: usize,
+    /// Whether the state holds unsqueezed input or a fresh permutation
+    fresh: bool,
+}
+
+impl<const T: usize> Default for PoseidonSponge<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Clears the state and the absorbed element count
+impl<const T: usize> Zeroize for PoseidonSponge<T> {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+        self.pending = 0;
+        self.fresh = false;
+    }
+}
+
+impl<const T: usize> Drop for PoseidonSponge<T> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<const T: usize> ZeroizeOnDrop for PoseidonSponge<T> {}
+
+impl<const T: usize> PoseidonSponge<T> {
+    /// Creates a sponge with an all-zero state
+    pub fn new() -> Self {
+        const {
+            assert!(T >= 2 && T <= 17, "circomlib constants cover widths 2 to 17");
+        }
+
+        Self {
+            state: [U256::ZERO; T],
+            pending: 0,
+            fresh: false,
+        }
+    }
+
+    /// Creates a sponge for exactly `len` elements, with `len << 64` as capacity
+    pub fn with_length(len: usize) -> Self {
+        let mut sponge = Self::new();
+        sponge.state[0] = U256::from(len) << 64;
+        sponge
+    }
+
+    /// Number of elements absorbed per permutation
+    pub const fn rate() -> usize {
+        T - 1
+    }
+
+    /// Absorbs one field element
+    pub fn absorb(&mut self, element: U256) -> Result<(), PoseidonError> {
+        if element >= BN254_MODULUS {
+            return Err(PoseidonError::FieldElementTooLarge(element));
+        }
+
+        let slot = 1 + self.pending;
+        self.state[slot] = self.state[slot].add_mod(element, BN254_MODULUS);
+        self.pending += 1;
+        self.fresh = false;
+
+        if self.pending == Self::rate() {
+            permute(&mut self.state)?;
+            self.pending = 0;
+            self.fresh = true;
+        }
+        Ok(())
+    }
+
+    /// Absorbs field elements in order
+    pub fn absorb_slice(&mut self, elements: &[U256]) -> Result<(), PoseidonError> {
+        elements.iter().try_for_each(|&element| self.absorb(element))
+    }
+
+    /// Absorbs `data` as big-endian integers of `BYTES_PER_ELEMENT` bytes each
+    ///
+    /// A shorter last chunk is read as a smaller integer, that is zero-padded
+    /// on the left, so leading zero bytes of a chunk and the data length are
+    /// not encoded. Absorb the length first when it varies.
+    pub fn absorb_bytes(&mut self, data: &[u8]) -> Result<(), PoseidonError> {
+        bytes_to_elements(data).try_for_each(|element| self.absorb(element))
+    }
+
+    /// Squeezes one field element
+    pub fn squeeze(&mut self) -> Result<U256, PoseidonError> {
+        if !self.fresh {
+            permute(&mut self.state)?;
+        }
+        self.pending = 0;
+        self.fresh = false;
+        Ok(self.state[0])
+    }
+
+    /// Absorbs `elements` into a new sponge and squeezes once
+    pub fn hash(elements: &[U256]) -> Result<U256, PoseidonError> {
+        if elements.is_empty() {
+            return Err(PoseidonError::InvalidInputLength(0));
+        }
+        let mut sponge = Self::new();
+        sponge.absorb_slice(elements)?;
+        sponge.squeeze()
+    }
+
+    /// Like `hash`, with the length of `elements` bound into the capacity
+    pub fn hash_with_length(elements: &[U256]) -> Result<U256, PoseidonError> {
+        if elements.is_empty() {
+            return Err(PoseidonError::InvalidInputLength(0));
+        }
+        let mut sponge = Self::with_length(elements.len());
+        sponge.absorb_slice(elements)?;
+        sponge.squeeze()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poseidon::PoseidonHash;
+
+    #[test]
+    fn test_single_block_matches_circomlib() {
+        let hasher = PoseidonHash::new();
+        let (left, right) = (U256::from(1), U256::from(2));
+
+        let mut sponge = PoseidonSponge::<3>::new();
+        sponge.absorb_slice(&[left, right]).unwrap();
+        assert_eq!(sponge.squeeze().unwrap(), hasher.hash_pair_production(left, right).unwrap());
+
+        let inputs = [1u64, 2, 3, 4].map(U256::from);
+        assert_eq!(
+            PoseidonSponge::<5>::hash(&inputs).unwrap(),
+            hasher.hash_array_production(&inputs).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_multi_block_absorption() {
+        let inputs: Vec<U256> = (1..=7u64).map(U256::from).collect();
+        let hash = PoseidonSponge::<3>::hash(&inputs).unwrap();
+
+        // Element by element absorption is the same as absorbing the slice
+        let mut sponge = PoseidonSponge::<3>::new();
+        for &input in &inputs {
+            sponge.absorb(input).unwrap();
+        }
+        assert_eq!(sponge.squeeze().unwrap(), hash);
+
+        // The partial last block is zero-filled, so only a full block tells a trailing zero apart
+        let mut padded = inputs.clone();
+        padded.push(U256::ZERO);
+        assert_eq!(PoseidonSponge::<3>::hash(&padded).unwrap(), hash);
+        padded.push(U256::ZERO);
+        assert_ne!(PoseidonSponge::<3>::hash(&padded).unwrap(), hash);
+        assert_ne!(PoseidonSponge::<3>::hash(&inputs[..6]).unwrap(), hash);
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut sponge = PoseidonSponge::<3>::new();
+        sponge.absorb_slice(&[U256::from(1), U256::from(2), U256::from(3)]).unwrap();
+        assert!(sponge.state.iter().any(|word| !word.is_zero()));
+
+        sponge.zeroize();
+        assert!(sponge.state.iter().flat_map(|word| word.as_limbs()).all(|limb| *limb == 0));
+        assert_eq!(sponge.pending, 0);
+    }
+
+    #[test]
+    fn test_length_in_capacity() {
+        let inputs: Vec<U256> = (1..=7u64).map(U256::from).collect();
+        let hash = PoseidonSponge::<3>::hash_with_length(&inputs).unwrap();
+        assert_ne!(hash, PoseidonSponge::<3>::hash(&inputs).unwrap());
+
+        // A trailing zero changes the length, so it no longer collides
+        let mut padded = inputs.clone();
+        padded.push(U256::ZERO);
+        assert_ne!(PoseidonSponge::<3>::hash_with_length(&padded).unwrap(), hash);
+    }
+
+    #[test]
+    fn test_absorb_bytes() {
+        let mut bytes = PoseidonSponge::<3>::new();
+        bytes.absorb_bytes(b"hello").unwrap();
+        let mut element = PoseidonSponge::<3>::new();
+        element.absorb(U256::from(0x68656c6c6fu64)).unwrap();
+        assert_eq!(bytes.squeeze().unwrap(), element.squeeze().unwrap());
+
+        // 64 bytes are two full chunks and a 2-byte tail
+        let data: Vec<u8> = (0..64).collect();
+        let mut sponge = PoseidonSponge::<3>::new();
+        sponge.absorb_bytes(&data).unwrap();
+        let chunks = [&data[..31], &data[31..62], &data[62..]].map(U256::from_be_slice);
+        assert_eq!(chunks[2], U256::from(0x3e3fu64));
+        assert_eq!(sponge.squeeze().unwrap(), PoseidonSponge::<3>::hash(&chunks).unwrap());
+
+        // An address is a single element
+        let address = [0xffu8; 20];
+        let mut sponge = PoseidonSponge::<3>::new();
+        sponge.absorb_bytes(&address).unwrap();
+        let expected = PoseidonSponge::<3>::hash(&[U256::from_be_slice(&address)]).unwrap();
+        assert_eq!(sponge.squeeze().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_repeated_squeeze() {
+        let mut sponge = PoseidonSponge::<3>::new();
+        sponge.absorb(U256::from(42)).unwrap();
+        let first = sponge.squeeze().unwrap();
+        let second = sponge.squeeze().unwrap();
+        assert_ne!(first, second);
+        assert!(second < BN254_MODULUS);
+    }
+
+    #[test]
+    fn test_rejects_invalid_input() {
+        let mut sponge = PoseidonSponge::<3>::new();
+        assert!(sponge.absorb(BN254_MODULUS).is_err());
+        assert!(PoseidonSponge::<3>::hash(&[]).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+//! Poseidon with the parameter set encoded in the type
+//!
+//! `PoseidonHashGeneric<FULL, PARTIAL, T>` fixes the number of full rounds,
+//! partial rounds and the state width at compile time, so every call is
+//! monomorphized for its parameter set and the round loops have constant
+//! bounds. The state lives in a `[U256; T]` array on the stack.
+//!
+//! The permutation uses the same placeholder round constant scheme as
+//! `POSEIDON_ROUND_CONSTANTS` and a Cauchy MDS matrix over the BN254 scalar
+//! field. Its outputs are deterministic but differ from `PoseidonHash`.
+
+use super::core::PoseidonParams;
+use super::mds::generate_cauchy_mds;
+use crate::errors::PoseidonError;
+use alloy_primitives::U256;
+
+/// Width 3 with 8 full and 57 partial rounds over placeholder constants
+///
+/// The round counts are circomlib's for two inputs, but the constants are
+/// not, so hashes do not match `PoseidonHash` or circomlib.
+pub type PlaceholderPoseidon = PoseidonHashGeneric<8, 57, 3>;
+
+/// Poseidon hash over the BN254 scalar field with a statically fixed parameter set
+pub struct PoseidonHashGeneric<const FULL: usize, const PARTIAL: usize, const T: usize> {
+    modulus: U256,
+    mds: [[U256; T]; T],
+}
+
+impl<const FULL: usize, const PARTIAL: usize, const T: usize> Default
+    for PoseidonHashGeneric<FULL, PARTIAL, T>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const FULL: usize, const PARTIAL: usize, const T: usize> PoseidonHashGeneric<FULL, PARTIAL, T> {
+    /// Creates the hasher for this parameter set
+    pub fn new() -> Self {
+        const {
+            assert!(T >= 2, "width must hold a capacity and a rate element");
+            assert!(FULL % 2 == 0, "full rounds are split evenly around the partial rounds");
+        }
+
+        let modulus = PoseidonParams::default().modulus;
+
+        let cauchy = generate_cauchy_mds(T, modulus);
+        let mds = core::array::from_fn(|i| core::array::from_fn(|j| cauchy[i][j]));
+
+        Self { modulus, mds }
+    }
+
+    /// Computes the Poseidon hash of a single element
+    pub fn hash_single(&self, input: U256) -> Result<U256, PoseidonError> {
+        let mut state = [U256::ZERO; T];
+        state[0] = U256::from(1);
+        state[1] = self.validate(input)?;
+        Ok(self.permute(state)[1])
+    }
+
+    /// Computes the Poseidon hash of two elements
+    pub fn hash_pair(&self, left: U256, right: U256) -> Result<U256, PoseidonError> {
+        const {
+            assert!(T >= 3, "hash_pair needs a width of at least 3");
+        }
+
+        let mut state = [U256::ZERO; T];
+        state[0] = U256::from(2);
+        state[1] = self.validate(left)?;
+        state[2] = self.validate(right)?;
+        Ok(self.permute(state)[1])
+    }
+
+    fn validate(&self, element: U256) -> Result<U256, PoseidonError> {
+        if element >= self.modulus {
+            return Err(PoseidonError::FieldElementTooLarge(element));
+        }
+        Ok(element)
+    }
+
+    /// Applies the full Poseidon permutation
+    fn permute(&self, mut state: [U256; T]) -> [U256; T] {
+        let half_full = FULL / 2;
+
+        for round in 0..FULL + PARTIAL {
+            // AddRoundConstants
+            for (i, element) in state.iter_mut().enumerate() {
+                let constant = U256::from(round * T + i + 1);
+                *element = element.add_mod(constant, self.modulus);
+            }
+
+            // SubWords: full rounds at both ends, partial rounds in the middle
+            if round < half_full || round >= half_full + PARTIAL {
+                for element in state.iter_mut() {
+                    *element = self.sbox(*element);
+                }
+            } else {
+                state[0] = self.sbox(state[0]);
+            }
+
+            // MixLayer
+            let mut mixed = [U256::ZERO; T];
+            for (i, out) in mixed.iter_mut().enumerate() {
+                for (j, element) in state.iter().enumerate() {
+                    let term = self.mds[i][j].mul_mod(*element, self.modulus);
+                    *out = out.add_mod(term, self.modulus);
+                }
+            }
+            state = mixed;
+        }
+
+        state
+    }
+
+    /// S-box: x^5 mod p
+    fn sbox(&self, x: U256) -> U256 {
+        let x2 = x.mul_mod(x, self.modulus);
+        let x4 = x2.mul_mod(x2, self.modulus);
+        x4.mul_mod(x, self.modulus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_placeholder_variant() {
+        let hasher = PlaceholderPoseidon::new();
+        let a = U256::from(1);
+        let b = U256::from(2);
+
+        assert_eq!(hasher.hash_pair(a, b).unwrap(), hasher.hash_pair(a, b).unwrap());
+        assert_ne!(hasher.hash_pair(a, b).unwrap(), hasher.hash_pair(b, a).unwrap());
+        assert_ne!(hasher.hash_single(a).unwrap(), hasher.hash_pair(a, U256::ZERO).unwrap());
+        assert!(hasher.hash_single(a).unwrap() < PoseidonParams::default().modulus);
+    }
+
+    #[test]
+    fn test_parameter_sets_are_distinct() {
+        let input = U256::from(42);
+        let placeholder = PlaceholderPoseidon::new().hash_single(input).unwrap();
+        let wider = PoseidonHashGeneric::<8, 57, 5>::new().hash_single(input).unwrap();
+        let fewer_rounds = PoseidonHashGeneric::<8, 56, 3>::new().hash_single(input).unwrap();
+
+        assert_ne!(placeholder, wider);
+        assert_ne!(placeholder, fewer_rounds);
+    }
+
+    #[test]
+    fn test_rejects_out_of_field_input() {
+        let hasher = PlaceholderPoseidon::new();
+        let modulus = PoseidonParams::default().modulus;
+        assert!(hasher.hash_single(modulus).is_err());
+        assert!(hasher.hash_pair(U256::ZERO, modulus).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+//! Poseidon hashing of a byte stream that arrives in pieces
+//!
+//! Bytes are packed into big-endian field elements of `BYTES_PER_ELEMENT`
+//! bytes, as `PoseidonSponge::absorb_bytes` does, but the chunk boundaries of
+//! the stream do not matter: a partial element waits in the buffer until the
+//! next bytes complete it. Squeezing pads the tail to a full element with a
+//! `0x01` byte and then zeros, so the padding marks where the data ends and
+//! streams that differ only by trailing zero bytes hash differently.
+
+use super::sponge::{PoseidonSponge, BYTES_PER_ELEMENT};
+use crate::errors::PoseidonError;
+use alloy_primitives::U256;
+
+/// Byte that ends the stream before squeezing
+const PADDING_BYTE: u8 = 0x01;
+
+/// Incremental Poseidon hash of a byte stream
+#[derive(Debug, Clone, Default)]
+pub struct PoseidonHashStream {
+    sponge: PoseidonSponge,
+    /// Bytes of the next, incomplete element
+    buffer: Vec<u8>,
+    elements_absorbed: usize,
+}
+
+impl PoseidonHashStream {
+    /// Creates an empty stream
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `data`, absorbing every element it completes
+    pub fn absorb_bytes(&mut self, data: &[u8]) -> Result<(), PoseidonError> {
+        for &byte in data {
+            self.buffer.push(byte);
+            if self.buffer.len() == BYTES_PER_ELEMENT {
+                self.sponge.absorb(U256::from_be_slice(&self.buffer))?;
+                self.buffer.clear();
+                self.elements_absorbed += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Pads the buffered tail, absorbs it and returns the hash
+    ///
+    /// The stream is reset afterwards, ready for new data.
+    pub fn squeeze_hash(&mut self) -> Result<U256, PoseidonError> {
+        // The buffer holds at most 30 bytes, so the padded tail fits one element.
+        // Padding to the full width keeps the tail's leading zeros significant.
+        self.buffer.push(PADDING_BYTE);
+        self.buffer.resize(BYTES_PER_ELEMENT, 0);
+        let mut stream = core::mem::take(self);
+        stream.sponge.absorb(U256::from_be_slice(&stream.buffer))?;
+        stream.sponge.squeeze()
+    }
+
+    /// Returns the number of bytes waiting for a complete element
+    pub fn bytes_buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns the number of complete elements absorbed so far
+    pub fn elements_absorbed(&self) -> usize {
+        self.elements_absorbed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_in_chunks(data: &[u8], chunk_size: usize) -> U256 {
+        let mut stream = PoseidonHashStream::new();
+        for chunk in data.chunks(chunk_size) {
+            stream.absorb_bytes(chunk).unwrap();
+        }
+        stream.squeeze_hash().unwrap()
+    }
+
+    #[test]
+    fn test_chunking_does_not_change_hash() {
+        let data: Vec<u8> = (0..200u32).map(|i| (i * 37 % 256) as u8).collect();
+        let expected = hash_in_chunks(&data, data.len());
+
+        for chunk_size in [1, 7, 31, 32] {
+            assert_eq!(hash_in_chunks(&data, chunk_size), expected, "chunks of {}", chunk_size);
+        }
+    }
+
+    #[test]
+    fn test_matches_sponge_packing() {
+        // 62 bytes are two full elements, then an element of padding alone
+        let data: Vec<u8> = (0..62).collect();
+        let mut sponge = PoseidonSponge::<3>::new();
+        sponge.absorb_bytes(&data).unwrap();
+        let mut padding = [0u8; BYTES_PER_ELEMENT];
+        padding[0] = PADDING_BYTE;
+        sponge.absorb(U256::from_be_slice(&padding)).unwrap();
+
+        assert_eq!(hash_in_chunks(&data, 5), sponge.squeeze().unwrap());
+    }
+
+    #[test]
+    fn test_padding_and_reset() {
+        let mut stream = PoseidonHashStream::new();
+        stream.absorb_bytes(&[7; 40]).unwrap();
+        assert_eq!(stream.bytes_buffered(), 9);
+        assert_eq!(stream.elements_absorbed(), 1);
+
+        let hash = stream.squeeze_hash().unwrap();
+        assert_eq!(stream.bytes_buffered(), 0);
+        assert_eq!(stream.elements_absorbed(), 0);
+        assert_eq!(hash_in_chunks(&[7; 40], 40), hash);
+
+        // Trailing zero bytes are part of the hashed data
+        assert_ne!(hash_in_chunks(&[7; 41], 41), hash);
+        let mut zero_tail = vec![7; 40];
+        zero_tail.push(0);
+        assert_ne!(hash_in_chunks(&zero_tail, 41), hash);
+        assert_ne!(hash_in_chunks(&[], 1), hash_in_chunks(&[0], 1));
+        assert_ne!(hash_in_chunks(&[0], 1), hash_in_chunks(&[0, 0], 1));
+        assert_ne!(hash_in_chunks(&[0; 30], 30), hash_in_chunks(&[0; 29], 29));
+    }
+}
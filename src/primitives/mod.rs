@@ -0,0 +1,10 @@
+//! Building blocks for protocols built on the precompiles
+
+pub mod nonce;
+pub mod signature;
+
+pub use nonce::FieldNonce;
+pub use signature::{verify_signature, PoseidonKeyPair, PoseidonSignature};
+
+#[cfg(feature = "stylus")]
+pub use nonce::NonceManager;
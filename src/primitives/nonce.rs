@@ -0,0 +1,205 @@
+//! Non-repeating field element nonces
+//!
+//! Reusing the same randomness for two commitments breaks hiding. A
+//! `FieldNonce` derives each nonce as `Poseidon([seed, counter])` and bumps the
+//! counter afterwards, so a seed never yields the same value twice. A counter
+//! that would wrap is an error rather than a restart from zero.
+
+use crate::errors::{NonceError, PoseidonError, PrecompileError};
+use crate::poseidon::PoseidonHash;
+use alloy_primitives::U256;
+
+/// Counter-based generator of field element nonces
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldNonce {
+    pub seed: U256,
+    pub counter: u64,
+}
+
+impl FieldNonce {
+    /// Creates a generator starting at counter zero
+    pub fn new(seed: U256) -> Self {
+        Self { seed, counter: 0 }
+    }
+
+    /// Returns the next nonce and advances the counter
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<U256, PrecompileError> {
+        let nonce = self.peek()?;
+        self.counter = advance(self.counter, 1)?;
+        Ok(nonce)
+    }
+
+    /// Skips `n` nonces without computing them
+    pub fn skip(&mut self, n: u64) -> Result<(), PrecompileError> {
+        self.counter = advance(self.counter, n)?;
+        Ok(())
+    }
+
+    /// Returns the next nonce without advancing the counter
+    pub fn peek(&self) -> Result<U256, PrecompileError> {
+        Ok(derive_nonce(self.seed, self.counter)?)
+    }
+}
+
+/// Adds `n` to `counter`, failing instead of wrapping back to used nonces
+fn advance(counter: u64, n: u64) -> Result<u64, NonceError> {
+    counter.checked_add(n).ok_or(NonceError::CounterOverflow { counter, n })
+}
+
+/// Computes Poseidon([seed, counter])
+fn derive_nonce(seed: U256, counter: u64) -> Result<U256, PoseidonError> {
+    PoseidonHash::new().hash_pair_production(seed, U256::from(counter))
+}
+
+#[cfg(feature = "stylus")]
+mod storage {
+    extern crate alloc;
+
+    use super::{advance, derive_nonce};
+    use crate::errors::encode_revert;
+    use crate::prelude::is_valid_bn254_field_element;
+    use crate::SolStorageLayout;
+    use alloy_primitives::{Address, U256, U64};
+    use stylus_sdk::prelude::*;
+    use stylus_sdk::storage::{StorageAddress, StorageU256, StorageU64};
+
+    /// Persists a nonce seed and counter across transactions
+    ///
+    /// The first `setSeed` call fixes the seed for good and makes its caller
+    /// the owner. Only the owner can advance the counter.
+    #[storage]
+    #[derive(SolStorageLayout)]
+    pub struct NonceManager {
+        owner: StorageAddress,
+        seed: StorageU256,
+        counter: StorageU64,
+    }
+
+    #[public]
+    impl NonceManager {
+        /// Sets the seed once and records the caller as owner
+        pub fn set_seed(&mut self, seed: U256) -> Result<(), Vec<u8>> {
+            if self.owner.get() != Address::ZERO {
+                return Err(encode_revert("Nonce seed already set"));
+            }
+            if !is_valid_bn254_field_element(seed) {
+                return Err(encode_revert("Nonce seed is not a field element"));
+            }
+            self.owner.set(stylus_sdk::msg::sender());
+            self.seed.set(seed);
+            Ok(())
+        }
+
+        /// Returns the next nonce and advances the stored counter
+        pub fn next_nonce(&mut self) -> Result<U256, Vec<u8>> {
+            self.only_owner()?;
+            let counter = self.counter.get().to::<u64>();
+            let nonce = derive_nonce(self.seed.get(), counter)
+                .map_err(|e| encode_revert(&e.to_string()))?;
+            self.set_counter(counter, 1)?;
+            Ok(nonce)
+        }
+
+        /// Returns the next nonce without advancing the stored counter
+        pub fn peek_nonce(&self) -> Result<U256, Vec<u8>> {
+            derive_nonce(self.seed.get(), self.counter.get().to::<u64>())
+                .map_err(|e| encode_revert(&e.to_string()))
+        }
+
+        /// Skips `n` nonces
+        pub fn skip_nonces(&mut self, n: u64) -> Result<(), Vec<u8>> {
+            self.only_owner()?;
+            self.set_counter(self.counter.get().to::<u64>(), n)
+        }
+
+        /// Returns the current counter
+        pub fn nonce_counter(&self) -> u64 {
+            self.counter.get().to::<u64>()
+        }
+
+        /// Returns the address allowed to advance the counter
+        pub fn nonce_owner(&self) -> Address {
+            self.owner.get()
+        }
+    }
+
+    impl NonceManager {
+        /// Reverts unless the caller set the seed
+        fn only_owner(&self) -> Result<(), Vec<u8>> {
+            if stylus_sdk::msg::sender() != self.owner.get() {
+                return Err(encode_revert("Caller is not the nonce owner"));
+            }
+            Ok(())
+        }
+
+        /// Stores `counter + n`, reverting on overflow
+        fn set_counter(&mut self, counter: u64, n: u64) -> Result<(), Vec<u8>> {
+            let next = advance(counter, n).map_err(|e| encode_revert(&e.to_string()))?;
+            self.counter.set(U64::from(next));
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "stylus")]
+pub use storage::NonceManager;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_next_never_repeats() {
+        let mut nonces = FieldNonce::new(U256::from(42));
+        let mut seen = HashSet::new();
+
+        for _ in 0..1000 {
+            assert!(seen.insert(nonces.next().unwrap()), "nonce repeated");
+        }
+        assert_eq!(nonces.counter, 1000);
+    }
+
+    #[test]
+    fn test_peek_does_not_advance() {
+        let mut nonces = FieldNonce::new(U256::from(7));
+
+        let peeked = nonces.peek().unwrap();
+        assert_eq!(nonces.peek().unwrap(), peeked);
+        assert_eq!(nonces.next().unwrap(), peeked);
+        assert_ne!(nonces.peek().unwrap(), peeked);
+    }
+
+    #[test]
+    fn test_skip_preserves_sequence() {
+        let mut skipped = FieldNonce::new(U256::from(7));
+        let mut stepped = skipped.clone();
+
+        skipped.skip(3).unwrap();
+        for _ in 0..3 {
+            stepped.next().unwrap();
+        }
+        assert_eq!(skipped.next().unwrap(), stepped.next().unwrap());
+    }
+
+    #[test]
+    fn test_counter_overflow_rejected() {
+        let mut nonces = FieldNonce { seed: U256::from(7), counter: u64::MAX - 1 };
+
+        assert!(nonces.skip(2).is_err());
+        assert_eq!(nonces.counter, u64::MAX - 1);
+        nonces.next().unwrap();
+        assert!(matches!(
+            nonces.next(),
+            Err(PrecompileError::Nonce(NonceError::CounterOverflow { .. }))
+        ));
+        assert_eq!(nonces.counter, u64::MAX);
+    }
+
+    #[test]
+    fn test_seed_out_of_field_rejected() {
+        let modulus = PoseidonHash::new().params.modulus;
+        assert!(FieldNonce::new(modulus).next().is_err());
+    }
+}
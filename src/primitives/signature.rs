@@ -0,0 +1,142 @@
+//! Schnorr signatures over Baby Jubjub with Poseidon challenges
+//!
+//! A key pair is a scalar `a` in `[1, l)` and its public key `A = a·B`, with
+//! `B` the Baby Jubjub base point of prime order `l`. Signing `m` takes the
+//! nonce `k = Poseidon(a, m) mod l`, sets `R = k·B`, the challenge
+//! `e = Poseidon(R.x, R.y, A.x, A.y, m) mod l` and `s = k + e·a mod l`; a
+//! signature `(R, s)` is valid when `s·B = R + e·A`.
+//!
+//! The nonce is derived from the key and message, so signing never needs
+//! randomness and the same message always gets the same signature. The
+//! private key is held in `Zeroizing` and wiped when the key pair drops.
+
+use crate::curves::{BabyJubjubPoint, BASE_POINT, SUBGROUP_ORDER};
+use crate::errors::{CurveError, PrecompileError};
+use crate::poseidon::PoseidonHash;
+use alloy_primitives::U256;
+use core::fmt;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Baby Jubjub key pair for Poseidon Schnorr signatures
+pub struct PoseidonKeyPair {
+    pub private_key: Zeroizing<U256>,
+    pub public_key: BabyJubjubPoint,
+}
+
+/// Signature `(R, s)` over a single field element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoseidonSignature {
+    pub r: BabyJubjubPoint,
+    pub s: U256,
+}
+
+impl PoseidonKeyPair {
+    /// Derives the public key, rejecting private keys outside `[1, l)`
+    pub fn from_private_key(private_key: U256) -> Result<Self, PrecompileError> {
+        let private_key = Zeroizing::new(private_key);
+        if private_key.is_zero() || *private_key >= SUBGROUP_ORDER {
+            return Err(CurveError::InvalidScalar(*private_key).into());
+        }
+
+        let public_key = BabyJubjubPoint::scalar_mul(&BASE_POINT, *private_key);
+        Ok(Self { private_key, public_key })
+    }
+
+    /// Signs `message`, which must be a BN254 scalar field element
+    pub fn sign(&self, message: U256) -> Result<PoseidonSignature, PrecompileError> {
+        let nonce = Zeroizing::new(
+            PoseidonHash::new().hash_pair_production(*self.private_key, message)? % SUBGROUP_ORDER,
+        );
+        let r = BabyJubjubPoint::scalar_mul(&BASE_POINT, *nonce);
+        let e = challenge(&r, &self.public_key, message)?;
+        let s = nonce.add_mod(e.mul_mod(*self.private_key, SUBGROUP_ORDER), SUBGROUP_ORDER);
+        Ok(PoseidonSignature { r, s })
+    }
+}
+
+impl Zeroize for PoseidonKeyPair {
+    fn zeroize(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
+impl fmt::Debug for PoseidonKeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoseidonKeyPair")
+            .field("private_key", &"<redacted>")
+            .field("public_key", &self.public_key)
+            .finish()
+    }
+}
+
+/// Checks `signature` on `message` against `public_key`
+pub fn verify_signature(
+    public_key: &BabyJubjubPoint,
+    message: U256,
+    signature: &PoseidonSignature,
+) -> bool {
+    if signature.s >= SUBGROUP_ORDER {
+        return false;
+    }
+    let Ok(e) = challenge(&signature.r, public_key, message) else {
+        return false;
+    };
+
+    let lhs = BabyJubjubPoint::scalar_mul(&BASE_POINT, signature.s);
+    let rhs = BabyJubjubPoint::add(&signature.r, &BabyJubjubPoint::scalar_mul(public_key, e));
+    lhs == rhs
+}
+
+/// `Poseidon(R.x, R.y, A.x, A.y, m) mod l`
+fn challenge(
+    r: &BabyJubjubPoint,
+    public_key: &BabyJubjubPoint,
+    message: U256,
+) -> Result<U256, PrecompileError> {
+    let ((rx, ry), (ax, ay)) = (r.to_affine(), public_key.to_affine());
+    let hash = PoseidonHash::new().hash_array_production(&[rx, ry, ax, ay, message])?;
+    Ok(hash % SUBGROUP_ORDER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let keys = PoseidonKeyPair::from_private_key(U256::from(0xc0ffee)).unwrap();
+        let message = U256::from(42);
+        let signature = keys.sign(message).unwrap();
+
+        assert!(verify_signature(&keys.public_key, message, &signature));
+        assert_eq!(keys.sign(message).unwrap(), signature);
+        assert!(!verify_signature(&keys.public_key, U256::from(43), &signature));
+
+        let other = PoseidonKeyPair::from_private_key(U256::from(7)).unwrap();
+        assert!(!verify_signature(&other.public_key, message, &signature));
+
+        let forged = PoseidonSignature { s: signature.s + U256::from(1), ..signature };
+        assert!(!verify_signature(&keys.public_key, message, &forged));
+        let unreduced = PoseidonSignature { s: signature.s + SUBGROUP_ORDER, ..signature };
+        assert!(!verify_signature(&keys.public_key, message, &unreduced));
+    }
+
+    #[test]
+    fn test_rejects_invalid_keys() {
+        for key in [U256::ZERO, SUBGROUP_ORDER] {
+            assert!(matches!(
+                PoseidonKeyPair::from_private_key(key),
+                Err(PrecompileError::Curve(CurveError::InvalidScalar(_)))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut keys = PoseidonKeyPair::from_private_key(U256::from(0xc0ffee)).unwrap();
+        assert!(!format!("{keys:?}").contains(&0xc0ffee.to_string()));
+
+        keys.zeroize();
+        assert!(keys.private_key.as_limbs().iter().all(|limb| *limb == 0));
+    }
+}
@@ -0,0 +1,174 @@
+//! Cycle estimates for Poseidon hashing
+//!
+//! Stylus charges for WASM execution by cycle count rather than wall time. No
+//! cycle counter is available natively, so elapsed nanoseconds measured with
+//! `Instant` serve as a proxy, and the cost of hashing `n` inputs with
+//! `hash_array_production` is modelled as `a + b*n + c*n^2`: the MDS layer
+//! grows quadratically with the state width.
+//!
+//! The default coefficients were fitted to a native release build hashing
+//! 2 to 16 inputs. Use `calibrate` to refit them on the target machine.
+
+use crate::poseidon::PoseidonHash;
+use alloy_primitives::U256;
+use std::time::Instant;
+
+/// Quadratic cost model `a + b*n + c*n^2`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CycleModel {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+impl CycleModel {
+    /// Estimated cycles for hashing `input_count` elements
+    pub fn estimate(&self, input_count: usize) -> u64 {
+        let n = input_count as f64;
+        (self.a + self.b * n + self.c * n * n).max(0.0).round() as u64
+    }
+}
+
+/// Fitted to a native release build (nanoseconds per hash_array_production call)
+const DEFAULT_MODEL: CycleModel = CycleModel {
+    a: 37_917.0,
+    b: -2_724.0,
+    c: 2_831.0,
+};
+
+/// Estimated cycles for hashing `input_count` elements with the default model
+pub fn estimate_poseidon_cycles(input_count: usize) -> u64 {
+    DEFAULT_MODEL.estimate(input_count)
+}
+
+/// Measures one `hash_array_production` call, in nanoseconds as a cycle proxy
+pub fn measure_actual_cycles(hasher: &PoseidonHash, inputs: &[U256]) -> u64 {
+    let start = Instant::now();
+    let _ = hasher.hash_array_production(inputs);
+    start.elapsed().as_nanos() as u64
+}
+
+/// Ratio of expected to actual cycles; above 1.0 means the estimate was pessimistic
+pub fn report_cycle_efficiency(expected: u64, actual: u64) -> f64 {
+    if actual == 0 {
+        return f64::INFINITY;
+    }
+    expected as f64 / actual as f64
+}
+
+/// Least-squares fit of the quadratic model to `(input count, cycles)` samples
+///
+/// Returns `None` with fewer than three distinct input counts.
+pub fn fit_cycle_model(samples: &[(usize, u64)]) -> Option<CycleModel> {
+    // Normal equations: sum(n^(i+j)) * coeff_j = sum(y * n^i)
+    let mut matrix = [[0.0f64; 4]; 3];
+    for &(n, y) in samples {
+        let n = n as f64;
+        let powers = [1.0, n, n * n];
+        for i in 0..3 {
+            for j in 0..3 {
+                matrix[i][j] += powers[i] * powers[j];
+            }
+            matrix[i][3] += powers[i] * y as f64;
+        }
+    }
+
+    // Gauss-Jordan elimination with partial pivoting
+    for col in 0..3 {
+        let pivot = (col..3).max_by(|&x, &y| matrix[x][col].abs().total_cmp(&matrix[y][col].abs()))?;
+        if matrix[pivot][col].abs() < 1e-9 {
+            return None;
+        }
+        matrix.swap(col, pivot);
+
+        let scale = matrix[col][col];
+        for value in matrix[col].iter_mut() {
+            *value /= scale;
+        }
+        for row in 0..3 {
+            if row != col {
+                let factor = matrix[row][col];
+                for k in 0..4 {
+                    matrix[row][k] -= factor * matrix[col][k];
+                }
+            }
+        }
+    }
+
+    Some(CycleModel {
+        a: matrix[0][3],
+        b: matrix[1][3],
+        c: matrix[2][3],
+    })
+}
+
+/// Measures 1 to `max_inputs` inputs and fits a model, keeping the fastest of `repetitions` runs
+pub fn calibrate(hasher: &PoseidonHash, max_inputs: usize, repetitions: usize) -> Option<CycleModel> {
+    let samples: Vec<(usize, u64)> = (1..=max_inputs)
+        .map(|n| {
+            let inputs: Vec<U256> = (1..=n).map(U256::from).collect();
+            let fastest = (0..repetitions.max(1))
+                .map(|_| measure_actual_cycles(hasher, &inputs))
+                .min()
+                .unwrap_or(0);
+            (n, fastest)
+        })
+        .collect();
+
+    fit_cycle_model(&samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_recovers_polynomial() {
+        let truth = CycleModel {
+            a: 40_000.0,
+            b: 1_500.0,
+            c: 2_800.0,
+        };
+
+        // Deterministic +-5% noise around the true cost
+        let samples: Vec<(usize, u64)> = (1..=16)
+            .map(|n| {
+                let noise = 1.0 + 0.05 * if n % 2 == 0 { 1.0 } else { -1.0 };
+                (n, (truth.estimate(n) as f64 * noise) as u64)
+            })
+            .collect();
+
+        let model = fit_cycle_model(&samples).unwrap();
+        for n in 1..=16 {
+            let expected = truth.estimate(n) as f64;
+            let error = (model.estimate(n) as f64 - expected).abs() / expected;
+            assert!(error < 0.10, "fit error {:.3} at n = {}", error, n);
+        }
+    }
+
+    #[test]
+    fn test_fit_needs_three_points() {
+        assert!(fit_cycle_model(&[(1, 100), (2, 200)]).is_none());
+        assert!(fit_cycle_model(&[(4, 100), (4, 110), (4, 120)]).is_none());
+    }
+
+    #[test]
+    fn test_default_model_grows_with_inputs() {
+        for n in 1..16 {
+            assert!(estimate_poseidon_cycles(n + 1) > estimate_poseidon_cycles(n));
+        }
+    }
+
+    #[test]
+    fn test_calibration_and_efficiency() {
+        let hasher = PoseidonHash::new();
+        let model = calibrate(&hasher, 6, 3).unwrap();
+
+        let inputs = [U256::from(1), U256::from(2)];
+        let actual = measure_actual_cycles(&hasher, &inputs).max(1);
+        assert!(report_cycle_efficiency(model.estimate(2).max(1), actual) > 0.0);
+
+        assert_eq!(report_cycle_efficiency(150, 100), 1.5);
+        assert!(report_cycle_efficiency(1, 0).is_infinite());
+    }
+}
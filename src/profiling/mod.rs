@@ -0,0 +1,8 @@
+//! Cost profiling for precompile operations
+
+pub mod cycle_count;
+
+pub use cycle_count::{
+    calibrate, estimate_poseidon_cycles, fit_cycle_model, measure_actual_cycles,
+    report_cycle_efficiency, CycleModel,
+};
@@ -0,0 +1,218 @@
+//! Core Rescue-Prime hash implementation over BN254
+//!
+//! Each round applies the S-box `x^α`, the MDS layer and round constants, then
+//! the inverse S-box `x^(1/α)`, the MDS layer and a second set of constants.
+//! `α = 5` is the smallest exponent coprime to `p - 1` for the BN254 scalar
+//! field, and `1/α` is its inverse modulo `p - 1`.
+//!
+//! StarkWare's reference Rescue instance is defined over the STARK field
+//! (`2^251 + 17·2^192 + 1`), so its constants do not carry over to BN254.
+//! Here the round constants are `keccak256("rescue-bn254" || i)` reduced into
+//! the field and the MDS matrix is a Cauchy matrix, so outputs are
+//! deterministic but specific to this crate.
+//!
+//! Hashing is a sponge of width 3 with the input count in the capacity
+//! element, absorbing two elements per permutation and squeezing `state[0]`.
+
+use crate::errors::RescueError;
+use crate::poseidon::mds::generate_cauchy_mds;
+use crate::utils::BN254_MODULUS;
+use alloy_primitives::{keccak256, U256};
+
+/// State width (two rate elements, one capacity element)
+pub const RESCUE_WIDTH: usize = 3;
+
+/// Elements absorbed per permutation
+const RATE: usize = RESCUE_WIDTH - 1;
+
+/// Seed of the round constants
+pub const RESCUE_SEED: &[u8] = b"rescue-bn254";
+
+/// Rescue parameters for the BN254 scalar field
+pub struct RescueParams {
+    /// Prime field modulus (BN254 scalar field)
+    pub modulus: U256,
+    /// Number of rounds, each with a forward and an inverse S-box layer
+    pub rounds: usize,
+    /// S-box exponent
+    pub alpha: U256,
+    /// Inverse S-box exponent, `alpha^-1 mod (p - 1)`
+    pub alpha_inv: U256,
+}
+
+impl Default for RescueParams {
+    fn default() -> Self {
+        let alpha = U256::from(5);
+        Self {
+            modulus: BN254_MODULUS,
+            rounds: 10,
+            alpha,
+            alpha_inv: alpha
+                .inv_mod(BN254_MODULUS - U256::from(1))
+                .expect("alpha is coprime to p - 1"),
+        }
+    }
+}
+
+/// Rescue hash over the BN254 scalar field
+pub struct RescueHash {
+    pub params: RescueParams,
+    round_constants: Vec<[U256; RESCUE_WIDTH]>,
+    mds: [[U256; RESCUE_WIDTH]; RESCUE_WIDTH],
+}
+
+impl Default for RescueHash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RescueHash {
+    /// Creates a new hasher with default parameters
+    pub fn new() -> Self {
+        let params = RescueParams::default();
+
+        // Two constant vectors per round
+        let round_constants = (0..2 * params.rounds)
+            .map(|step| {
+                core::array::from_fn(|i| {
+                    let mut preimage = RESCUE_SEED.to_vec();
+                    preimage.extend_from_slice(&((step * RESCUE_WIDTH + i) as u64).to_be_bytes());
+                    U256::from_be_bytes(keccak256(preimage).0) % params.modulus
+                })
+            })
+            .collect();
+
+        let cauchy = generate_cauchy_mds(RESCUE_WIDTH, params.modulus);
+        let mds = core::array::from_fn(|i| core::array::from_fn(|j| cauchy[i][j]));
+
+        Self { params, round_constants, mds }
+    }
+
+    /// Validates that a field element is within the valid range for BN254
+    pub fn validate_field_element(&self, element: U256) -> Result<U256, RescueError> {
+        if element >= self.params.modulus {
+            return Err(RescueError::FieldElementTooLarge(element));
+        }
+        Ok(element)
+    }
+
+    /// Computes Rescue hash for a single element
+    pub fn hash_single(&self, input: U256) -> Result<U256, RescueError> {
+        self.hash_array(&[input])
+    }
+
+    /// Computes Rescue hash of two elements
+    pub fn hash_pair(&self, left: U256, right: U256) -> Result<U256, RescueError> {
+        self.hash_array(&[left, right])
+    }
+
+    /// Computes Rescue hash for an array of elements
+    pub fn hash_array(&self, inputs: &[U256]) -> Result<U256, RescueError> {
+        if inputs.is_empty() {
+            return Err(RescueError::InvalidInputLength(0));
+        }
+
+        let mut state = [U256::ZERO; RESCUE_WIDTH];
+        state[RATE] = U256::from(inputs.len());
+        for block in inputs.chunks(RATE) {
+            for (element, &input) in state.iter_mut().zip(block) {
+                *element = element.add_mod(self.validate_field_element(input)?, self.params.modulus);
+            }
+            self.permute(&mut state);
+        }
+
+        Ok(state[0])
+    }
+
+    /// Forward S-box `x^alpha`
+    pub fn sbox(&self, x: U256) -> U256 {
+        x.pow_mod(self.params.alpha, self.params.modulus)
+    }
+
+    /// Inverse S-box `x^(1/alpha)`
+    pub fn inverse_sbox(&self, x: U256) -> U256 {
+        x.pow_mod(self.params.alpha_inv, self.params.modulus)
+    }
+
+    /// Applies the Rescue permutation in place
+    pub fn permute(&self, state: &mut [U256; RESCUE_WIDTH]) {
+        for round in 0..self.params.rounds {
+            for element in state.iter_mut() {
+                *element = self.sbox(*element);
+            }
+            self.mix_and_add(state, 2 * round);
+
+            for element in state.iter_mut() {
+                *element = self.inverse_sbox(*element);
+            }
+            self.mix_and_add(state, 2 * round + 1);
+        }
+    }
+
+    /// MDS layer followed by the constants of `step`
+    fn mix_and_add(&self, state: &mut [U256; RESCUE_WIDTH], step: usize) {
+        let modulus = self.params.modulus;
+        let mut mixed = self.round_constants[step];
+        for (out, row) in mixed.iter_mut().zip(&self.mds) {
+            for (m, element) in row.iter().zip(state.iter()) {
+                *out = out.add_mod(m.mul_mod(*element, modulus), modulus);
+            }
+        }
+        *state = mixed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sbox_inversion() {
+        let hasher = RescueHash::new();
+        let modulus = hasher.params.modulus;
+
+        // alpha * alpha_inv = 1 (mod p - 1)
+        assert_eq!(
+            hasher.params.alpha.mul_mod(hasher.params.alpha_inv, modulus - U256::from(1)),
+            U256::from(1)
+        );
+
+        for seed in 0u64..32 {
+            let x = U256::from_be_bytes(keccak256(seed.to_be_bytes()).0) % modulus;
+            assert_eq!(hasher.inverse_sbox(hasher.sbox(x)), x);
+            assert_eq!(hasher.sbox(hasher.inverse_sbox(x)), x);
+        }
+
+        // Fixed points of x^5 are preserved by the inverse
+        for x in [U256::ZERO, U256::from(1), modulus - U256::from(1)] {
+            assert_eq!(hasher.inverse_sbox(x), x);
+        }
+    }
+
+    #[test]
+    fn test_sbox_is_multiplicative() {
+        let hasher = RescueHash::new();
+        let modulus = hasher.params.modulus;
+        let (a, b) = (U256::from(123456789), U256::from(987654321));
+
+        assert_eq!(
+            hasher.inverse_sbox(a.mul_mod(b, modulus)),
+            hasher.inverse_sbox(a).mul_mod(hasher.inverse_sbox(b), modulus)
+        );
+    }
+
+    #[test]
+    fn test_hash_interface() {
+        let hasher = RescueHash::new();
+        let (one, two) = (U256::from(1), U256::from(2));
+
+        assert_eq!(hasher.hash_pair(one, two).unwrap(), hasher.hash_pair(one, two).unwrap());
+        assert_ne!(hasher.hash_pair(one, two).unwrap(), hasher.hash_pair(two, one).unwrap());
+        assert_ne!(hasher.hash_single(one).unwrap(), hasher.hash_array(&[one, U256::ZERO]).unwrap());
+        assert!(hasher.hash_array(&[one, two, one]).unwrap() < hasher.params.modulus);
+
+        assert!(hasher.hash_array(&[]).is_err());
+        assert!(hasher.hash_single(hasher.params.modulus).is_err());
+    }
+}
@@ -0,0 +1,123 @@
+//! Rescue precompile interface and ABI definitions
+
+use super::core::RescueHash;
+use crate::errors::{RescueError, PrecompileError};
+use crate::poseidon::interface::decode_call;
+use crate::traits::{calldata_gas_estimate, GenericPrecompile};
+use alloy_sol_types::{sol, SolCall, SolValue};
+
+// Solidity interface definition
+sol! {
+    #[sol(abi)]
+    interface IRescueHash {
+        /// Computes Rescue hash of a single field element
+        /// @param input The field element to hash
+        /// @return hash The resulting Rescue hash
+        function rescue1(uint256 input) external pure returns (uint256 hash);
+
+        /// Computes Rescue hash of two field elements
+        /// @param left The left field element
+        /// @param right The right field element
+        /// @return hash The resulting Rescue hash
+        function rescue2(uint256 left, uint256 right) external pure returns (uint256 hash);
+
+        /// Computes Rescue hash of an array of field elements
+        /// @param inputs Array of field elements to hash
+        /// @return hash The resulting Rescue hash
+        function rescueN(uint256[] inputs) external pure returns (uint256 hash);
+    }
+}
+
+/// Estimated gas of a Rescue call before argument words
+const RESCUE_BASE_GAS: u64 = 25000;
+
+/// Estimated gas per 32-byte argument word
+const RESCUE_WORD_GAS: u64 = 6000;
+
+/// Rescue precompile behind the `IRescueHash` selectors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RescuePrecompile;
+
+impl RescuePrecompile {
+    /// Selectors of every `IRescueHash` function
+    pub const SELECTORS: [[u8; 4]; 3] = [
+        IRescueHash::rescue1Call::SELECTOR,
+        IRescueHash::rescue2Call::SELECTOR,
+        IRescueHash::rescueNCall::SELECTOR,
+    ];
+}
+
+impl GenericPrecompile for RescuePrecompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        Ok(rescue_precompile(input)?)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, input: &[u8]) -> u64 {
+        calldata_gas_estimate(input, RESCUE_BASE_GAS, RESCUE_WORD_GAS)
+    }
+}
+
+/// Precompile entry point - handles the raw call interface
+pub fn rescue_precompile(input: &[u8]) -> Result<Vec<u8>, RescueError> {
+    if input.len() < 4 {
+        return Err(RescueError::InvalidSelector);
+    }
+
+    let hasher = RescueHash::new();
+
+    let result = match &input[0..4] {
+        // rescue1(uint256)
+        s if s == IRescueHash::rescue1Call::SELECTOR => {
+            let decoded = decode_call::<IRescueHash::rescue1Call>(input)?;
+
+            hasher.hash_single(decoded.input)?
+        }
+
+        // rescue2(uint256,uint256)
+        s if s == IRescueHash::rescue2Call::SELECTOR => {
+            let decoded = decode_call::<IRescueHash::rescue2Call>(input)?;
+
+            hasher.hash_pair(decoded.left, decoded.right)?
+        }
+
+        // rescueN(uint256[])
+        s if s == IRescueHash::rescueNCall::SELECTOR => {
+            let decoded = decode_call::<IRescueHash::rescueNCall>(input)?;
+
+            hasher.hash_array(&decoded.inputs)?
+        }
+
+        _ => return Err(RescueError::InvalidSelector),
+    };
+
+    Ok(result.abi_encode())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    #[test]
+    fn test_precompile_interface() {
+        let hasher = RescueHash::new();
+        let inputs = vec![U256::from(1), U256::from(2), U256::from(3)];
+
+        let call = IRescueHash::rescueNCall { inputs: inputs.clone() }.abi_encode();
+        let output = rescue_precompile(&call).unwrap();
+        assert_eq!(U256::abi_decode(&output, true).unwrap(), hasher.hash_array(&inputs).unwrap());
+
+        let call = IRescueHash::rescue2Call { left: inputs[0], right: inputs[1] }.abi_encode();
+        let output = rescue_precompile(&call).unwrap();
+        assert_eq!(
+            U256::abi_decode(&output, true).unwrap(),
+            hasher.hash_pair(inputs[0], inputs[1]).unwrap()
+        );
+
+        assert!(matches!(rescue_precompile(&[0u8; 8]), Err(RescueError::InvalidSelector)));
+    }
+}
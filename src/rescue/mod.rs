@@ -0,0 +1,8 @@
+//! Rescue-Prime hash implementation for zero-knowledge proof systems
+
+pub mod core;
+pub mod interface;
+
+// Re-export the main components
+pub use core::{RescueHash, RescueParams, RESCUE_WIDTH};
+pub use interface::{rescue_precompile, IRescueHash, RescuePrecompile};
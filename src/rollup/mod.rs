@@ -0,0 +1,5 @@
+//! State roots for rollup contracts that append leaves in batches
+
+pub mod state_tree;
+
+pub use state_tree::{verify_update, RollupStateTree, UpdateProof};
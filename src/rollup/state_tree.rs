@@ -0,0 +1,243 @@
+//! Append-only state tree with provable batch transitions
+//!
+//! A rollup appends one batch of leaves per state update. `RollupStateTree`
+//! keeps the Poseidon `IncrementalMerkleTree` of every leaf so far, and
+//! `UpdateProof` lets a verifier holding only the old root check the new one.
+//!
+//! The proof carries the last leaf of the old tree and its Merkle path. The
+//! path authenticates the old root, its siblings rebuild the frontier (the
+//! latest left child at each level) that the incremental tree appends from,
+//! and its right siblings must all be empty subtrees, so the old tree held
+//! exactly `leaf_count` leaves. Replaying the new leaves from that frontier
+//! gives the new root.
+
+use crate::errors::MerkleError;
+use crate::merkle::{IncrementalMerkleTree, MerkleProof};
+use crate::poseidon::PoseidonHash;
+use alloy_primitives::U256;
+
+type StateTree = IncrementalMerkleTree<PoseidonHash>;
+
+/// Transition of a state tree by appending a batch of leaves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateProof {
+    pub depth: usize,
+    /// Leaves in the tree before the update
+    pub leaf_count: usize,
+    /// Last leaf before the update with its path, `None` for an empty tree
+    pub last_leaf: Option<(U256, MerkleProof)>,
+    pub new_leaves: Vec<U256>,
+}
+
+/// Poseidon state tree that applies leaves a batch at a time
+#[derive(Debug, Clone)]
+pub struct RollupStateTree {
+    tree: StateTree,
+    /// Leaves queued for the next batch
+    pending: Vec<U256>,
+}
+
+impl RollupStateTree {
+    /// Creates an empty state tree with room for `2^depth` leaves
+    pub fn new(depth: usize) -> Result<Self, MerkleError> {
+        Ok(Self { tree: StateTree::new(depth)?, pending: Vec::new() })
+    }
+
+    /// Returns the current state root
+    pub fn root(&self) -> U256 {
+        self.tree.root()
+    }
+
+    /// Returns the number of leaves applied so far
+    pub fn leaf_count(&self) -> usize {
+        self.tree.next_index()
+    }
+
+    /// Queues a leaf for the next `batch_update`
+    pub fn queue(&mut self, leaf: U256) {
+        self.pending.push(leaf);
+    }
+
+    /// Returns the leaves queued for the next batch
+    pub fn pending(&self) -> &[U256] {
+        &self.pending
+    }
+
+    /// Appends the queued leaves and then `new_leaves`, returning the new root
+    ///
+    /// Nothing is applied, and the queue is kept, if the batch does not fit.
+    pub fn batch_update(&mut self, new_leaves: &[U256]) -> Result<U256, MerkleError> {
+        let root = self.tree.insert_batch(&self.batch(new_leaves))?;
+        self.pending.clear();
+        Ok(root)
+    }
+
+    /// Proves the transition `batch_update(new_leaves)` makes from `old_root`
+    ///
+    /// `old_root` must be the current root, since the proof is cut from the
+    /// tree as it stands.
+    pub fn generate_update_proof(
+        &self,
+        old_root: U256,
+        new_leaves: &[U256],
+    ) -> Result<UpdateProof, MerkleError> {
+        if old_root != self.root() {
+            return Err(MerkleError::StaleRoot(old_root));
+        }
+        let new_leaves = self.batch(new_leaves);
+        if new_leaves.is_empty() {
+            return Err(MerkleError::EmptyBatch);
+        }
+
+        let leaf_count = self.leaf_count();
+        let last_leaf = match leaf_count.checked_sub(1) {
+            Some(index) => {
+                let leaf = self.tree.leaf(index).ok_or(MerkleError::PathUnavailable(index))?;
+                Some((leaf, self.tree.path_for_leaf(index)?))
+            }
+            None => None,
+        };
+        Ok(UpdateProof { depth: self.tree.depth(), leaf_count, last_leaf, new_leaves })
+    }
+
+    fn batch(&self, new_leaves: &[U256]) -> Vec<U256> {
+        [self.pending.as_slice(), new_leaves].concat()
+    }
+}
+
+/// Checks that appending `proof.new_leaves` to the tree at `old_root` gives `new_root`
+pub fn verify_update(old_root: U256, new_root: U256, proof: &UpdateProof) -> bool {
+    matches!(replay(old_root, proof), Ok(root) if root == new_root)
+}
+
+/// Restores the tree at `old_root` from the proof and appends the new leaves
+fn replay(old_root: U256, proof: &UpdateProof) -> Result<U256, MerkleError> {
+    let empty = StateTree::new(proof.depth)?;
+    let frontier = match (&proof.last_leaf, proof.leaf_count.checked_sub(1)) {
+        (None, None) if old_root == empty.root() => None,
+        (Some((leaf, path)), Some(index)) => Some(frontier(&empty, old_root, *leaf, index, path)?),
+        _ => return Err(MerkleError::MalformedProof("old root does not match the proof".into())),
+    };
+
+    let mut tree = StateTree::from_frontier(proof.depth, proof.leaf_count, frontier)?;
+    tree.insert_batch(&proof.new_leaves)
+}
+
+/// Authenticates `leaf` as the last leaf under `root` and returns the frontier after it
+fn frontier(
+    empty: &StateTree,
+    root: U256,
+    leaf: U256,
+    index: usize,
+    path: &MerkleProof,
+) -> Result<Vec<U256>, MerkleError> {
+    let malformed = |reason: &str| MerkleError::MalformedProof(reason.into());
+    if path.siblings.len() != empty.depth() || path.path_indices.len() != empty.depth() {
+        return Err(malformed("path length does not match the depth"));
+    }
+
+    let mut frontier = Vec::with_capacity(empty.depth());
+    let mut node = leaf;
+    for (level, (&sibling, &is_right)) in path.siblings.iter().zip(&path.path_indices).enumerate() {
+        if is_right != ((index >> level) % 2 == 1) {
+            return Err(malformed("path is not for the last leaf"));
+        }
+        if is_right {
+            frontier.push(sibling);
+            node = StateTree::hash_pair(sibling, node)?;
+        } else {
+            // Everything to the right of the last leaf must be empty
+            if sibling != empty.zeros()[level] {
+                return Err(malformed("tree has leaves after the last leaf"));
+            }
+            frontier.push(node);
+            node = StateTree::hash_pair(node, sibling)?;
+        }
+    }
+
+    if node != root {
+        return Err(malformed("old root does not match the proof"));
+    }
+    Ok(frontier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch(number: usize, size: usize) -> Vec<U256> {
+        (0..size).map(|i| U256::from(number * 1000 + i + 1)).collect()
+    }
+
+    #[test]
+    fn test_sequential_batches() {
+        let mut state = RollupStateTree::new(10).unwrap();
+        let mut reference = StateTree::new(10).unwrap();
+
+        for number in 0..10 {
+            let leaves = batch(number, 100);
+            let old_root = state.root();
+            let proof = state.generate_update_proof(old_root, &leaves).unwrap();
+            let new_root = state.batch_update(&leaves).unwrap();
+
+            assert_eq!(new_root, reference.insert_batch(&leaves).unwrap());
+            assert!(verify_update(old_root, new_root, &proof));
+            assert!(!verify_update(old_root, old_root, &proof));
+        }
+        assert_eq!(state.leaf_count(), 1000);
+    }
+
+    #[test]
+    fn test_rejects_tampered_proofs() {
+        let mut state = RollupStateTree::new(4).unwrap();
+        state.batch_update(&batch(0, 5)).unwrap();
+        let old_root = state.root();
+        let leaves = batch(1, 3);
+        let proof = state.generate_update_proof(old_root, &leaves).unwrap();
+        let new_root = state.clone().batch_update(&leaves).unwrap();
+        assert!(verify_update(old_root, new_root, &proof));
+
+        let mut wrong_leaf = proof.clone();
+        wrong_leaf.new_leaves[1] += U256::from(1);
+        assert!(!verify_update(old_root, new_root, &wrong_leaf));
+
+        // Claiming fewer leaves would overwrite the last one
+        let mut wrong_count = proof.clone();
+        wrong_count.leaf_count = 4;
+        assert!(!verify_update(old_root, new_root, &wrong_count));
+
+        let mut wrong_last = proof.clone();
+        wrong_last.last_leaf.as_mut().unwrap().0 += U256::from(1);
+        assert!(!verify_update(old_root, new_root, &wrong_last));
+
+        assert!(!verify_update(new_root, new_root, &proof));
+        assert!(matches!(
+            state.generate_update_proof(new_root, &leaves),
+            Err(MerkleError::StaleRoot(_))
+        ));
+    }
+
+    #[test]
+    fn test_pending_leaves_join_the_batch() {
+        let mut state = RollupStateTree::new(4).unwrap();
+        let empty_root = state.root();
+        state.queue(U256::from(7));
+        state.queue(U256::from(8));
+        assert_eq!(state.pending().len(), 2);
+
+        let proof = state.generate_update_proof(empty_root, &[U256::from(9)]).unwrap();
+        assert_eq!(proof.new_leaves, [7u64, 8, 9].map(U256::from));
+        assert!(proof.last_leaf.is_none());
+
+        let new_root = state.batch_update(&[U256::from(9)]).unwrap();
+        assert!(verify_update(empty_root, new_root, &proof));
+        assert!(state.pending().is_empty());
+        assert!(matches!(state.batch_update(&[]), Err(MerkleError::EmptyBatch)));
+
+        // A batch that overflows the tree leaves the queue in place
+        state.queue(U256::from(10));
+        assert!(matches!(state.batch_update(&batch(2, 16)), Err(MerkleError::TreeFull(16))));
+        assert_eq!(state.pending(), [U256::from(10)]);
+        assert_eq!(state.root(), new_root);
+    }
+}
@@ -0,0 +1,196 @@
+//! Selector-based routing across precompiles
+//!
+//! `PrecompileRouter` maps each 4-byte function selector to the
+//! `GenericPrecompile` that serves it, so one entry point can front several
+//! precompiles. `router_builder!` registers a fixed set of precompiles in one
+//! expression.
+
+use crate::errors::{GasExhaustedError, InvalidSelectorError, PrecompileError};
+use crate::testing::MockPrecompile;
+use crate::traits::GenericPrecompile;
+use std::collections::BTreeMap;
+
+/// Builds a `PrecompileRouter` from `name => precompile` pairs
+///
+/// ```
+/// use precompile::mimc::MiMCPrecompile;
+/// use precompile::poseidon::PoseidonPrecompile;
+/// use precompile::router_builder;
+///
+/// let router = router_builder! {
+///     poseidon => PoseidonPrecompile,
+///     mimc => MiMCPrecompile,
+/// };
+/// assert!(!router.is_empty());
+/// ```
+#[macro_export]
+macro_rules! router_builder {
+    ($($name:ident => $precompile:expr),* $(,)?) => {{
+        let mut router = $crate::router::PrecompileRouter::new();
+        $(router.register($precompile);)*
+        router
+    }};
+}
+
+/// Dispatches calldata to the precompile registered for its selector
+#[derive(Default)]
+pub struct PrecompileRouter {
+    routes: BTreeMap<[u8; 4], Box<dyn GenericPrecompile>>,
+}
+
+impl PrecompileRouter {
+    /// Creates a router with no registered precompiles
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a mock precompile for tests that stub out routing
+    pub fn mock() -> MockPrecompile {
+        MockPrecompile::new()
+    }
+
+    /// Routes every selector of `precompile` to it, replacing earlier routes
+    pub fn register<P>(&mut self, precompile: P) -> &mut Self
+    where
+        P: GenericPrecompile + Clone + 'static,
+    {
+        for selector in precompile.selector_list() {
+            self.routes.insert(*selector, Box::new(precompile.clone()));
+        }
+        self
+    }
+
+    /// Returns whether a precompile handles `selector`
+    pub fn handles(&self, selector: [u8; 4]) -> bool {
+        self.routes.contains_key(&selector)
+    }
+
+    /// Number of routed selectors
+    pub fn len(&self) -> usize {
+        self.routes.len()
+    }
+
+    /// Returns whether no selectors are routed
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+
+    /// Looks up the precompile for the calldata selector
+    fn route(&self, input: &[u8]) -> Result<&dyn GenericPrecompile, PrecompileError> {
+        let InvalidSelectorError { selector } = InvalidSelectorError::from_input(input);
+        if input.len() < 4 {
+            return Err(InvalidSelectorError { selector }.into());
+        }
+
+        self.routes
+            .get(&selector)
+            .map(|precompile| precompile.as_ref())
+            .ok_or(InvalidSelectorError { selector }.into())
+    }
+
+    /// Calls the precompile registered for the calldata selector
+    pub fn dispatch(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        self.route(input)?.call(input)
+    }
+
+    /// Estimated gas of dispatching `input`
+    pub fn gas_cost(&self, input: &[u8]) -> Result<u64, PrecompileError> {
+        Ok(self.route(input)?.gas_cost(input))
+    }
+
+    /// Charges the call against `gas_limit`, then dispatches it
+    ///
+    /// Returns the output and the gas left over.
+    pub fn dispatch_with_gas(&self, input: &[u8], gas_limit: u64) -> Result<(Vec<u8>, u64), PrecompileError> {
+        let precompile = self.route(input)?;
+        let required = precompile.gas_cost(input);
+        if gas_limit < required {
+            return Err(GasExhaustedError {
+                gas_provided: gas_limit,
+                gas_required: required,
+            }
+            .into());
+        }
+
+        let output = precompile.call(input)?;
+        Ok((output, gas_limit - required))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mimc::{IMiMCHash, MiMCHash, MiMCPrecompile};
+    use crate::poseidon::PoseidonPrecompile;
+    use crate::rescue::{IRescueHash, RescueHash, RescuePrecompile};
+    use alloy_primitives::U256;
+    use alloy_sol_types::SolCall;
+
+    fn router() -> PrecompileRouter {
+        router_builder! {
+            poseidon => PoseidonPrecompile,
+            mimc => MiMCPrecompile,
+            rescue => RescuePrecompile,
+        }
+    }
+
+    #[test]
+    fn test_registers_every_selector() {
+        let router = router();
+        assert_eq!(
+            router.len(),
+            PoseidonPrecompile::SELECTORS.len()
+                + MiMCPrecompile::SELECTORS.len()
+                + RescuePrecompile::SELECTORS.len()
+        );
+        assert!(router.handles(IMiMCHash::mimc2Call::SELECTOR));
+    }
+
+    #[test]
+    fn test_routes_to_matching_precompile() {
+        let router = router();
+        let (a, b) = (U256::from(1), U256::from(2));
+
+        let mimc = IMiMCHash::mimc2Call { left: a, right: b }.abi_encode();
+        let expected = MiMCHash::new().hash_pair(a, b).unwrap();
+        assert_eq!(router.dispatch(&mimc).unwrap(), expected.to_be_bytes::<32>());
+
+        let rescue = IRescueHash::rescue2Call { left: a, right: b }.abi_encode();
+        let expected = RescueHash::new().hash_pair(a, b).unwrap();
+        assert_eq!(router.dispatch(&rescue).unwrap(), expected.to_be_bytes::<32>());
+    }
+
+    #[test]
+    fn test_unknown_selector() {
+        let router = router();
+        let selector = [0xde, 0xad, 0xbe, 0xef];
+        assert!(matches!(
+            router.dispatch(&selector),
+            Err(PrecompileError::Selector(error)) if error == InvalidSelectorError { selector }
+        ));
+        assert!(router.dispatch(&[0x01]).is_err());
+    }
+
+    #[test]
+    fn test_gas_cost_grows_with_calldata() {
+        let router = router();
+        let one = IMiMCHash::mimc1Call { input: U256::from(1) }.abi_encode();
+        let two = IMiMCHash::mimc2Call { left: U256::from(1), right: U256::from(2) }.abi_encode();
+        assert!(router.gas_cost(&two).unwrap() > router.gas_cost(&one).unwrap());
+    }
+
+    #[test]
+    fn test_dispatch_with_gas() {
+        let router = router();
+        let call = IMiMCHash::mimc1Call { input: U256::from(1) }.abi_encode();
+        let required = router.gas_cost(&call).unwrap();
+
+        let (output, remaining) = router.dispatch_with_gas(&call, required + 10).unwrap();
+        assert_eq!(output, router.dispatch(&call).unwrap());
+        assert_eq!(remaining, 10);
+        assert!(matches!(
+            router.dispatch_with_gas(&call, required - 1),
+            Err(PrecompileError::Gas(GasExhaustedError { gas_required, .. })) if gas_required == required
+        ));
+    }
+}
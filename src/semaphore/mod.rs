@@ -0,0 +1,5 @@
+//! Semaphore identities and nullifiers hashed with Poseidon
+
+pub mod v4;
+
+pub use v4::{identity_commitment_v4, nullifier_hash_v4, IdentityV4};
@@ -0,0 +1,119 @@
+//! Semaphore v4 style identities
+//!
+//! An identity is a `(trapdoor, nullifier)` pair of secret field elements.
+//! Its public commitment is `poseidon2([trapdoor, nullifier])`, and the
+//! nullifier hash that marks one signal per scope is
+//! `poseidon2([nullifier, scope])`. `poseidon2` is circomlib's two-input
+//! Poseidon (`poseidon-lite`'s `poseidon2`), the hash the Semaphore SDK and
+//! circuits import.
+//!
+//! The Semaphore v4 SDK derives its commitment from an EdDSA public key on
+//! Baby JubJub rather than from a trapdoor, so commitments from this scheme do
+//! not match SDK-generated identities.
+//!
+//! There is deliberately no precompile selector: checking an identity needs
+//! its secrets, and calldata is public. On-chain verification takes a
+//! zero-knowledge proof of the relations below instead.
+
+use crate::errors::PoseidonError;
+use crate::poseidon::PoseidonHash;
+use alloy_primitives::U256;
+use core::fmt;
+
+/// Secret Semaphore identity
+#[derive(Clone, PartialEq, Eq)]
+pub struct IdentityV4 {
+    pub trapdoor: U256,
+    pub nullifier: U256,
+}
+
+impl fmt::Debug for IdentityV4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IdentityV4")
+            .field("trapdoor", &"<redacted>")
+            .field("nullifier", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Public commitment `poseidon2([trapdoor, nullifier])` inserted in the group tree
+pub fn identity_commitment_v4(id: &IdentityV4) -> Result<U256, PoseidonError> {
+    PoseidonHash::new().hash_pair_production(id.trapdoor, id.nullifier)
+}
+
+/// Nullifier hash `poseidon2([nullifier, scope])`, unique per identity and scope
+pub fn nullifier_hash_v4(id: &IdentityV4, scope: U256) -> Result<U256, PoseidonError> {
+    PoseidonHash::new().hash_pair_production(id.nullifier, scope)
+}
+
+/// Whether `id` opens `commitment` and yields `nullifier_hash` for `scope`
+///
+/// The off-chain counterpart of the circuit's constraints, for tests and tooling.
+pub fn verify_v4(
+    id: &IdentityV4,
+    commitment: U256,
+    nullifier_hash: U256,
+    scope: U256,
+) -> Result<bool, PoseidonError> {
+    Ok(identity_commitment_v4(id)? == commitment && nullifier_hash_v4(id, scope)? == nullifier_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity(trapdoor: u64, nullifier: u64) -> IdentityV4 {
+        IdentityV4 { trapdoor: U256::from(trapdoor), nullifier: U256::from(nullifier) }
+    }
+
+    #[test]
+    fn test_matches_circomlib_poseidon2() {
+        // circomlibjs poseidon([1, 2])
+        let expected: U256 =
+            "7853200120776062878684798364095072458815029376092732009249414926327459813530"
+                .parse()
+                .unwrap();
+        assert_eq!(identity_commitment_v4(&identity(1, 2)).unwrap(), expected);
+        assert_eq!(nullifier_hash_v4(&identity(9, 1), U256::from(2)).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_nullifier_hash_per_scope() {
+        let id = identity(0xdead, 0xbeef);
+        let first = nullifier_hash_v4(&id, U256::from(1)).unwrap();
+        assert_eq!(nullifier_hash_v4(&id, U256::from(1)).unwrap(), first);
+        assert_ne!(nullifier_hash_v4(&id, U256::from(2)).unwrap(), first);
+
+        // The trapdoor does not enter the nullifier hash
+        let other = identity(0xf00d, 0xbeef);
+        assert_eq!(nullifier_hash_v4(&other, U256::from(1)).unwrap(), first);
+        assert_ne!(identity_commitment_v4(&other).unwrap(), identity_commitment_v4(&id).unwrap());
+    }
+
+    #[test]
+    fn test_verify() {
+        let id = identity(11, 22);
+        let scope = U256::from(33);
+        let commitment = identity_commitment_v4(&id).unwrap();
+        let nullifier_hash = nullifier_hash_v4(&id, scope).unwrap();
+
+        assert!(verify_v4(&id, commitment, nullifier_hash, scope).unwrap());
+        assert!(!verify_v4(&id, commitment, nullifier_hash, scope + U256::from(1)).unwrap());
+        assert!(!verify_v4(&identity(12, 22), commitment, nullifier_hash, scope).unwrap());
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let debug = format!("{:?}", identity(0x1234567, 0x7654321));
+        assert!(!debug.contains("1234567") && !debug.contains("7654321"));
+        assert!(!debug.contains(&0x1234567u64.to_string()));
+    }
+
+    #[test]
+    fn test_rejects_invalid_secrets() {
+        let modulus = PoseidonHash::new().params.modulus;
+        let id = IdentityV4 { trapdoor: modulus, nullifier: U256::from(1) };
+        assert!(identity_commitment_v4(&id).is_err());
+        assert!(nullifier_hash_v4(&identity(1, 2), modulus).is_err());
+    }
+}
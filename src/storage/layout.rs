@@ -0,0 +1,201 @@
+//! Storage slot layouts
+//!
+//! A `StorageLayout` lists where each field of a Stylus storage struct lives.
+//! `#[derive(SolStorageLayout)]` computes it with the same packing rules as
+//! the `#[storage]` macro: fields are packed into 32-byte words from the low
+//! end, a field that does not fit starts a new word, and nested structs take
+//! whole words of their own.
+//!
+//! Layouts can be combined by hand when a contract embeds another contract's
+//! storage, and `check_collisions` then reports fields that share bytes.
+
+use alloy_primitives::U256;
+use stylus_sdk::storage::StorageType;
+
+/// Bytes in one storage word
+const WORD_BYTES: usize = 32;
+
+/// Location of one field in storage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotEntry {
+    pub name: &'static str,
+    pub slot: U256,
+    /// Byte offset of the field within its slot, counted from the low end
+    pub offset: u8,
+    pub size_bytes: usize,
+}
+
+impl SlotEntry {
+    /// Byte range the entry covers, as positions `slot * 32 + offset`
+    fn byte_range(&self) -> (U256, U256) {
+        let start = self
+            .slot
+            .saturating_mul(U256::from(WORD_BYTES))
+            .saturating_add(U256::from(self.offset));
+        (start, start.saturating_add(U256::from(self.size_bytes)))
+    }
+}
+
+/// Storage slots used by a contract
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageLayout {
+    pub slots: Vec<SlotEntry>,
+}
+
+impl StorageLayout {
+    /// Returns the layout of a storage struct
+    pub fn from_contract<T: SolStorageLayout>() -> Self {
+        T::storage_layout()
+    }
+}
+
+/// Implemented by storage structs to describe their layout
+///
+/// Use `#[derive(SolStorageLayout)]` rather than implementing this by hand.
+pub trait SolStorageLayout {
+    fn storage_layout() -> StorageLayout;
+}
+
+/// Assigns slots to fields in declaration order, as the `#[storage]` macro does
+pub struct StorageLayoutBuilder {
+    slots: Vec<SlotEntry>,
+    slot: usize,
+    space: usize,
+}
+
+impl Default for StorageLayoutBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StorageLayoutBuilder {
+    /// Starts a layout at slot zero
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            slot: 0,
+            space: WORD_BYTES,
+        }
+    }
+
+    /// Places the next field
+    pub fn field<T: StorageType>(&mut self, name: &'static str) -> &mut Self {
+        let bytes = T::SLOT_BYTES;
+        let words = T::REQUIRED_SLOTS;
+
+        if self.space < bytes {
+            self.space = WORD_BYTES;
+            self.slot += 1;
+        }
+        self.space -= bytes;
+
+        let size_bytes = if words > 0 { words * WORD_BYTES } else { bytes };
+        self.slots.push(SlotEntry {
+            name,
+            slot: U256::from(self.slot),
+            offset: self.space as u8,
+            size_bytes,
+        });
+
+        if words > 0 {
+            self.slot += words;
+            self.space = WORD_BYTES;
+        }
+        self
+    }
+
+    /// Finishes the layout
+    pub fn build(&mut self) -> StorageLayout {
+        StorageLayout {
+            slots: std::mem::take(&mut self.slots),
+        }
+    }
+}
+
+/// Returns the names of every pair of entries whose byte ranges overlap
+pub fn check_collisions(layout: &StorageLayout) -> Vec<(&'static str, &'static str)> {
+    let mut collisions = Vec::new();
+
+    for (i, a) in layout.slots.iter().enumerate() {
+        let (a_start, a_end) = a.byte_range();
+        for b in &layout.slots[i + 1..] {
+            let (b_start, b_end) = b.byte_range();
+            if a_start < b_end && b_start < a_end {
+                collisions.push((a.name, b.name));
+            }
+        }
+    }
+
+    collisions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SolStorageLayout;
+    use stylus_sdk::storage::{StorageAddress, StorageBool, StorageMap, StorageU256, StorageU64};
+
+    #[derive(SolStorageLayout)]
+    #[allow(dead_code)]
+    struct Packed {
+        owner: StorageAddress,
+        paused: StorageBool,
+        root: StorageU256,
+        count: StorageU64,
+        limit: StorageU64,
+        balances: StorageMap<U256, StorageU256>,
+    }
+
+    #[test]
+    fn test_packing_matches_storage_macro() {
+        let layout = StorageLayout::from_contract::<Packed>();
+        let placed: Vec<_> = layout
+            .slots
+            .iter()
+            .map(|entry| (entry.name, entry.slot.to::<u64>(), entry.offset, entry.size_bytes))
+            .collect();
+
+        assert_eq!(
+            placed,
+            vec![
+                ("owner", 0, 12, 20),
+                ("paused", 0, 11, 1),
+                ("root", 1, 0, 32),
+                ("count", 2, 24, 8),
+                ("limit", 2, 16, 8),
+                ("balances", 3, 0, 32),
+            ]
+        );
+        assert!(check_collisions(&layout).is_empty());
+    }
+
+    #[test]
+    fn test_detects_overlapping_entries() {
+        let mut layout = StorageLayout::from_contract::<Packed>();
+        layout.slots.push(SlotEntry {
+            name: "base_root",
+            slot: U256::from(1),
+            offset: 0,
+            size_bytes: 32,
+        });
+        layout.slots.push(SlotEntry {
+            name: "base_flag",
+            slot: U256::from(0),
+            offset: 0,
+            size_bytes: 1,
+        });
+
+        // base_flag sits in the unused low bytes of slot 0
+        assert_eq!(check_collisions(&layout), vec![("root", "base_root")]);
+    }
+
+    #[cfg(feature = "stylus")]
+    #[test]
+    fn test_poseidon_precompile_has_no_collisions() {
+        use crate::entrypoint::PoseidonPrecompile;
+
+        let layout = StorageLayout::from_contract::<PoseidonPrecompile>();
+        assert!(check_collisions(&layout).is_empty());
+    }
+}
@@ -0,0 +1,5 @@
+//! Storage slot documentation and verification for Stylus contracts
+
+pub mod layout;
+
+pub use layout::{check_collisions, SlotEntry, SolStorageLayout, StorageLayout, StorageLayoutBuilder};
@@ -0,0 +1,109 @@
+//! Mock precompile returning canned responses
+//!
+//! Lets contract and integration tests stub out the precompile with fixed
+//! input -> output expectations instead of running the real hash.
+
+use crate::errors::{InvalidSelectorError, PrecompileError};
+use std::collections::HashMap;
+
+/// What the mock does when it receives calldata without a registered expectation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnexpectedCallBehavior {
+    /// Panic with the offending calldata
+    Panic,
+    /// Return a single ABI-encoded zero word
+    ReturnZero,
+    /// Return an `InvalidSelectorError`, like the real dispatcher
+    #[default]
+    ReturnErr,
+}
+
+/// Precompile stand-in that serves registered responses
+#[derive(Debug, Clone, Default)]
+pub struct MockPrecompile {
+    /// Registered calldata -> return data pairs
+    pub expectations: HashMap<Vec<u8>, Vec<u8>>,
+    /// Behavior for calldata that has no expectation
+    pub unexpected_call_behavior: UnexpectedCallBehavior,
+}
+
+impl MockPrecompile {
+    /// Creates a mock with no expectations that errors on unexpected calls
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a fixed response for the given calldata
+    pub fn expect(&mut self, input: &[u8], output: &[u8]) -> &mut Self {
+        self.expectations.insert(input.to_vec(), output.to_vec());
+        self
+    }
+
+    /// Sets the behavior for calls without a registered expectation
+    pub fn on_unexpected_call(&mut self, behavior: UnexpectedCallBehavior) -> &mut Self {
+        self.unexpected_call_behavior = behavior;
+        self
+    }
+
+    /// Serves a call, mirroring the signature of `poseidon_precompile`
+    pub fn dispatch(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        if let Some(output) = self.expectations.get(input) {
+            return Ok(output.clone());
+        }
+
+        match self.unexpected_call_behavior {
+            UnexpectedCallBehavior::Panic => {
+                panic!("unexpected precompile call: 0x{}", hex::encode(input))
+            }
+            UnexpectedCallBehavior::ReturnZero => Ok(vec![0u8; 32]),
+            UnexpectedCallBehavior::ReturnErr => {
+                Err(InvalidSelectorError::from_input(input).into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poseidon::IPoseidonHash;
+    use alloy_primitives::U256;
+    use alloy_sol_types::{SolCall, SolValue};
+
+    fn poseidon1_calldata(input: U256) -> Vec<u8> {
+        IPoseidonHash::poseidon1Call { input }.abi_encode()
+    }
+
+    #[test]
+    fn test_expected_call_returns_mocked_output() {
+        let calldata = poseidon1_calldata(U256::from(42));
+        let mocked = U256::from(1234).abi_encode();
+
+        let mut mock = MockPrecompile::new();
+        mock.expect(&calldata, &mocked);
+
+        assert_eq!(mock.dispatch(&calldata).unwrap(), mocked);
+    }
+
+    #[test]
+    fn test_unexpected_call_behaviors() {
+        let calldata = poseidon1_calldata(U256::from(1));
+
+        let mut mock = MockPrecompile::new();
+        let Err(PrecompileError::Selector(InvalidSelectorError { selector })) = mock.dispatch(&calldata) else {
+            panic!("expected an invalid selector error");
+        };
+        assert_eq!(selector, IPoseidonHash::poseidon1Call::SELECTOR);
+
+        mock.on_unexpected_call(UnexpectedCallBehavior::ReturnZero);
+        assert_eq!(mock.dispatch(&calldata).unwrap(), vec![0u8; 32]);
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpected precompile call")]
+    fn test_unexpected_call_panics() {
+        let mut mock = MockPrecompile::new();
+        mock.on_unexpected_call(UnexpectedCallBehavior::Panic);
+        let _ = mock.dispatch(&[0xde, 0xad, 0xbe, 0xef]);
+    }
+}
@@ -0,0 +1,5 @@
+//! Test helpers for code that calls the precompiles
+
+pub mod mock;
+
+pub use mock::{MockPrecompile, UnexpectedCallBehavior};
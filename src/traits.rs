@@ -0,0 +1,45 @@
+//! Traits shared across the precompile hashes
+
+use crate::errors::{PoseidonError, PrecompileError};
+use alloy_primitives::U256;
+
+/// Hash that absorbs its input one element at a time
+///
+/// The trait is object safe: `finalize` consumes a sized hasher and
+/// `finalize_boxed` consumes a `Box<dyn StatefulHasher>`.
+pub trait StatefulHasher {
+    /// Appends one field element to the input
+    fn update(&mut self, input: U256) -> Result<(), PoseidonError>;
+
+    /// Hashes everything passed to `update` since creation or the last `reset`
+    fn finalize(self) -> Result<U256, PoseidonError>
+    where
+        Self: Sized;
+
+    /// `finalize` for boxed trait objects
+    fn finalize_boxed(self: Box<Self>) -> Result<U256, PoseidonError>;
+
+    /// Discards the accumulated input
+    fn reset(&mut self);
+}
+
+/// Precompile that can be dispatched to through raw calldata
+///
+/// Object safe, so a `PrecompileRouter` can hold different precompiles as
+/// `Box<dyn GenericPrecompile>`, and `Send + Sync` so a router can be shared
+/// from a `static`.
+pub trait GenericPrecompile: Send + Sync {
+    /// Handles calldata starting with one of `selector_list`
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError>;
+
+    /// Function selectors the precompile handles
+    fn selector_list(&self) -> &'static [[u8; 4]];
+
+    /// Estimated gas of serving `input`
+    fn gas_cost(&self, input: &[u8]) -> u64;
+}
+
+/// Flat per-call charge plus a charge per 32-byte argument word
+pub(crate) fn calldata_gas_estimate(input: &[u8], base: u64, per_word: u64) -> u64 {
+    base + per_word * (input.len().saturating_sub(4) / 32) as u64
+}
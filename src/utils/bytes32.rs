@@ -0,0 +1,54 @@
+//! Conversions between Ethereum `bytes32` values and BN254 field elements
+//!
+//! A `bytes32` can hold values up to 2^256 - 1, above the BN254 scalar field
+//! modulus, so it is reduced modulo the field order before hashing. Distinct
+//! `bytes32` values that differ by a multiple of the modulus map to the same
+//! field element.
+
+use alloy_primitives::U256;
+
+pub use crate::constants::BN254_MODULUS;
+
+/// Interprets `data` as a big-endian integer reduced modulo the BN254 scalar field
+pub fn bytes32_to_field(data: [u8; 32]) -> U256 {
+    U256::from_be_bytes(data) % BN254_MODULUS
+}
+
+/// Encodes a field element as big-endian `bytes32`
+pub fn field_to_bytes32(elem: U256) -> [u8; 32] {
+    elem.to_be_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modulus_matches_poseidon_params() {
+        assert_eq!(BN254_MODULUS, crate::poseidon::PoseidonParams::default().modulus);
+    }
+
+    #[test]
+    fn test_reduction_above_modulus() {
+        // Values in the field are unchanged
+        let small = U256::from(42);
+        assert_eq!(bytes32_to_field(field_to_bytes32(small)), small);
+
+        // The modulus maps to zero and larger values wrap around
+        assert_eq!(bytes32_to_field(field_to_bytes32(BN254_MODULUS)), U256::ZERO);
+        let above = BN254_MODULUS + U256::from(7);
+        assert_eq!(bytes32_to_field(field_to_bytes32(above)), U256::from(7));
+
+        // 2^256 - 1 reduces to (2^256 - 1) mod p
+        let max = bytes32_to_field([0xff; 32]);
+        assert_eq!(max, U256::MAX % BN254_MODULUS);
+        assert!(max < BN254_MODULUS);
+    }
+
+    #[test]
+    fn test_big_endian_encoding() {
+        let bytes = field_to_bytes32(U256::from(0x0102));
+        assert_eq!(bytes[30..], [0x01, 0x02]);
+        assert!(bytes[..30].iter().all(|b| *b == 0));
+    }
+}
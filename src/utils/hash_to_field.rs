@@ -0,0 +1,104 @@
+//! RFC 9380 `hash_to_field` for the BN254 scalar field
+//!
+//! Arbitrary bytes are stretched with `expand_message_xmd` over SHA-256 to
+//! `L = ceil((ceil(log2(p)) + k) / 8) = 48` bytes for `k = 128` bits of
+//! security, then read as a big-endian integer and reduced modulo `p`. The
+//! 128 extra bits keep the bias of the reduction negligible.
+
+use super::bytes32::BN254_MODULUS;
+use alloy_primitives::{U256, U512};
+use sha2::{Digest, Sha256};
+
+/// Domain separation tag for hashing bytes to BN254 field elements
+pub const HASH_TO_FIELD_DST: &[u8] = b"STYLUS-PRECOMPILE-V01-BN254_XMD:SHA-256";
+
+/// Bytes expanded per field element
+const EXPANDED_BYTES: usize = 48;
+
+/// SHA-256 block size, `s_in_bytes` in RFC 9380
+const SHA256_BLOCK_BYTES: usize = 64;
+
+/// SHA-256 output size, `b_in_bytes` in RFC 9380
+const SHA256_OUTPUT_BYTES: usize = 32;
+
+/// `expand_message_xmd` from RFC 9380 section 5.3.1 with SHA-256
+///
+/// `dst` must be at most 255 bytes and `len_in_bytes` at most `255 * 32`.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+    let ell = len_in_bytes.div_ceil(SHA256_OUTPUT_BYTES);
+    assert!(ell <= 255 && len_in_bytes <= u16::MAX as usize, "requested output is too long");
+    assert!(dst.len() <= 255, "domain separation tag is too long");
+
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+
+    let b_0 = Sha256::new()
+        .chain_update([0u8; SHA256_BLOCK_BYTES])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * SHA256_OUTPUT_BYTES);
+    let mut b_i = Sha256::new()
+        .chain_update(b_0)
+        .chain_update([1u8])
+        .chain_update(&dst_prime)
+        .finalize();
+    uniform_bytes.extend_from_slice(&b_i);
+
+    for i in 2..=ell {
+        let mixed: Vec<u8> = b_0.iter().zip(&b_i).map(|(a, b)| a ^ b).collect();
+        b_i = Sha256::new()
+            .chain_update(mixed)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// Maps `msg` to one BN254 scalar field element (`hash_to_field` with `count = 1`)
+pub fn hash_to_field(msg: &[u8], dst: &[u8]) -> U256 {
+    let uniform_bytes = expand_message_xmd(msg, dst, EXPANDED_BYTES);
+
+    let mut padded = [0u8; 64];
+    padded[64 - EXPANDED_BYTES..].copy_from_slice(&uniform_bytes);
+    let reduced = U512::from_be_bytes(padded) % U512::from(BN254_MODULUS);
+    U256::from(reduced)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_message_xmd_rfc_vectors() {
+        // RFC 9380 appendix K.1, expand_message_xmd(SHA-256) with len_in_bytes = 0x20
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        assert_eq!(
+            hex::encode(expand_message_xmd(b"", dst, 0x20)),
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+        );
+        assert_eq!(
+            hex::encode(expand_message_xmd(b"abc", dst, 0x20)),
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+        );
+    }
+
+    #[test]
+    fn test_hash_to_field() {
+        let value = hash_to_field(b"hello", HASH_TO_FIELD_DST);
+        assert!(value < BN254_MODULUS);
+        assert_eq!(
+            value,
+            alloy_primitives::uint!(
+                0x0d4fa0f0c7873c8225923a770c566374771ad4c119ddde90dcfa41d91e05cda1_U256
+            )
+        );
+        assert_ne!(hash_to_field(b"hello", b"OTHER-DST"), value);
+    }
+}
@@ -0,0 +1,120 @@
+//! Utility functions for the precompile library
+
+use alloy_primitives::U256;
+
+use crate::errors::PoseidonError;
+use crate::poseidon::PoseidonHash;
+
+pub mod bytes32;
+pub mod hash_to_field;
+
+pub use bytes32::{bytes32_to_field, field_to_bytes32, BN254_MODULUS};
+pub use hash_to_field::{expand_message_xmd, hash_to_field, HASH_TO_FIELD_DST};
+
+/// Converts a hex string to U256
+pub fn hex_to_u256(hex_str: &str) -> Result<U256, &'static str> {
+    let clean_hex = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+    U256::from_str_radix(clean_hex, 16).map_err(|_| "Invalid hex string")
+}
+
+/// Converts U256 to hex string with 0x prefix
+pub fn u256_to_hex(value: U256) -> String {
+    format!("0x{:x}", value)
+}
+
+// Utility functions for precompile development
+
+/// Convert bytes to hex string for debugging
+pub fn bytes_to_hex(bytes: &[u8]) -> String {
+    hex::encode(bytes)
+}
+
+/// Validate BN254 field element
+#[deprecated(note = "use prelude::is_valid_bn254_field_element")]
+pub fn is_valid_bn254_field_element(value: U256) -> bool {
+    crate::prelude::is_valid_bn254_field_element(value)
+}
+
+/// Hashes each step into the previous link, starting from `initial`
+///
+/// Returns every link of the chain: `[H(initial, s₀), H(H(initial, s₀), s₁), ...]`.
+pub fn poseidon_hash_chain(
+    initial: U256,
+    steps: &[U256],
+    hasher: &PoseidonHash,
+) -> Result<Vec<U256>, PoseidonError> {
+    let mut link = initial;
+    steps
+        .iter()
+        .map(|&step| {
+            link = hasher.hash_pair_production(link, step)?;
+            Ok(link)
+        })
+        .collect()
+}
+
+/// Checks that `chain` is the sequence of links produced by `steps` from `initial`
+///
+/// Any contiguous sub-chain verifies when `initial` is the link preceding it
+/// and `steps` are the matching steps.
+pub fn verify_hash_chain(
+    chain: &[U256],
+    initial: U256,
+    steps: &[U256],
+    hasher: &PoseidonHash,
+) -> bool {
+    chain.len() == steps.len()
+        && poseidon_hash_chain(initial, steps, hasher).is_ok_and(|links| links == chain)
+}
+
+/// Generate test field elements for testing
+#[cfg(test)]
+pub fn generate_test_elements(count: usize) -> Vec<U256> {
+    (1..=count).map(|i| U256::from(i * 42)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_conversion() {
+        let value = U256::from(42);
+        let hex_str = u256_to_hex(value);
+        let converted_back = hex_to_u256(&hex_str).unwrap();
+        assert_eq!(value, converted_back);
+    }
+
+    #[test]
+    fn test_field_element_check() {
+        use crate::prelude::is_valid_bn254_field_element;
+
+        assert!(is_valid_bn254_field_element(U256::ZERO));
+        assert!(is_valid_bn254_field_element(BN254_MODULUS - U256::from(1)));
+        assert!(!is_valid_bn254_field_element(BN254_MODULUS));
+        assert!(!is_valid_bn254_field_element(U256::MAX));
+    }
+
+    #[test]
+    fn test_hash_chain() {
+        let hasher = PoseidonHash::new();
+        let initial = U256::from(1);
+        let steps: Vec<U256> = (1..=5u64).map(|i| U256::from(i * 1000)).collect();
+
+        let chain = poseidon_hash_chain(initial, &steps, &hasher).unwrap();
+        assert_eq!(chain.len(), 5);
+        assert_eq!(chain[0], hasher.hash_pair_production(initial, steps[0]).unwrap());
+        assert_eq!(chain[4], hasher.hash_pair_production(chain[3], steps[4]).unwrap());
+        assert!(verify_hash_chain(&chain, initial, &steps, &hasher));
+
+        // Truncated and interior sub-chains
+        assert!(verify_hash_chain(&chain[..3], initial, &steps[..3], &hasher));
+        assert!(verify_hash_chain(&chain[2..4], chain[1], &steps[2..4], &hasher));
+
+        assert!(!verify_hash_chain(&chain[..3], initial, &steps[..4], &hasher));
+        assert!(!verify_hash_chain(&chain[1..3], initial, &steps[1..3], &hasher));
+        let mut forged = chain.clone();
+        forged[2] += U256::from(1);
+        assert!(!verify_hash_chain(&forged, initial, &steps, &hasher));
+    }
+}
@@ -0,0 +1,81 @@
+//! JavaScript bindings for browser-side Poseidon hashing
+//!
+//! Built with `wasm-pack build -- --features wasm-bindgen`. Field elements
+//! cross the JS boundary as decimal strings to avoid BigInt serialization
+//! issues, and hashing uses the production (circomlibjs-compatible) path.
+
+use crate::poseidon::PoseidonHash;
+use alloy_primitives::U256;
+use wasm_bindgen::prelude::*;
+
+/// Parses a decimal string into a field element candidate
+fn parse_decimal(value: &str) -> Result<U256, JsError> {
+    U256::from_str_radix(value.trim(), 10)
+        .map_err(|_| JsError::new(&format!("Invalid decimal field element: {}", value)))
+}
+
+/// Computes the Poseidon hash of a single decimal-encoded element
+#[wasm_bindgen]
+pub fn poseidon_hash_single(input: &str) -> Result<String, JsError> {
+    let hash = PoseidonHash::new().hash_single_production(parse_decimal(input)?)?;
+    Ok(hash.to_string())
+}
+
+/// Computes the Poseidon hash of two decimal-encoded elements
+#[wasm_bindgen]
+pub fn poseidon_hash_pair(left: &str, right: &str) -> Result<String, JsError> {
+    let hash =
+        PoseidonHash::new().hash_pair_production(parse_decimal(left)?, parse_decimal(right)?)?;
+    Ok(hash.to_string())
+}
+
+/// Computes the Poseidon hash of an array of decimal-encoded elements
+#[wasm_bindgen]
+pub fn poseidon_hash_array(inputs: &js_sys::Array) -> Result<String, JsError> {
+    let mut elements = Vec::with_capacity(inputs.length() as usize);
+    for value in inputs.iter() {
+        let value = value
+            .as_string()
+            .ok_or_else(|| JsError::new("Array elements must be decimal strings"))?;
+        elements.push(parse_decimal(&value)?);
+    }
+
+    let hash = PoseidonHash::new().hash_array_production(&elements)?;
+    Ok(hash.to_string())
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_hash_single_vector() {
+        assert_eq!(
+            poseidon_hash_single("1").unwrap(),
+            "18586133768512220936620570745912940619677854269274689475585506675881198879027"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_hash_pair_vector() {
+        assert_eq!(
+            poseidon_hash_pair("1", "2").unwrap(),
+            "7853200120776062878684798364095072458815029376092732009249414926327459813530"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_hash_array_vector() {
+        let inputs = js_sys::Array::of2(&JsValue::from_str("1"), &JsValue::from_str("2"));
+        assert_eq!(
+            poseidon_hash_array(&inputs).unwrap(),
+            poseidon_hash_pair("1", "2").unwrap()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_invalid_input_errors() {
+        assert!(poseidon_hash_single("not a number").is_err());
+    }
+}
@@ -0,0 +1,124 @@
+//! ZK-Email verifier precompile interface and ABI definitions
+
+use super::verifier::{verify_dkim_proof, DkimVerificationInput, DKIM_PUBLIC_SIGNALS};
+use crate::errors::{InvalidSelectorError, PrecompileError};
+use crate::gas::GasCosts;
+use crate::groth16::{Groth16Proof, VerifyingKey};
+use crate::poseidon::interface::decode_call;
+use crate::traits::GenericPrecompile;
+use alloy_sol_types::{sol, SolCall, SolValue};
+
+// Solidity interface definition
+sol! {
+    #[sol(abi)]
+    interface IZkEmailVerifier {
+        /// Verifies a ZK-Email proof of a DKIM-signed email
+        /// @param emailDomain The sender domain
+        /// @param publicKeyHash The Poseidon hash of the DKIM RSA public key
+        /// @param maskedSubject The subject with private parts zeroed
+        /// @param nullifier The email nullifier
+        /// @param a The proof point A in G1
+        /// @param b The proof point B in G2, imaginary parts first
+        /// @param c The proof point C in G1
+        /// @param vk The email circuit's verifying key: alpha, beta, gamma, delta, then the IC points
+        /// @return valid Whether the proof is accepted
+        function dkimVerify(string emailDomain, uint256 publicKeyHash, string maskedSubject, uint256 nullifier, uint256[2] a, uint256[2][2] b, uint256[2] c, uint256[] vk) external view returns (bool valid);
+    }
+}
+
+/// ZK-Email verifier precompile behind the `IZkEmailVerifier` selectors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ZkEmailVerifierPrecompile;
+
+impl ZkEmailVerifierPrecompile {
+    /// Selectors of every `IZkEmailVerifier` function
+    pub const SELECTORS: [[u8; 4]; 1] = [IZkEmailVerifier::dkimVerifyCall::SELECTOR];
+}
+
+impl GenericPrecompile for ZkEmailVerifierPrecompile {
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        zk_email_precompile(input)
+    }
+
+    fn selector_list(&self) -> &'static [[u8; 4]] {
+        &Self::SELECTORS
+    }
+
+    fn gas_cost(&self, _input: &[u8]) -> u64 {
+        // The circuit fixes the number of public signals
+        GasCosts::groth16_verify(DKIM_PUBLIC_SIGNALS as u64)
+    }
+}
+
+/// Precompile entry point - handles the raw call interface
+pub fn zk_email_precompile(input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+    if input.len() < 4 {
+        return Err(InvalidSelectorError::from_input(input).into());
+    }
+
+    match &input[0..4] {
+        // dkimVerify(string,uint256,string,uint256,uint256[2],uint256[2][2],uint256[2],uint256[])
+        s if s == IZkEmailVerifier::dkimVerifyCall::SELECTOR => {
+            let decoded = decode_call::<IZkEmailVerifier::dkimVerifyCall>(input)?;
+
+            let vk = VerifyingKey::from_words(&decoded.vk)?;
+            let proof = Groth16Proof::from_calldata(decoded.a, decoded.b, decoded.c);
+            let statement = DkimVerificationInput {
+                email_domain: decoded.emailDomain,
+                public_key_hash: decoded.publicKeyHash,
+                masked_subject: decoded.maskedSubject,
+                nullifier: decoded.nullifier,
+            };
+            Ok(verify_dkim_proof(&statement, &proof, &vk)?.abi_encode())
+        }
+
+        _ => Err(InvalidSelectorError::from_input(input).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::Groth16Error;
+    use crate::zk_email::verifier::tests::{fixture, statement};
+    use alloy_primitives::U256;
+
+    fn call() -> IZkEmailVerifier::dkimVerifyCall {
+        let input = statement();
+        let (vk, proof) = fixture(&input);
+        IZkEmailVerifier::dkimVerifyCall {
+            emailDomain: input.email_domain,
+            publicKeyHash: input.public_key_hash,
+            maskedSubject: input.masked_subject,
+            nullifier: input.nullifier,
+            a: [proof.a.x, proof.a.y],
+            b: [proof.b.x, proof.b.y],
+            c: [proof.c.x, proof.c.y],
+            vk: vk.to_words(),
+        }
+    }
+
+    #[test]
+    fn test_precompile_interface() {
+        let call = call();
+        let output = ZkEmailVerifierPrecompile.call(&call.abi_encode()).unwrap();
+        assert!(IZkEmailVerifier::dkimVerifyCall::abi_decode_returns(&output, true).unwrap().valid);
+        assert_eq!(
+            ZkEmailVerifierPrecompile.gas_cost(&call.abi_encode()),
+            GasCosts::groth16_verify(DKIM_PUBLIC_SIGNALS as u64)
+        );
+
+        let forged = IZkEmailVerifier::dkimVerifyCall { nullifier: U256::from(1), ..call.clone() };
+        let output = zk_email_precompile(&forged.abi_encode()).unwrap();
+        assert!(
+            !IZkEmailVerifier::dkimVerifyCall::abi_decode_returns(&output, true).unwrap().valid
+        );
+
+        let long = IZkEmailVerifier::dkimVerifyCall { maskedSubject: "x".repeat(256), ..call };
+        assert!(matches!(
+            zk_email_precompile(&long.abi_encode()),
+            Err(PrecompileError::Groth16(Groth16Error::PublicInputTooLong { .. }))
+        ));
+        assert!(matches!(zk_email_precompile(&[0u8; 8]), Err(PrecompileError::Selector(_))));
+    }
+}
@@ -0,0 +1,12 @@
+//! On-chain verification of ZK-Email proofs
+//!
+//! ZK-Email circuits prove in zero knowledge that an email was DKIM-signed
+//! by its sender domain, revealing only the domain, the key hash, a masked
+//! subject and a nullifier. Verifying the Groth16 proof is the on-chain cost,
+//! so it runs here against the email circuit's verifying key.
+
+pub mod interface;
+pub mod verifier;
+
+pub use interface::{zk_email_precompile, IZkEmailVerifier, ZkEmailVerifierPrecompile};
+pub use verifier::{verify_dkim_proof, DkimVerificationInput, DKIM_PUBLIC_SIGNALS};
@@ -0,0 +1,166 @@
+//! DKIM proof verification
+//!
+//! A ZK-Email circuit proves that an email carries a valid DKIM signature
+//! from `email_domain` under the RSA key whose Poseidon hash is
+//! `public_key_hash`, and reveals the subject with private parts masked.
+//! Its public signals are, in order:
+//!
+//! `public_key_hash, domain[0..DOMAIN_FIELDS], subject[0..SUBJECT_FIELDS], nullifier`
+//!
+//! Strings are packed as in the zk-email circuits: zero-padded to their
+//! maximum length and cut into 31-byte chunks, each read little-endian, so
+//! every chunk is below the BN254 scalar field modulus.
+
+use crate::errors::{Groth16Error, PrecompileError};
+use crate::groth16::{groth16_verify, Groth16Proof, VerifyingKey};
+use alloy_primitives::U256;
+
+/// Bytes packed into one public signal
+pub const PACK_BYTES: usize = 31;
+
+/// Longest sender domain the circuit takes
+pub const MAX_DOMAIN_BYTES: usize = 255;
+
+/// Longest masked subject the circuit takes
+pub const MAX_SUBJECT_BYTES: usize = 255;
+
+/// Signals holding the packed domain
+pub const DOMAIN_FIELDS: usize = MAX_DOMAIN_BYTES.div_ceil(PACK_BYTES);
+
+/// Signals holding the packed subject
+pub const SUBJECT_FIELDS: usize = MAX_SUBJECT_BYTES.div_ceil(PACK_BYTES);
+
+/// Public signals of the email circuit
+pub const DKIM_PUBLIC_SIGNALS: usize = 2 + DOMAIN_FIELDS + SUBJECT_FIELDS;
+
+/// Statement a DKIM proof is checked against
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DkimVerificationInput {
+    pub email_domain: String,
+    /// Poseidon hash of the DKIM RSA public key
+    pub public_key_hash: U256,
+    /// Subject with the private parts replaced by zero bytes
+    pub masked_subject: String,
+    pub nullifier: U256,
+}
+
+impl DkimVerificationInput {
+    /// Returns the circuit's public signals for this statement
+    pub fn public_signals(&self) -> Result<Vec<U256>, Groth16Error> {
+        let mut signals = Vec::with_capacity(DKIM_PUBLIC_SIGNALS);
+        signals.push(self.public_key_hash);
+        signals.extend(pack_bytes("email domain", self.email_domain.as_bytes(), MAX_DOMAIN_BYTES)?);
+        signals.extend(pack_bytes(
+            "masked subject",
+            self.masked_subject.as_bytes(),
+            MAX_SUBJECT_BYTES,
+        )?);
+        signals.push(self.nullifier);
+        Ok(signals)
+    }
+}
+
+/// Verifies an email proof against the email circuit's verifying key
+pub fn verify_dkim_proof(
+    input: &DkimVerificationInput,
+    proof: &Groth16Proof,
+    vk: &VerifyingKey,
+) -> Result<bool, PrecompileError> {
+    Ok(groth16_verify(vk, proof, &input.public_signals()?)?)
+}
+
+/// Packs `bytes`, zero-padded to `max` bytes, into little-endian 31-byte signals
+fn pack_bytes(name: &'static str, bytes: &[u8], max: usize) -> Result<Vec<U256>, Groth16Error> {
+    if bytes.len() > max {
+        return Err(Groth16Error::PublicInputTooLong { name, max, actual: bytes.len() });
+    }
+
+    let mut padded = bytes.to_vec();
+    padded.resize(max, 0);
+    Ok(padded.chunks(PACK_BYTES).map(U256::from_le_slice).collect())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::curves::{BN254G1Point, BN254G2Point};
+    use crate::utils::BN254_MODULUS;
+
+    /// Statement of the fixture proof
+    pub(crate) fn statement() -> DkimVerificationInput {
+        DkimVerificationInput {
+            email_domain: "gmail.com".into(),
+            public_key_hash: U256::from_be_slice(&[0x2a; 31]),
+            masked_subject: "Send 1 ETH to \0\0\0\0\0\0\0\0".into(),
+            nullifier: U256::from(0xdead_beef_u64),
+        }
+    }
+
+    fn g1(scalar: U256) -> BN254G1Point {
+        BN254G1Point::scalar_mul(&BN254G1Point::GENERATOR, scalar).unwrap()
+    }
+
+    /// Circuit key and proof with known discrete logs for `input`
+    ///
+    /// Real zk-email keys come from a trusted setup whose artifacts are not
+    /// vendored here, so the fixture uses `α = 5·G1`, `β = γ = δ = G2`,
+    /// `IC_i = (i + 2)·G1`, `A = 3·G1`, `B = 4·G2` and `C` solving the
+    /// verification equation.
+    pub(crate) fn fixture(input: &DkimVerificationInput) -> (VerifyingKey, Groth16Proof) {
+        let ic_scalars: Vec<U256> =
+            (0..=DKIM_PUBLIC_SIGNALS as u64).map(|i| U256::from(i + 2)).collect();
+        let signals = input.public_signals().unwrap();
+
+        // C = 3·4 - 5 - Σ (i + 2)·x_i with x_0 = 1
+        let mut c = U256::from(7);
+        for (scalar, signal) in ic_scalars.iter().zip([U256::from(1)].iter().chain(&signals)) {
+            c = c.add_mod(BN254_MODULUS - scalar.mul_mod(*signal, BN254_MODULUS), BN254_MODULUS);
+        }
+
+        let vk = VerifyingKey {
+            alpha_g1: g1(U256::from(5)),
+            beta_g2: BN254G2Point::GENERATOR,
+            gamma_g2: BN254G2Point::GENERATOR,
+            delta_g2: BN254G2Point::GENERATOR,
+            ic: ic_scalars.into_iter().map(g1).collect(),
+        };
+        let proof = Groth16Proof {
+            a: g1(U256::from(3)),
+            b: BN254G2Point::scalar_mul(&BN254G2Point::GENERATOR, U256::from(4)).unwrap(),
+            c: g1(c),
+        };
+        (vk, proof)
+    }
+
+    #[test]
+    fn test_verify_dkim_proof() {
+        let input = statement();
+        let (vk, proof) = fixture(&input);
+        assert!(verify_dkim_proof(&input, &proof, &vk).unwrap());
+
+        let other_domain =
+            DkimVerificationInput { email_domain: "gmail.co".into(), ..input.clone() };
+        assert!(!verify_dkim_proof(&other_domain, &proof, &vk).unwrap());
+        let other_subject =
+            DkimVerificationInput { masked_subject: "Send 2 ETH to".into(), ..input.clone() };
+        assert!(!verify_dkim_proof(&other_subject, &proof, &vk).unwrap());
+        let other_nullifier = DkimVerificationInput { nullifier: U256::from(1), ..input };
+        assert!(!verify_dkim_proof(&other_nullifier, &proof, &vk).unwrap());
+    }
+
+    #[test]
+    fn test_packing() {
+        let signals = statement().public_signals().unwrap();
+        assert_eq!(signals.len(), DKIM_PUBLIC_SIGNALS);
+        assert_eq!(signals[1], U256::from_le_slice(b"gmail.com"));
+        assert!(signals[2..=DOMAIN_FIELDS].iter().all(U256::is_zero));
+        assert!(signals.iter().all(|signal| *signal < BN254_MODULUS));
+
+        let long =
+            DkimVerificationInput { email_domain: "a".repeat(MAX_DOMAIN_BYTES + 1), ..statement() };
+        assert!(matches!(
+            long.public_signals(),
+            Err(Groth16Error::PublicInputTooLong { name: "email domain", max: 255, actual: 256 })
+        ));
+    }
+}
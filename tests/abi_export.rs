@@ -0,0 +1,56 @@
+//! Checks the committed JSON ABI artifacts against the sol! interfaces
+
+#![cfg(feature = "export-abi")]
+
+use alloy_json_abi::JsonAbi;
+use precompile::abi_export::{abi_to_json, generate_abi, write_abi_artifacts};
+use std::path::PathBuf;
+
+fn abi_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("abi")
+}
+
+#[cfg(test)]
+mod abi_export_tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_json_parses_as_abi() {
+        for (name, abi) in generate_abi() {
+            let parsed: JsonAbi = serde_json::from_str(&abi_to_json(&abi)).unwrap();
+            assert_eq!(parsed, abi, "{} does not round-trip", name);
+            assert!(parsed.functions().next().is_some(), "{} has no functions", name);
+        }
+    }
+
+    #[test]
+    fn test_poseidon_abi_functions() {
+        let (_, abi) = generate_abi()
+            .into_iter()
+            .find(|(name, _)| *name == "IPoseidonHash")
+            .unwrap();
+
+        for function in ["poseidon1", "poseidon2", "poseidonN", "poseidonBytes32"] {
+            assert!(abi.function(function).is_some(), "missing {}", function);
+        }
+    }
+
+    #[test]
+    fn test_committed_artifacts_are_fresh() {
+        if std::env::var_os("UPDATE_ABI").is_some() {
+            write_abi_artifacts(&abi_dir()).unwrap();
+        }
+
+        for (name, abi) in generate_abi() {
+            let path = abi_dir().join(format!("{}.json", name));
+            let committed = std::fs::read_to_string(&path)
+                .unwrap_or_else(|_| panic!("{} is missing, rerun with UPDATE_ABI=1", path.display()));
+            assert_eq!(
+                committed,
+                abi_to_json(&abi),
+                "{} is stale, rerun with UPDATE_ABI=1",
+                path.display()
+            );
+        }
+    }
+}
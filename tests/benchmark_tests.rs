@@ -1,4 +1,5 @@
 //! Benchmark tests for Poseidon hash precompile
+#![allow(deprecated)]
 
 use alloy_primitives::U256;
 use precompile::*;
@@ -77,12 +78,10 @@ mod benchmark_tests {
     fn benchmark_precompile_interface() {
         let input = U256::from(42);
         let call_data = IPoseidonHash::poseidon1Call { input }.abi_encode();
-        let mut full_input = IPoseidonHash::poseidon1Call::SELECTOR.to_vec();
-        full_input.extend_from_slice(&call_data);
 
         let start = Instant::now();
         for _ in 0..1000 {
-            let _ = poseidon_precompile(&full_input).unwrap();
+            let _ = poseidon_precompile(&call_data).unwrap();
         }
         let duration = start.elapsed();
 
@@ -94,4 +93,214 @@ mod benchmark_tests {
             "Precompile interface benchmark too slow"
         );
     }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn benchmark_parallel_tree_build() {
+        use precompile::merkle::{build_tree_parallel, build_tree_serial};
+
+        let hasher = PoseidonHash::new();
+        let leaves: Vec<U256> = (0..4096u64).map(U256::from).collect();
+
+        let start = Instant::now();
+        let serial = build_tree_serial(&leaves, &hasher).unwrap();
+        let serial_duration = start.elapsed();
+
+        let start = Instant::now();
+        let parallel = build_tree_parallel(&leaves, &hasher).unwrap();
+        let parallel_duration = start.elapsed();
+
+        println!("Serial build of 4096 leaves took: {:?}", serial_duration);
+        println!("Parallel build of 4096 leaves took: {:?}", parallel_duration);
+
+        assert_eq!(serial, parallel);
+    }
+
+    #[test]
+    fn benchmark_static_vs_dynamic_dispatch() {
+        use precompile::poseidon::PlaceholderPoseidon;
+        use std::hint::black_box;
+
+        trait PairHasher {
+            fn hash_pair(&self, left: U256, right: U256) -> U256;
+        }
+
+        impl PairHasher for PlaceholderPoseidon {
+            fn hash_pair(&self, left: U256, right: U256) -> U256 {
+                PlaceholderPoseidon::hash_pair(self, left, right).unwrap()
+            }
+        }
+
+        let hasher = PlaceholderPoseidon::new();
+        let dynamic: Box<dyn PairHasher> = Box::new(PlaceholderPoseidon::new());
+        let left = U256::from(111);
+        let right = U256::from(222);
+
+        let start = Instant::now();
+        for _ in 0..1000 {
+            black_box(hasher.hash_pair(black_box(left), black_box(right)).unwrap());
+        }
+        let static_duration = start.elapsed();
+
+        let start = Instant::now();
+        for _ in 0..1000 {
+            black_box(dynamic.hash_pair(black_box(left), black_box(right)));
+        }
+        let dynamic_duration = start.elapsed();
+
+        println!("1000 statically dispatched pair hashes took: {:?}", static_duration);
+        println!("1000 dynamically dispatched pair hashes took: {:?}", dynamic_duration);
+
+        assert_eq!(
+            hasher.hash_pair(left, right).unwrap(),
+            dynamic.hash_pair(left, right)
+        );
+    }
+
+    #[test]
+    fn benchmark_montgomery_vs_mul_mod() {
+        use precompile::field::MontgomeryField;
+
+        let input: U256 = U256::from(0x1234_5678_9abc_def0u64) << 190;
+        let iterations = 100_000;
+
+        // x^5 with U256::mul_mod, reducing through big-integer division
+        let modulus = precompile::utils::BN254_MODULUS;
+        let start = Instant::now();
+        let mut plain = input;
+        for _ in 0..iterations {
+            let square = plain.mul_mod(plain, modulus);
+            plain = square.mul_mod(square, modulus).mul_mod(plain, modulus);
+        }
+        let mul_mod_duration = start.elapsed();
+
+        // x^5 with CIOS Montgomery multiplication
+        let start = Instant::now();
+        let mut montgomery = MontgomeryField::to_montgomery(input);
+        for _ in 0..iterations {
+            montgomery = montgomery.pow5();
+        }
+        let montgomery_duration = start.elapsed();
+
+        println!("{} mul_mod S-boxes took: {:?}", iterations, mul_mod_duration);
+        println!("{} Montgomery S-boxes took: {:?}", iterations, montgomery_duration);
+
+        assert_eq!(MontgomeryField::from_montgomery(montgomery), plain);
+    }
+
+    #[test]
+    fn benchmark_msm_vs_naive() {
+        use precompile::curves::BN254G1Point;
+
+        let modulus = precompile::utils::BN254_MODULUS;
+        let g = BN254G1Point::GENERATOR;
+
+        for n in [16u64, 64, 256] {
+            let points: Vec<BN254G1Point> = (1..=n)
+                .map(|i| BN254G1Point::scalar_mul(&g, U256::from(i)).unwrap())
+                .collect();
+            let scalars: Vec<U256> = (1..=n).map(|i| modulus - U256::from(i * 7919)).collect();
+
+            let start = Instant::now();
+            let mut naive = BN254G1Point::INFINITY;
+            for (scalar, point) in scalars.iter().zip(&points) {
+                let term = BN254G1Point::scalar_mul(point, *scalar).unwrap();
+                naive = BN254G1Point::add(&naive, &term).unwrap();
+            }
+            let naive_duration = start.elapsed();
+
+            let start = Instant::now();
+            let msm = bn254_msm(&scalars, &points).unwrap();
+            let msm_duration = start.elapsed();
+
+            println!("{} naive scalar multiplications took: {:?}", n, naive_duration);
+            println!("{}-term Pippenger MSM took: {:?}", n, msm_duration);
+            println!("Per term: {:?} vs {:?}", naive_duration / n as u32, msm_duration / n as u32);
+
+            assert_eq!(msm, naive);
+            if n == 256 {
+                assert!(msm_duration < naive_duration, "Pippenger MSM slower than naive");
+            }
+        }
+    }
+
+    #[test]
+    fn benchmark_groth16_batch_vs_individual() {
+        use precompile::curves::{BN254G1Point, BN254G2Point};
+        use precompile::gas::GasCosts;
+        use precompile::groth16::{batch_verify, groth16_verify, Groth16Proof, VerifyingKey};
+        use rand_chacha::rand_core::SeedableRng;
+
+        let modulus = precompile::utils::BN254_MODULUS;
+        let g1 = |k: U256| BN254G1Point::scalar_mul(&BN254G1Point::GENERATOR, k).unwrap();
+        let g2 = |k: U256| BN254G2Point::scalar_mul(&BN254G2Point::GENERATOR, k).unwrap();
+
+        // α = 5·G1, β = γ = δ = G2 and IC = [7, 11]·G1, so C = (r·s - 12 - 11·x)·G1
+        let vk = VerifyingKey {
+            alpha_g1: g1(U256::from(5)),
+            beta_g2: BN254G2Point::GENERATOR,
+            gamma_g2: BN254G2Point::GENERATOR,
+            delta_g2: BN254G2Point::GENERATOR,
+            ic: vec![g1(U256::from(7)), g1(U256::from(11))],
+        };
+        let proofs: Vec<(Groth16Proof, Vec<U256>)> = (1..=10u64)
+            .map(|i| {
+                let (r, s, x) = (U256::from(i), U256::from(i + 100), U256::from(i * 3));
+                let c = r
+                    .mul_mod(s, modulus)
+                    .add_mod(modulus - U256::from(12), modulus)
+                    .add_mod(modulus - x.mul_mod(U256::from(11), modulus), modulus);
+                (Groth16Proof { a: g1(r), b: g2(s), c: g1(c) }, vec![x])
+            })
+            .collect();
+
+        let start = Instant::now();
+        for (proof, inputs) in &proofs {
+            assert!(groth16_verify(&vk, proof, inputs).unwrap());
+        }
+        let individual_duration = start.elapsed();
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        let start = Instant::now();
+        assert!(batch_verify(&proofs, &vk, &mut rng).unwrap());
+        let batch_duration = start.elapsed();
+
+        let individual_gas = proofs.len() as u64 * GasCosts::groth16_verify(1);
+        let batch_gas = GasCosts::groth16_batch_verify(proofs.len() as u64, 1);
+        println!("Individual verification took: {:?}, {} gas", individual_duration, individual_gas);
+        println!("Batch verification took: {:?}, {} gas", batch_duration, batch_gas);
+
+        assert!(batch_gas * 2 < individual_gas, "batch verification saves less than half the gas");
+    }
+
+    #[test]
+    fn benchmark_router_lookup() {
+        use alloy_sol_types::SolCall;
+        use precompile::mimc::{IMiMCHash, MiMCPrecompile};
+        use precompile::poseidon::PoseidonPrecompile;
+        use precompile::rescue::RescuePrecompile;
+        use precompile::router_builder;
+        use std::hint::black_box;
+
+        let router = router_builder! {
+            poseidon => PoseidonPrecompile,
+            mimc => MiMCPrecompile,
+            rescue => RescuePrecompile,
+        };
+        let selector = IMiMCHash::mimc1Call::SELECTOR;
+        let iterations = 100_000u32;
+
+        let start = Instant::now();
+        for _ in 0..iterations {
+            black_box(router.handles(black_box(selector)));
+        }
+        let per_call = start.elapsed() / iterations;
+
+        println!("Selector lookup took {:?} per call", per_call);
+
+        // Unoptimized builds do not inline the map lookup
+        if !cfg!(debug_assertions) {
+            assert!(per_call.as_nanos() < 50, "routing overhead {:?} exceeds 50ns", per_call);
+        }
+    }
 }
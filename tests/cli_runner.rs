@@ -1,4 +1,5 @@
 //! CLI runner for manual testing of the Poseidon precompile
+#![allow(deprecated)]
 
 use alloy_primitives::U256;
 use alloy_sol_types::{SolCall, SolValue};
@@ -43,10 +44,8 @@ mod cli_tests {
             input: U256::from(999),
         };
         let encoded = call.abi_encode();
-        let mut full_call = IPoseidonHash::poseidon1Call::SELECTOR.to_vec();
-        full_call.extend_from_slice(&encoded);
 
-        let result = poseidon_precompile(&full_call).unwrap();
+        let result = poseidon_precompile(&encoded).unwrap();
         let decoded_hash = U256::abi_decode(&result, true).unwrap();
         println!("   Precompile Input: 999");
         println!("   Precompile Hash:  0x{:x}", decoded_hash);
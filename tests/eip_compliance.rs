@@ -0,0 +1,105 @@
+//! EIP-196 and EIP-197 compliance of the BN254 precompile
+//!
+//! Vectors live in `test-vectors/eip196.json` and `test-vectors/eip197.json`
+//! in go-ethereum's precompile test format: the raw `Input` bytes of the
+//! `0x06`, `0x07` or `0x08` call, the raw `Expected` output and the EIP-1108
+//! `Gas`. Vectors marked `ExpectedError` must make the call fail. Expected
+//! outputs were computed with plain affine arithmetic over the BN254 base
+//! field, independently of this crate.
+//!
+//! The `IBN254` functions take whole words, so the raw input is first padded
+//! or truncated as the EIPs specify. The output must then equal the expected
+//! bytes exactly.
+
+use alloy_primitives::U256;
+use alloy_sol_types::SolCall;
+use precompile::curves::{bn254_precompile, BN254Precompile, IBN254};
+use precompile::traits::GenericPrecompile;
+use serde_json::Value;
+
+/// One vector from a test file
+struct Vector {
+    name: String,
+    input: Vec<u8>,
+    /// `None` when the call must fail
+    expected: Option<Vec<u8>>,
+    gas: Option<u64>,
+}
+
+/// Loads the vectors of `operation` from `test-vectors/<file>`
+fn load(file: &str, operation: &str) -> Vec<Vector> {
+    let path = format!("{}/test-vectors/{}", env!("CARGO_MANIFEST_DIR"), file);
+    let json: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let bytes = |value: &Value| hex::decode(value.as_str().unwrap()).unwrap();
+
+    let vectors: Vec<Vector> = json[operation]
+        .as_array()
+        .unwrap_or_else(|| panic!("{} has no {} vectors", path, operation))
+        .iter()
+        .map(|vector| Vector {
+            name: vector["Name"].as_str().unwrap().to_string(),
+            input: bytes(&vector["Input"]),
+            expected: vector.get("Expected").map(bytes),
+            gas: vector["Gas"].as_u64(),
+        })
+        .collect();
+    assert!(!vectors.is_empty());
+    vectors
+}
+
+/// Splits input padded with zeros, or truncated, to `words` words
+fn words(input: &[u8], words: usize) -> Vec<U256> {
+    let mut padded = input.to_vec();
+    padded.resize(words * 32, 0);
+    padded.chunks(32).map(U256::from_be_slice).collect()
+}
+
+/// Runs `call` on every vector and checks the exact output and the gas
+fn check(vectors: &[Vector], call: impl Fn(&[u8]) -> Vec<u8>) {
+    for vector in vectors {
+        let calldata = call(&vector.input);
+        let output = bn254_precompile(&calldata);
+        match &vector.expected {
+            Some(expected) => {
+                let output =
+                    output.unwrap_or_else(|e| panic!("{}: failed with {}", vector.name, e));
+                assert_eq!(
+                    hex::encode(&output),
+                    hex::encode(expected),
+                    "{}: output differs",
+                    vector.name
+                );
+            }
+            None => assert!(output.is_err(), "{}: expected the call to fail", vector.name),
+        }
+
+        if let Some(gas) = vector.gas {
+            assert_eq!(BN254Precompile.gas_cost(&calldata), gas, "{}: gas differs", vector.name);
+        }
+    }
+}
+
+#[test]
+fn test_eip196_ecadd_vectors() {
+    check(&load("eip196.json", "ecAdd"), |input| {
+        let [ax, ay, bx, by] = words(input, 4)[..] else { unreachable!() };
+        IBN254::ecAddCall { ax, ay, bx, by }.abi_encode()
+    });
+}
+
+#[test]
+fn test_eip196_ecmul_vectors() {
+    check(&load("eip196.json", "ecMul"), |input| {
+        let [px, py, s] = words(input, 3)[..] else { unreachable!() };
+        IBN254::ecMulCall { px, py, s }.abi_encode()
+    });
+}
+
+#[test]
+fn test_eip197_pairing_vectors() {
+    // EIP-197 takes no padding, and the vectors are all whole words
+    check(&load("eip197.json", "ecPairing"), |input| {
+        assert_eq!(input.len() % 32, 0);
+        IBN254::ecPairingCheckCall { input: words(input, input.len() / 32) }.abi_encode()
+    });
+}
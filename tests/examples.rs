@@ -1,6 +1,10 @@
 //! Usage examples for the Poseidon hash precompile
+#![allow(deprecated)]
 
 use alloy_primitives::U256;
+use precompile::commitment::Commitment;
+use precompile::poseidon::COMMITMENT_TAG;
+use precompile::prelude::{poseidon_hash_chain, verify_hash_chain};
 use precompile::*;
 
 #[cfg(test)]
@@ -13,7 +17,7 @@ mod examples {
         let hasher = PoseidonHash::new();
 
         // Sample data for leaves
-        let data = vec!["alice", "bob", "charlie", "diana"];
+        let data = ["alice", "bob", "charlie", "diana"];
 
         // Convert to field elements (simplified - real implementation would use proper encoding)
         let leaves: Vec<U256> = data
@@ -62,17 +66,17 @@ mod examples {
         let secret = U256::from(42);
         let randomness = U256::from(12345);
 
-        // Create commitment: H(secret, randomness)
-        let commitment = hasher.hash_pair(secret, randomness).unwrap();
+        // Create commitment: H(COMMITMENT_TAG, secret, randomness)
+        let commitment = Commitment::commit(secret, randomness, COMMITMENT_TAG, &hasher).unwrap();
 
         println!("Commitment scheme example:");
         println!("Secret: {}", secret);
         println!("Randomness: {}", randomness);
-        println!("Commitment: 0x{:x}", commitment);
+        println!("Commitment: 0x{:x}", commitment.0);
 
-        // Verify commitment by recomputing
-        let verification = hasher.hash_pair(secret, randomness).unwrap();
-        assert_eq!(commitment, verification);
+        // Verify commitment by opening it
+        assert!(Commitment::open(&commitment, secret, randomness, COMMITMENT_TAG, &hasher));
+        assert!(!Commitment::open(&commitment, secret, U256::ZERO, COMMITMENT_TAG, &hasher));
 
         println!("✓ Commitment verified");
     }
@@ -108,30 +112,24 @@ mod examples {
         let hasher = PoseidonHash::new();
 
         // Initial value
-        let mut current_hash = U256::from(1);
-        let timestamps = vec![1000, 2000, 3000, 4000, 5000];
+        let initial = U256::from(1);
+        let timestamps: Vec<U256> = [1000, 2000, 3000, 4000, 5000].map(U256::from).to_vec();
 
         println!("Hash chain example:");
-        println!("Initial: 0x{:x}", current_hash);
-
-        for (i, &timestamp) in timestamps.iter().enumerate() {
-            // Chain: H(previous_hash, timestamp)
-            current_hash = hasher
-                .hash_pair(current_hash, U256::from(timestamp))
-                .unwrap();
-            println!(
-                "Step {}: 0x{:x} (timestamp: {})",
-                i + 1,
-                current_hash,
-                timestamp
-            );
+        println!("Initial: 0x{:x}", initial);
+
+        // Chain: H(previous_hash, timestamp)
+        let chain = poseidon_hash_chain(initial, &timestamps, &hasher).unwrap();
+        for (i, (link, timestamp)) in chain.iter().zip(&timestamps).enumerate() {
+            println!("Step {}: 0x{:x} (timestamp: {})", i + 1, link, timestamp);
         }
 
         // Final hash represents the entire chain
-        let final_hash = current_hash;
+        let final_hash = chain[chain.len() - 1];
         println!("Final chain hash: 0x{:x}", final_hash);
 
         // Verify we can't forge the chain without knowing intermediate steps
         assert_ne!(final_hash, U256::from(1));
+        assert!(verify_hash_chain(&chain, initial, &timestamps, &hasher));
     }
 }
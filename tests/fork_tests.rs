@@ -0,0 +1,169 @@
+//! Poseidon precompile calls against a live Arbitrum Nitro node
+//!
+//! Only builds with the `fork-tests` feature, and only runs when `FORK_URL`
+//! is set: `FORK_URL=http://localhost:8547 cargo test --features fork-tests
+//! --test fork_tests`.
+//!
+//! The node must execute Stylus programs, so `FORK_URL` points at a Nitro
+//! node such as the nitro-testnode dev chain. `anvil --fork-url` does not
+//! work: anvil runs the plain EVM and never executes the activated WASM.
+//! Programs are signed, deployed and activated by `cargo stylus deploy`
+//! with the key in `FORK_PRIVATE_KEY`, which defaults to the funded
+//! nitro-testnode dev account. The program is the release build at
+//! `FORK_WASM`, by default `target/wasm32-unknown-unknown/release/precompile.wasm`.
+
+#![cfg(feature = "fork-tests")]
+
+use std::process::Command;
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::SolCall;
+use precompile::deployment::{
+    parse_deploy_tx_hash, CurlTransport, DeployMonitor, IStylusPrecompile, RpcTransport,
+};
+use precompile::errors::{DeployError, PrecompileError};
+use precompile::*;
+use serde_json::json;
+
+/// Funded dev account of the nitro-testnode chain
+const TESTNODE_PRIVATE_KEY: &str =
+    "0xb6b15c8cb491557369f3c7d2c287b053eb229daa9c22138887752191c9520659";
+
+/// Release build deployed when `FORK_WASM` is unset, as in `scripts/deploy.sh`
+const DEFAULT_WASM: &str = "target/wasm32-unknown-unknown/release/precompile.wasm";
+
+/// Seconds to wait for a deployment to be confirmed
+const DEPLOY_TIMEOUT_SECS: u64 = 120;
+
+/// Deploys Stylus programs to a Nitro node and calls them
+struct ForkHarness {
+    rpc_url: String,
+    private_key: String,
+    transport: CurlTransport,
+}
+
+impl ForkHarness {
+    /// Connects to the node at `fork_url`
+    fn new(fork_url: &str) -> Result<Self, PrecompileError> {
+        let transport = CurlTransport::new(fork_url);
+        transport.request("eth_chainId", json!([]))?;
+
+        Ok(Self {
+            rpc_url: fork_url.to_string(),
+            private_key: std::env::var("FORK_PRIVATE_KEY")
+                .unwrap_or_else(|_| TESTNODE_PRIVATE_KEY.to_string()),
+            transport,
+        })
+    }
+
+    /// Deploys and activates `bytecode`, a Stylus WASM program
+    fn deploy_precompile(&self, bytecode: &[u8]) -> Result<Address, PrecompileError> {
+        let write_error =
+            |e: std::io::Error| DeployError::Rpc(format!("writing the program: {}", e));
+        let dir = tempfile::tempdir().map_err(write_error)?;
+        let wasm_file = dir.path().join("program.wasm");
+        std::fs::write(&wasm_file, bytecode).map_err(write_error)?;
+
+        let output = Command::new("cargo")
+            .args(["stylus", "deploy", "--no-verify"])
+            .args(["--endpoint", &self.rpc_url])
+            .args(["--private-key", &self.private_key])
+            .arg("--wasm-file")
+            .arg(&wasm_file)
+            .output()
+            .map_err(|e| DeployError::Rpc(format!("cargo stylus: {}", e)))?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(DeployError::Rpc(format!("cargo stylus deploy: {}", stderr.trim())).into());
+        }
+
+        let invalid = || DeployError::InvalidResponse(format!("cargo stylus deploy: {}", stdout));
+        let tx_hash = parse_deploy_tx_hash(&stdout).ok_or_else(invalid)?;
+        DeployMonitor::new(tx_hash, &self.rpc_url).wait_for_deployment(DEPLOY_TIMEOUT_SECS)?;
+        parse_deployed_address(&stdout).ok_or_else(|| invalid().into())
+    }
+
+    /// Sends `input` to the program's `dispatch(bytes)` method with `eth_call`
+    ///
+    /// Returns the bytes the precompile produced for `input`.
+    fn call(&self, address: Address, input: &[u8]) -> Result<Vec<u8>, PrecompileError> {
+        let data = IStylusPrecompile::dispatchCall { input: input.to_vec().into() }.abi_encode();
+        let params = json!([
+            { "to": address.to_string(), "data": format!("0x{}", hex::encode(data)) },
+            "latest"
+        ]);
+        let result = self.transport.request("eth_call", params)?;
+
+        let invalid = || DeployError::InvalidResponse(format!("bad eth_call result {}", result));
+        let returned = result
+            .as_str()
+            .and_then(|s| hex::decode(s.trim_start_matches("0x")).ok())
+            .ok_or_else(invalid)?;
+        let output = IStylusPrecompile::dispatchCall::abi_decode_returns(&returned, true)
+            .map_err(|_| invalid())?
+            .output;
+        Ok(output.to_vec())
+    }
+}
+
+/// Extracts the program address from `cargo stylus deploy` output
+fn parse_deployed_address(output: &str) -> Option<Address> {
+    output.lines().filter(|line| line.contains("deployed code at address")).find_map(|line| {
+        let start = line.find("0x")?;
+        line.get(start..start + 42)?.parse().ok()
+    })
+}
+
+/// Connects to `FORK_URL` and deploys the precompile, or `None` without `FORK_URL`
+fn deployed_precompile() -> Option<(ForkHarness, Address)> {
+    let fork_url = std::env::var("FORK_URL").ok()?;
+    let harness = ForkHarness::new(&fork_url).unwrap();
+
+    let wasm = std::env::var("FORK_WASM").unwrap_or_else(|_| DEFAULT_WASM.to_string());
+    let bytecode = std::fs::read(&wasm).unwrap_or_else(|e| {
+        panic!(
+            "{}: {}, build it with `cargo build --release --target wasm32-unknown-unknown`",
+            wasm, e
+        )
+    });
+    let address = harness.deploy_precompile(&bytecode).unwrap();
+    Some((harness, address))
+}
+
+#[test]
+fn test_poseidon_matches_native_path() {
+    let Some((harness, address)) = deployed_precompile() else {
+        return;
+    };
+
+    let calls = [
+        IPoseidonHash::poseidon1Call { input: U256::from(42) }.abi_encode(),
+        IPoseidonHash::poseidon2Call { left: U256::from(1), right: U256::from(2) }.abi_encode(),
+        IPoseidonHash::poseidonNCall { inputs: (1..=20).map(U256::from).collect() }.abi_encode(),
+    ];
+    for input in calls {
+        let on_chain_result = harness.call(address, &input).unwrap();
+        let local_result = poseidon_precompile(&input).unwrap();
+        assert_eq!(on_chain_result, local_result);
+    }
+}
+
+#[test]
+fn test_invalid_input_reverts_on_chain() {
+    let Some((harness, address)) = deployed_precompile() else {
+        return;
+    };
+
+    let modulus = PoseidonHash::new().params.modulus;
+    let input = IPoseidonHash::poseidon1Call { input: modulus }.abi_encode();
+    assert!(poseidon_precompile(&input).is_err());
+    assert!(matches!(harness.call(address, &input), Err(PrecompileError::Deploy(_))));
+}
+
+#[test]
+fn test_parse_deployed_address() {
+    let output = "deployed code at address: 0x00000000000000000000000000000000000000aa\n";
+    assert_eq!(parse_deployed_address(output), Some(Address::with_last_byte(0xaa)));
+    assert_eq!(parse_deployed_address("no address here"), None);
+}
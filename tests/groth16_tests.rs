@@ -0,0 +1,100 @@
+//! Integration tests for the Groth16 verifier precompile
+//!
+//! The verifying key and proof are built from known discrete logs: with
+//! `α = a·G1`, `β = b·G2`, `γ = δ = G2`, `IC_i = k_i·G1` and `A = r·G1`,
+//! `B = s·G2`, the proof verifies exactly when
+//! `C = (r·s - a·b - k_0 - Σ x_i·k_i)·G1`.
+
+use alloy_primitives::U256;
+use alloy_sol_types::SolCall;
+use precompile::curves::{BN254G1Point, BN254G2Point};
+use precompile::groth16::{
+    groth16_precompile, groth16_verify, Groth16Proof, IGroth16Verifier, VerifyingKey,
+};
+use precompile::utils::BN254_MODULUS;
+
+fn g1(scalar: u64) -> BN254G1Point {
+    g1_big(U256::from(scalar))
+}
+
+fn g1_big(scalar: U256) -> BN254G1Point {
+    BN254G1Point::scalar_mul(&BN254G1Point::GENERATOR, scalar).unwrap()
+}
+
+fn sub(a: U256, b: U256) -> U256 {
+    a.add_mod(BN254_MODULUS - b, BN254_MODULUS)
+}
+
+fn mul(a: U256, b: U256) -> U256 {
+    a.mul_mod(b, BN254_MODULUS)
+}
+
+/// Verifying key with `a = 5, b = 1, k = [7, 11, 13]` and a proof for `inputs`
+fn fixture(inputs: &[U256]) -> (VerifyingKey, Groth16Proof) {
+    let (a, r, s) = (U256::from(5), U256::from(3), U256::from(1));
+    let ks = [U256::from(7), U256::from(11), U256::from(13)];
+    let g2 = BN254G2Point::GENERATOR;
+
+    let vk = VerifyingKey {
+        alpha_g1: g1(5),
+        beta_g2: g2,
+        gamma_g2: g2,
+        delta_g2: g2,
+        ic: ks.iter().map(|k| g1_big(*k)).collect(),
+    };
+
+    let mut c = sub(mul(r, s), a);
+    c = sub(c, ks[0]);
+    for (x, k) in inputs.iter().zip(&ks[1..]) {
+        c = sub(c, mul(*x, *k));
+    }
+    let proof = Groth16Proof { a: g1_big(r), b: g2, c: g1_big(c) };
+    (vk, proof)
+}
+
+fn vk_words(vk: &VerifyingKey) -> Vec<U256> {
+    let mut words = vec![vk.alpha_g1.x, vk.alpha_g1.y];
+    for g2 in [vk.beta_g2, vk.gamma_g2, vk.delta_g2] {
+        words.extend(g2.x.iter().chain(&g2.y));
+    }
+    words.extend(vk.ic.iter().flat_map(|p| [p.x, p.y]));
+    words
+}
+
+#[test]
+fn test_accepts_valid_proof() {
+    let inputs = [U256::from(42), U256::from(1337)];
+    let (vk, proof) = fixture(&inputs);
+    assert!(groth16_verify(&vk, &proof, &inputs).unwrap());
+}
+
+#[test]
+fn test_rejects_wrong_inputs_and_tampered_proof() {
+    let inputs = [U256::from(42), U256::from(1337)];
+    let (vk, proof) = fixture(&inputs);
+
+    assert!(!groth16_verify(&vk, &proof, &[U256::from(42), U256::from(1338)]).unwrap());
+
+    let tampered = Groth16Proof { c: g1(2), ..proof };
+    assert!(!groth16_verify(&vk, &tampered, &inputs).unwrap());
+}
+
+#[test]
+fn test_precompile_round_trip() {
+    let inputs = vec![U256::from(42), U256::from(1337)];
+    let (vk, proof) = fixture(&inputs);
+
+    let call = IGroth16Verifier::verifyProofCall {
+        a: [proof.a.x, proof.a.y],
+        b: [proof.b.x, proof.b.y],
+        c: [proof.c.x, proof.c.y],
+        input: inputs.clone(),
+        vk: vk_words(&vk),
+    };
+    let output = groth16_precompile(&call.abi_encode()).unwrap();
+    assert!(IGroth16Verifier::verifyProofCall::abi_decode_returns(&output, true).unwrap().valid);
+
+    let call = IGroth16Verifier::verifyProofCall { input: vec![U256::from(1), U256::from(2)], ..call };
+    let output = groth16_precompile(&call.abi_encode()).unwrap();
+    assert!(!IGroth16Verifier::verifyProofCall::abi_decode_returns(&output, true).unwrap().valid);
+}
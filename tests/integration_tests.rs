@@ -1,8 +1,8 @@
 //! Integration tests for Poseidon hash precompile
+#![allow(deprecated)]
 
 use alloy_primitives::U256;
 use alloy_sol_types::{SolCall, SolValue};
-use hex_literal::hex;
 use precompile::*;
 
 #[cfg(test)]
@@ -72,10 +72,7 @@ mod integration_tests {
         let call = IPoseidonHash::poseidon1Call { input };
         let encoded = call.abi_encode();
 
-        let mut full_call = IPoseidonHash::poseidon1Call::SELECTOR.to_vec();
-        full_call.extend_from_slice(&encoded);
-
-        let result = poseidon_precompile(&full_call);
+        let result = poseidon_precompile(&encoded);
         assert!(result.is_ok());
 
         let output = result.unwrap();
@@ -86,7 +83,7 @@ mod integration_tests {
     #[test]
     fn test_error_cases() {
         // Test invalid selector
-        let invalid_call = vec![0x00, 0x00, 0x00, 0x00]; // Invalid selector
+        let mut invalid_call = vec![0x00, 0x00, 0x00, 0x00]; // Invalid selector
         invalid_call.extend_from_slice(&[0u8; 32]); // Add some data
         let result = poseidon_precompile(&invalid_call);
         assert!(result.is_err());
@@ -107,7 +104,7 @@ mod use_case_tests {
         let hasher = PoseidonHash::new();
 
         // Leaf nodes
-        let leaves = vec![U256::from(1), U256::from(2), U256::from(3), U256::from(4)];
+        let leaves = [U256::from(1), U256::from(2), U256::from(3), U256::from(4)];
 
         // Build tree level by level
         let mut level1 = Vec::new();
@@ -1,7 +1,7 @@
 //! Integration tests for Poseidon hash precompile
+#![allow(deprecated)]
 
 use alloy_primitives::U256;
-use hex_literal::hex;
 use precompile::*;
 
 #[cfg(test)]
@@ -73,10 +73,7 @@ mod integration_tests {
         let call = IPoseidonHash::poseidon1Call { input };
         let encoded = call.abi_encode();
 
-        let mut full_call = IPoseidonHash::poseidon1Call::SELECTOR.to_vec();
-        full_call.extend_from_slice(&encoded);
-
-        let result = poseidon_precompile(&full_call);
+        let result = poseidon_precompile(&encoded);
         assert!(result.is_ok());
 
         let output = result.unwrap();
@@ -150,18 +147,17 @@ mod production_tests {
         // First get the expected hash
         let expected = hasher.hash_single_production(input).unwrap();
         
-        // Now test through the precompile interface
-        // Note: In real deployment, we would switch to production implementation
+        // `poseidon1` hashes with circomlib only under the opt-in use-production-hash feature
         let call = IPoseidonHash::poseidon1Call { input };
         let encoded = call.abi_encode();
-        let mut full_call = IPoseidonHash::poseidon1Call::SELECTOR.to_vec();
-        full_call.extend_from_slice(&encoded);
-        
-        let result = poseidon_precompile(&full_call);
-        assert!(result.is_ok());
         
-        // For now, the interface uses simplified version
-        // In production, we would update interface.rs to use production methods
+        let result = poseidon_precompile(&encoded).unwrap();
+        let hash = U256::abi_decode(&result, true).unwrap();
+        if cfg!(feature = "use-production-hash") {
+            assert_eq!(hash, expected);
+        } else {
+            assert_ne!(hash, expected);
+        }
     }
 }
 
@@ -174,7 +170,7 @@ mod use_case_tests {
         let hasher = PoseidonHash::new();
 
         // Leaf nodes
-        let leaves = vec![U256::from(1), U256::from(2), U256::from(3), U256::from(4)];
+        let leaves = [U256::from(1), U256::from(2), U256::from(3), U256::from(4)];
 
         // Build tree level by level
         let mut level1 = Vec::new();
@@ -0,0 +1,51 @@
+//! Property tests for PoseidonHash over arbitrary field elements
+//!
+//! Slow next to the unit tests, so they only build with the `prop-tests`
+//! feature: `cargo test --features prop-tests --test prop_tests`.
+
+#![cfg(feature = "prop-tests")]
+
+use alloy_primitives::U256;
+use precompile::utils::BN254_MODULUS;
+use precompile::*;
+use proptest::prelude::*;
+
+/// Arbitrary values below the BN254 modulus
+fn field_element() -> impl Strategy<Value = U256> {
+    any::<[u64; 4]>().prop_map(|limbs| U256::from_limbs(limbs) % BN254_MODULUS)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn prop_hash_single_stays_in_field(x in field_element()) {
+        let hash = PoseidonHash::new().hash_single_production(x).unwrap();
+        prop_assert!(hash < BN254_MODULUS);
+    }
+
+    #[test]
+    fn prop_hash_pair_is_not_commutative(a in field_element(), b in field_element()) {
+        prop_assume!(a != b);
+
+        let hasher = PoseidonHash::new();
+        prop_assert_ne!(
+            hasher.hash_pair_production(a, b).unwrap(),
+            hasher.hash_pair_production(b, a).unwrap()
+        );
+    }
+
+    #[test]
+    fn prop_single_element_array_matches_hash_single(x in field_element()) {
+        let hasher = PoseidonHash::new();
+        prop_assert_eq!(
+            hasher.hash_array_production(&[x]).unwrap(),
+            hasher.hash_single_production(x).unwrap()
+        );
+    }
+
+    #[test]
+    fn prop_validate_accepts_field_elements(x in field_element()) {
+        prop_assert_eq!(PoseidonHash::new().validate_field_element(x).unwrap(), x);
+    }
+}
@@ -0,0 +1,98 @@
+//! Tests for the SolStruct derive macro
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, SolValue};
+use precompile::errors::{AbiDecodeError, PrecompileError};
+use precompile::SolStruct;
+
+sol! {
+    struct SolProof {
+        uint256 pi_a_x;
+        uint256 pi_a_y;
+        uint256[] public_inputs;
+        address prover;
+        bool verified;
+    }
+
+    struct SolTimestamped {
+        uint256 value;
+        uint64 timestamp;
+    }
+}
+
+#[derive(SolStruct, Debug, Clone, PartialEq)]
+struct Proof {
+    pi_a_x: U256,
+    pi_a_y: U256,
+    public_inputs: Vec<U256>,
+    prover: Address,
+    verified: bool,
+}
+
+#[derive(SolStruct, Debug, Clone, PartialEq)]
+struct Timestamped {
+    value: U256,
+    #[sol_struct_type(uint64)]
+    timestamp: u64,
+}
+
+#[cfg(test)]
+mod sol_struct_tests {
+    use super::*;
+
+    fn sample_proof() -> Proof {
+        Proof {
+            pi_a_x: U256::from(1),
+            pi_a_y: U256::from(2),
+            public_inputs: vec![U256::from(3), U256::from(4), U256::from(5)],
+            prover: Address::repeat_byte(0xab),
+            verified: true,
+        }
+    }
+
+    #[test]
+    fn test_encoding_matches_sol_struct() {
+        let proof = sample_proof();
+        let expected = SolProof {
+            pi_a_x: proof.pi_a_x,
+            pi_a_y: proof.pi_a_y,
+            public_inputs: proof.public_inputs.clone(),
+            prover: proof.prover,
+            verified: proof.verified,
+        };
+
+        assert_eq!(proof.abi_encode(), expected.abi_encode());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let proof = sample_proof();
+        let decoded = Proof::abi_decode(&proof.abi_encode()).unwrap();
+        assert_eq!(decoded, proof);
+    }
+
+    #[test]
+    fn test_type_override() {
+        let value = Timestamped {
+            value: U256::from(42),
+            timestamp: 1_700_000_000,
+        };
+        let expected = SolTimestamped {
+            value: value.value,
+            timestamp: value.timestamp,
+        };
+
+        assert_eq!(value.abi_encode(), expected.abi_encode());
+        assert_eq!(Timestamped::abi_decode(&value.abi_encode()).unwrap(), value);
+    }
+
+    #[test]
+    fn test_decode_rejects_truncated_data() {
+        let encoded = sample_proof().abi_encode();
+        let result = Proof::abi_decode(&encoded[..encoded.len() - 1]);
+        assert!(matches!(
+            result,
+            Err(PrecompileError::Abi(AbiDecodeError { selector: [0, 0, 0, 0], .. }))
+        ));
+    }
+}
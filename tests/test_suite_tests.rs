@@ -0,0 +1,33 @@
+//! Generated test batteries for the precompile hashers
+
+use precompile::precompile_test_suite;
+
+#[precompile_test_suite(
+    hasher = PoseidonHash,
+    modulus = BN254_MODULUS,
+    single = hash_single_production,
+    pair = hash_pair_production,
+    array = hash_array_production
+)]
+mod poseidon_suite {
+    use precompile::poseidon::PoseidonHash;
+    use precompile::utils::BN254_MODULUS;
+}
+
+#[precompile_test_suite(hasher = MiMCHash, modulus = BN254_MODULUS)]
+mod mimc_suite {
+    use precompile::mimc::MiMCHash;
+    use precompile::utils::BN254_MODULUS;
+}
+
+#[precompile_test_suite(hasher = PedersenHash, modulus = BN254_MODULUS)]
+mod pedersen_suite {
+    use precompile::pedersen::PedersenHash;
+    use precompile::utils::BN254_MODULUS;
+}
+
+#[precompile_test_suite(hasher = RescueHash, modulus = BN254_MODULUS)]
+mod rescue_suite {
+    use precompile::rescue::RescueHash;
+    use precompile::utils::BN254_MODULUS;
+}
@@ -0,0 +1,67 @@
+//! Poseidon test vectors from the reference implementations
+//!
+//! Expected outputs come from iden3/go-iden3-crypto (`poseidon_test.go`) and
+//! circomlibjs (`poseidon.js`), which agree on every vector below. Matching
+//! them shows the production path hashes the same way circom circuits and
+//! on-chain verifiers do.
+
+use alloy_primitives::U256;
+use precompile::*;
+
+/// Asserts a hash, printing both values in hex on mismatch
+fn assert_vector(name: &str, actual: U256, expected: &str) {
+    let expected: U256 = expected.parse().unwrap();
+    assert_eq!(
+        actual, expected,
+        "{}: got 0x{:064x}, expected 0x{:064x}",
+        name, actual, expected
+    );
+}
+
+#[cfg(test)]
+mod test_vector_tests {
+    use super::*;
+
+    #[test]
+    fn test_poseidon1_zero() {
+        let actual = PoseidonHash::new().hash_single_production(U256::ZERO).unwrap();
+        assert_vector(
+            "poseidon([0])",
+            actual,
+            "19014214495641488759237505126948346942972912379615652741039992445865937985820",
+        );
+    }
+
+    #[test]
+    fn test_poseidon1_one() {
+        let actual = PoseidonHash::new().hash_single_production(U256::from(1)).unwrap();
+        assert_vector(
+            "poseidon([1])",
+            actual,
+            "18586133768512220936620570745912940619677854269274689475585506675881198879027",
+        );
+    }
+
+    #[test]
+    fn test_poseidon2() {
+        let actual = PoseidonHash::new()
+            .hash_pair_production(U256::from(1), U256::from(2))
+            .unwrap();
+        assert_vector(
+            "poseidon([1, 2])",
+            actual,
+            "7853200120776062878684798364095072458815029376092732009249414926327459813530",
+        );
+    }
+
+    #[test]
+    fn test_poseidon5() {
+        let inputs: Vec<U256> = (1..=5u64).map(U256::from).collect();
+        let actual = PoseidonHash::new().hash_array_production(&inputs).unwrap();
+        assert_vector(
+            "poseidon([1, 2, 3, 4, 5])",
+            actual,
+            "6183221330272524995739186171720101788151706631170188140075976616310159254464",
+        );
+    }
+}